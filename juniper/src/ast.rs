@@ -1,3 +1,7 @@
+//! [GraphQL executable documents][0] syntax tree.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Executable-Definitions
+
 use std::{borrow::Cow, fmt, hash::Hash, mem, slice, vec};
 
 use arcstr::ArcStr;
@@ -12,6 +16,14 @@ use crate::{
     value::{DefaultScalarValue, Scalar, ScalarValue, ToScalarValue},
 };
 
+pub use self::{
+    printer::{PrintStyle, print_document},
+    visitor::{Visitor, visit},
+};
+
+mod printer;
+mod visitor;
+
 /// Possible modifiers in a [`Type`] literal.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TypeModifier {
@@ -305,49 +317,96 @@ pub enum InputValue<S = DefaultScalarValue> {
     Object(Vec<(Spanning<String>, Spanning<InputValue<S>>)>),
 }
 
+/// [Variable definition][0] of an [`Operation`] (without its name).
+///
+/// [0]: https://spec.graphql.org/October2021#VariableDefinition
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariableDefinition<'a, S> {
+    /// Description of this variable, if any.
     pub description: Option<Spanning<Cow<'a, str>>>,
+
+    /// [`Type`] of this variable.
     pub var_type: Spanning<Type<&'a str>>,
+
+    /// Default value of this variable, if any.
     pub default_value: Option<Spanning<InputValue<S>>>,
+
+    /// [`Directive`]s applied to this variable, if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
 }
 
+/// [Arguments][0] of a [`Field`] or a [`Directive`], in the order they're specified.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Arguments
 #[derive(Clone, Debug, PartialEq)]
 pub struct Arguments<'a, S> {
+    /// Names and values of these [`Arguments`].
     pub items: Vec<(Spanning<&'a str>, Spanning<InputValue<S>>)>,
 }
 
+/// [Variables definition][0] of an [`Operation`], in the order they're specified.
+///
+/// [0]: https://spec.graphql.org/October2021#VariableDefinitions
 #[derive(Clone, Debug, PartialEq)]
 pub struct VariablesDefinition<'a, S> {
+    /// Names and [`VariableDefinition`]s of the variables.
     pub items: Vec<(Spanning<&'a str>, VariableDefinition<'a, S>)>,
 }
 
 impl<'a, S> VariablesDefinition<'a, S> {
+    /// Returns an iterator over names and [`VariableDefinition`]s of the variables.
     pub fn iter(&self) -> slice::Iter<'_, (Spanning<&'a str>, VariableDefinition<'a, S>)> {
         self.items.iter()
     }
 }
 
+/// [Field][0] selected in a [`Selection`] set.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Fields
 #[derive(Clone, Debug, PartialEq)]
 pub struct Field<'a, S> {
+    /// [Alias][0] of this [`Field`], if any.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Field-Alias
     pub alias: Option<Spanning<&'a str>>,
+
+    /// Name of this [`Field`].
     pub name: Spanning<&'a str>,
+
+    /// [`Arguments`] of this [`Field`], if any.
     pub arguments: Option<Spanning<Arguments<'a, S>>>,
+
+    /// [`Directive`]s applied to this [`Field`], if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+
+    /// [`Selection`] set of this [`Field`], if it has any subfields.
     pub selection_set: Option<Vec<Selection<'a, S>>>,
 }
 
+/// [Fragment spread][0] in a [`Selection`] set.
+///
+/// [0]: https://spec.graphql.org/October2021#FragmentSpread
 #[derive(Clone, Debug, PartialEq)]
 pub struct FragmentSpread<'a, S> {
+    /// Name of the spread [`Fragment`].
     pub name: Spanning<&'a str>,
+
+    /// [`Directive`]s applied to this [`FragmentSpread`], if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
 }
 
+/// [Inline fragment][0] in a [`Selection`] set.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Inline-Fragments
 #[derive(Clone, Debug, PartialEq)]
 pub struct InlineFragment<'a, S> {
+    /// Name of the type this [`InlineFragment`] applies to, if any.
     pub type_condition: Option<Spanning<&'a str>>,
+
+    /// [`Directive`]s applied to this [`InlineFragment`], if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+
+    /// [`Selection`] set of this [`InlineFragment`].
     pub selection_set: Vec<Selection<'a, S>>,
 }
 
@@ -374,9 +433,15 @@ pub enum Selection<'a, S = DefaultScalarValue> {
     InlineFragment(Spanning<InlineFragment<'a, S>>),
 }
 
+/// [Directive][0] applied to an executable definition.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Language.Directives
 #[derive(Clone, Debug, PartialEq)]
 pub struct Directive<'a, S> {
+    /// Name of this [`Directive`] (without `@`).
     pub name: Spanning<&'a str>,
+
+    /// [`Arguments`] of this [`Directive`], if any.
     pub arguments: Option<Spanning<Arguments<'a, S>>>,
 }
 
@@ -399,16 +464,31 @@ pub struct Operation<'a, S> {
     pub selection_set: Vec<Selection<'a, S>>,
 }
 
+/// [Fragment definition][0].
+///
+/// [0]: https://spec.graphql.org/October2021#FragmentDefinition
 #[derive(Clone, Debug, PartialEq)]
 pub struct Fragment<'a, S> {
+    /// Name of this [`Fragment`].
     pub name: Spanning<&'a str>,
+
+    /// Description of this [`Fragment`], if any.
     pub description: Option<Spanning<Cow<'a, str>>>,
+
+    /// Name of the type this [`Fragment`] applies to.
     pub type_condition: Spanning<&'a str>,
+
+    /// [`Directive`]s applied to this [`Fragment`], if any.
     pub directives: Option<Vec<Spanning<Directive<'a, S>>>>,
+
+    /// [`Selection`] set of this [`Fragment`].
     pub selection_set: Vec<Selection<'a, S>>,
 }
 
-#[doc(hidden)]
+/// [Executable definition][0] in a [`Document`].
+///
+/// [0]: https://spec.graphql.org/October2021#ExecutableDefinition
+#[expect(missing_docs, reason = "self-explanatory")]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Definition<'a, S> {
     Operation(Spanning<Operation<'a, S>>),
@@ -425,9 +505,12 @@ impl<'a, S> Definition<'a, S> {
     }
 }
 
-#[doc(hidden)]
+/// [Executable document][0], borrowing the source text it's parsed from.
+///
+/// [0]: https://spec.graphql.org/October2021#ExecutableDocument
 pub type Document<'a, S> = [Definition<'a, S>];
-#[doc(hidden)]
+
+/// Owned [`Document`].
 pub type OwnedDocument<'a, S> = Vec<Definition<'a, S>>;
 
 /// Parsing of an unstructured [`InputValue`] into a Rust data type.
@@ -804,23 +887,37 @@ where
     }
 }
 
-impl<'a, S> Arguments<'a, S> {
-    pub fn into_iter(self) -> vec::IntoIter<(Spanning<&'a str>, Spanning<InputValue<S>>)> {
+impl<'a, S> IntoIterator for Arguments<'a, S> {
+    type Item = (Spanning<&'a str>, Spanning<InputValue<S>>);
+    type IntoIter = vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
         self.items.into_iter()
     }
+}
 
+impl<'a, S> Arguments<'a, S> {
+    /// Returns an iterator over names and values of these [`Arguments`].
     pub fn iter(&self) -> slice::Iter<'_, (Spanning<&'a str>, Spanning<InputValue<S>>)> {
         self.items.iter()
     }
 
+    /// Returns an iterator over names and mutable values of these [`Arguments`].
     pub fn iter_mut(&mut self) -> slice::IterMut<'_, (Spanning<&'a str>, Spanning<InputValue<S>>)> {
         self.items.iter_mut()
     }
 
+    /// Returns the number of these [`Arguments`].
     pub fn len(&self) -> usize {
         self.items.len()
     }
 
+    /// Indicates whether there are no [`Arguments`].
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the value of the argument with the provided name, if any.
     pub fn get(&self, key: &str) -> Option<&Spanning<InputValue<S>>> {
         self.items
             .iter()
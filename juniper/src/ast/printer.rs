@@ -0,0 +1,746 @@
+//! Printing of executable [`Document`]s back into GraphQL source text.
+
+use std::fmt::{self, Write as _};
+
+use crate::{parser::Spanning, value::ScalarValue};
+
+use super::{
+    Arguments, Definition, Directive, Document, Fragment, InputValue, Operation, OperationType,
+    Selection, VariablesDefinition,
+};
+
+/// Style of printing GraphQL source text.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PrintStyle {
+    /// Multiline text indented with 2 spaces, the way GraphQL documents are usually written by
+    /// hand.
+    #[default]
+    Pretty,
+
+    /// Single-line text without insignificant whitespace and commas, except single spaces
+    /// separating selections and definitions.
+    Minified,
+}
+
+/// Prints the provided executable [`Document`] as GraphQL source text in the provided
+/// [`PrintStyle`].
+///
+/// Parsing the printed text results in the same [`Document`] (apart from source positions).
+///
+/// ```rust
+/// # use juniper::{
+/// #     DefaultScalarValue, EmptyMutation, EmptySubscription, PrintStyle, RootNode, graphql_object,
+/// #     parser::parse_document_source, print_document,
+/// # };
+/// #
+/// # struct Query;
+/// #
+/// # #[graphql_object]
+/// # impl Query {
+/// #     fn hero(episode: Option<i32>) -> &'static str {
+/// #         "Luke"
+/// #     }
+/// # }
+/// #
+/// # let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let document =
+///     parse_document_source::<DefaultScalarValue>("query Q{ hero(episode:4) }", &schema.schema)
+///         .unwrap();
+///
+/// assert_eq!(
+///     print_document(&document, PrintStyle::Pretty),
+///     "query Q {\n  hero(episode: 4)\n}\n",
+/// );
+/// assert_eq!(
+///     print_document(&document, PrintStyle::Minified),
+///     "query Q{hero(episode:4)}",
+/// );
+/// ```
+#[must_use]
+pub fn print_document<S: ScalarValue>(document: &Document<'_, S>, style: PrintStyle) -> String {
+    let mut out = String::new();
+    for (i, def) in document.iter().enumerate() {
+        match style {
+            PrintStyle::Pretty => {
+                if i > 0 {
+                    out.push('\n');
+                }
+                writeln!(out, "{def:#}")
+            }
+            PrintStyle::Minified => {
+                if i > 0 {
+                    out.push(' ');
+                }
+                write!(out, "{def}")
+            }
+        }
+        .unwrap_or_else(|e| unreachable!("writing to `String` never fails: {e}"));
+    }
+    out
+}
+
+/// Prints [`PrintStyle::Minified`] GraphQL source text of this [`Definition`], or
+/// [`PrintStyle::Pretty`] one if the alternate flag (`{:#}`) is specified.
+impl<S: ScalarValue> fmt::Display for Definition<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Operation(op) => Printer::new(f).operation(&op.item),
+            Self::Fragment(frag) => Printer::new(f).fragment(&frag.item),
+        }
+    }
+}
+
+/// Prints [`PrintStyle::Minified`] GraphQL source text of this [`Operation`], or
+/// [`PrintStyle::Pretty`] one if the alternate flag (`{:#}`) is specified.
+impl<S: ScalarValue> fmt::Display for Operation<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Printer::new(f).operation(self)
+    }
+}
+
+/// Prints [`PrintStyle::Minified`] GraphQL source text of this [`Fragment`], or
+/// [`PrintStyle::Pretty`] one if the alternate flag (`{:#}`) is specified.
+impl<S: ScalarValue> fmt::Display for Fragment<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Printer::new(f).fragment(self)
+    }
+}
+
+/// Prints [`PrintStyle::Minified`] GraphQL source text of this [`Selection`], or
+/// [`PrintStyle::Pretty`] one if the alternate flag (`{:#}`) is specified.
+impl<S: ScalarValue> fmt::Display for Selection<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Printer::new(f).selection(self)
+    }
+}
+
+/// Printer of GraphQL source text into a [`fmt::Formatter`], in the [`PrintStyle::Pretty`] if its
+/// alternate flag is specified, or in the [`PrintStyle::Minified`] otherwise.
+struct Printer<'a, 'f> {
+    /// [`fmt::Formatter`] to print into.
+    f: &'a mut fmt::Formatter<'f>,
+
+    /// Current indentation level (in the [`PrintStyle::Pretty`] only).
+    indent: usize,
+}
+
+impl<'a, 'f> Printer<'a, 'f> {
+    /// Creates a new [`Printer`] into the provided [`fmt::Formatter`].
+    fn new(f: &'a mut fmt::Formatter<'f>) -> Self {
+        Self { f, indent: 0 }
+    }
+
+    /// Indicates whether this [`Printer`] prints in the [`PrintStyle::Pretty`].
+    fn is_pretty(&self) -> bool {
+        self.f.alternate()
+    }
+
+    /// Prints the provided `pretty` text in the [`PrintStyle::Pretty`], or the provided `minified`
+    /// one otherwise.
+    fn either(&mut self, pretty: &str, minified: &str) -> fmt::Result {
+        let s = if self.is_pretty() { pretty } else { minified };
+        self.f.write_str(s)
+    }
+
+    /// Prints a new line at the current indentation level in the [`PrintStyle::Pretty`], or the
+    /// provided `minified` text otherwise.
+    fn newline_or(&mut self, minified: &str) -> fmt::Result {
+        if self.is_pretty() {
+            write!(self.f, "\n{:1$}", "", self.indent * 2)
+        } else {
+            self.f.write_str(minified)
+        }
+    }
+
+    fn operation<S: ScalarValue>(&mut self, op: &Operation<'_, S>) -> fmt::Result {
+        let vars = op
+            .variables_definition
+            .as_ref()
+            .filter(|vars| !vars.item.items.is_empty());
+        let is_shorthand = op.operation_type == OperationType::Query
+            && op.description.is_none()
+            && op.name.is_none()
+            && vars.is_none()
+            && op.directives.as_ref().is_none_or(Vec::is_empty);
+
+        if !is_shorthand {
+            self.description(op.description.as_ref())?;
+            self.f.write_str(match op.operation_type {
+                OperationType::Query => "query",
+                OperationType::Mutation => "mutation",
+                OperationType::Subscription => "subscription",
+            })?;
+            if let Some(name) = &op.name {
+                write!(self.f, " {}", name.item)?;
+            }
+            if let Some(vars) = vars {
+                self.variables(&vars.item)?;
+            }
+            self.directives(op.directives.as_deref())?;
+            self.either(" ", "")?;
+        }
+        self.selection_set(&op.selection_set)
+    }
+
+    fn fragment<S: ScalarValue>(&mut self, frag: &Fragment<'_, S>) -> fmt::Result {
+        self.description(frag.description.as_ref())?;
+        write!(
+            self.f,
+            "fragment {} on {}",
+            frag.name.item, frag.type_condition.item,
+        )?;
+        self.directives(frag.directives.as_deref())?;
+        self.either(" ", "")?;
+        self.selection_set(&frag.selection_set)
+    }
+
+    fn description(&mut self, description: Option<&Spanning<impl AsRef<str>>>) -> fmt::Result {
+        if let Some(description) = description {
+            self.string(description.item.as_ref())?;
+            self.newline_or("")?;
+        }
+        Ok(())
+    }
+
+    fn variables<S: ScalarValue>(&mut self, vars: &VariablesDefinition<'_, S>) -> fmt::Result {
+        self.f.write_char('(')?;
+        for (i, (name, var)) in vars.items.iter().enumerate() {
+            if i > 0 {
+                self.either(", ", ",")?;
+            }
+            if let Some(description) = &var.description {
+                self.string(&description.item)?;
+                self.either(" ", "")?;
+            }
+            write!(self.f, "${}", name.item)?;
+            self.either(": ", ":")?;
+            write!(self.f, "{}", var.var_type.item)?;
+            if let Some(default) = &var.default_value {
+                self.either(" = ", "=")?;
+                self.value(&default.item)?;
+            }
+            self.directives(var.directives.as_deref())?;
+        }
+        self.f.write_char(')')
+    }
+
+    fn directives<S: ScalarValue>(
+        &mut self,
+        directives: Option<&[Spanning<Directive<'_, S>>]>,
+    ) -> fmt::Result {
+        for dir in directives.unwrap_or_default() {
+            self.either(" @", "@")?;
+            self.f.write_str(dir.item.name.item)?;
+            self.arguments(dir.item.arguments.as_ref())?;
+        }
+        Ok(())
+    }
+
+    fn arguments<S: ScalarValue>(
+        &mut self,
+        arguments: Option<&Spanning<Arguments<'_, S>>>,
+    ) -> fmt::Result {
+        let Some(args) = arguments.filter(|args| !args.item.items.is_empty()) else {
+            return Ok(());
+        };
+        self.f.write_char('(')?;
+        for (i, (name, value)) in args.item.items.iter().enumerate() {
+            if i > 0 {
+                self.either(", ", ",")?;
+            }
+            self.f.write_str(name.item)?;
+            self.either(": ", ":")?;
+            self.value(&value.item)?;
+        }
+        self.f.write_char(')')
+    }
+
+    fn selection_set<S: ScalarValue>(&mut self, selection_set: &[Selection<'_, S>]) -> fmt::Result {
+        self.f.write_char('{')?;
+        self.indent += 1;
+        for (i, selection) in selection_set.iter().enumerate() {
+            self.newline_or(if i > 0 { " " } else { "" })?;
+            self.selection(selection)?;
+        }
+        self.indent -= 1;
+        if !selection_set.is_empty() {
+            self.newline_or("")?;
+        }
+        self.f.write_char('}')
+    }
+
+    fn selection<S: ScalarValue>(&mut self, selection: &Selection<'_, S>) -> fmt::Result {
+        match selection {
+            Selection::Field(field) => {
+                let field = &field.item;
+                if let Some(alias) = &field.alias {
+                    self.f.write_str(alias.item)?;
+                    self.either(": ", ":")?;
+                }
+                self.f.write_str(field.name.item)?;
+                self.arguments(field.arguments.as_ref())?;
+                self.directives(field.directives.as_deref())?;
+                if let Some(selection_set) = &field.selection_set {
+                    self.either(" ", "")?;
+                    self.selection_set(selection_set)?;
+                }
+                Ok(())
+            }
+            Selection::FragmentSpread(spread) => {
+                write!(self.f, "...{}", spread.item.name.item)?;
+                self.directives(spread.item.directives.as_deref())
+            }
+            Selection::InlineFragment(frag) => {
+                let frag = &frag.item;
+                self.f.write_str("...")?;
+                if let Some(type_condition) = &frag.type_condition {
+                    self.either(" ", "")?;
+                    write!(self.f, "on {}", type_condition.item)?;
+                }
+                self.directives(frag.directives.as_deref())?;
+                self.either(" ", "")?;
+                self.selection_set(&frag.selection_set)
+            }
+        }
+    }
+
+    fn value<S: ScalarValue>(&mut self, value: &InputValue<S>) -> fmt::Result {
+        match value {
+            InputValue::Null => self.f.write_str("null"),
+            InputValue::Scalar(s) => self.scalar(s),
+            InputValue::Enum(v) => self.f.write_str(v),
+            InputValue::Variable(v) => write!(self.f, "${v}"),
+            InputValue::List(items) => {
+                self.f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        self.either(", ", ",")?;
+                    }
+                    self.value(&item.item)?;
+                }
+                self.f.write_char(']')
+            }
+            InputValue::Object(fields) => {
+                self.f.write_char('{')?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        self.either(", ", ",")?;
+                    }
+                    self.f.write_str(&name.item)?;
+                    self.either(": ", ":")?;
+                    self.value(&value.item)?;
+                }
+                self.f.write_char('}')
+            }
+        }
+    }
+
+    /// Prints the provided [`ScalarValue`] as a literal parsed back into the same value.
+    ///
+    /// [`f64`] values are printed in their [`fmt::Debug`] representation, so are never confused with
+    /// integers.
+    fn scalar<S: ScalarValue>(&mut self, scalar: &S) -> fmt::Result {
+        if let Some(s) = scalar.try_as_str() {
+            self.string(s)
+        } else if let Some(b) = scalar.try_to_bool() {
+            write!(self.f, "{b}")
+        } else if let Some(i) = scalar.try_to_int() {
+            write!(self.f, "{i}")
+        } else if let Some(f) = scalar.try_to_float() {
+            write!(self.f, "{f:?}")
+        } else {
+            write!(self.f, "{scalar}")
+        }
+    }
+
+    /// Prints the provided `s`tring as a quoted [String Value][0], escaping the characters not
+    /// allowed to appear in it literally.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-String-Value
+    fn string(&mut self, s: &str) -> fmt::Result {
+        self.f.write_char('"')?;
+        for c in s.chars() {
+            match c {
+                '"' => self.f.write_str(r#"\""#),
+                '\\' => self.f.write_str(r"\\"),
+                '\u{8}' => self.f.write_str(r"\b"),
+                '\u{c}' => self.f.write_str(r"\f"),
+                '\n' => self.f.write_str(r"\n"),
+                '\r' => self.f.write_str(r"\r"),
+                '\t' => self.f.write_str(r"\t"),
+                c if c.is_control() => write!(self.f, r"\u{:04X}", u32::from(c)),
+                c => self.f.write_char(c),
+            }?;
+        }
+        self.f.write_char('"')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use proptest::{collection::vec, option, prelude::*, sample::select};
+
+    use crate::{
+        ast::{
+            Arguments, Definition, Directive, Field, Fragment, FragmentSpread, InlineFragment,
+            InputValue, Operation, OperationType, OwnedDocument, Selection, Type,
+            VariableDefinition, VariablesDefinition,
+        },
+        parser::{Spanning, parse_document_source},
+        schema::model::SchemaType,
+        validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
+        value::DefaultScalarValue,
+    };
+
+    use super::{PrintStyle, print_document};
+
+    fn parse(s: &str) -> OwnedDocument<'_, DefaultScalarValue> {
+        parse_document_source(
+            s,
+            &SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &()),
+        )
+        .unwrap_or_else(|e| panic!("parse error on input {s:#?}:\n{e}"))
+    }
+
+    const SOURCE: &str = r#"
+        "Fetches \"heroes\"."
+        query Heroes($episode: [Episode!] = [NEW_HOPE], "Limit." $first: Int! @dir(x: null)) @dir {
+            hero(episode: $episode, filter: {name: "R2\n", height: 1.0}) @include(if: true) {
+                id, heroName: name
+                ...Friends @dir(y: [1, -2.5e-3])
+                ... on Droid { primaryFunction }
+                ... @skip(if: false) { appearsIn }
+            }
+        }
+
+        { hero { id } }
+
+        fragment Friends on Character { friends(first: $first) { name } }
+    "#;
+
+    #[test]
+    fn pretty() {
+        assert_eq!(
+            print_document(&parse(SOURCE), PrintStyle::Pretty),
+            r#""Fetches \"heroes\"."
+query Heroes($episode: [Episode!] = [NEW_HOPE], "Limit." $first: Int! @dir(x: null)) @dir {
+  hero(episode: $episode, filter: {name: "R2\n", height: 1.0}) @include(if: true) {
+    id
+    heroName: name
+    ...Friends @dir(y: [1, -0.0025])
+    ... on Droid {
+      primaryFunction
+    }
+    ... @skip(if: false) {
+      appearsIn
+    }
+  }
+}
+
+{
+  hero {
+    id
+  }
+}
+
+fragment Friends on Character {
+  friends(first: $first) {
+    name
+  }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn minified() {
+        assert_eq!(
+            print_document(&parse(SOURCE), PrintStyle::Minified),
+            r#""Fetches \"heroes\"."query Heroes($episode:[Episode!]=[NEW_HOPE],"Limit."$first:Int!@dir(x:null))@dir{hero(episode:$episode,filter:{name:"R2\n",height:1.0})@include(if:true){id heroName:name ...Friends@dir(y:[1,-0.0025]) ...on Droid{primaryFunction} ...@skip(if:false){appearsIn}}} {hero{id}} fragment Friends on Character{friends(first:$first){name}}"#,
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let doc = parse(r#"{ a(s: "\"\\/\b\f\n\r\t\u0000é😀") }"#);
+
+        assert_eq!(
+            print_document(&doc, PrintStyle::Minified),
+            r#"{a(s:"\"\\/\b\f\n\r\t\u0000é😀")}"#,
+        );
+    }
+
+    #[test]
+    fn displays_nodes() {
+        let doc = parse("query Q { a { b } ...F } fragment F on T { c }");
+        let (Definition::Operation(op), Definition::Fragment(frag)) = (&doc[0], &doc[1]) else {
+            panic!("unexpected definitions: {doc:#?}");
+        };
+
+        assert_eq!(op.item.to_string(), "query Q{a{b} ...F}");
+        assert_eq!(
+            format!("{:#}", op.item),
+            "query Q {\n  a {\n    b\n  }\n  ...F\n}"
+        );
+        assert_eq!(frag.item.to_string(), "fragment F on T{c}");
+        assert_eq!(format!("{:#}", op.item.selection_set[0]), "a {\n  b\n}");
+        assert_eq!(doc[1].to_string(), frag.item.to_string());
+    }
+
+    const NAMES: &[&str] = &[
+        "a", "b", "foo", "barBaz", "_q1", "on", "query", "fragment", "true",
+    ];
+    const ENUM_VALUES: &[&str] = &["A", "b", "ON", "on", "query"];
+    const FRAGMENT_NAMES: &[&str] = &["F", "frag", "_f", "query"];
+    const TYPES: &[&str] = &["Foo", "Bar", "on", "query"];
+    const DIRECTIVES: &[&str] = &["dir", "other", "on"];
+
+    type Value = InputValue<DefaultScalarValue>;
+
+    fn value(is_const: bool) -> impl Strategy<Value = Value> {
+        let leaf = prop_oneof![
+            Just(Value::Null),
+            any::<bool>().prop_map(Value::scalar),
+            any::<i32>().prop_map(Value::scalar),
+            any::<f64>()
+                .prop_filter("finite", |f| f.is_finite())
+                .prop_map(Value::scalar),
+            any::<String>().prop_map(Value::scalar),
+            select(ENUM_VALUES).prop_map(Value::enum_value),
+            select(NAMES).prop_map(move |n| if is_const {
+                Value::enum_value(n)
+            } else {
+                Value::variable(n)
+            }),
+        ];
+        leaf.prop_recursive(3, 16, 4, |inner| {
+            prop_oneof![
+                vec(inner.clone(), 0..4).prop_map(|items| {
+                    Value::List(items.into_iter().map(Spanning::unlocated).collect())
+                }),
+                vec((select(NAMES), inner), 0..4).prop_map(|fields| {
+                    Value::Object(
+                        fields
+                            .into_iter()
+                            .map(|(k, v)| (Spanning::unlocated(k.into()), Spanning::unlocated(v)))
+                            .collect(),
+                    )
+                }),
+            ]
+        })
+    }
+
+    fn arguments(
+        is_const: bool,
+    ) -> impl Strategy<Value = Option<Spanning<Arguments<'static, DefaultScalarValue>>>> {
+        option::of(vec((select(NAMES), value(is_const)), 1..3)).prop_map(|items| {
+            items.map(|items| {
+                Spanning::unlocated(Arguments {
+                    items: items
+                        .into_iter()
+                        .map(|(n, v)| (Spanning::unlocated(n), Spanning::unlocated(v)))
+                        .collect(),
+                })
+            })
+        })
+    }
+
+    fn directives(
+        is_const: bool,
+    ) -> impl Strategy<Value = Option<Vec<Spanning<Directive<'static, DefaultScalarValue>>>>> {
+        option::of(vec((select(DIRECTIVES), arguments(is_const)), 1..3)).prop_map(|dirs| {
+            dirs.map(|dirs| {
+                dirs.into_iter()
+                    .map(|(name, arguments)| {
+                        Spanning::unlocated(Directive {
+                            name: Spanning::unlocated(name),
+                            arguments,
+                        })
+                    })
+                    .collect()
+            })
+        })
+    }
+
+    fn description() -> impl Strategy<Value = Option<Spanning<Cow<'static, str>>>> {
+        option::of(any::<String>().prop_map(|s| Spanning::unlocated(s.into())))
+    }
+
+    fn type_literal() -> impl Strategy<Value = Type<&'static str>> {
+        (select(TYPES), any::<bool>(), vec(any::<bool>(), 0..3)).prop_map(
+            |(name, non_null, lists)| {
+                let mut ty = Type::nullable(name);
+                if non_null {
+                    ty = ty.wrap_non_null();
+                }
+                for non_null in lists {
+                    ty = ty.wrap_list(None);
+                    if non_null {
+                        ty = ty.wrap_non_null();
+                    }
+                }
+                ty
+            },
+        )
+    }
+
+    fn selection() -> impl Strategy<Value = Selection<'static, DefaultScalarValue>> {
+        let leaf = prop_oneof![
+            (
+                option::of(select(NAMES)),
+                select(NAMES),
+                arguments(false),
+                directives(false),
+            )
+                .prop_map(|(alias, name, arguments, directives)| {
+                    Selection::Field(Spanning::unlocated(Field {
+                        alias: alias.map(Spanning::unlocated),
+                        name: Spanning::unlocated(name),
+                        arguments,
+                        directives,
+                        selection_set: None,
+                    }))
+                }),
+            (select(FRAGMENT_NAMES), directives(false)).prop_map(|(name, directives)| {
+                Selection::FragmentSpread(Spanning::unlocated(FragmentSpread {
+                    name: Spanning::unlocated(name),
+                    directives,
+                }))
+            }),
+        ];
+        leaf.prop_recursive(3, 24, 3, |inner| {
+            prop_oneof![
+                (
+                    option::of(select(NAMES)),
+                    select(NAMES),
+                    arguments(false),
+                    directives(false),
+                    vec(inner.clone(), 1..4),
+                )
+                    .prop_map(
+                        |(alias, name, arguments, directives, selection_set)| {
+                            Selection::Field(Spanning::unlocated(Field {
+                                alias: alias.map(Spanning::unlocated),
+                                name: Spanning::unlocated(name),
+                                arguments,
+                                directives,
+                                selection_set: Some(selection_set),
+                            }))
+                        }
+                    ),
+                (
+                    option::of(select(TYPES)),
+                    directives(false),
+                    vec(inner, 1..4),
+                )
+                    .prop_map(|(type_condition, directives, selection_set)| {
+                        Selection::InlineFragment(Spanning::unlocated(InlineFragment {
+                            type_condition: type_condition.map(Spanning::unlocated),
+                            directives,
+                            selection_set,
+                        }))
+                    }),
+            ]
+        })
+    }
+
+    fn variables()
+    -> impl Strategy<Value = Option<Spanning<VariablesDefinition<'static, DefaultScalarValue>>>>
+    {
+        let var = (
+            description(),
+            select(NAMES),
+            type_literal(),
+            option::of(value(true)),
+            directives(true),
+        )
+            .prop_map(|(description, name, ty, default_value, directives)| {
+                (
+                    Spanning::unlocated(name),
+                    VariableDefinition {
+                        description,
+                        var_type: Spanning::unlocated(ty),
+                        default_value: default_value.map(Spanning::unlocated),
+                        directives,
+                    },
+                )
+            });
+        option::of(vec(var, 1..3))
+            .prop_map(|items| items.map(|items| Spanning::unlocated(VariablesDefinition { items })))
+    }
+
+    fn definition() -> impl Strategy<Value = Definition<'static, DefaultScalarValue>> {
+        prop_oneof![
+            (
+                description(),
+                select(
+                    &[
+                        OperationType::Query,
+                        OperationType::Mutation,
+                        OperationType::Subscription,
+                    ][..]
+                ),
+                option::of(select(NAMES)),
+                variables(),
+                directives(false),
+                vec(selection(), 1..4),
+            )
+                .prop_map(
+                    |(
+                        description,
+                        operation_type,
+                        name,
+                        variables_definition,
+                        directives,
+                        selection_set,
+                    )| {
+                        Definition::Operation(Spanning::unlocated(Operation {
+                            description,
+                            operation_type,
+                            name: name.map(Spanning::unlocated),
+                            variables_definition,
+                            directives,
+                            selection_set,
+                        }))
+                    },
+                ),
+            (
+                description(),
+                select(FRAGMENT_NAMES),
+                select(TYPES),
+                directives(false),
+                vec(selection(), 1..4),
+            )
+                .prop_map(
+                    |(description, name, type_condition, directives, selection_set)| {
+                        Definition::Fragment(Spanning::unlocated(Fragment {
+                            name: Spanning::unlocated(name),
+                            description,
+                            type_condition: Spanning::unlocated(type_condition),
+                            directives,
+                            selection_set,
+                        }))
+                    }
+                ),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips(document in vec(definition(), 1..4)) {
+            for style in [PrintStyle::Pretty, PrintStyle::Minified] {
+                let printed = print_document(&document, style);
+                let parsed = parse(&printed);
+
+                for style in [PrintStyle::Pretty, PrintStyle::Minified] {
+                    prop_assert_eq!(
+                        print_document(&parsed, style),
+                        print_document(&document, style),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! Read-only traversal of executable [`Document`]s.
+
+use crate::parser::Spanning;
+
+use super::{
+    Arguments, Definition, Directive, Document, Field, Fragment, FragmentSpread, InlineFragment,
+    InputValue, Operation, Selection, VariableDefinition,
+};
+
+/// Read-only visitor of an executable [`Document`], being [`visit`]ed in depth-first order.
+///
+/// Every `enter_*` method is called before visiting the children of the node, while the matching
+/// `exit_*` one is called after that. All the methods do nothing by default, so only the relevant
+/// ones should be implemented.
+///
+/// Unlike the one used for validation, this [`Visitor`] doesn't require any schema, so may be used
+/// along with the [`parse_document_source_without_schema()`] function.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     DefaultScalarValue, ParseLimits,
+/// #     ast::{Field, Visitor, visit},
+/// #     parser::{Spanning, parse_document_source_without_schema},
+/// # };
+/// #
+/// #[derive(Default)]
+/// struct FieldNames<'a>(Vec<&'a str>);
+///
+/// impl<'a, S> Visitor<'a, S> for FieldNames<'a> {
+///     fn enter_field(&mut self, field: &'a Spanning<Field<'a, S>>) {
+///         self.0.push(field.item.name.item);
+///     }
+/// }
+///
+/// let document = parse_document_source_without_schema::<DefaultScalarValue>(
+///     "{ hero { name ...Friends } } fragment Friends on Character { friends { name } }",
+///     ParseLimits::default(),
+/// )
+/// .unwrap();
+///
+/// let mut names = FieldNames::default();
+/// visit(&mut names, &document);
+///
+/// assert_eq!(names.0, ["hero", "name", "friends", "name"]);
+/// ```
+///
+/// [`parse_document_source_without_schema()`]: crate::parser::parse_document_source_without_schema
+pub trait Visitor<'a, S> {
+    /// Enters the whole [`Document`].
+    fn enter_document(&mut self, _: &'a Document<'a, S>) {}
+
+    /// Exits the whole [`Document`].
+    fn exit_document(&mut self, _: &'a Document<'a, S>) {}
+
+    /// Enters an [`Operation`] definition.
+    fn enter_operation_definition(&mut self, _: &'a Spanning<Operation<'a, S>>) {}
+
+    /// Exits an [`Operation`] definition.
+    fn exit_operation_definition(&mut self, _: &'a Spanning<Operation<'a, S>>) {}
+
+    /// Enters a [`Fragment`] definition.
+    fn enter_fragment_definition(&mut self, _: &'a Spanning<Fragment<'a, S>>) {}
+
+    /// Exits a [`Fragment`] definition.
+    fn exit_fragment_definition(&mut self, _: &'a Spanning<Fragment<'a, S>>) {}
+
+    /// Enters a [`VariableDefinition`] along with its name.
+    fn enter_variable_definition(&mut self, _: &'a (Spanning<&'a str>, VariableDefinition<'a, S>)) {
+    }
+
+    /// Exits a [`VariableDefinition`] along with its name.
+    fn exit_variable_definition(&mut self, _: &'a (Spanning<&'a str>, VariableDefinition<'a, S>)) {}
+
+    /// Enters a [`Directive`].
+    fn enter_directive(&mut self, _: &'a Spanning<Directive<'a, S>>) {}
+
+    /// Exits a [`Directive`].
+    fn exit_directive(&mut self, _: &'a Spanning<Directive<'a, S>>) {}
+
+    /// Enters an argument (of a [`Field`] or a [`Directive`]) along with its name.
+    fn enter_argument(&mut self, _: &'a (Spanning<&'a str>, Spanning<InputValue<S>>)) {}
+
+    /// Exits an argument (of a [`Field`] or a [`Directive`]) along with its name.
+    fn exit_argument(&mut self, _: &'a (Spanning<&'a str>, Spanning<InputValue<S>>)) {}
+
+    /// Enters a [`Selection`] set.
+    fn enter_selection_set(&mut self, _: &'a [Selection<'a, S>]) {}
+
+    /// Exits a [`Selection`] set.
+    fn exit_selection_set(&mut self, _: &'a [Selection<'a, S>]) {}
+
+    /// Enters a [`Field`].
+    fn enter_field(&mut self, _: &'a Spanning<Field<'a, S>>) {}
+
+    /// Exits a [`Field`].
+    fn exit_field(&mut self, _: &'a Spanning<Field<'a, S>>) {}
+
+    /// Enters a [`FragmentSpread`].
+    fn enter_fragment_spread(&mut self, _: &'a Spanning<FragmentSpread<'a, S>>) {}
+
+    /// Exits a [`FragmentSpread`].
+    fn exit_fragment_spread(&mut self, _: &'a Spanning<FragmentSpread<'a, S>>) {}
+
+    /// Enters an [`InlineFragment`].
+    fn enter_inline_fragment(&mut self, _: &'a Spanning<InlineFragment<'a, S>>) {}
+
+    /// Exits an [`InlineFragment`].
+    fn exit_inline_fragment(&mut self, _: &'a Spanning<InlineFragment<'a, S>>) {}
+
+    /// Enters an [`InputValue`] (an argument value, a variable default value, or a list item).
+    fn enter_input_value(&mut self, _: &'a Spanning<InputValue<S>>) {}
+
+    /// Exits an [`InputValue`] (an argument value, a variable default value, or a list item).
+    fn exit_input_value(&mut self, _: &'a Spanning<InputValue<S>>) {}
+
+    /// Enters a field of an [`InputValue::Object`] along with its name.
+    fn enter_object_field(&mut self, _: &'a (Spanning<String>, Spanning<InputValue<S>>)) {}
+
+    /// Exits a field of an [`InputValue::Object`] along with its name.
+    fn exit_object_field(&mut self, _: &'a (Spanning<String>, Spanning<InputValue<S>>)) {}
+}
+
+/// Visits the provided executable [`Document`] with the provided [`Visitor`] in depth-first
+/// order.
+///
+/// [`FragmentSpread`]s are not followed into the [`Fragment`]s they refer to, as those are visited
+/// on their own as [`Document`] definitions.
+pub fn visit<'a, S, V>(v: &mut V, d: &'a Document<'a, S>)
+where
+    V: Visitor<'a, S> + ?Sized,
+{
+    v.enter_document(d);
+    for def in d {
+        match def {
+            Definition::Operation(op) => {
+                v.enter_operation_definition(op);
+                if let Some(vars) = &op.item.variables_definition {
+                    for var in &vars.item.items {
+                        v.enter_variable_definition(var);
+                        if let Some(default) = &var.1.default_value {
+                            visit_input_value(v, default);
+                        }
+                        visit_directives(v, var.1.directives.as_deref());
+                        v.exit_variable_definition(var);
+                    }
+                }
+                visit_directives(v, op.item.directives.as_deref());
+                visit_selection_set(v, &op.item.selection_set);
+                v.exit_operation_definition(op);
+            }
+            Definition::Fragment(f) => {
+                v.enter_fragment_definition(f);
+                visit_directives(v, f.item.directives.as_deref());
+                visit_selection_set(v, &f.item.selection_set);
+                v.exit_fragment_definition(f);
+            }
+        }
+    }
+    v.exit_document(d);
+}
+
+fn visit_directives<'a, S, V>(v: &mut V, directives: Option<&'a [Spanning<Directive<'a, S>>]>)
+where
+    V: Visitor<'a, S> + ?Sized,
+{
+    for directive in directives.unwrap_or_default() {
+        v.enter_directive(directive);
+        visit_arguments(v, directive.item.arguments.as_ref());
+        v.exit_directive(directive);
+    }
+}
+
+fn visit_arguments<'a, S, V>(v: &mut V, arguments: Option<&'a Spanning<Arguments<'a, S>>>)
+where
+    V: Visitor<'a, S> + ?Sized,
+{
+    if let Some(arguments) = arguments {
+        for argument in &arguments.item.items {
+            v.enter_argument(argument);
+            visit_input_value(v, &argument.1);
+            v.exit_argument(argument);
+        }
+    }
+}
+
+fn visit_selection_set<'a, S, V>(v: &mut V, selection_set: &'a [Selection<'a, S>])
+where
+    V: Visitor<'a, S> + ?Sized,
+{
+    v.enter_selection_set(selection_set);
+    for selection in selection_set {
+        match selection {
+            Selection::Field(field) => {
+                v.enter_field(field);
+                visit_arguments(v, field.item.arguments.as_ref());
+                visit_directives(v, field.item.directives.as_deref());
+                if let Some(selection_set) = &field.item.selection_set {
+                    visit_selection_set(v, selection_set);
+                }
+                v.exit_field(field);
+            }
+            Selection::FragmentSpread(spread) => {
+                v.enter_fragment_spread(spread);
+                visit_directives(v, spread.item.directives.as_deref());
+                v.exit_fragment_spread(spread);
+            }
+            Selection::InlineFragment(fragment) => {
+                v.enter_inline_fragment(fragment);
+                visit_directives(v, fragment.item.directives.as_deref());
+                visit_selection_set(v, &fragment.item.selection_set);
+                v.exit_inline_fragment(fragment);
+            }
+        }
+    }
+    v.exit_selection_set(selection_set);
+}
+
+fn visit_input_value<'a, S, V>(v: &mut V, input_value: &'a Spanning<InputValue<S>>)
+where
+    V: Visitor<'a, S> + ?Sized,
+{
+    v.enter_input_value(input_value);
+    match &input_value.item {
+        InputValue::List(items) => {
+            for item in items {
+                visit_input_value(v, item);
+            }
+        }
+        InputValue::Object(fields) => {
+            for field in fields {
+                v.enter_object_field(field);
+                visit_input_value(v, &field.1);
+                v.exit_object_field(field);
+            }
+        }
+        InputValue::Null
+        | InputValue::Scalar(_)
+        | InputValue::Enum(_)
+        | InputValue::Variable(_) => {}
+    }
+    v.exit_input_value(input_value);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ast::{
+            Directive, Document, Field, Fragment, FragmentSpread, InlineFragment, InputValue,
+            Operation, VariableDefinition,
+        },
+        parser::{ParseLimits, Spanning, parse_document_source_without_schema},
+        value::DefaultScalarValue,
+    };
+
+    use super::{Visitor, visit};
+
+    #[derive(Default)]
+    struct Trace(Vec<String>);
+
+    impl<'a> Visitor<'a, DefaultScalarValue> for Trace {
+        fn enter_document(&mut self, _: &'a Document<'a, DefaultScalarValue>) {
+            self.0.push("enter document".into());
+        }
+        fn exit_document(&mut self, _: &'a Document<'a, DefaultScalarValue>) {
+            self.0.push("exit document".into());
+        }
+        fn enter_operation_definition(
+            &mut self,
+            op: &'a Spanning<Operation<'a, DefaultScalarValue>>,
+        ) {
+            let name = op.item.name.as_ref().map_or("", |n| n.item);
+            self.0.push(format!("enter operation {name}"));
+        }
+        fn exit_operation_definition(
+            &mut self,
+            _: &'a Spanning<Operation<'a, DefaultScalarValue>>,
+        ) {
+            self.0.push("exit operation".into());
+        }
+        fn enter_fragment_definition(&mut self, f: &'a Spanning<Fragment<'a, DefaultScalarValue>>) {
+            self.0.push(format!("enter fragment {}", f.item.name.item));
+        }
+        fn exit_fragment_definition(&mut self, _: &'a Spanning<Fragment<'a, DefaultScalarValue>>) {
+            self.0.push("exit fragment".into());
+        }
+        fn enter_variable_definition(
+            &mut self,
+            (name, _): &'a (
+                Spanning<&'a str>,
+                VariableDefinition<'a, DefaultScalarValue>,
+            ),
+        ) {
+            self.0.push(format!("enter variable {}", name.item));
+        }
+        fn exit_variable_definition(
+            &mut self,
+            _: &'a (
+                Spanning<&'a str>,
+                VariableDefinition<'a, DefaultScalarValue>,
+            ),
+        ) {
+            self.0.push("exit variable".into());
+        }
+        fn enter_directive(&mut self, d: &'a Spanning<Directive<'a, DefaultScalarValue>>) {
+            self.0.push(format!("enter directive {}", d.item.name.item));
+        }
+        fn exit_directive(&mut self, _: &'a Spanning<Directive<'a, DefaultScalarValue>>) {
+            self.0.push("exit directive".into());
+        }
+        fn enter_argument(
+            &mut self,
+            (name, _): &'a (Spanning<&'a str>, Spanning<InputValue<DefaultScalarValue>>),
+        ) {
+            self.0.push(format!("enter argument {}", name.item));
+        }
+        fn exit_argument(
+            &mut self,
+            _: &'a (Spanning<&'a str>, Spanning<InputValue<DefaultScalarValue>>),
+        ) {
+            self.0.push("exit argument".into());
+        }
+        fn enter_field(&mut self, f: &'a Spanning<Field<'a, DefaultScalarValue>>) {
+            self.0.push(format!("enter field {}", f.item.name.item));
+        }
+        fn exit_field(&mut self, _: &'a Spanning<Field<'a, DefaultScalarValue>>) {
+            self.0.push("exit field".into());
+        }
+        fn enter_fragment_spread(
+            &mut self,
+            s: &'a Spanning<FragmentSpread<'a, DefaultScalarValue>>,
+        ) {
+            self.0.push(format!("enter spread {}", s.item.name.item));
+        }
+        fn exit_fragment_spread(
+            &mut self,
+            _: &'a Spanning<FragmentSpread<'a, DefaultScalarValue>>,
+        ) {
+            self.0.push("exit spread".into());
+        }
+        fn enter_inline_fragment(
+            &mut self,
+            f: &'a Spanning<InlineFragment<'a, DefaultScalarValue>>,
+        ) {
+            let on = f.item.type_condition.as_ref().map_or("", |t| t.item);
+            self.0.push(format!("enter inline fragment {on}"));
+        }
+        fn exit_inline_fragment(
+            &mut self,
+            _: &'a Spanning<InlineFragment<'a, DefaultScalarValue>>,
+        ) {
+            self.0.push("exit inline fragment".into());
+        }
+        fn enter_input_value(&mut self, v: &'a Spanning<InputValue<DefaultScalarValue>>) {
+            self.0.push(format!("enter value {}", v.item));
+        }
+        fn exit_input_value(&mut self, _: &'a Spanning<InputValue<DefaultScalarValue>>) {
+            self.0.push("exit value".into());
+        }
+        fn enter_object_field(
+            &mut self,
+            (name, _): &'a (Spanning<String>, Spanning<InputValue<DefaultScalarValue>>),
+        ) {
+            self.0.push(format!("enter object field {}", name.item));
+        }
+        fn exit_object_field(
+            &mut self,
+            _: &'a (Spanning<String>, Spanning<InputValue<DefaultScalarValue>>),
+        ) {
+            self.0.push("exit object field".into());
+        }
+    }
+
+    #[test]
+    fn visits_in_depth_first_order() {
+        let document = parse_document_source_without_schema(
+            r#"
+                query Q($id: ID = "1" @dir) @dir(arg: [1, {x: $id}]) {
+                    node(id: $id) { ...F ... on Droid { name } }
+                }
+                fragment F on Node { id }
+            "#,
+            ParseLimits::default(),
+        )
+        .unwrap();
+
+        let mut trace = Trace::default();
+        visit(&mut trace, &document);
+
+        assert_eq!(
+            trace.0,
+            [
+                "enter document",
+                "enter operation Q",
+                "enter variable id",
+                "enter value \"1\"",
+                "exit value",
+                "enter directive dir",
+                "exit directive",
+                "exit variable",
+                "enter directive dir",
+                "enter argument arg",
+                "enter value [1, {x: $id}]",
+                "enter value 1",
+                "exit value",
+                "enter value {x: $id}",
+                "enter object field x",
+                "enter value $id",
+                "exit value",
+                "exit object field",
+                "exit value",
+                "exit value",
+                "exit argument",
+                "exit directive",
+                "enter field node",
+                "enter argument id",
+                "enter value $id",
+                "exit value",
+                "exit argument",
+                "enter spread F",
+                "exit spread",
+                "enter inline fragment Droid",
+                "enter field name",
+                "exit field",
+                "exit inline fragment",
+                "exit field",
+                "exit operation",
+                "enter fragment F",
+                "enter field id",
+                "exit field",
+                "exit fragment",
+                "exit document",
+            ],
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Helpers for exporting a [GraphQL schema][0] into files from a build script or a test, so tools
+//! (like code generators) may consume it without a running server.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Schema
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use derive_more::with_trait::{Display, Error, From};
+use itertools::Itertools as _;
+
+use crate::{GraphQLError, GraphQLType, IntrospectionFormat, RootNode, ScalarValue, introspect};
+
+/// Snapshot of a [GraphQL schema][0], represented both in [SDL (schema definition language)][1]
+/// and as an [introspection] result in [JSON].
+///
+/// Both representations are stable: they don't change unless the [GraphQL schema][0] does.
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{EmptyMutation, EmptySubscription, RootNode, build::SchemaSnapshot, graphql_object};
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn hello() -> &'static str {
+///         "world"
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let snapshot = SchemaSnapshot::new(&schema, &()).unwrap();
+///
+/// # let dir = std::env::temp_dir().join("juniper_build_doc_example");
+/// # std::fs::create_dir_all(&dir).unwrap();
+/// // Update the snapshot files...
+/// snapshot.write_sdl(dir.join("schema.graphql")).unwrap();
+/// snapshot.write_introspection(dir.join("schema.json")).unwrap();
+///
+/// // ...or fail if the committed ones are stale.
+/// snapshot.check_sdl(dir.join("schema.graphql")).unwrap();
+/// snapshot.check_introspection(dir.join("schema.json")).unwrap();
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+///
+/// [introspection]: https://spec.graphql.org/October2021#sec-Introspection
+/// [JSON]: https://www.json.org
+/// [0]: https://spec.graphql.org/October2021#sec-Schema
+/// [1]: https://graphql.org/learn/schema#type-language
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaSnapshot {
+    /// [SDL (schema definition language)][0] representation of the schema.
+    ///
+    /// [0]: https://graphql.org/learn/schema#type-language
+    sdl: String,
+
+    /// [JSON] representation of the schema [introspection] result.
+    ///
+    /// [introspection]: https://spec.graphql.org/October2021#sec-Introspection
+    /// [JSON]: https://www.json.org
+    introspection: String,
+}
+
+impl SchemaSnapshot {
+    /// Takes a [`SchemaSnapshot`] of the provided [`RootNode`], running the
+    /// [`IntrospectionFormat::All`] introspection with the provided `context`.
+    ///
+    /// # Errors
+    ///
+    /// If the introspection fails.
+    pub fn new<S, QueryT, MutationT, SubscriptionT>(
+        root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+        context: &QueryT::Context,
+    ) -> Result<Self, SnapshotError>
+    where
+        S: ScalarValue,
+        QueryT: GraphQLType<S>,
+        MutationT: GraphQLType<S, Context = QueryT::Context>,
+        SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
+    {
+        let (res, errs) = introspect(root_node, context, IntrospectionFormat::All)?;
+        if !errs.is_empty() {
+            return Err(SnapshotError::Execution(
+                errs.iter().map(|e| e.error().message()).join("\n"),
+            ));
+        }
+        let mut introspection = serde_json::to_string_pretty(&res)
+            .map_err(|e| SnapshotError::Execution(e.to_string()))?;
+        introspection.push('\n');
+
+        Ok(Self {
+            sdl: root_node.as_sdl(),
+            introspection,
+        })
+    }
+
+    /// Returns the [SDL (schema definition language)][0] representation of this
+    /// [`SchemaSnapshot`].
+    ///
+    /// [0]: https://graphql.org/learn/schema#type-language
+    #[must_use]
+    pub fn sdl(&self) -> &str {
+        &self.sdl
+    }
+
+    /// Returns the [JSON] representation of the [introspection] result of this
+    /// [`SchemaSnapshot`].
+    ///
+    /// [introspection]: https://spec.graphql.org/October2021#sec-Introspection
+    /// [JSON]: https://www.json.org
+    #[must_use]
+    pub fn introspection(&self) -> &str {
+        &self.introspection
+    }
+
+    /// Writes the [`SchemaSnapshot::sdl()`] into the file at the provided `path`.
+    ///
+    /// The file is left untouched if it's up-to-date already, so doesn't trigger rebuilds of the
+    /// things depending on it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or written.
+    pub fn write_sdl(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        write(path.as_ref(), &self.sdl)
+    }
+
+    /// Writes the [`SchemaSnapshot::introspection()`] into the file at the provided `path`.
+    ///
+    /// The file is left untouched if it's up-to-date already, so doesn't trigger rebuilds of the
+    /// things depending on it.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or written.
+    pub fn write_introspection(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        write(path.as_ref(), &self.introspection)
+    }
+
+    /// Checks whether the file at the provided `path` contains the up-to-date
+    /// [`SchemaSnapshot::sdl()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`SnapshotError::Stale`] if the file is missing or differs.
+    /// - [`SnapshotError::Io`] if the file cannot be read.
+    pub fn check_sdl(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        check(path.as_ref(), &self.sdl)
+    }
+
+    /// Checks whether the file at the provided `path` contains the up-to-date
+    /// [`SchemaSnapshot::introspection()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`SnapshotError::Stale`] if the file is missing or differs.
+    /// - [`SnapshotError::Io`] if the file cannot be read.
+    pub fn check_introspection(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        check(path.as_ref(), &self.introspection)
+    }
+}
+
+/// Possible errors of taking, writing or checking a [`SchemaSnapshot`].
+#[derive(Debug, Display, Error, From)]
+pub enum SnapshotError {
+    /// Introspection query failed to execute.
+    #[display("failed to introspect schema: {_0}")]
+    #[from]
+    Introspection(GraphQLError),
+
+    /// Introspection query executed with errors.
+    #[display("failed to introspect schema: {_0}")]
+    Execution(#[error(not(source))] String),
+
+    /// Snapshot file cannot be read or written.
+    #[display("failed to access `{}` schema snapshot: {source}", path.display())]
+    Io {
+        /// Path to the snapshot file.
+        path: PathBuf,
+
+        /// Underlying [`io::Error`].
+        source: io::Error,
+    },
+
+    /// Snapshot file is missing or doesn't match the schema.
+    #[display("`{}` schema snapshot is stale and should be regenerated", path.display())]
+    Stale {
+        /// Path to the snapshot file.
+        #[error(not(source))]
+        path: PathBuf,
+    },
+}
+
+/// Reads the file at the provided `path`, returning [`None`] if it doesn't exist.
+fn read(path: &Path) -> Result<Option<String>, SnapshotError> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(Some(s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(SnapshotError::Io {
+            path: path.into(),
+            source,
+        }),
+    }
+}
+
+/// Writes the provided `contents` into the file at the provided `path`, unless it contains them
+/// already.
+fn write(path: &Path, contents: &str) -> Result<(), SnapshotError> {
+    if read(path)?.as_deref() == Some(contents) {
+        return Ok(());
+    }
+    fs::write(path, contents).map_err(|source| SnapshotError::Io {
+        path: path.into(),
+        source,
+    })
+}
+
+/// Checks whether the file at the provided `path` contains the provided `contents`.
+fn check(path: &Path, contents: &str) -> Result<(), SnapshotError> {
+    if read(path)?.as_deref() == Some(contents) {
+        Ok(())
+    } else {
+        Err(SnapshotError::Stale { path: path.into() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        schema::model::RootNode,
+        tests::fixtures::starwars::{
+            schema::{Database, Query},
+            schema_language::STATIC_GRAPHQL_SCHEMA_DEFINITION,
+        },
+        types::scalars::{EmptyMutation, EmptySubscription},
+    };
+
+    use super::{SchemaSnapshot, SnapshotError};
+
+    fn snapshot() -> SchemaSnapshot {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        SchemaSnapshot::new(&schema, &Database::new()).unwrap()
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("juniper_build_{name}"));
+        _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn is_stable() {
+        let snapshot = snapshot();
+
+        assert_eq!(snapshot, self::snapshot());
+        #[cfg(not(windows))]
+        assert_eq!(snapshot.sdl(), STATIC_GRAPHQL_SCHEMA_DEFINITION);
+        assert!(snapshot.introspection().contains(r#""name": "Droid""#));
+    }
+
+    #[test]
+    fn writes_and_checks() {
+        let dir = temp_dir("writes_and_checks");
+        let (sdl, json) = (dir.join("schema.graphql"), dir.join("schema.json"));
+        let snapshot = snapshot();
+
+        assert!(matches!(
+            snapshot.check_sdl(&sdl),
+            Err(SnapshotError::Stale { path }) if path == sdl,
+        ));
+
+        snapshot.write_sdl(&sdl).unwrap();
+        snapshot.write_introspection(&json).unwrap();
+
+        assert_eq!(fs::read_to_string(&sdl).unwrap(), snapshot.sdl());
+        assert_eq!(fs::read_to_string(&json).unwrap(), snapshot.introspection());
+        snapshot.check_sdl(&sdl).unwrap();
+        snapshot.check_introspection(&json).unwrap();
+
+        fs::write(&json, "{}").unwrap();
+
+        assert!(matches!(
+            snapshot.check_introspection(&json),
+            Err(SnapshotError::Stale { path }) if path == json,
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{self, AtomicBool},
+    },
+    task::{self, Poll, Waker},
+    time::{Duration, Instant},
+};
+
+use fnv::FnvHashMap;
+
+use crate::BoxFuture;
+
+/// Token signaling cancellation of an operation being executed.
+///
+/// An operation is cancelled once its [deadline][0] passes, or once its execution is dropped
+/// before completion. Resolvers may observe it via [`Executor::is_cancelled()`], or pass a clone
+/// of it (via [`Executor::cancellation_token()`]) into the work they spawn.
+///
+/// > __NOTE__: HTTP client disconnection cancels the operation only if the HTTP server drops the
+/// >           request handling future once the client disconnects (like [`hyper`] does, for
+/// >           example). Servers not detecting the disconnection until writing the response
+/// >           complete the execution as usual.
+///
+/// [`hyper`]: https://docs.rs/hyper
+/// [`Executor::cancellation_token()`]: crate::Executor::cancellation_token
+/// [`Executor::is_cancelled()`]: crate::Executor::is_cancelled
+/// [0]: CancellationToken::deadline
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// Indicator whether this token has been cancelled explicitly, or its deadline has passed.
+    cancelled: AtomicBool,
+
+    /// Deadline after which this token is considered cancelled.
+    deadline: Option<Instant>,
+
+    /// [`Waker`]s of the [`CancellationToken::cancelled()`] futures waiting for cancellation.
+    wakers: Mutex<Wakers>,
+}
+
+/// [`Waker`]s of the [`CancellationToken::cancelled()`] futures, keyed by the futures, so the
+/// dropped ones may remove theirs.
+#[derive(Debug, Default)]
+struct Wakers {
+    /// Key to be assigned to the next registered [`Waker`].
+    next_key: usize,
+
+    /// Registered [`Waker`]s by their keys.
+    registered: FnvHashMap<usize, Waker>,
+}
+
+impl Inner {
+    fn cancel(&self) {
+        self.cancelled.store(true, atomic::Ordering::Release);
+        let wakers = std::mem::take(&mut self.wakers.lock().unwrap().registered);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+}
+
+impl CancellationToken {
+    /// Creates a new [`CancellationToken`] without any deadline.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`CancellationToken`] being considered cancelled once the provided
+    /// `deadline` passes.
+    ///
+    /// > __NOTE__: Nothing is woken once the `deadline` passes, so the
+    /// >           [`CancellationToken::cancelled()`] futures resolve only on the explicit
+    /// >           [`CancellationToken::cancel()`] call or on their next poll afterwards.
+    #[must_use]
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                deadline: Some(deadline),
+                ..Inner::default()
+            }),
+        }
+    }
+
+    /// Returns the deadline of this [`CancellationToken`], if any.
+    #[must_use]
+    pub fn deadline(&self) -> Option<Instant> {
+        self.inner.deadline
+    }
+
+    /// Indicates whether this [`CancellationToken`] has been cancelled, or its deadline has passed.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(atomic::Ordering::Acquire) || self.is_expired()
+    }
+
+    /// Indicates whether the deadline of this [`CancellationToken`] has passed.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.inner.deadline.is_some_and(|d| Instant::now() >= d)
+    }
+
+    /// Cancels this [`CancellationToken`] and all its clones.
+    pub fn cancel(&self) {
+        self.inner.cancel();
+    }
+
+    /// Returns a [`Future`] resolving once this [`CancellationToken`] is cancelled.
+    pub fn cancelled(&self) -> impl Future<Output = ()> + Send + '_ {
+        Cancelled {
+            token: self,
+            key: None,
+        }
+    }
+
+    /// Returns a guard cancelling this [`CancellationToken`] on drop, unless
+    /// [disarmed][DropGuard::disarm].
+    pub(crate) fn drop_guard(&self) -> DropGuard<'_> {
+        DropGuard { token: Some(self) }
+    }
+}
+
+/// [`Future`] returned by the [`CancellationToken::cancelled()`] method.
+struct Cancelled<'t> {
+    token: &'t CancellationToken,
+
+    /// Key of the [`Waker`] registered by this [`Cancelled`] future, if any.
+    key: Option<usize>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        let token = self.token;
+        let mut wakers = token.inner.wakers.lock().unwrap();
+        // Re-check under the lock to not miss a concurrent cancellation.
+        if token.inner.cancelled.load(atomic::Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        match self.key {
+            Some(key) => match wakers.registered.get_mut(&key) {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                Some(waker) => waker.clone_from(cx.waker()),
+                None => _ = wakers.registered.insert(key, cx.waker().clone()),
+            },
+            None => {
+                let key = wakers.next_key;
+                wakers.next_key = key.wrapping_add(1);
+                wakers.registered.insert(key, cx.waker().clone());
+                self.key = Some(key);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token
+                .inner
+                .wakers
+                .lock()
+                .unwrap()
+                .registered
+                .remove(&key);
+        }
+    }
+}
+
+/// Guard cancelling a [`CancellationToken`] on drop.
+pub(crate) struct DropGuard<'t> {
+    token: Option<&'t CancellationToken>,
+}
+
+impl DropGuard<'_> {
+    /// Prevents this [`DropGuard`] from cancelling its [`CancellationToken`].
+    pub(crate) fn disarm(mut self) {
+        self.token = None;
+    }
+}
+
+impl Drop for DropGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(token) = self.token {
+            token.cancel();
+        }
+    }
+}
+
+/// Timeout of an operation execution, along with the timer awaiting it.
+#[derive(Clone)]
+pub struct ExecutionTimeout {
+    /// [`Duration`] after which the execution is cancelled.
+    pub(crate) duration: Duration,
+
+    /// Function returning a [`Future`] resolving once the provided [`Duration`] elapses.
+    pub(crate) sleep: Arc<dyn Fn(Duration) -> BoxFuture<'static, ()> + Send + Sync>,
+}
+
+impl fmt::Debug for ExecutionTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecutionTimeout")
+            .field("duration", &self.duration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ExecutionTimeout {
+    /// Returns a [`Future`] resolving once this [`ExecutionTimeout`] elapses.
+    pub(crate) fn elapsed(&self) -> BoxFuture<'static, ()> {
+        (self.sleep)(self.duration)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::CancellationToken;
+
+    #[test]
+    fn cancels_explicitly() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        assert!(!clone.is_cancelled());
+
+        token.cancel();
+
+        assert!(clone.is_cancelled());
+        assert!(!clone.is_expired());
+    }
+
+    #[test]
+    fn cancels_on_drop_unless_disarmed() {
+        let token = CancellationToken::new();
+        token.drop_guard().disarm();
+
+        assert!(!token.is_cancelled());
+
+        drop(token.drop_guard());
+
+        assert!(token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn wakes_on_cancel() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            clone.cancel();
+        });
+        tokio::time::timeout(Duration::from_secs(5), token.cancelled())
+            .await
+            .expect("cancellation should wake the `cancelled()` future");
+
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn expires_on_deadline() {
+        let token = CancellationToken::with_deadline(Instant::now());
+
+        assert!(token.is_cancelled());
+        assert!(token.is_expired());
+    }
+
+    #[tokio::test]
+    async fn unregisters_dropped_futures() {
+        let token = CancellationToken::new();
+
+        for _ in 0..3 {
+            let res = tokio::time::timeout(Duration::from_millis(1), token.cancelled()).await;
+
+            assert!(res.is_err(), "token shouldn't be cancelled");
+        }
+
+        assert!(token.inner.wakers.lock().unwrap().registered.is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    pin::{Pin, pin},
+    sync::{Arc, Mutex},
+    task::{self, Poll, Waker},
+};
+
+use futures::{
+    future::{self, Either},
+    stream::{self, Stream, StreamExt as _},
+};
+
+/// Limit of the number of fields and list elements being resolved concurrently during a single
+/// operation execution, shared by all its [`Executor`]s.
+///
+/// Every resolving future occupies a slot. The operation itself occupies a slot too, so the
+/// limit of `1` makes the whole operation being resolved serially.
+///
+/// [`Executor`]: crate::Executor
+#[derive(Clone, Debug)]
+pub(crate) struct ConcurrencyLimit {
+    /// Slots available to the resolving futures, besides the ones inherited from their parents.
+    slots: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimit {
+    /// Creates a new [`ConcurrencyLimit`] allowing at most `limit` futures to be resolved at once.
+    pub(crate) fn new(limit: usize) -> Self {
+        Self {
+            slots: Arc::new(Semaphore::new(limit.max(1) - 1)),
+        }
+    }
+}
+
+/// Resolves the provided `futures` concurrently, yielding their outputs in the original order.
+///
+/// No more than `limit` of the `futures` are resolved at once, if provided (the per-field limit).
+///
+/// If the per-operation [`ConcurrencyLimit`] is provided, every future waits for a free slot
+/// before being resolved. The slot of the parent (being idle while awaiting the `futures`) is
+/// inherited by them one by one, so the `futures` always make progress, even once all the shared
+/// slots are occupied by the other parents.
+pub(crate) fn resolve_concurrently<F: Future>(
+    futures: Vec<F>,
+    limit: Option<usize>,
+    shared: Option<&ConcurrencyLimit>,
+) -> impl Stream<Item = F::Output> {
+    let limit = limit.unwrap_or(futures.len()).max(1);
+    let slots = shared.map(|shared| (Arc::new(Semaphore::new(1)), Arc::clone(&shared.slots)));
+    stream::iter(futures)
+        .map(move |f| resolve_in_slot(f, slots.clone()))
+        .buffered(limit)
+}
+
+/// Resolves the provided `future` once it occupies either the `inherited` or a `shared` slot, if
+/// any provided.
+async fn resolve_in_slot<F: Future>(
+    future: F,
+    slots: Option<(Arc<Semaphore>, Arc<Semaphore>)>,
+) -> F::Output {
+    let Some((inherited, shared)) = slots else {
+        return future.await;
+    };
+    let _slot = match future::select(pin!(inherited.acquire()), pin!(shared.acquire())).await {
+        Either::Left((slot, _)) | Either::Right((slot, _)) => slot,
+    };
+    future.await
+}
+
+/// Asynchronous counting semaphore, handing out its permits to the [`Acquire`] futures in the
+/// order they started waiting.
+#[derive(Debug)]
+struct Semaphore {
+    state: Mutex<SemaphoreState>,
+}
+
+#[derive(Debug)]
+struct SemaphoreState {
+    /// Number of permits not being held by anyone.
+    permits: usize,
+
+    /// Key to be assigned to the next waiting [`Acquire`] future.
+    next_key: usize,
+
+    /// [`Waker`]s of the [`Acquire`] futures waiting for a permit, by their keys.
+    waiters: BTreeMap<usize, Waker>,
+}
+
+impl Semaphore {
+    /// Creates a new [`Semaphore`] with the provided number of `permits`.
+    fn new(permits: usize) -> Self {
+        Self {
+            state: Mutex::new(SemaphoreState {
+                permits,
+                next_key: 0,
+                waiters: BTreeMap::new(),
+            }),
+        }
+    }
+
+    /// Returns a [`Future`] resolving into a [`Permit`] once this [`Semaphore`] has a free one.
+    fn acquire(&self) -> Acquire<'_> {
+        Acquire {
+            semaphore: self,
+            key: None,
+        }
+    }
+
+    /// Wakes the longest waiting [`Acquire`] future, if there is a free permit.
+    fn wake_next(state: &mut SemaphoreState) {
+        if state.permits > 0 {
+            if let Some((_, waker)) = state.waiters.pop_first() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// [`Future`] returned by the [`Semaphore::acquire()`] method.
+struct Acquire<'s> {
+    semaphore: &'s Semaphore,
+
+    /// Key of the [`Waker`] registered by this [`Acquire`] future, if it's waiting.
+    key: Option<usize>,
+}
+
+impl<'s> Future for Acquire<'s> {
+    type Output = Permit<'s>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.semaphore.state.lock().unwrap();
+        if state.permits > 0 {
+            state.permits -= 1;
+            if let Some(key) = self.key.take() {
+                _ = state.waiters.remove(&key);
+            }
+            return Poll::Ready(Permit {
+                semaphore: self.semaphore,
+            });
+        }
+        let key = *self.key.get_or_insert_with(|| {
+            state.next_key += 1;
+            state.next_key
+        });
+        match state.waiters.get_mut(&key) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            Some(waker) => waker.clone_from(cx.waker()),
+            // Either not registered yet, or woken already, but outrun by another `Acquire`.
+            None => _ = state.waiters.insert(key, cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            let mut state = self.semaphore.state.lock().unwrap();
+            if state.waiters.remove(&key).is_none() {
+                // Woken already, so pass the wake-up on, not to lose the free permit.
+                Semaphore::wake_next(&mut state);
+            }
+        }
+    }
+}
+
+/// Permit of a [`Semaphore`], released on drop.
+struct Permit<'s> {
+    semaphore: &'s Semaphore,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        let mut state = self.semaphore.state.lock().unwrap();
+        state.permits += 1;
+        Semaphore::wake_next(&mut state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+
+    use futures::FutureExt as _;
+
+    use super::Semaphore;
+
+    #[test]
+    fn waits_for_released_permit() {
+        let semaphore = Semaphore::new(1);
+
+        let permit = semaphore.acquire().now_or_never().unwrap();
+        let mut waiting = pin!(semaphore.acquire());
+        assert!(waiting.as_mut().now_or_never().is_none());
+
+        drop(permit);
+        assert!(waiting.as_mut().now_or_never().is_some());
+    }
+
+    #[test]
+    fn passes_permit_on_when_woken_waiter_is_dropped() {
+        let semaphore = Semaphore::new(1);
+
+        let permit = semaphore.acquire().now_or_never().unwrap();
+        let mut first = Box::pin(semaphore.acquire());
+        let mut second = pin!(semaphore.acquire());
+        assert!(first.as_mut().now_or_never().is_none());
+        assert!(second.as_mut().now_or_never().is_none());
+
+        drop(permit);
+        drop(first);
+        // The `second` is woken (so its `Waker` is unregistered), despite the `first` being the
+        // one woken by the released permit.
+        assert!(semaphore.state.lock().unwrap().waiters.is_empty());
+        assert!(second.now_or_never().is_some());
+    }
+}
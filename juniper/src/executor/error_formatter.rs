@@ -0,0 +1,58 @@
+use std::fmt::{self, Display};
+
+use crate::{DefaultScalarValue, ExecutionError};
+
+/// Marker of errors whose messages are safe to be exposed to clients as is.
+///
+/// [`FieldError`]s constructed out of such errors (via [`FieldError::new_safe()`]) bypass the
+/// [`ErrorFormatter`] of a [`RootNode`], being returned to clients unchanged.
+///
+/// > __NOTE__: Converting a [`SafeError`] into a [`FieldError`] via the [`From`] implementation
+/// >           (like the `?` operator does) doesn't make it safe, as the conversion is implemented
+/// >           for any [`Display`] type. Use [`FieldError::new_safe()`] or
+/// >           [`error_code::into_safe_field_error()`] (in an [`IntoFieldError`] implementation,
+/// >           for example) instead.
+///
+/// [`error_code::into_safe_field_error()`]: crate::error_code::into_safe_field_error
+/// [`FieldError`]: crate::FieldError
+/// [`FieldError::new_safe()`]: crate::FieldError::new_safe
+/// [`IntoFieldError`]: crate::IntoFieldError
+/// [`RootNode`]: crate::RootNode
+pub trait SafeError: Display {}
+
+/// Formatter of the [`ExecutionError`]s happened while executing an operation, before they're
+/// returned to clients.
+///
+/// Sees every [`ExecutionError`] (along with its [path][0], [`FieldError`] and its
+/// [source][2]), except the [safe][1] ones, so may log it and replace it (via
+/// [`ExecutionError::map_error()`]) with a masked one, not leaking any internal details.
+///
+/// Implemented for all `Fn(ExecutionError<S>) -> ExecutionError<S>` closures.
+///
+/// See [`RootNode::format_errors()`] for an example.
+///
+/// [`FieldError`]: crate::FieldError
+/// [`RootNode::format_errors()`]: crate::RootNode::format_errors
+/// [0]: ExecutionError::path
+/// [1]: SafeError
+/// [2]: crate::FieldError::source
+pub trait ErrorFormatter<S = DefaultScalarValue>: Send + Sync {
+    /// Formats the provided [`ExecutionError`] to be returned to clients.
+    #[must_use]
+    fn format(&self, error: ExecutionError<S>) -> ExecutionError<S>;
+}
+
+impl<F, S> ErrorFormatter<S> for F
+where
+    F: Fn(ExecutionError<S>) -> ExecutionError<S> + Send + Sync,
+{
+    fn format(&self, error: ExecutionError<S>) -> ExecutionError<S> {
+        self(error)
+    }
+}
+
+impl<S> fmt::Debug for dyn ErrorFormatter<S> + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorFormatter").finish_non_exhaustive()
+    }
+}
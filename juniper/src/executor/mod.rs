@@ -4,15 +4,21 @@ use std::{
     borrow::Cow,
     cmp::Ordering,
     collections::HashMap,
+    convert::Infallible,
+    error::Error,
     fmt::{Debug, Display},
     mem,
-    pin::Pin,
+    pin::{Pin, pin},
     sync::{Arc, RwLock},
+    time::Instant,
 };
 
 use arcstr::ArcStr;
 use fnv::FnvHashMap;
-use futures::Stream;
+use futures::{
+    Stream,
+    future::{self, Either},
+};
 
 use crate::{
     GraphQLError,
@@ -35,10 +41,17 @@ use crate::{
         name::Name,
         subscriptions::{GraphQLSubscriptionType, GraphQLSubscriptionValue},
     },
+    usage::FieldUsageRecorder,
     value::{DefaultScalarValue, ParseScalarValue, ScalarValue, Value},
 };
 
+use self::concurrency::ConcurrencyLimit;
+
+pub(crate) use self::cancellation::ExecutionTimeout;
+
 pub use self::{
+    cancellation::CancellationToken,
+    error_formatter::{ErrorFormatter, SafeError},
     look_ahead::{
         Applies, LookAheadArgument, LookAheadChildren, LookAheadList, LookAheadObject,
         LookAheadSelection, LookAheadValue,
@@ -46,6 +59,9 @@ pub use self::{
     owned_executor::OwnedExecutor,
 };
 
+mod cancellation;
+mod concurrency;
+mod error_formatter;
 mod look_ahead;
 mod owned_executor;
 
@@ -74,6 +90,11 @@ where
     context: &'a CtxT,
     errors: &'r RwLock<Vec<ExecutionError<S>>>,
     field_path: Arc<FieldPath<'a>>,
+    usage: Option<FieldUsageRecorder<'a>>,
+    cancellation: Option<&'r CancellationToken>,
+    concurrency_limit: Option<usize>,
+    shared_concurrency_limit: Option<&'r ConcurrencyLimit>,
+    error_formatter: Option<&'a dyn ErrorFormatter<S>>,
 }
 
 /// Error type for errors that occur during query execution
@@ -98,6 +119,17 @@ impl<S> ExecutionError<S> {
             error,
         }
     }
+
+    /// Maps the [`FieldError`] of this [`ExecutionError`] with the given function, preserving
+    /// its location and path.
+    #[must_use]
+    pub fn map_error(self, f: impl FnOnce(FieldError<S>) -> FieldError<S>) -> Self {
+        Self {
+            location: self.location,
+            path: self.path,
+            error: f(self.error),
+        }
+    }
 }
 
 impl<S> PartialOrd for ExecutionError<S>
@@ -128,7 +160,9 @@ where
 /// optional `Value` structure containing additional information.
 ///
 /// They can be converted to from any type that implements `std::fmt::Display`,
-/// which makes error chaining with the `?` operator a breeze:
+/// which makes error chaining with the `?` operator a breeze. Such conversion never produces a
+/// [safe][0] [`FieldError`] (even out of a [`SafeError`]), so its message is subject to the
+/// [`ErrorFormatter`] of a [`RootNode`]:
 ///
 /// ```rust
 /// # use juniper::{FieldError, ScalarValue};
@@ -138,10 +172,41 @@ where
 ///     Ok(s)
 /// }
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+///
+/// To preserve the original [`Error`] (along with its [`Error::source()`] chain and backtrace)
+/// for logging, it may be attached as the [`FieldError::source()`], which is never returned to
+/// clients:
+///
+/// ```rust
+/// # use juniper::FieldError;
+/// fn get_string(data: Vec<u8>) -> Result<String, FieldError> {
+///     String::from_utf8(data).map_err(FieldError::from_error)
+/// }
+///
+/// let err = get_string(vec![0xff]).unwrap_err();
+/// assert!(
+///     err.source()
+///         .and_then(|e| e.downcast_ref::<std::string::FromUtf8Error>())
+///         .is_some(),
+/// );
+/// ```
+///
+/// [`RootNode`]: crate::RootNode
+/// [0]: FieldError::new_safe
+#[derive(Clone, Debug)]
 pub struct FieldError<S = DefaultScalarValue> {
     message: String,
     extensions: Value<S>,
+    safe: bool,
+    source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl<S: PartialEq> PartialEq for FieldError<S> {
+    fn eq(&self, other: &Self) -> bool {
+        // Neither the source of the error, nor whether it's safe, affect what is returned to
+        // clients.
+        self.message == other.message && self.extensions == other.extensions
+    }
 }
 
 impl<T: Display, S> From<T> for FieldError<S> {
@@ -149,6 +214,8 @@ impl<T: Display, S> From<T> for FieldError<S> {
         Self {
             message: e.to_string(),
             extensions: Value::Null,
+            safe: false,
+            source: None,
         }
     }
 }
@@ -189,6 +256,22 @@ impl<S> FieldError<S> {
         Self {
             message: e.to_string(),
             extensions,
+            safe: false,
+            source: None,
+        }
+    }
+
+    /// Construct a new [`FieldError`] out of the [`SafeError`], which is returned to clients as
+    /// is, bypassing the [`ErrorFormatter`] of a [`RootNode`].
+    ///
+    /// See [`FieldError::new()`] for the `extensions` description.
+    #[must_use]
+    pub fn new_safe<T: SafeError>(e: T, extensions: Value<S>) -> Self {
+        Self {
+            message: e.to_string(),
+            extensions,
+            safe: true,
+            source: None,
         }
     }
 
@@ -206,6 +289,42 @@ impl<S> FieldError<S> {
         &self.extensions
     }
 
+    /// Construct a new [`FieldError`] out of the provided [`Error`], keeping it as the
+    /// [`FieldError::source()`].
+    ///
+    /// The [`Display`] representation of the `error` is used as the message.
+    #[must_use]
+    pub fn from_error<E: Error + Send + Sync + 'static>(error: E) -> Self {
+        Self::new(&error, Value::Null).with_source(error)
+    }
+
+    /// Attaches the provided `source` to this [`FieldError`], replacing the existing one, if any.
+    ///
+    /// The `source` is never serialized, so is not returned to clients, and is intended to be
+    /// inspected (and [downcast][0]) by loggers, middlewares or an [`ErrorFormatter`] only.
+    ///
+    /// [0]: https://doc.rust-lang.org/std/error/trait.Error.html#method.downcast_ref
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        self.source = Some(source.into().into());
+        self
+    }
+
+    /// Returns the source [`Error`] of this [`FieldError`], if any.
+    ///
+    /// See [`FieldError::with_source()`] for details.
+    #[must_use]
+    pub fn source(&self) -> Option<&Arc<dyn Error + Send + Sync>> {
+        self.source.as_ref()
+    }
+
+    /// Indicates whether this [`FieldError`] is constructed out of a [`SafeError`], so bypasses
+    /// the [`ErrorFormatter`] of a [`RootNode`].
+    #[must_use]
+    pub fn is_safe(&self) -> bool {
+        self.safe
+    }
+
     /// Maps the [`ScalarValue`] type of this [`FieldError`] into the specified
     /// one.
     #[must_use]
@@ -217,6 +336,8 @@ impl<S> FieldError<S> {
         FieldError {
             message: self.message,
             extensions: self.extensions.map_scalar_value(),
+            safe: self.safe,
+            source: self.source,
         }
     }
 
@@ -226,6 +347,8 @@ impl<S> FieldError<S> {
         Self {
             message: f(self.message),
             extensions: self.extensions,
+            safe: self.safe,
+            source: self.source,
         }
     }
 }
@@ -558,6 +681,11 @@ where
             context: ctx,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation,
+            concurrency_limit: self.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit,
+            error_formatter: self.error_formatter,
         }
     }
 
@@ -569,19 +697,18 @@ where
         location: SourcePosition,
         selection_set: Option<&'s [Selection<'a, S>]>,
     ) -> Executor<'s, 'a, CtxT, S> {
+        let field = self
+            .current_type
+            .innermost_concrete()
+            .field_by_name(field_name)
+            .expect("Field not found on inner type");
+
         Executor {
             fragments: self.fragments,
             variables: self.variables,
             current_selection_set: selection_set,
             parent_selection_set: self.current_selection_set,
-            current_type: self.schema.make_type(
-                &self
-                    .current_type
-                    .innermost_concrete()
-                    .field_by_name(field_name)
-                    .expect("Field not found on inner type")
-                    .field_type,
-            ),
+            current_type: self.schema.make_type(&field.field_type),
             schema: self.schema,
             context: self.context,
             errors: self.errors,
@@ -590,6 +717,11 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation,
+            concurrency_limit: field.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit,
+            error_formatter: self.error_formatter,
         }
     }
 
@@ -612,9 +744,118 @@ where
             context: self.context,
             errors: self.errors,
             field_path: self.field_path.clone(),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation,
+            concurrency_limit: self.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit,
+            error_formatter: self.error_formatter,
+        }
+    }
+
+    /// Records the resolution of the field with the provided name of the provided `parent` type,
+    /// if this [`Executor`] collects field usage.
+    pub(crate) fn record_field_usage(
+        &self,
+        parent: &MetaType<S>,
+        field_name: &str,
+        arguments: Option<&Spanning<crate::ast::Arguments<'a, S>>>,
+    ) where
+        S: ScalarValue,
+    {
+        if let Some(usage) = &self.usage {
+            usage.record_field(self.schema, parent, field_name, arguments, self.variables);
+        }
+    }
+
+    /// Records the enum values contained in the provided resolved `value` of the provided `field`,
+    /// if this [`Executor`] collects field usage.
+    pub(crate) fn record_output_usage(&self, field: &Field<S>, value: &Value<S>)
+    where
+        S: ScalarValue,
+    {
+        if let Some(usage) = &self.usage {
+            usage.record_output(self.schema, field, value);
+        }
+    }
+
+    /// Returns the deadline of the operation being executed, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.cancellation?.deadline()
+    }
+
+    /// Indicates whether the operation being executed has been cancelled, or its deadline has
+    /// passed.
+    ///
+    /// Long-running resolvers may check it to stop early, as their results will be discarded
+    /// anyway.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+
+    /// Returns the [`CancellationToken`] of the operation being executed, if any.
+    ///
+    /// Resolvers may pass a clone of it into the work they spawn, so the latter may observe the
+    /// cancellation too.
+    pub fn cancellation_token(&self) -> Option<&'r CancellationToken> {
+        self.cancellation
+    }
+
+    /// Returns the [`FieldError`] to resolve the fields with once the operation being executed is
+    /// cancelled.
+    pub(crate) fn cancellation_error(&self) -> FieldError<S> {
+        let message = if self.cancellation.is_some_and(CancellationToken::is_expired) {
+            "Operation timed out"
+        } else {
+            "Operation cancelled"
+        };
+        FieldError {
+            message: message.into(),
+            extensions: Value::Null,
+            safe: true,
+            source: None,
+        }
+    }
+
+    /// Awaits the provided `resolving` of a field, unless the operation being executed is
+    /// cancelled before it completes, resolving into a [`Executor::cancellation_error()`] then.
+    pub(crate) async fn resolve_until_cancelled(
+        &self,
+        resolving: impl Future<Output = ExecutionResult<S>>,
+    ) -> ExecutionResult<S> {
+        let Some(token) = self.cancellation else {
+            return resolving.await;
+        };
+        if token.is_cancelled() {
+            return Err(self.cancellation_error());
+        }
+        // Without a deadline, the cancellation only happens when the whole execution is dropped,
+        // so there is nothing to race with.
+        if token.deadline().is_none() {
+            return resolving.await;
+        }
+        match future::select(pin!(resolving), pin!(token.cancelled())).await {
+            Either::Left((res, _)) => res,
+            Either::Right(_) => Err(self.cancellation_error()),
         }
     }
 
+    /// Resolves the provided `futures` of list elements or selected fields concurrently, yielding
+    /// their outputs in the original order.
+    ///
+    /// Respects both the concurrency limit of the field being resolved by this [`Executor`] and the
+    /// one of the whole operation.
+    pub(crate) fn resolve_concurrently<F: Future>(
+        &self,
+        futures: Vec<F>,
+    ) -> impl Stream<Item = F::Output> {
+        concurrency::resolve_concurrently(
+            futures,
+            self.concurrency_limit,
+            self.shared_concurrency_limit,
+        )
+    }
+
     /// `Executor`'s current selection set
     pub(crate) fn current_selection_set(&self) -> Option<&[Selection<'a, S>]> {
         self.current_selection_set
@@ -663,13 +904,15 @@ where
         let mut path = Vec::new();
         self.field_path.construct_path(&mut path);
 
-        let mut errors = self.errors.write().unwrap();
-
-        errors.push(ExecutionError {
+        let error = self.format_error(ExecutionError {
             location,
             path,
             error,
         });
+
+        let mut errors = self.errors.write().unwrap();
+
+        errors.push(error);
     }
 
     /// Returns new [`ExecutionError`] at current location
@@ -677,10 +920,21 @@ where
         let mut path = Vec::new();
         self.field_path.construct_path(&mut path);
 
-        ExecutionError {
+        self.format_error(ExecutionError {
             location: *self.location(),
             path,
             error,
+        })
+    }
+
+    /// Formats the provided [`ExecutionError`] with the [`ErrorFormatter`] of this [`Executor`],
+    /// unless it's [safe][0].
+    ///
+    /// [0]: FieldError::is_safe
+    fn format_error(&self, error: ExecutionError<S>) -> ExecutionError<S> {
+        match self.error_formatter {
+            Some(formatter) if !error.error.safe => formatter.format(error),
+            _ => error,
         }
     }
 
@@ -754,6 +1008,11 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: Arc::clone(&self.field_path),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation.cloned(),
+            concurrency_limit: self.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit.cloned(),
+            error_formatter: self.error_formatter,
         }
     }
 }
@@ -840,6 +1099,11 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let cancellation = root_node
+        .execution_timeout
+        .as_ref()
+        .map(|timeout| CancellationToken::with_deadline(Instant::now() + timeout.duration));
+
     let errors = RwLock::new(Vec::new());
     let value;
 
@@ -882,6 +1146,14 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.span.start)),
+            usage: root_node
+                .field_usage_collector
+                .as_deref()
+                .map(|c| FieldUsageRecorder::new(c, operation.item.name.map(|n| n.item))),
+            cancellation: cancellation.as_ref(),
+            concurrency_limit: None,
+            shared_concurrency_limit: None,
+            error_formatter: root_node.error_formatter.as_deref(),
         };
 
         value = match operation.item.operation_type {
@@ -941,6 +1213,14 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let cancellation = match &root_node.execution_timeout {
+        Some(timeout) => CancellationToken::with_deadline(Instant::now() + timeout.duration),
+        None => CancellationToken::new(),
+    };
+    // Cancels the execution if it's dropped before completion (e.g. on client disconnection).
+    let cancel_on_drop = cancellation.drop_guard();
+    let concurrency_limit = root_node.concurrency_limit.map(ConcurrencyLimit::new);
+
     let errors = RwLock::new(Vec::new());
     let value;
 
@@ -983,23 +1263,55 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.span.start)),
+            usage: root_node
+                .field_usage_collector
+                .as_deref()
+                .map(|c| FieldUsageRecorder::new(c, operation.item.name.map(|n| n.item))),
+            cancellation: Some(&cancellation),
+            concurrency_limit: match operation.item.operation_type {
+                OperationType::Mutation if root_node.serial_mutations => Some(1),
+                _ => None,
+            },
+            shared_concurrency_limit: concurrency_limit.as_ref(),
+            error_formatter: root_node.error_formatter.as_deref(),
         };
 
-        value = match operation.item.operation_type {
-            OperationType::Query => {
-                executor
-                    .resolve_into_value_async(&root_node.query_info, &root_node)
-                    .await
+        let resolving = async {
+            match operation.item.operation_type {
+                OperationType::Query => {
+                    executor
+                        .resolve_into_value_async(&root_node.query_info, &root_node)
+                        .await
+                }
+                OperationType::Mutation => {
+                    executor
+                        .resolve_into_value_async(
+                            &root_node.mutation_info,
+                            &root_node.mutation_type,
+                        )
+                        .await
+                }
+                OperationType::Subscription => unreachable!(),
             }
-            OperationType::Mutation => {
-                executor
-                    .resolve_into_value_async(&root_node.mutation_info, &root_node.mutation_type)
-                    .await
+        };
+        // Cancels the execution once its timeout elapses, still letting it complete with the
+        // already resolved fields.
+        let timer = async {
+            if let Some(timeout) = &root_node.execution_timeout {
+                timeout.elapsed().await;
+                cancellation.cancel();
             }
-            OperationType::Subscription => unreachable!(),
+            future::pending::<Infallible>().await
+        };
+
+        value = match future::select(pin!(resolving), pin!(timer)).await {
+            Either::Left((value, _)) => value,
+            Either::Right((never, _)) => match never {},
         };
     }
 
+    cancel_on_drop.disarm();
+
     let mut errors = errors.into_inner().unwrap();
     errors.sort();
 
@@ -1091,6 +1403,7 @@ where
             .collect::<HashMap<String, InputValue<S>>>()
     });
 
+    let concurrency_limit = root_node.concurrency_limit.map(ConcurrencyLimit::new);
     let errors = RwLock::new(Vec::new());
     let value;
 
@@ -1131,6 +1444,14 @@ where
             context,
             errors: &errors,
             field_path: Arc::new(FieldPath::Root(operation.span.start)),
+            usage: root_node
+                .field_usage_collector
+                .as_deref()
+                .map(|c| FieldUsageRecorder::new(c, operation.item.name.map(|n| n.item))),
+            cancellation: None,
+            concurrency_limit: None,
+            shared_concurrency_limit: concurrency_limit.as_ref(),
+            error_formatter: root_node.error_formatter.as_deref(),
         };
 
         value = match operation.item.operation_type {
@@ -1202,6 +1523,7 @@ impl<S> Registry<S> {
             arguments: None,
             field_type: self.get_type::<T>(info),
             deprecation_status: DeprecationStatus::Current,
+            concurrency_limit: None,
         }
     }
 
@@ -1221,6 +1543,7 @@ impl<S> Registry<S> {
             arguments: None,
             field_type: self.get_type::<I>(info),
             deprecation_status: DeprecationStatus::Current,
+            concurrency_limit: None,
         }
     }
 
@@ -1248,6 +1571,23 @@ impl<S> Registry<S> {
             .default_value(value.to_input_value())
     }
 
+    /// Creates an [`Argument`] with the provided `name`, which default value is computed at
+    /// execution time.
+    ///
+    /// As such [`Argument`] has no static default value, it's registered as a nullable one, so
+    /// may be omitted (or provided as `null`) by clients.
+    pub fn arg_with_computed_default<T>(
+        &mut self,
+        name: impl Into<ArcStr>,
+        info: &T::TypeInfo,
+    ) -> Argument<S>
+    where
+        T: GraphQLType<S> + FromInputValue<S>,
+        S: ScalarValue,
+    {
+        Argument::new(name, self.get_type::<T>(info).into_nullable())
+    }
+
     fn insert_placeholder(&mut self, name: Name, of_type: Type) {
         self.types
             .entry(name)
@@ -6,9 +6,10 @@ use std::{
 use crate::{
     ExecutionError, Executor, Selection, Variables,
     ast::Fragment,
-    executor::FieldPath,
+    executor::{CancellationToken, ConcurrencyLimit, ErrorFormatter, FieldPath},
     parser::SourcePosition,
     schema::model::{SchemaType, TypeType},
+    usage::FieldUsageRecorder,
 };
 
 /// [`Executor`] owning all its variables. Can be used after [`Executor`] was
@@ -23,6 +24,11 @@ pub struct OwnedExecutor<'a, CtxT, S> {
     pub(super) context: &'a CtxT,
     pub(super) errors: RwLock<Vec<ExecutionError<S>>>,
     pub(super) field_path: Arc<FieldPath<'a>>,
+    pub(super) usage: Option<FieldUsageRecorder<'a>>,
+    pub(super) cancellation: Option<CancellationToken>,
+    pub(super) concurrency_limit: Option<usize>,
+    pub(super) shared_concurrency_limit: Option<ConcurrencyLimit>,
+    pub(super) error_formatter: Option<&'a dyn ErrorFormatter<S>>,
 }
 
 impl<CtxT, S> Clone for OwnedExecutor<'_, CtxT, S>
@@ -40,6 +46,11 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation.clone(),
+            concurrency_limit: self.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit.clone(),
+            error_formatter: self.error_formatter,
         }
     }
 }
@@ -68,6 +79,11 @@ where
             context: self.context,
             errors: RwLock::new(vec![]),
             field_path: self.field_path.clone(),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation.clone(),
+            concurrency_limit: self.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit.clone(),
+            error_formatter: self.error_formatter,
         }
     }
 
@@ -85,19 +101,18 @@ where
         location: SourcePosition,
         selection_set: Option<Vec<Selection<'a, S>>>,
     ) -> OwnedExecutor<'a, CtxT, S> {
+        let field = self
+            .current_type
+            .innermost_concrete()
+            .field_by_name(field_name)
+            .expect("Field not found on inner type");
+
         OwnedExecutor {
             fragments: self.fragments.clone(),
             variables: self.variables.clone(),
             current_selection_set: selection_set,
             parent_selection_set: self.current_selection_set.clone(),
-            current_type: self.schema.make_type(
-                &self
-                    .current_type
-                    .innermost_concrete()
-                    .field_by_name(field_name)
-                    .expect("Field not found on inner type")
-                    .field_type,
-            ),
+            current_type: self.schema.make_type(&field.field_type),
             schema: self.schema,
             context: self.context,
             errors: RwLock::new(vec![]),
@@ -106,6 +121,11 @@ where
                 location,
                 Arc::clone(&self.field_path),
             )),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation.clone(),
+            concurrency_limit: field.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit.clone(),
+            error_formatter: self.error_formatter,
         }
     }
 
@@ -121,6 +141,11 @@ where
             context: self.context,
             errors: &self.errors,
             field_path: Arc::clone(&self.field_path),
+            usage: self.usage.clone(),
+            cancellation: self.cancellation.as_ref(),
+            concurrency_limit: self.concurrency_limit,
+            shared_concurrency_limit: self.shared_concurrency_limit.as_ref(),
+            error_formatter: self.error_formatter,
         }
     }
 }
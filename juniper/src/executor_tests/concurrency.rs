@@ -0,0 +1,183 @@
+use std::{
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use crate::{EmptySubscription, RootNode, execute, graphql_object};
+
+#[derive(Default)]
+struct Context {
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+    log: Mutex<Vec<i32>>,
+}
+
+impl crate::Context for Context {}
+
+impl Context {
+    async fn track(&self, n: i32) -> i32 {
+        let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+        // Later items finish earlier, so any concurrency reorders the log.
+        tokio::time::sleep(Duration::from_millis(5 * (10 - n as u64))).await;
+        self.log.lock().unwrap().push(n);
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        n
+    }
+
+    fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    fn log(&self) -> Vec<i32> {
+        self.log.lock().unwrap().clone()
+    }
+}
+
+struct Item(i32);
+
+#[graphql_object(context = Context)]
+impl Item {
+    async fn value(&self, ctx: &Context) -> i32 {
+        ctx.track(self.0).await
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context)]
+impl Query {
+    fn items() -> Vec<Item> {
+        (0..6).map(Item).collect()
+    }
+
+    #[graphql(concurrency = 2)]
+    fn limited_items() -> Vec<Item> {
+        (0..6).map(Item).collect()
+    }
+
+    async fn first(ctx: &Context) -> i32 {
+        ctx.track(1).await
+    }
+
+    async fn second(ctx: &Context) -> i32 {
+        ctx.track(2).await
+    }
+
+    async fn third(ctx: &Context) -> i32 {
+        ctx.track(3).await
+    }
+}
+
+struct Mutation;
+
+#[graphql_object(context = Context)]
+impl Mutation {
+    async fn first(ctx: &Context) -> i32 {
+        ctx.track(1).await
+    }
+
+    async fn second(ctx: &Context) -> i32 {
+        ctx.track(2).await
+    }
+
+    async fn third(ctx: &Context) -> i32 {
+        ctx.track(3).await
+    }
+}
+
+type Schema = RootNode<Query, Mutation, EmptySubscription<Context>>;
+
+fn schema() -> Schema {
+    Schema::new(Query, Mutation, EmptySubscription::new())
+}
+
+async fn run(schema: &Schema, query: &str) -> Context {
+    let ctx = Context::default();
+    let (_, errs) = execute(query, None, schema, &graphql_vars! {}, &ctx)
+        .await
+        .unwrap();
+    assert_eq!(errs, []);
+    ctx
+}
+
+#[tokio::test]
+async fn resolves_concurrently_by_default() {
+    // language=GraphQL
+    let ctx = run(&schema(), "{ items { value } }").await;
+
+    assert_eq!(ctx.max_in_flight(), 6);
+}
+
+#[tokio::test]
+async fn limits_list_elements() {
+    // language=GraphQL
+    let ctx = run(&schema().limit_concurrency(3), "{ items { value } }").await;
+
+    assert_eq!(ctx.max_in_flight(), 3);
+}
+
+#[tokio::test]
+async fn limits_whole_operation() {
+    // language=GraphQL
+    let query = "{ a: items { value } b: items { value } first second }";
+
+    let ctx = run(&schema().limit_concurrency(3), query).await;
+
+    assert_eq!(ctx.max_in_flight(), 3);
+
+    let ctx = run(&schema().limit_concurrency(1), query).await;
+
+    assert_eq!(ctx.max_in_flight(), 1);
+}
+
+#[tokio::test]
+async fn limits_sibling_fields() {
+    // language=GraphQL
+    let ctx = run(&schema().limit_concurrency(1), "{ first second third }").await;
+
+    assert_eq!(ctx.max_in_flight(), 1);
+    assert_eq!(ctx.log(), [1, 2, 3]);
+}
+
+#[tokio::test]
+async fn limits_per_field() {
+    // language=GraphQL
+    let ctx = run(&schema(), "{ limitedItems { value } }").await;
+
+    assert_eq!(ctx.max_in_flight(), 2);
+
+    // language=GraphQL
+    let ctx = run(&schema().limit_concurrency(4), "{ limitedItems { value } }").await;
+
+    assert_eq!(ctx.max_in_flight(), 2);
+}
+
+#[tokio::test]
+async fn resolves_mutations_serially() {
+    // language=GraphQL
+    let query = "mutation { first second third }";
+
+    let ctx = run(&schema(), query).await;
+
+    assert_eq!(ctx.max_in_flight(), 3);
+    assert_eq!(ctx.log(), [3, 2, 1]);
+
+    let ctx = run(&schema().resolve_mutations_serially(), query).await;
+
+    assert_eq!(ctx.max_in_flight(), 1);
+    assert_eq!(ctx.log(), [1, 2, 3]);
+
+    // Queries are not affected.
+    // language=GraphQL
+    let ctx = run(
+        &schema().resolve_mutations_serially(),
+        "{ first second third }",
+    )
+    .await;
+
+    assert_eq!(ctx.max_in_flight(), 3);
+}
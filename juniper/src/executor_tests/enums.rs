@@ -1,5 +1,5 @@
 use crate::{
-    GraphQLEnum,
+    GraphQLEnum, error_code,
     executor::Variables,
     graphql,
     parser::SourcePosition,
@@ -142,6 +142,7 @@ async fn does_not_accept_incorrect_enum_name_in_variables() {
             r#"Variable "$color" got invalid value. Invalid value for enum "Color"."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -167,6 +168,7 @@ async fn does_not_accept_incorrect_type_in_variables() {
             r#"Variable "$color" got invalid value. Expected "Color", found not a string or enum."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
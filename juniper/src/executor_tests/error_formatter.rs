@@ -0,0 +1,267 @@
+use std::{
+    num::ParseIntError,
+    pin::Pin,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use futures::{StreamExt as _, stream};
+
+use crate::{
+    DefaultScalarValue, EmptyMutation, ExecutionError, FieldError, FieldResult, RootNode,
+    SafeError, Value, execute, execute_sync, graphql, graphql_object, graphql_subscription,
+    guard::Rejection, resolve_into_stream,
+};
+
+#[derive(derive_more::Display)]
+#[display("Not found")]
+struct NotFound;
+
+impl SafeError for NotFound {}
+
+struct Item;
+
+#[graphql_object]
+impl Item {
+    fn id() -> i32 {
+        1
+    }
+
+    fn broken() -> FieldResult<Option<i32>> {
+        Err("index `items_pkey` is corrupted".into())
+    }
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn internal() -> FieldResult<Option<i32>> {
+        Err(FieldError::new(
+            "connection to `db.internal:5432` refused",
+            graphql::value!({"host": "db.internal"}),
+        ))
+    }
+
+    fn missing() -> FieldResult<Option<i32>> {
+        Err(FieldError::new_safe(NotFound, graphql::value!(null)))
+    }
+
+    fn forbidden() -> Result<Option<i32>, Rejection> {
+        Err(Rejection::new("Admins only"))
+    }
+
+    fn item() -> Item {
+        Item
+    }
+
+    async fn delayed() -> FieldResult<Option<i32>> {
+        Err("timed out reading from `cache.internal`".into())
+    }
+
+    fn parsed() -> FieldResult<Option<i32>> {
+        "forty-two"
+            .parse()
+            .map(Some)
+            .map_err(FieldError::from_error)
+    }
+}
+
+type NumberStream = Pin<Box<dyn futures::Stream<Item = FieldResult<i32>> + Send>>;
+
+struct Subscription;
+
+#[graphql_subscription]
+impl Subscription {
+    async fn numbers() -> NumberStream {
+        Box::pin(stream::iter([
+            Ok(1),
+            Err("queue `events` is unavailable".into()),
+        ]))
+    }
+}
+
+type Schema = RootNode<Query, EmptyMutation, Subscription>;
+
+/// Masks the provided [`ExecutionError`] with the given `id`, recording its original message into
+/// the `log`.
+fn mask(
+    error: ExecutionError<DefaultScalarValue>,
+    id: usize,
+    log: &Mutex<Vec<String>>,
+) -> ExecutionError<DefaultScalarValue> {
+    log.lock().unwrap().push(format!(
+        "{id}: {} at {}",
+        error.error().message(),
+        error.path().join("."),
+    ));
+    error.map_error(|_| {
+        FieldError::new(
+            "Internal server error",
+            graphql::value!({"correlationId": (id as i32)}),
+        )
+    })
+}
+
+fn schema(log: &'static Mutex<Vec<String>>) -> Schema {
+    let next_id = AtomicUsize::new(1);
+    Schema::new(Query, EmptyMutation::new(), Subscription).format_errors(
+        move |err: ExecutionError<_>| mask(err, next_id.fetch_add(1, Ordering::Relaxed), log),
+    )
+}
+
+#[test]
+fn masks_errors() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    let schema = schema(&LOG);
+
+    // language=GraphQL
+    let query = "{ internal item { id broken } }";
+
+    let (res, errs) = execute_sync(query, None, &schema, &graphql::vars! {}, &()).unwrap();
+
+    assert_eq!(
+        res,
+        graphql::value!({"internal": null, "item": {"id": 1, "broken": null}}),
+    );
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].path(), ["internal"]);
+    assert_eq!(errs[0].error().message(), "Internal server error");
+    assert_eq!(
+        errs[0].error().extensions(),
+        &graphql::value!({"correlationId": 1}),
+    );
+    assert_eq!(errs[1].path(), ["item", "broken"]);
+    assert_eq!(errs[1].error().message(), "Internal server error");
+    assert_eq!(
+        errs[1].error().extensions(),
+        &graphql::value!({"correlationId": 2}),
+    );
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        [
+            "1: connection to `db.internal:5432` refused at internal",
+            "2: index `items_pkey` is corrupted at item.broken",
+        ],
+    );
+}
+
+#[tokio::test]
+async fn masks_async_errors() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    let schema = schema(&LOG);
+
+    // language=GraphQL
+    let query = "{ delayed }";
+
+    let (res, errs) = execute(query, None, &schema, &graphql::vars! {}, &())
+        .await
+        .unwrap();
+
+    assert_eq!(res, graphql::value!({"delayed": null}));
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].error().message(), "Internal server error");
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        ["1: timed out reading from `cache.internal` at delayed"],
+    );
+}
+
+#[tokio::test]
+async fn masks_subscription_errors() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    let schema = schema(&LOG);
+
+    // language=GraphQL
+    let query = "subscription { numbers }";
+
+    let (res, errs) = resolve_into_stream(query, None, &schema, &graphql::vars! {}, &())
+        .await
+        .unwrap();
+
+    assert!(errs.is_empty());
+    let Value::Object(obj) = res else {
+        panic!("expected `Value::Object`");
+    };
+    let Some((_, Value::Scalar(stream))) = obj.into_iter().next() else {
+        panic!("expected `numbers` stream");
+    };
+    let items = stream.collect::<Vec<_>>().await;
+
+    assert_eq!(items[0], Ok(graphql::value!(1)));
+    let errs = items[1].as_ref().unwrap_err();
+    assert_eq!(errs[0].error().message(), "Internal server error");
+    assert_eq!(
+        *LOG.lock().unwrap(),
+        ["1: queue `events` is unavailable at numbers"],
+    );
+}
+
+#[test]
+fn passes_safe_errors_through() {
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    let schema = schema(&LOG);
+
+    // language=GraphQL
+    let query = "{ missing forbidden }";
+
+    let (res, errs) = execute_sync(query, None, &schema, &graphql::vars! {}, &()).unwrap();
+
+    assert_eq!(res, graphql::value!({"missing": null, "forbidden": null}));
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].error().message(), "Not found");
+    assert!(errs[0].error().is_safe());
+    assert_eq!(errs[1].error().message(), "Admins only");
+    assert_eq!(
+        errs[1].error().extensions(),
+        &graphql::value!({"code": "FORBIDDEN"}),
+    );
+    assert!(LOG.lock().unwrap().is_empty());
+}
+
+#[test]
+fn returns_errors_as_is_by_default() {
+    let schema = Schema::new(Query, EmptyMutation::new(), Subscription);
+
+    // language=GraphQL
+    let query = "{ internal }";
+
+    let (_, errs) = execute_sync(query, None, &schema, &graphql::vars! {}, &()).unwrap();
+
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].error().message(),
+        "connection to `db.internal:5432` refused",
+    );
+    assert_eq!(
+        errs[0].error().extensions(),
+        &graphql::value!({"host": "db.internal"}),
+    );
+}
+
+#[test]
+fn sees_error_source() {
+    let schema = Schema::new(Query, EmptyMutation::new(), Subscription).format_errors(
+        |err: ExecutionError<_>| {
+            let kind = err
+                .error()
+                .source()
+                .and_then(|e| e.downcast_ref::<ParseIntError>())
+                .map_or_else(|| "unknown".into(), |e| format!("{:?}", e.kind()));
+            err.map_error(|e| e.map_message(|msg| format!("{msg} ({kind})")))
+        },
+    );
+
+    // language=GraphQL
+    let query = "{ parsed }";
+
+    let (_, errs) = execute_sync(query, None, &schema, &graphql::vars! {}, &()).unwrap();
+
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].error().message(),
+        "invalid digit found in string (InvalidDigit)",
+    );
+}
@@ -7,3 +7,7 @@ mod variables;
 mod interfaces_unions;
 
 mod async_await;
+mod concurrency;
+
+mod error_formatter;
+mod timeout;
@@ -0,0 +1,147 @@
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    CancellationToken, DefaultScalarValue, EmptyMutation, EmptySubscription, Executor, RootNode,
+    execute, execute_sync, graphql_object,
+};
+
+#[derive(Default)]
+struct Context {
+    token: Mutex<Option<CancellationToken>>,
+}
+
+impl crate::Context for Context {}
+
+struct Item;
+
+#[graphql_object(context = Context)]
+impl Item {
+    fn id() -> i32 {
+        1
+    }
+
+    async fn pending() -> i32 {
+        std::future::pending().await
+    }
+}
+
+struct Query;
+
+#[graphql_object(context = Context, scalar = DefaultScalarValue)]
+impl Query {
+    fn fast() -> bool {
+        true
+    }
+
+    async fn slow() -> Option<bool> {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        Some(true)
+    }
+
+    fn blocking() -> bool {
+        thread::sleep(Duration::from_millis(50));
+        true
+    }
+
+    fn item() -> Option<Item> {
+        Some(Item)
+    }
+
+    fn has_deadline(executor: &Executor<'_, '_, Context>) -> bool {
+        executor.deadline().is_some() && !executor.is_cancelled()
+    }
+
+    async fn spawned(executor: &Executor<'_, '_, Context>) -> bool {
+        let token = executor.cancellation_token().unwrap().clone();
+        *executor.context().token.lock().unwrap() = Some(token);
+        std::future::pending().await
+    }
+}
+
+type Schema = RootNode<Query, EmptyMutation<Context>, EmptySubscription<Context>>;
+
+fn schema(timeout: Option<Duration>) -> Schema {
+    let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new());
+    match timeout {
+        Some(timeout) => schema.limit_execution_time(timeout, tokio::time::sleep),
+        None => schema,
+    }
+}
+
+#[tokio::test]
+async fn returns_partial_result_on_timeout() {
+    let schema = schema(Some(Duration::from_millis(20)));
+
+    // language=GraphQL
+    let query = "{ fast slow hasDeadline item { id pending } }";
+
+    let (res, errs) = execute(query, None, &schema, &graphql_vars! {}, &Context::default())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res,
+        graphql_value!({"fast": true, "slow": null, "hasDeadline": true, "item": null}),
+    );
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].path(), ["slow"]);
+    assert_eq!(errs[0].error().message(), "Operation timed out");
+    assert_eq!(errs[1].path(), ["item", "pending"]);
+    assert_eq!(errs[1].error().message(), "Operation timed out");
+}
+
+#[test]
+fn skips_pending_fields_on_timeout_in_sync() {
+    let schema = schema(Some(Duration::from_millis(10)));
+
+    // language=GraphQL
+    let query = "{ blocking fast }";
+
+    let (res, errs) =
+        execute_sync(query, None, &schema, &graphql_vars! {}, &Context::default()).unwrap();
+
+    // Non-`null`able `fast` field propagates `null` to the parent.
+    assert_eq!(res, graphql_value!(null));
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].path(), ["fast"]);
+    assert_eq!(errs[0].error().message(), "Operation timed out");
+}
+
+#[tokio::test]
+async fn has_no_deadline_by_default() {
+    let schema = schema(None);
+
+    // language=GraphQL
+    let query = "{ hasDeadline }";
+
+    let (res, errs) = execute(query, None, &schema, &graphql_vars! {}, &Context::default())
+        .await
+        .unwrap();
+
+    assert_eq!(res, graphql_value!({"hasDeadline": false}));
+    assert_eq!(errs, []);
+}
+
+#[tokio::test]
+async fn cancels_when_dropped() {
+    let schema = schema(None);
+    let ctx = Arc::new(Context::default());
+
+    // language=GraphQL
+    let query = "{ spawned }";
+
+    let res = tokio::time::timeout(
+        Duration::from_millis(20),
+        execute(query, None, &schema, &graphql_vars! {}, &*ctx),
+    )
+    .await;
+
+    assert!(res.is_err(), "execution should not complete");
+    let token = ctx.token.lock().unwrap().clone().unwrap();
+    assert!(token.is_cancelled());
+    assert!(!token.is_expired());
+}
@@ -1,5 +1,5 @@
 use crate::{
-    GraphQLInputObject, GraphQLScalar,
+    GraphQLInputObject, GraphQLScalar, error_code,
     executor::Variables,
     graphql, graphql_object,
     parser::SourcePosition,
@@ -285,6 +285,7 @@ async fn variable_error_on_nested_non_null() {
             r#"Variable "$input" got invalid value. In field "c": Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -309,7 +310,9 @@ async fn variable_error_on_incorrect_type() {
         RuleError::new(
             r#"Variable "$input" got invalid value. Expected "TestInputObject", found not an object."#,
             &[SourcePosition::new(8, 0, 8)],
-        ).into(),
+        )
+        .with_code(error_code::BAD_USER_INPUT)
+        .into(),
     );
 }
 
@@ -339,6 +342,7 @@ async fn variable_error_on_omit_non_null() {
             r#"Variable "$input" got invalid value. In field "c": Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -369,11 +373,13 @@ async fn variable_multiple_errors_with_nesting() {
             RuleError::new(
                 r#"Variable "$input" got invalid value. In field "na": In field "c": Expected "String!", found null."#,
                 &[SourcePosition::new(8, 0, 8)],
-            ),
+            )
+            .with_code(error_code::BAD_USER_INPUT),
             RuleError::new(
                 r#"Variable "$input" got invalid value. In field "nb": Expected "String!", found null."#,
                 &[SourcePosition::new(8, 0, 8)],
-            ),
+            )
+            .with_code(error_code::BAD_USER_INPUT),
         ].into(),
     );
 }
@@ -406,6 +412,7 @@ async fn variable_error_on_additional_field() {
             r#"Variable "$input" got invalid value. In field "extra": Unknown field."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -517,6 +524,7 @@ async fn does_not_allow_non_nullable_input_to_be_omitted_in_variable() {
             r#"Variable "$value" of required type "String!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -542,6 +550,7 @@ async fn does_not_allow_non_nullable_input_to_be_set_to_null_in_variable() {
             r#"Variable "$value" of required type "String!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -626,6 +635,7 @@ async fn does_not_allow_null_for_non_nullable_variable_with_default() {
             r#"Variable "$value" of required type "String!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -696,6 +706,7 @@ async fn does_not_allow_non_null_lists_to_be_null() {
             r#"Variable "$input" of required type "[String]!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -779,7 +790,9 @@ async fn does_not_allow_lists_of_non_null_to_contain_null() {
         RuleError::new(
             r#"Variable "$input" got invalid value. In element #1: Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
-        ).into(),
+        )
+        .with_code(error_code::BAD_USER_INPUT)
+        .into(),
     );
 }
 
@@ -803,7 +816,9 @@ async fn does_not_allow_non_null_lists_of_non_null_to_contain_null() {
         RuleError::new(
             r#"Variable "$input" got invalid value. In element #1: Expected "String!", found null."#,
             &[SourcePosition::new(8, 0, 8)],
-        ).into(),
+        )
+        .with_code(error_code::BAD_USER_INPUT)
+        .into(),
     );
 }
 
@@ -828,6 +843,7 @@ async fn does_not_allow_non_null_lists_of_non_null_to_be_null() {
             r#"Variable "$input" of required type "[String!]!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -1034,6 +1050,7 @@ async fn does_not_allow_missing_variable_for_required_field() {
             r#"Variable "$var" of required type "Int!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -1059,6 +1076,7 @@ async fn does_not_allow_null_variable_for_required_field() {
             r#"Variable "$var" of required type "Int!" was not provided."#,
             &[SourcePosition::new(8, 0, 8)],
         )
+        .with_code(error_code::BAD_USER_INPUT)
         .into(),
     );
 }
@@ -1162,6 +1180,7 @@ mod integers {
                  Got: `10`. Details: Expected `Int`, found: 10.",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_code(error_code::BAD_USER_INPUT)
             .into(),
         );
     }
@@ -1189,6 +1208,7 @@ mod integers {
                  Details: Expected `Int`, found: \"10\".",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_code(error_code::BAD_USER_INPUT)
             .into(),
         );
     }
@@ -1250,6 +1270,7 @@ mod floats {
                  Details: Expected `Float`, found: \"10\".",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_code(error_code::BAD_USER_INPUT)
             .into(),
         );
     }
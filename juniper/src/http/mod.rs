@@ -9,8 +9,8 @@ use serde::{
 };
 
 use crate::{
-    FieldError, GraphQLError, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync, RootNode,
-    Value, Variables,
+    FieldError, GraphQLError, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync, Resolved,
+    RootNode, Value, Variables,
     ast::InputValue,
     executor::{ExecutionError, ValuesStream},
     value::{DefaultScalarValue, ScalarValue},
@@ -157,6 +157,44 @@ where
     crate::resolve_into_stream(&req.query, op, root_node, &vars, context).await
 }
 
+/// Execute a GraphQL query or mutation, or resolve a GraphQL subscription into
+/// `Value<ValuesStream<S>>`, depending on the type of the requested operation, using the specified
+/// schema and context.
+/// This is a wrapper around the `execute_or_resolve_into_stream` function exposed at the top
+/// level of this crate.
+pub async fn execute_or_resolve_into_stream<
+    'req,
+    'rn,
+    'ctx,
+    'a,
+    QueryT,
+    MutationT,
+    SubscriptionT,
+    S,
+>(
+    req: &'req GraphQLRequest<S>,
+    root_node: &'rn RootNode<QueryT, MutationT, SubscriptionT, S>,
+    context: &'ctx QueryT::Context,
+) -> Result<Resolved<'a, S>, GraphQLError>
+where
+    'req: 'a,
+    'rn: 'a,
+    'ctx: 'a,
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let op = req.operation_name.as_deref();
+    let vars = req.variables();
+
+    crate::execute_or_resolve_into_stream(&req.query, op, root_node, &vars, context).await
+}
+
 /// Simple wrapper around the result from executing a GraphQL query
 ///
 /// This struct implements Serialize, so you can simply serialize this
@@ -788,6 +826,7 @@ pub mod tests {
                                 "line": 1,
                                 "column": 16,
                             }],
+                            "extensions": {"code": "GRAPHQL_VALIDATION_FAILED"},
                         }],
                     }),
                     WS_INTEGRATION_EXPECT_DEFAULT_TIMEOUT,
@@ -928,6 +967,7 @@ pub mod tests {
                                 "line": 1,
                                 "column": 16,
                             }],
+                            "extensions": {"code": "GRAPHQL_VALIDATION_FAILED"},
                         }],
                     }),
                     WS_INTEGRATION_EXPECT_DEFAULT_TIMEOUT,
@@ -28,6 +28,14 @@
 //! > - If you want only errors to have backtraces, set `RUST_LIB_BACKTRACE=1`;
 //! > - If you want only panics to have backtraces, set `RUST_BACKTRACE=1` and
 //! >   `RUST_LIB_BACKTRACE=0`.
+//!
+//! # Source
+//!
+//! The original error is kept as the [`FieldError::source()`], so may be [downcast][0] by loggers
+//! or an [`ErrorFormatter`].
+//!
+//! [`ErrorFormatter`]: crate::ErrorFormatter
+//! [0]: https://doc.rust-lang.org/std/error/trait.Error.html#method.downcast_ref
 
 use crate::{FieldError, IntoFieldError, ScalarValue, Value};
 
@@ -55,7 +63,7 @@ impl<S: ScalarValue> IntoFieldError<S> for anyhow::Error {
         #[cfg(not(any(nightly, feature = "backtrace")))]
         let extensions = Value::Null;
 
-        FieldError::new(self, extensions)
+        FieldError::new(&self, extensions).with_source(self)
     }
 }
 
@@ -67,8 +75,8 @@ mod test {
     use serial_test::serial;
 
     use crate::{
-        EmptyMutation, EmptySubscription, RootNode, execute, graphql, graphql_object,
-        parser::SourcePosition,
+        EmptyMutation, EmptySubscription, FieldError, IntoFieldError as _, RootNode, execute,
+        graphql, graphql_object, parser::SourcePosition,
     };
 
     #[tokio::test]
@@ -134,4 +142,25 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn keeps_source() {
+        #[derive(Debug, derive_more::Display, derive_more::Error)]
+        #[display("database is unavailable")]
+        struct DbError;
+
+        let err: FieldError = anyhow::Error::new(DbError)
+            .context("failed to load user")
+            .into_field_error();
+
+        assert_eq!(err.message(), "failed to load user");
+        let source = err.source().expect("no source");
+        assert_eq!(source.to_string(), "failed to load user");
+        assert!(
+            source
+                .source()
+                .is_some_and(|e| e.downcast_ref::<DbError>().is_some()),
+            "no `DbError` in the source chain: {source:?}",
+        );
+    }
 }
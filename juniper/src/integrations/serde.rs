@@ -10,6 +10,7 @@ use serde::{
 use crate::{
     DefaultScalarValue, GraphQLError, Object, Value,
     ast::{InputValue, OperationType},
+    error_code,
     executor::ExecutionError,
     parser::{ParseError, SourcePosition, Spanning},
     validation::RuleError,
@@ -43,44 +44,46 @@ impl Serialize for GraphQLError {
         #[derive(Serialize)]
         struct Helper {
             message: &'static str,
+            extensions: Extensions,
         }
 
-        match self {
-            Self::ParseError(e) => [e].serialize(ser),
-            Self::ValidationError(es) => es.serialize(ser),
-            Self::NoOperationProvided => [Helper {
-                message: "Must provide an operation",
-            }]
-            .serialize(ser),
-            Self::MultipleOperationsProvided => [Helper {
-                message: "Must provide operation name \
-                          if query contains multiple operations",
-            }]
-            .serialize(ser),
-            Self::UnknownOperationName => [Helper {
-                message: "Unknown operation",
-            }]
-            .serialize(ser),
-            Self::IsSubscription => [Helper {
-                message: "Expected query, got subscription",
-            }]
-            .serialize(ser),
-            Self::NotSubscription => [Helper {
-                message: "Expected subscription, got query",
-            }]
-            .serialize(ser),
-            Self::NotSupported(op) => [Helper {
-                message: match op {
-                    OperationType::Query => "Schema is not configured for queries",
-                    OperationType::Mutation => "Schema is not configured for mutations",
-                    OperationType::Subscription => "Schema is not configured for subscriptions",
-                },
-            }]
-            .serialize(ser),
-        }
+        let message = match self {
+            Self::ParseError(e) => return [e].serialize(ser),
+            Self::ValidationError(es) => return es.serialize(ser),
+            Self::NoOperationProvided => "Must provide an operation",
+            Self::MultipleOperationsProvided => {
+                "Must provide operation name \
+                 if query contains multiple operations"
+            }
+            Self::UnknownOperationName => "Unknown operation",
+            Self::IsSubscription => "Expected query, got subscription",
+            Self::NotSubscription => "Expected subscription, got query",
+            Self::NotSupported(op) => match op {
+                OperationType::Query => "Schema is not configured for queries",
+                OperationType::Mutation => "Schema is not configured for mutations",
+                OperationType::Subscription => "Schema is not configured for subscriptions",
+            },
+            // NOTE: Apollo clients rely on this exact message to fall back to sending the full
+            //       query.
+            Self::PersistedQueryNotFound => "PersistedQueryNotFound",
+            Self::Rejected { message, .. } => message,
+        };
+        [Helper {
+            message,
+            extensions: Extensions { code: self.code() },
+        }]
+        .serialize(ser)
     }
 }
 
+/// Extensions of the [`GraphQLError`]s, containing their stable machine-readable [code][0] only.
+///
+/// [0]: crate::error_code
+#[derive(Serialize)]
+struct Extensions {
+    code: &'static str,
+}
+
 impl<'de, S: Deserialize<'de>> Deserialize<'de> for InputValue<S> {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         struct Visitor<S: ?Sized>(PhantomData<S>);
@@ -219,7 +222,7 @@ impl<T: Serialize> Serialize for InputValue<T> {
 
 impl Serialize for RuleError {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
-        let mut map = ser.serialize_map(Some(2))?;
+        let mut map = ser.serialize_map(Some(3))?;
 
         map.serialize_key("message")?;
         map.serialize_value(self.message())?;
@@ -227,6 +230,9 @@ impl Serialize for RuleError {
         map.serialize_key("locations")?;
         map.serialize_value(self.locations())?;
 
+        map.serialize_key("extensions")?;
+        map.serialize_value(&Extensions { code: self.code() })?;
+
         map.end()
     }
 }
@@ -249,7 +255,7 @@ impl Serialize for SourcePosition {
 
 impl Serialize for Spanning<ParseError> {
     fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
-        let mut map = ser.serialize_map(Some(2))?;
+        let mut map = ser.serialize_map(Some(3))?;
 
         let msg = self.item.to_string();
         map.serialize_key("message")?;
@@ -263,6 +269,11 @@ impl Serialize for Spanning<ParseError> {
         map.serialize_key("locations")?;
         map.serialize_value(&locations)?;
 
+        map.serialize_key("extensions")?;
+        map.serialize_value(&Extensions {
+            code: error_code::GRAPHQL_PARSE_FAILED,
+        })?;
+
         map.end()
     }
 }
@@ -355,7 +366,11 @@ impl<'de> Deserialize<'de> for DefaultScalarValue {
 mod tests {
     use serde_json::{from_str, to_string};
 
-    use crate::{DefaultScalarValue, FieldError, InputValue, graphql};
+    use crate::{
+        DefaultScalarValue, FieldError, InputValue, error_code, graphql,
+        parser::{ParseError, SourcePosition, Spanning},
+        validation::RuleError,
+    };
 
     use super::{ExecutionError, GraphQLError};
 
@@ -384,7 +399,43 @@ mod tests {
     fn errors() {
         assert_eq!(
             to_string(&GraphQLError::UnknownOperationName).unwrap(),
-            r#"[{"message":"Unknown operation"}]"#,
+            r#"[{"message":"Unknown operation","extensions":{"code":"OPERATION_RESOLUTION_FAILURE"}}]"#,
+        );
+        assert_eq!(
+            to_string(&GraphQLError::PersistedQueryNotFound).unwrap(),
+            r#"[{"message":"PersistedQueryNotFound","extensions":{"code":"PERSISTED_QUERY_NOT_FOUND"}}]"#,
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            to_string(&GraphQLError::ParseError(Spanning::zero_width(
+                &SourcePosition::new(2, 0, 2),
+                ParseError::UnexpectedEndOfFile,
+            )))
+            .unwrap(),
+            r#"[{"message":"Unexpected end of input","locations":[{"line":1,"column":3}],"extensions":{"code":"GRAPHQL_PARSE_FAILED"}}]"#,
+        );
+    }
+
+    #[test]
+    fn validation_errors() {
+        assert_eq!(
+            to_string(&GraphQLError::ValidationError(vec![RuleError::new(
+                "Unknown field",
+                &[SourcePosition::new(2, 0, 2)],
+            )]))
+            .unwrap(),
+            r#"[{"message":"Unknown field","locations":[{"line":1,"column":3}],"extensions":{"code":"GRAPHQL_VALIDATION_FAILED"}}]"#,
+        );
+        assert_eq!(
+            to_string(&GraphQLError::ValidationError(vec![
+                RuleError::new("Invalid variable", &[SourcePosition::new(2, 0, 2)])
+                    .with_code(error_code::BAD_USER_INPUT),
+            ]))
+            .unwrap(),
+            r#"[{"message":"Invalid variable","locations":[{"line":1,"column":3}],"extensions":{"code":"BAD_USER_INPUT"}}]"#,
         );
     }
 
@@ -398,4 +449,17 @@ mod tests {
             r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[],"extensions":{"foo":"bar"}}"#,
         );
     }
+
+    #[test]
+    fn error_source_is_not_serialized() {
+        let err = "secret".parse::<i32>().unwrap_err();
+        assert_eq!(
+            to_string(&ExecutionError::at_origin(
+                FieldError::<DefaultScalarValue>::new("foo error", graphql::value!(null))
+                    .with_source(err),
+            ))
+            .unwrap(),
+            r#"{"message":"foo error","locations":[{"line":1,"column":1}],"path":[]}"#,
+        );
+    }
 }
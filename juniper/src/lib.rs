@@ -36,14 +36,17 @@ pub use arcstr::{self, ArcStr};
 // This allows users to just depend on juniper and get the derive
 // functionality automatically.
 pub use juniper_codegen::{
-    GraphQLEnum, GraphQLInputObject, GraphQLInterface, GraphQLObject, GraphQLScalar, GraphQLUnion,
-    graphql_interface, graphql_object, graphql_scalar, graphql_subscription, graphql_union,
+    GraphQLEnum, GraphQLErrorCode, GraphQLInputObject, GraphQLInterface, GraphQLMergedObject,
+    GraphQLObject, GraphQLScalar, GraphQLUnion, graphql_interface, graphql_object, graphql_scalar,
+    graphql_subscription, graphql_union,
 };
 
 #[doc(hidden)]
 #[macro_use]
 pub mod macros;
-mod ast;
+pub mod ast;
+#[cfg(feature = "build")]
+pub mod build;
 pub mod executor;
 #[cfg(test)]
 pub(crate) mod graphql;
@@ -51,6 +54,7 @@ mod introspection;
 pub mod parser;
 pub(crate) mod schema;
 mod types;
+pub mod usage;
 mod util;
 pub mod validation;
 mod value;
@@ -76,27 +80,29 @@ pub use crate::util::to_camel_case;
 use crate::{
     executor::{execute_validated_query, get_operation},
     introspection::{INTROSPECTION_QUERY, INTROSPECTION_QUERY_WITHOUT_DESCRIPTIONS},
-    parser::parse_document_source,
+    parser::parse_document_source_with_limits,
     validation::{
         MultiVisitorNil, ValidatorContext, rules, validate_input_values, visit as visit_rule,
         visit_all_rules,
     },
 };
 
+#[cfg(feature = "relay")]
+pub use crate::types::relay;
 pub use crate::{
     ast::{
         Definition, Document, FromInputValue, InputValue, IntoInputValue, Operation, OperationType,
-        Selection, ToInputValue, Type,
+        PrintStyle, Selection, ToInputValue, Type, print_document,
     },
     executor::{
-        Applies, Context, ExecutionError, ExecutionResult, Executor, FieldError, FieldResult,
-        FromContext, IntoFieldError, IntoResolvable, LookAheadArgument, LookAheadChildren,
-        LookAheadList, LookAheadObject, LookAheadSelection, LookAheadValue, OwnedExecutor,
-        Registry, ValuesStream, Variables,
+        Applies, CancellationToken, Context, ErrorFormatter, ExecutionError, ExecutionResult,
+        Executor, FieldError, FieldResult, FromContext, IntoFieldError, IntoResolvable,
+        LookAheadArgument, LookAheadChildren, LookAheadList, LookAheadObject, LookAheadSelection,
+        LookAheadValue, OwnedExecutor, Registry, SafeError, ValuesStream, Variables,
     },
     introspection::IntrospectionFormat,
     macros::helper::subscription::{ExtractTypeFromStream, IntoFieldResult},
-    parser::{ParseError, ScalarToken, Span, Spanning, StringLiteral},
+    parser::{ParseError, ParseLimits, ScalarToken, Span, Spanning, StringLiteral},
     schema::{
         meta,
         model::{RootNode, SchemaType},
@@ -104,6 +110,8 @@ pub use crate::{
     types::{
         async_await::{GraphQLTypeAsync, GraphQLValueAsync},
         base::{Arguments, GraphQLType, GraphQLValue, TypeKind},
+        error_code::{self, GraphQLErrorCode},
+        guard::{self, Guard},
         marker::{self, GraphQLInterface, GraphQLObject, GraphQLUnion},
         nullable::Nullable,
         scalars::{EmptyMutation, EmptySubscription, ID},
@@ -111,6 +119,7 @@ pub use crate::{
             ExecutionOutput, GraphQLSubscriptionType, GraphQLSubscriptionValue,
             SubscriptionConnection, SubscriptionCoordinator,
         },
+        validator::{self, Validatable},
     },
     validation::RuleError,
     value::{
@@ -123,6 +132,7 @@ pub use crate::{
 /// An error that prevented query execution
 #[expect(missing_docs, reason = "self-explanatory")]
 #[derive(Clone, Debug, Display, Eq, From, PartialEq)]
+#[non_exhaustive]
 pub enum GraphQLError {
     ParseError(Spanning<ParseError>),
     #[display("{}", _0.iter().format("\n"))]
@@ -149,6 +159,52 @@ pub enum GraphQLError {
         OperationType::Subscription => "subscriptions",
     })]
     NotSupported(OperationType),
+    /// The requested persisted query is unknown to the server.
+    ///
+    /// Not returned by [`juniper`](crate) itself, but may be returned by integrations resolving
+    /// queries by their identifiers or hashes (like [automatic persisted queries][0]), so clients
+    /// are able to fall back to sending the full query.
+    ///
+    /// [0]: https://www.apollographql.com/docs/apollo-server/performance/apq
+    #[display("Persisted query not found")]
+    PersistedQueryNotFound,
+    /// The operation has been rejected by the server before being executed (like because of a
+    /// rate limit being exceeded, or a protocol feature being disabled).
+    ///
+    /// Not returned by [`juniper`](crate) itself, but may be returned by integrations throttling
+    /// clients (see [`error_code::RATE_LIMITED`]).
+    #[display("{message}")]
+    #[from(skip)]
+    Rejected {
+        /// Human-readable message describing why the operation has been rejected.
+        message: &'static str,
+
+        /// Stable machine-readable [code](error_code) of the rejection.
+        code: &'static str,
+    },
+}
+
+impl GraphQLError {
+    /// Returns the stable machine-readable [code][0] of this [`GraphQLError`].
+    ///
+    /// [0]: error_code
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ParseError(_) => error_code::GRAPHQL_PARSE_FAILED,
+            Self::ValidationError(errs) => errs
+                .first()
+                .map_or(error_code::GRAPHQL_VALIDATION_FAILED, RuleError::code),
+            Self::NoOperationProvided
+            | Self::MultipleOperationsProvided
+            | Self::UnknownOperationName
+            | Self::IsSubscription
+            | Self::NotSubscription
+            | Self::NotSupported(_) => error_code::OPERATION_RESOLUTION_FAILURE,
+            Self::PersistedQueryNotFound => error_code::PERSISTED_QUERY_NOT_FOUND,
+            Self::Rejected { code, .. } => code,
+        }
+    }
 }
 
 impl From<RuleError> for GraphQLError {
@@ -167,11 +223,22 @@ impl std::error::Error for GraphQLError {
             | Self::UnknownOperationName
             | Self::IsSubscription
             | Self::NotSubscription
-            | Self::NotSupported(_) => None,
+            | Self::NotSupported(_)
+            | Self::PersistedQueryNotFound
+            | Self::Rejected { .. } => None,
         }
     }
 }
 
+/// Outcome of [`execute_or_resolve_into_stream()`], depending on the type of the operation.
+pub enum Resolved<'a, S> {
+    /// Result of executing a query or a mutation.
+    Executed(Value<S>, Vec<ExecutionError<S>>),
+
+    /// Result of resolving a subscription into `ValuesStream`.
+    Subscribed(Value<ValuesStream<'a, S>>, Vec<ExecutionError<S>>),
+}
+
 /// Execute a query synchronously in a provided schema
 pub fn execute_sync<'a, S, QueryT, MutationT, SubscriptionT>(
     document_source: &'a str,
@@ -186,35 +253,9 @@ where
     MutationT: GraphQLType<S, Context = QueryT::Context>,
     SubscriptionT: GraphQLType<S, Context = QueryT::Context>,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-        if root_node.introspection_disabled {
-            visit_rule(
-                &mut MultiVisitorNil.with(rules::disable_introspection::factory()),
-                &mut ctx,
-                &document,
-            );
-        }
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(errors.into());
-        }
-    }
-
+    let document = parse_and_validate(document_source, operation_name, root_node, variables)?;
     let operation = get_operation(&document, operation_name)?;
 
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(errors.into());
-        }
-    }
-
     execute_validated_query(&document, operation, root_node, variables, context)
 }
 
@@ -236,35 +277,9 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document = parse_document_source(document_source, &root_node.schema)?;
-
-    {
-        let mut ctx = ValidatorContext::new(&root_node.schema, &document);
-        visit_all_rules(&mut ctx, &document);
-        if root_node.introspection_disabled {
-            visit_rule(
-                &mut MultiVisitorNil.with(rules::disable_introspection::factory()),
-                &mut ctx,
-                &document,
-            );
-        }
-
-        let errors = ctx.into_errors();
-        if !errors.is_empty() {
-            return Err(errors.into());
-        }
-    }
-
+    let document = parse_and_validate(document_source, operation_name, root_node, variables)?;
     let operation = get_operation(&document, operation_name)?;
 
-    {
-        let errors = validate_input_values(variables, operation, &root_node.schema);
-
-        if !errors.is_empty() {
-            return Err(errors.into());
-        }
-    }
-
     executor::execute_validated_query_async(&document, operation, root_node, variables, context)
         .await
 }
@@ -287,8 +302,70 @@ where
     SubscriptionT::TypeInfo: Sync,
     S: ScalarValue + Send + Sync,
 {
-    let document: ast::OwnedDocument<'a, S> =
-        parse_document_source(document_source, &root_node.schema)?;
+    let document = parse_and_validate(document_source, operation_name, root_node, variables)?;
+    let operation = get_operation(&document, operation_name)?;
+
+    executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
+        .await
+}
+
+/// Executes a query or a mutation in a provided schema, or resolves a subscription into
+/// `ValuesStream`, depending on the type of the operation.
+///
+/// Unlike trying [`execute()`] first and falling back to [`resolve_into_stream()`] on
+/// [`GraphQLError::IsSubscription`], parses and validates the document only once.
+pub async fn execute_or_resolve_into_stream<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &'a RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+    context: &'a QueryT::Context,
+) -> Result<Resolved<'a, S>, GraphQLError>
+where
+    QueryT: GraphQLTypeAsync<S>,
+    QueryT::TypeInfo: Sync,
+    QueryT::Context: Sync,
+    MutationT: GraphQLTypeAsync<S, Context = QueryT::Context>,
+    MutationT::TypeInfo: Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = QueryT::Context>,
+    SubscriptionT::TypeInfo: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    let document = parse_and_validate(document_source, operation_name, root_node, variables)?;
+    let operation = get_operation(&document, operation_name)?;
+
+    if operation.item.operation_type == OperationType::Subscription {
+        executor::resolve_validated_subscription(
+            &document, operation, root_node, variables, context,
+        )
+        .await
+        .map(|(value, errors)| Resolved::Subscribed(value, errors))
+    } else {
+        executor::execute_validated_query_async(&document, operation, root_node, variables, context)
+            .await
+            .map(|(value, errors)| Resolved::Executed(value, errors))
+    }
+}
+
+/// Parses the provided `document_source` and validates it, along with the `variables` of the
+/// operation to be executed, against the schema of the provided `root_node`.
+fn parse_and_validate<'a, S, QueryT, MutationT, SubscriptionT>(
+    document_source: &'a str,
+    operation_name: Option<&str>,
+    root_node: &RootNode<QueryT, MutationT, SubscriptionT, S>,
+    variables: &Variables<S>,
+) -> Result<ast::OwnedDocument<'a, S>, GraphQLError>
+where
+    S: ScalarValue,
+    QueryT: GraphQLType<S>,
+    MutationT: GraphQLType<S>,
+    SubscriptionT: GraphQLType<S>,
+{
+    let document = parse_document_source_with_limits(
+        document_source,
+        &root_node.schema,
+        root_node.parse_limits,
+    )?;
 
     {
         let mut ctx = ValidatorContext::new(&root_node.schema, &document);
@@ -317,8 +394,7 @@ where
         }
     }
 
-    executor::resolve_validated_subscription(&document, operation, root_node, variables, context)
-        .await
+    Ok(document)
 }
 
 /// Executes the [canonical introspection query][0] in the provided schema.
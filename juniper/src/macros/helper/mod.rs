@@ -7,7 +7,11 @@ use std::convert::Infallible;
 use derive_more::with_trait::Display;
 use futures::future::{self, BoxFuture};
 
-use crate::{FieldError, InputValue, ScalarValue, ToScalarValue};
+use crate::{
+    Executor, FieldError, GraphQLType, InputValue, Registry, ScalarValue, Selection, ToScalarValue,
+    meta::{Argument, Field, MetaType},
+    types::base::is_excluded,
+};
 
 /// This trait is used by [`graphql_scalar`] macro to retrieve [`Error`] type from a [`Result`].
 ///
@@ -54,6 +58,109 @@ where
     Box::pin(future::err(err_unnamed_type(name)))
 }
 
+/// Indicates whether the provided `selection_set` selects a [GraphQL field][1] with the provided
+/// `name`, either directly or via the nested fragments, ignoring the ones excluded by `@skip` and
+/// `@include` directives.
+///
+/// Used for checking [`Guard`]s of [GraphQL interface][2] fields, selected via fragments on its
+/// implementers.
+///
+/// [`Guard`]: crate::Guard
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+/// [2]: https://spec.graphql.org/October2021#sec-Interfaces
+pub fn selects_field<CtxT, S>(
+    selection_set: Option<&[Selection<'_, S>]>,
+    name: &str,
+    executor: &Executor<'_, '_, CtxT, S>,
+) -> bool
+where
+    S: ScalarValue,
+{
+    let vars = executor.variables();
+    selection_set
+        .into_iter()
+        .flatten()
+        .any(|selection| match selection {
+            Selection::Field(f) => {
+                f.item.name.item == name && !is_excluded(&f.item.directives, vars)
+            }
+            Selection::FragmentSpread(spread) => {
+                !is_excluded(&spread.item.directives, vars)
+                    && executor
+                        .fragment_by_name(spread.item.name.item)
+                        .is_some_and(|f| selects_field(Some(&f.selection_set), name, executor))
+            }
+            Selection::InlineFragment(fragment) => {
+                !is_excluded(&fragment.item.directives, vars)
+                    && selects_field(Some(&fragment.item.selection_set), name, executor)
+            }
+        })
+}
+
+/// Returns the [`Field`]s of the [GraphQL object][1] represented by the provided `T` type, to be
+/// spliced into another [GraphQL object][1] by the `#[graphql(flatten)]` attribute argument.
+///
+/// # Panics
+///
+/// If the provided `T` type doesn't represent a [GraphQL object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub fn flattened_fields<T, S>(info: &T::TypeInfo, registry: &mut Registry<S>) -> Vec<Field<S>>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue,
+{
+    match T::meta(info, registry) {
+        MetaType::Object(meta) => meta
+            .fields
+            .into_iter()
+            .filter(|f| !f.name.starts_with("__"))
+            .collect(),
+        meta => panic!(
+            "Only GraphQL objects can be flattened into a GraphQL object, but `{}` is not",
+            meta.name().map_or("", |n| n.as_str()),
+        ),
+    }
+}
+
+/// Returns the [`Argument`]s of the [GraphQL input object][1] represented by the provided `T`
+/// type, to be spliced into another [GraphQL input object][1] by the `#[graphql(flatten)]`
+/// attribute argument.
+///
+/// # Panics
+///
+/// If the provided `T` type doesn't represent a [GraphQL input object][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+pub fn flattened_input_fields<T, S>(
+    info: &T::TypeInfo,
+    registry: &mut Registry<S>,
+) -> Vec<Argument<S>>
+where
+    T: GraphQLType<S> + ?Sized,
+    S: ScalarValue,
+{
+    match T::meta(info, registry) {
+        MetaType::InputObject(meta) => meta.input_fields,
+        meta => panic!(
+            "Only GraphQL input objects can be flattened into a GraphQL input object, but `{}` is \
+             not",
+            meta.name().map_or("", |n| n.as_str()),
+        ),
+    }
+}
+
+/// Returns the fields of the provided [`InputValue`] object, to be spliced into another one by the
+/// `#[graphql(flatten)]` attribute argument.
+///
+/// Returns no fields if the provided [`InputValue`] is not an object.
+pub fn flattened_input_value<S>(value: InputValue<S>) -> Vec<(String, InputValue<S>)> {
+    match value {
+        InputValue::Object(fields) => fields.into_iter().map(|(k, v)| (k.item, v.item)).collect(),
+        _ => vec![],
+    }
+}
+
 /// Error of an [`InputValue`] not representing a [`ScalarValue`], used in macro expansions.
 #[derive(Display)]
 #[display("Expected GraphQL scalar, found: {_0}")]
@@ -439,6 +439,13 @@ pub const fn can_be_subtype(ty: WrappedValue, subtype: WrappedValue) -> bool {
     }
 }
 
+/// Strips the given [`WrappedValue`] from its outermost non-`null` modifier, if any, basing on the
+/// [`WrappedType`] encoding.
+#[must_use]
+pub const fn nullable(val: WrappedValue) -> WrappedValue {
+    if val % 10 == 2 { val } else { val * 10 + 2 }
+}
+
 /// Checks whether the given `val` exists in the given `arr`.
 #[must_use]
 pub const fn str_exists_in_arr(val: &str, arr: &[&str]) -> bool {
@@ -1001,6 +1008,66 @@ macro_rules! assert_input_field_deprecable {
     };
 }
 
+/// Statically asserts that the `$own` fields [`Names`] of the provided `$ty` don't collide with
+/// the [`Fields`] spliced into it from the `$flattened` types (via `#[graphql(flatten)]` attribute
+/// argument or `#[derive(GraphQLMergedObject)]` macro).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_flattened_fields {
+    ($ty: ty, $scalar: ty, $own: expr $(, $flattened: ty)+ $(,)?) => {
+        const _: () = {
+            const TY_NAME: &::core::primitive::str =
+                <$ty as $crate::macros::reflect::BaseType<$scalar>>::NAME;
+
+            const DUPLICATE: ::core::option::Option<$crate::macros::reflect::Name> =
+                $crate::macros::reflect::find_duplicate_name(&[
+                    $own,
+                    $( <$flattened as $crate::macros::reflect::Fields<$scalar>>::NAMES, )+
+                ]);
+
+            if DUPLICATE.is_some() {
+                const FIELD_NAME: &::core::primitive::str = match DUPLICATE {
+                    ::core::option::Option::Some(name) => name,
+                    ::core::option::Option::None => "",
+                };
+                const ERROR_MSG: &::core::primitive::str = $crate::const_concat!(
+                    "field `",
+                    FIELD_NAME,
+                    "` of `",
+                    TY_NAME,
+                    "` is defined more than once across its own, flattened or merged fields",
+                );
+                ::core::panic!("{}", ERROR_MSG);
+            }
+        };
+    };
+}
+
+/// Finds the first [`Name`] occurring more than once across all the provided [`Names`].
+#[must_use]
+pub const fn find_duplicate_name(names: &[Names]) -> Option<Name> {
+    let mut i = 0;
+    while i < names.len() {
+        let mut n = 0;
+        while n < names[i].len() {
+            let name = names[i][n];
+            if str_exists_in_arr(name, names[i].split_at(n + 1).1) {
+                return Some(name);
+            }
+            let mut j = i + 1;
+            while j < names.len() {
+                if str_exists_in_arr(name, names[j]) {
+                    return Some(name);
+                }
+                j += 1;
+            }
+            n += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
 /// Ensures that the given `$impl_ty` has the specified [`Field`], otherwise panics with an
 /// understandable message.
 #[macro_export]
@@ -1066,6 +1133,151 @@ pub const fn const_concat_into_bytes<const LEN: usize>(input: &[&str]) -> [u8; L
     bytes
 }
 
+/// Concatenates `const` [`str`]s in a `const` context depending on generic parameters (like an
+/// associated `const` of a generic type), where [`const_concat!`] cannot be used.
+///
+/// The result cannot exceed [`CONCAT_GENERIC_CAPACITY`] bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use juniper::{
+/// #     DefaultScalarValue, const_concat_generic,
+/// #     macros::reflect::{BaseType, Type},
+/// # };
+/// #
+/// struct Page<T>(T);
+///
+/// impl<S, T: BaseType<S>> BaseType<S> for Page<T> {
+///     const NAME: Type = const_concat_generic!(T::NAME, "Page");
+/// }
+///
+/// assert_eq!(<Page<i32> as BaseType<DefaultScalarValue>>::NAME, "IntPage");
+/// ```
+///
+/// [`str`]: prim@str
+#[macro_export]
+macro_rules! const_concat_generic {
+    ($($s:expr),* $(,)?) => {
+        $crate::macros::reflect::const_concat_buf_as_str(
+            &$crate::macros::reflect::const_concat_into_buf(&[$($s),*]),
+        )
+    };
+}
+
+/// Maximum length (in bytes) of a [`str`] concatenated via [`const_concat_generic!`] macro.
+///
+/// [`str`]: prim@str
+pub const CONCAT_GENERIC_CAPACITY: usize = 256;
+
+/// Bytes buffer of a [`str`] concatenated via [`const_concat_generic!`] macro, along with its
+/// actual length.
+///
+/// [`str`]: prim@str
+pub type ConcatBuf = ([u8; CONCAT_GENERIC_CAPACITY], usize);
+
+/// Concatenates the provided [`str`]s into a [`ConcatBuf`].
+///
+/// # Panics
+///
+/// If the provided [`str`]s don't fit into [`CONCAT_GENERIC_CAPACITY`] bytes.
+///
+/// [`str`]: prim@str
+#[must_use]
+pub const fn const_concat_into_buf(input: &[&str]) -> ConcatBuf {
+    let mut bytes = [0; CONCAT_GENERIC_CAPACITY];
+    let (mut i, mut byte) = (0, 0);
+    while i < input.len() {
+        let mut b = 0;
+        let string_bytes = input[i].as_bytes();
+        assert!(
+            byte + string_bytes.len() <= CONCAT_GENERIC_CAPACITY,
+            "concatenated `str` exceeds `CONCAT_GENERIC_CAPACITY`",
+        );
+        while b < string_bytes.len() {
+            bytes[byte] = string_bytes[b];
+            byte += 1;
+            b += 1;
+        }
+        i += 1;
+    }
+    (bytes, byte)
+}
+
+/// Returns the [`str`] concatenated into the provided [`ConcatBuf`].
+///
+/// [`str`]: prim@str
+#[must_use]
+pub const fn const_concat_buf_as_str(buf: &'static ConcatBuf) -> &'static str {
+    let (bytes, _) = buf.0.split_at(buf.1);
+    // TODO: Use `.unwrap()` once it becomes `const`.
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => unreachable!(),
+    }
+}
+
+/// Concatenates `const` [`Names`] in a `const` context, including the one depending on generic
+/// parameters (like [`Fields::NAMES`] of a generic type).
+///
+/// The result cannot exceed [`CONCAT_NAMES_CAPACITY`] names.
+///
+/// # Examples
+///
+/// ```rust
+/// # use juniper::{const_concat_names, macros::reflect::Names};
+/// #
+/// const NAMES: Names = const_concat_names!(&["id", "name"], &["email"]);
+///
+/// assert_eq!(NAMES, ["id", "name", "email"]);
+/// ```
+#[macro_export]
+macro_rules! const_concat_names {
+    ($($names:expr),* $(,)?) => {
+        $crate::macros::reflect::const_concat_names_buf_as_slice(
+            &$crate::macros::reflect::const_concat_names_into_buf(&[$($names),*]),
+        )
+    };
+}
+
+/// Maximum number of [`Names`] concatenated via [`const_concat_names!`] macro.
+pub const CONCAT_NAMES_CAPACITY: usize = 256;
+
+/// Buffer of [`Names`] concatenated via [`const_concat_names!`] macro, along with their actual
+/// number.
+pub type ConcatNamesBuf = ([Name; CONCAT_NAMES_CAPACITY], usize);
+
+/// Concatenates the provided [`Names`] into a [`ConcatNamesBuf`].
+///
+/// # Panics
+///
+/// If the provided [`Names`] don't fit into [`CONCAT_NAMES_CAPACITY`].
+#[must_use]
+pub const fn const_concat_names_into_buf(input: &[Names]) -> ConcatNamesBuf {
+    let mut names = [""; CONCAT_NAMES_CAPACITY];
+    let (mut i, mut len) = (0, 0);
+    while i < input.len() {
+        assert!(
+            len + input[i].len() <= CONCAT_NAMES_CAPACITY,
+            "concatenated `Names` exceed `CONCAT_NAMES_CAPACITY`",
+        );
+        let mut n = 0;
+        while n < input[i].len() {
+            names[len] = input[i][n];
+            len += 1;
+            n += 1;
+        }
+        i += 1;
+    }
+    (names, len)
+}
+
+/// Returns the [`Names`] concatenated into the provided [`ConcatNamesBuf`].
+#[must_use]
+pub const fn const_concat_names_buf_as_slice(buf: &'static ConcatNamesBuf) -> Names {
+    buf.0.split_at(buf.1).0
+}
+
 /// Formats the provided [`Type`] and [`WrappedValue`] into a readable GraphQL type name.
 ///
 /// # Examples
@@ -7,8 +7,8 @@ use crate::{
         VariablesDefinition,
     },
     parser::{
-        Lexer, OptionParseResult, ParseError, ParseResult, Parser, ScalarToken, Spanning, Token,
-        UnlocatedParseResult, value::parse_value_literal,
+        Lexer, OptionParseResult, ParseError, ParseLimits, ParseResult, Parser, ScalarToken,
+        Spanning, Token, UnlocatedParseResult, value::parse_value_literal,
     },
     schema::{
         meta::{Argument, Field as MetaField},
@@ -27,12 +27,89 @@ where
 {
     let mut lexer = Lexer::new(s);
     let mut parser = Parser::new(&mut lexer).map_err(|s| s.map(Into::into))?;
+    parse_document(&mut parser, Some(schema))
+}
+
+/// Parses the provided GraphQL document source, enforcing the provided [`ParseLimits`].
+///
+/// # Errors
+///
+/// If the source is not a valid GraphQL document, or exceeds the provided [`ParseLimits`].
+#[doc(hidden)]
+pub fn parse_document_source_with_limits<'a, S>(
+    s: &'a str,
+    schema: &SchemaType<S>,
+    limits: ParseLimits,
+) -> UnlocatedParseResult<OwnedDocument<'a, S>>
+where
+    S: ScalarValue,
+{
+    parse_limited_document_source(s, Some(schema), limits)
+}
+
+/// Parses the provided GraphQL executable document source without any [`SchemaType`], enforcing
+/// the provided [`ParseLimits`].
+///
+/// As no types are known, scalar literals are parsed as they're written (`Int`, `Float`, `String`
+/// or `Boolean` ones, with `Int` ones out of `i32` range being rejected), without coercing them
+/// to the types expected by the schema. This is enough for tooling (linters, query registries,
+/// usage collectors) inspecting operations, but not for executing them.
+///
+/// # Errors
+///
+/// If the source is not a valid GraphQL document, or exceeds the provided [`ParseLimits`].
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     DefaultScalarValue, ParseLimits, ast::{Definition, Selection},
+/// #     parser::parse_document_source_without_schema,
+/// # };
+/// #
+/// let document = parse_document_source_without_schema::<DefaultScalarValue>(
+///     "query Hero { hero(episode: EMPIRE) { name } }",
+///     ParseLimits::default(),
+/// )
+/// .unwrap();
+///
+/// let Definition::Operation(op) = &document[0] else { unreachable!() };
+/// let Selection::Field(hero) = &op.item.selection_set[0] else { unreachable!() };
+/// assert_eq!(hero.item.name.item, "hero");
+/// ```
+pub fn parse_document_source_without_schema<S>(
+    s: &str,
+    limits: ParseLimits,
+) -> UnlocatedParseResult<OwnedDocument<'_, S>>
+where
+    S: ScalarValue,
+{
+    parse_limited_document_source(s, None, limits)
+}
+
+fn parse_limited_document_source<'a, S>(
+    s: &'a str,
+    schema: Option<&SchemaType<S>>,
+    limits: ParseLimits,
+) -> UnlocatedParseResult<OwnedDocument<'a, S>>
+where
+    S: ScalarValue,
+{
+    if let Some(max) = limits.max_source_length {
+        if s.len() > max {
+            return Err(Spanning::unlocated(ParseError::SourceLengthLimitExceeded(
+                max,
+            )));
+        }
+    }
+    let mut lexer = Lexer::new(s);
+    let mut parser = Parser::with_limits(&mut lexer, limits)?;
     parse_document(&mut parser, schema)
 }
 
 fn parse_document<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> UnlocatedParseResult<OwnedDocument<'a, S>>
 where
     S: ScalarValue,
@@ -40,6 +117,14 @@ where
     let mut defs = Vec::new();
 
     loop {
+        if let Some(max) = parser.limits().max_definitions {
+            if defs.len() >= max {
+                return Err(Spanning::new(
+                    parser.peek().span,
+                    ParseError::DefinitionLimitExceeded(max),
+                ));
+            }
+        }
         defs.push(parse_definition(parser, schema)?);
 
         if parser.peek().item == Token::EndOfFile {
@@ -50,7 +135,7 @@ where
 
 fn parse_definition<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> UnlocatedParseResult<Definition<'a, S>>
 where
     S: ScalarValue,
@@ -78,13 +163,13 @@ where
 
 fn parse_operation_definition<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> ParseResult<Operation<'a, S>>
 where
     S: ScalarValue,
 {
     if parser.peek().item == Token::CurlyOpen {
-        let fields = schema.concrete_query_type().fields(schema);
+        let fields = schema.and_then(|s| s.concrete_query_type().fields(s));
         let fields = fields.as_ref().map(|c| c as &[_]);
         let selection_set = parse_selection_set(parser, schema, fields)?;
 
@@ -102,12 +187,14 @@ where
     } else {
         let start_pos = parser.peek().span.start;
         let operation_type = parse_operation_type(parser)?;
-        let op = match operation_type.item {
-            OperationType::Query => Some(schema.concrete_query_type()),
-            OperationType::Mutation => schema.concrete_mutation_type(),
-            OperationType::Subscription => schema.concrete_subscription_type(),
-        };
-        let fields = op.and_then(|m| m.fields(schema));
+        let fields = schema.and_then(|s| {
+            match operation_type.item {
+                OperationType::Query => Some(s.concrete_query_type()),
+                OperationType::Mutation => s.concrete_mutation_type(),
+                OperationType::Subscription => s.concrete_subscription_type(),
+            }
+            .and_then(|m| m.fields(s))
+        });
         let fields = fields.as_ref().map(|c| c as &[_]);
 
         let name = match parser.peek().item {
@@ -135,7 +222,7 @@ where
 
 fn parse_fragment_definition<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> ParseResult<Fragment<'a, S>>
 where
     S: ScalarValue,
@@ -155,9 +242,10 @@ where
     parser.expect(&Token::Name("on"))?;
     let type_cond = parser.expect_name()?;
 
-    let fields = schema
-        .concrete_type_by_name(type_cond.item)
-        .and_then(|m| m.fields(schema));
+    let fields = schema.and_then(|s| {
+        s.concrete_type_by_name(type_cond.item)
+            .and_then(|m| m.fields(s))
+    });
     let fields = fields.as_ref().map(|c| c as &[_]);
 
     let directives = parse_directives(parser, schema)?;
@@ -178,7 +266,7 @@ where
 
 fn parse_optional_selection_set<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     fields: Option<&[&MetaField<S>]>,
 ) -> OptionParseResult<Vec<Selection<'a, S>>>
 where
@@ -193,22 +281,24 @@ where
 
 fn parse_selection_set<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     fields: Option<&[&MetaField<S>]>,
 ) -> ParseResult<Vec<Selection<'a, S>>>
 where
     S: ScalarValue,
 {
-    parser.unlocated_delimited_nonempty_list(
-        &Token::CurlyOpen,
-        |p| parse_selection(p, schema, fields),
-        &Token::CurlyClose,
-    )
+    parser.nested(|p| {
+        p.unlocated_delimited_nonempty_list(
+            &Token::CurlyOpen,
+            |p| parse_selection(p, schema, fields),
+            &Token::CurlyClose,
+        )
+    })
 }
 
 fn parse_selection<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     fields: Option<&[&MetaField<S>]>,
 ) -> UnlocatedParseResult<Selection<'a, S>>
 where
@@ -222,7 +312,7 @@ where
 
 fn parse_fragment<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     fields: Option<&[&MetaField<S>]>,
 ) -> UnlocatedParseResult<Selection<'a, S>>
 where
@@ -235,9 +325,8 @@ where
             parser.next_token()?;
             let name = parser.expect_name()?;
 
-            let fields = schema
-                .concrete_type_by_name(name.item)
-                .and_then(|m| m.fields(schema));
+            let fields =
+                schema.and_then(|s| s.concrete_type_by_name(name.item).and_then(|m| m.fields(s)));
             let fields = fields.as_ref().map(|c| c as &[_]);
             let directives = parse_directives(parser, schema)?;
             let selection_set = parse_selection_set(parser, schema, fields)?;
@@ -301,7 +390,7 @@ where
 
 fn parse_field<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     fields: Option<&[&MetaField<S>]>,
 ) -> ParseResult<Field<'a, S>>
 where
@@ -321,9 +410,8 @@ where
         .and_then(|f| f.arguments.as_ref().map(|a| a as &[_]));
 
     let fields = field
-        .as_ref()
-        .and_then(|f| schema.lookup_type(&f.field_type))
-        .and_then(|m| m.fields(schema));
+        .zip(schema)
+        .and_then(|(f, s)| s.lookup_type(&f.field_type).and_then(|m| m.fields(s)));
     let fields = fields.as_ref().map(|c| c as &[_]);
 
     let arguments = parse_arguments(parser, schema, args)?;
@@ -352,7 +440,7 @@ where
 
 fn parse_arguments<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     arguments: Option<&[Argument<S>]>,
 ) -> OptionParseResult<Arguments<'a, S>>
 where
@@ -377,7 +465,7 @@ where
 
 fn parse_argument<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     arguments: Option<&[Argument<S>]>,
 ) -> ParseResult<(Spanning<&'a str>, Spanning<InputValue<S>>)>
 where
@@ -386,7 +474,8 @@ where
     let name = parser.expect_name()?;
     let tpe = arguments
         .and_then(|args| args.iter().find(|a| a.name == name.item))
-        .and_then(|arg| schema.lookup_type(&arg.arg_type));
+        .zip(schema)
+        .and_then(|(arg, s)| s.lookup_type(&arg.arg_type));
 
     parser.expect(&Token::Colon)?;
     let value = parse_value_literal(parser, false, schema, tpe)?;
@@ -411,7 +500,7 @@ fn parse_operation_type(parser: &mut Parser<'_>) -> ParseResult<OperationType> {
 
 fn parse_variables_definition<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> OptionParseResult<VariablesDefinition<'a, S>>
 where
     S: ScalarValue,
@@ -435,7 +524,7 @@ where
 
 fn parse_variable_definition<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> ParseResult<(Spanning<&'a str>, VariableDefinition<'a, S>)>
 where
     S: ScalarValue,
@@ -446,7 +535,7 @@ where
     let var_name = parser.expect_name()?;
     parser.expect(&Token::Colon)?;
     let var_type = parse_type(parser)?;
-    let tpe = schema.lookup_type(&var_type.item);
+    let tpe = schema.and_then(|s| s.lookup_type(&var_type.item));
 
     let default_value = if parser.skip(&Token::Equals)?.is_some() {
         Some(parse_value_literal(parser, true, schema, tpe)?)
@@ -491,7 +580,7 @@ fn parse_description<'a>(parser: &mut Parser<'a>) -> OptionParseResult<Cow<'a, s
 
 fn parse_directives<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> OptionParseResult<Vec<Spanning<Directive<'a, S>>>>
 where
     S: ScalarValue,
@@ -510,7 +599,7 @@ where
 
 fn parse_directive<'a, S>(
     parser: &mut Parser<'a>,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> ParseResult<Directive<'a, S>>
 where
     S: ScalarValue,
@@ -518,7 +607,7 @@ where
     let start_pos = parser.expect(&Token::At)?.span.start;
     let name = parser.expect_name()?;
 
-    let directive = schema.directive_by_name(name.item);
+    let directive = schema.and_then(|s| s.directive_by_name(name.item));
 
     let arguments = parse_arguments(
         parser,
@@ -541,7 +630,7 @@ pub fn parse_type<'a>(parser: &mut Parser<'a>) -> ParseResult<Type<&'a str>> {
         span: start_span, ..
     }) = parser.skip(&Token::BracketOpen)?
     {
-        let inner_type = parse_type(parser)?;
+        let inner_type = parser.nested(parse_type)?;
         let end_pos = parser.expect(&Token::BracketClose)?.span.end;
         Spanning::start_end(&start_span.start, &end_pos, inner_type.item.wrap_list(None))
     } else {
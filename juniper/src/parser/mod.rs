@@ -10,11 +10,15 @@ mod value;
 #[cfg(test)]
 mod tests;
 
-pub use self::document::parse_document_source;
+pub use self::document::{
+    parse_document_source, parse_document_source_with_limits, parse_document_source_without_schema,
+};
 
 pub(crate) use self::lexer::UnicodeCodePoint;
 pub use self::{
     lexer::{Lexer, LexerError, ScalarToken, StringLiteral, Token},
-    parser::{OptionParseResult, ParseError, ParseResult, Parser, UnlocatedParseResult},
+    parser::{
+        OptionParseResult, ParseError, ParseLimits, ParseResult, Parser, UnlocatedParseResult,
+    },
     utils::{SourcePosition, Span, Spanning},
 };
@@ -26,6 +26,22 @@ pub enum ParseError {
 
     /// A scalar of unexpected type occurred in the source
     ExpectedScalarError(#[error(not(source))] &'static str),
+
+    /// The source exceeds the [`ParseLimits::max_source_length`].
+    #[display("Document exceeds the maximum length of {_0} bytes")]
+    SourceLengthLimitExceeded(#[error(not(source))] usize),
+
+    /// The source exceeds the [`ParseLimits::max_tokens`].
+    #[display("Document exceeds the maximum number of {_0} tokens")]
+    TokenLimitExceeded(#[error(not(source))] usize),
+
+    /// The source exceeds the [`ParseLimits::max_depth`].
+    #[display("Document exceeds the maximum nesting depth of {_0}")]
+    DepthLimitExceeded(#[error(not(source))] usize),
+
+    /// The source exceeds the [`ParseLimits::max_definitions`].
+    #[display("Document exceeds the maximum number of {_0} definitions")]
+    DefinitionLimitExceeded(#[error(not(source))] usize),
 }
 
 impl ParseError {
@@ -45,10 +61,90 @@ pub type UnlocatedParseResult<T> = Result<T, Spanning<ParseError>>;
 #[doc(hidden)]
 pub type OptionParseResult<T> = Result<Option<Spanning<T>>, Spanning<ParseError>>;
 
+/// Limits of resources a GraphQL document may consume while being parsed.
+///
+/// By default, only the [`ParseLimits::max_depth`] is applied (being
+/// [`ParseLimits::DEFAULT_MAX_DEPTH`]), so the parser's stack cannot be overflowed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseLimits {
+    /// Maximum length of a document source, in bytes.
+    pub max_source_length: Option<usize>,
+
+    /// Maximum number of tokens in a document.
+    pub max_tokens: Option<usize>,
+
+    /// Maximum nesting depth of selection sets, list and object values, and list types in a
+    /// document.
+    ///
+    /// Bounds the recursion of the parser, so a maliciously deep document cannot overflow the
+    /// stack.
+    pub max_depth: Option<usize>,
+
+    /// Maximum number of definitions (operations and fragments) in a document.
+    pub max_definitions: Option<usize>,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_source_length: None,
+            max_tokens: None,
+            max_depth: Some(Self::DEFAULT_MAX_DEPTH),
+            max_definitions: None,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// Default [`ParseLimits::max_depth`], being deep enough for any sane document, while still
+    /// keeping the parser's recursion far from overflowing the stack.
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+    /// Specifies the maximum length of a document source, in bytes.
+    #[must_use]
+    pub fn with_max_source_length(mut self, max: usize) -> Self {
+        self.max_source_length = Some(max);
+        self
+    }
+
+    /// Specifies the maximum number of tokens in a document.
+    #[must_use]
+    pub fn with_max_tokens(mut self, max: usize) -> Self {
+        self.max_tokens = Some(max);
+        self
+    }
+
+    /// Specifies the maximum nesting depth of selection sets, list and object values, and list
+    /// types in a document.
+    #[must_use]
+    pub fn with_max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+
+    /// Disables the limit of the nesting depth, applied by default.
+    ///
+    /// Without it, a maliciously deep document is able to overflow the stack.
+    #[must_use]
+    pub fn without_max_depth(mut self) -> Self {
+        self.max_depth = None;
+        self
+    }
+
+    /// Specifies the maximum number of definitions (operations and fragments) in a document.
+    #[must_use]
+    pub fn with_max_definitions(mut self, max: usize) -> Self {
+        self.max_definitions = Some(max);
+        self
+    }
+}
+
 #[doc(hidden)]
 #[derive(Debug)]
 pub struct Parser<'a> {
     tokens: Vec<Spanning<Token<'a>>>,
+    limits: ParseLimits,
+    depth: usize,
 }
 
 impl<'a> Parser<'a> {
@@ -63,7 +159,76 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok(Parser { tokens })
+        Ok(Parser {
+            tokens,
+            limits: ParseLimits::default(),
+            depth: 0,
+        })
+    }
+
+    /// Creates a new [`Parser`] enforcing the provided [`ParseLimits`].
+    ///
+    /// Stops tokenizing as soon as the [`ParseLimits::max_tokens`] is exceeded.
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseError::LexerError`] if tokenizing fails.
+    /// - [`ParseError::TokenLimitExceeded`] if the [`ParseLimits::max_tokens`] is exceeded.
+    #[doc(hidden)]
+    pub fn with_limits(
+        lexer: &mut Lexer<'a>,
+        limits: ParseLimits,
+    ) -> Result<Parser<'a>, Spanning<ParseError>> {
+        let mut tokens = Vec::new();
+
+        for res in lexer {
+            let token = res.map_err(|e| e.map(Into::into))?;
+            if let Some(max) = limits.max_tokens {
+                if token.item != Token::EndOfFile && tokens.len() >= max {
+                    return Err(token.map(|_| ParseError::TokenLimitExceeded(max)));
+                }
+            }
+            tokens.push(token);
+        }
+
+        Ok(Parser {
+            tokens,
+            limits,
+            depth: 0,
+        })
+    }
+
+    /// Returns the [`ParseLimits`] enforced by this [`Parser`].
+    #[doc(hidden)]
+    #[must_use]
+    pub fn limits(&self) -> &ParseLimits {
+        &self.limits
+    }
+
+    /// Runs the provided `parse` function one nesting level deeper, enforcing the
+    /// [`ParseLimits::max_depth`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseError::DepthLimitExceeded`] if the [`ParseLimits::max_depth`] is exceeded.
+    /// - If the provided `parse` function fails.
+    #[doc(hidden)]
+    pub fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, Spanning<ParseError>>,
+    ) -> Result<T, Spanning<ParseError>> {
+        if let Some(max) = self.limits.max_depth {
+            if self.depth >= max {
+                return Err(Spanning::new(
+                    self.peek().span,
+                    ParseError::DepthLimitExceeded(max),
+                ));
+            }
+        }
+        self.depth += 1;
+        let res = parse(self);
+        self.depth -= 1;
+        res
     }
 
     #[doc(hidden)]
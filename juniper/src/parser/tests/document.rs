@@ -2,7 +2,13 @@ use std::borrow::Cow;
 
 use crate::{
     ast, graphql,
-    parser::{ParseError, SourcePosition, Spanning, Token, document::parse_document_source},
+    parser::{
+        ParseError, ParseLimits, SourcePosition, Spanning, Token,
+        document::{
+            parse_document_source, parse_document_source_with_limits,
+            parse_document_source_without_schema,
+        },
+    },
     schema::model::SchemaType,
     types::scalars::{EmptyMutation, EmptySubscription},
     validation::test_harness::{MutationRoot, QueryRoot, SubscriptionRoot},
@@ -435,3 +441,163 @@ fn issue_427_panic_is_not_expected() {
         ParseError::ExpectedScalarError("There needs to be a Float type")
     );
 }
+
+#[test]
+fn limits() {
+    let schema = SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &());
+    let parse = |s, limits| {
+        parse_document_source_with_limits::<DefaultScalarValue>(s, &schema, limits)
+            .map(|_| ())
+            .map_err(|e| e.item)
+    };
+
+    // language=GraphQL
+    let query = "{ dog { name } } { cat { name } }";
+    for (limits, expected) in [
+        (ParseLimits::default(), Ok(())),
+        (ParseLimits::default().with_max_source_length(33), Ok(())),
+        (
+            ParseLimits::default().with_max_source_length(32),
+            Err(ParseError::SourceLengthLimitExceeded(32)),
+        ),
+        (ParseLimits::default().with_max_tokens(12), Ok(())),
+        (
+            ParseLimits::default().with_max_tokens(11),
+            Err(ParseError::TokenLimitExceeded(11)),
+        ),
+        (ParseLimits::default().with_max_depth(2), Ok(())),
+        (
+            ParseLimits::default().with_max_depth(1),
+            Err(ParseError::DepthLimitExceeded(1)),
+        ),
+        (ParseLimits::default().with_max_definitions(2), Ok(())),
+        (
+            ParseLimits::default().with_max_definitions(1),
+            Err(ParseError::DefinitionLimitExceeded(1)),
+        ),
+    ] {
+        assert_eq!(parse(query, limits), expected, "limits: {limits:?}");
+    }
+
+    // Selection sets, values and types are counted as nesting levels altogether.
+    let limits = ParseLimits::default().with_max_depth(4);
+    // language=GraphQL
+    for (query, expected) in [
+        ("{ dog { name(surname: [[1]]) } }", Ok(())),
+        (
+            "{ dog { name(surname: [[[1]]]) } }",
+            Err(ParseError::DepthLimitExceeded(4)),
+        ),
+        ("{ dog { unknown(arg: {a: {b: 1}}) } }", Ok(())),
+        (
+            "{ dog { unknown(arg: {a: {b: {c: 1}}}) } }",
+            Err(ParseError::DepthLimitExceeded(4)),
+        ),
+        ("query($a: [[[[Int]]]]) { dog { name } }", Ok(())),
+        (
+            "query($a: [[[[[Int]]]]]) { dog { name } }",
+            Err(ParseError::DepthLimitExceeded(4)),
+        ),
+    ] {
+        assert_eq!(parse(query, limits), expected, "query: {query}");
+    }
+}
+
+#[test]
+fn deep_nesting_is_rejected_without_overflowing_stack() {
+    let schema = SchemaType::new::<QueryRoot, MutationRoot, SubscriptionRoot>(&(), &(), &());
+    let query = format!(
+        "{{ dog(arg: {}1{}) }}",
+        "[".repeat(100_000),
+        "]".repeat(100_000)
+    );
+
+    let err = parse_document_source_with_limits::<DefaultScalarValue>(
+        &query,
+        &schema,
+        ParseLimits::default().with_max_depth(64),
+    )
+    .unwrap_err();
+
+    assert_eq!(err.item, ParseError::DepthLimitExceeded(64));
+    assert_eq!(err.span.start, SourcePosition::new(74, 0, 74));
+
+    let err = parse_document_source_with_limits::<DefaultScalarValue>(
+        &query,
+        &schema,
+        Default::default(),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.item,
+        ParseError::DepthLimitExceeded(ParseLimits::DEFAULT_MAX_DEPTH),
+    );
+}
+
+#[test]
+fn without_schema() {
+    // language=GraphQL
+    let query =
+        r#"{ complicatedArgs { floatArgField(floatArg: 1) } unknown(a: "1", b: [2.5, true]) }"#;
+    let argument = |doc: &ast::Document<'_, DefaultScalarValue>, path: &[usize], name: &str| {
+        let ast::Definition::Operation(op) = &doc[0] else {
+            panic!("not an operation: {doc:#?}");
+        };
+        let mut selections = &op.item.selection_set;
+        let mut field = None;
+        for &i in path {
+            let ast::Selection::Field(f) = &selections[i] else {
+                panic!("not a field: {:#?}", selections[i]);
+            };
+            field = Some(&f.item);
+            selections = f.item.selection_set.as_ref().unwrap_or(selections);
+        }
+        field
+            .and_then(|f| f.arguments.as_ref())
+            .and_then(|args| args.item.get(name))
+            .map(|v| v.item.clone())
+            .unwrap()
+    };
+
+    let typed = parse_document::<DefaultScalarValue>(query);
+    let untyped =
+        parse_document_source_without_schema::<DefaultScalarValue>(query, ParseLimits::default())
+            .unwrap();
+
+    // Literals are coerced to the types expected by the schema only if there is one.
+    assert_eq!(
+        argument(&typed, &[0, 0], "floatArg"),
+        graphql::input_value!(1.0)
+    );
+    assert_eq!(
+        argument(&untyped, &[0, 0], "floatArg"),
+        graphql::input_value!(1)
+    );
+    assert_eq!(argument(&untyped, &[1], "a"), graphql::input_value!("1"));
+    assert!(argument(&untyped, &[1], "b").unlocated_eq(&graphql::input_value!([2.5, true])));
+
+    // `Int` literals out of `i32` range are rejected.
+    assert_eq!(
+        parse_document_source_without_schema::<DefaultScalarValue>(
+            "{ unknown(c: 9999999999) }",
+            ParseLimits::default(),
+        )
+        .unwrap_err(),
+        Spanning::start_end(
+            &SourcePosition::new(13, 0, 13),
+            &SourcePosition::new(23, 0, 23),
+            ParseError::UnexpectedToken("9999999999".into()),
+        ),
+    );
+
+    assert_eq!(
+        parse_document_source_without_schema::<DefaultScalarValue>(
+            query,
+            ParseLimits::default().with_max_depth(1),
+        )
+        .unwrap_err()
+        .item,
+        ParseError::DepthLimitExceeded(1),
+    );
+}
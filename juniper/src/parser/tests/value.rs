@@ -69,7 +69,7 @@ where
         Parser::new(&mut lexer).unwrap_or_else(|_| panic!("Lexer error on input {s:#?}"));
     let schema = SchemaType::new::<Query, EmptyMutation<()>, EmptySubscription<()>>(&(), &(), &());
 
-    parse_value_literal(&mut parser, false, &schema, Some(meta))
+    parse_value_literal(&mut parser, false, Some(&schema), Some(meta))
         .unwrap_or_else(|_| panic!("Parse error on input {s:#?}"))
 }
 
@@ -6,7 +6,7 @@ use crate::{
         meta::{InputObjectMeta, MetaType},
         model::SchemaType,
     },
-    value::ScalarValue,
+    value::{ParseScalarValue, ScalarValue},
 };
 
 use super::utils::Span;
@@ -14,7 +14,7 @@ use super::utils::Span;
 pub fn parse_value_literal<S>(
     parser: &mut Parser<'_>,
     is_const: bool,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     tpe: Option<&MetaType<S>>,
 ) -> ParseResult<InputValue<S>>
 where
@@ -120,43 +120,47 @@ where
 fn parse_list_literal<S>(
     parser: &mut Parser<'_>,
     is_const: bool,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     tpe: Option<&MetaType<S>>,
 ) -> ParseResult<InputValue<S>>
 where
     S: ScalarValue,
 {
     Ok(parser
-        .delimited_list(
-            &Token::BracketOpen,
-            |p| parse_value_literal(p, is_const, schema, tpe),
-            &Token::BracketClose,
-        )?
+        .nested(|p| {
+            p.delimited_list(
+                &Token::BracketOpen,
+                |p| parse_value_literal(p, is_const, schema, tpe),
+                &Token::BracketClose,
+            )
+        })?
         .map(InputValue::parsed_list))
 }
 
 fn parse_object_literal<S>(
     parser: &mut Parser<'_>,
     is_const: bool,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     object_tpe: Option<&InputObjectMeta<S>>,
 ) -> ParseResult<InputValue<S>>
 where
     S: ScalarValue,
 {
     Ok(parser
-        .delimited_list(
-            &Token::CurlyOpen,
-            |p| parse_object_field(p, is_const, schema, object_tpe),
-            &Token::CurlyClose,
-        )?
+        .nested(|p| {
+            p.delimited_list(
+                &Token::CurlyOpen,
+                |p| parse_object_field(p, is_const, schema, object_tpe),
+                &Token::CurlyClose,
+            )
+        })?
         .map(|items| InputValue::parsed_object(items.into_iter().map(|s| s.item).collect())))
 }
 
 fn parse_object_field<S>(
     parser: &mut Parser<'_>,
     is_const: bool,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
     object_meta: Option<&InputObjectMeta<S>>,
 ) -> ParseResult<(Spanning<String>, Spanning<InputValue<S>>)>
 where
@@ -166,7 +170,8 @@ where
 
     let tpe = object_meta
         .and_then(|o| o.input_fields.iter().find(|f| f.name == key.item))
-        .and_then(|f| schema.lookup_type(&f.arg_type));
+        .zip(schema)
+        .and_then(|(f, s)| s.lookup_type(&f.arg_type));
 
     parser.expect(&Token::Colon)?;
 
@@ -200,11 +205,23 @@ where
 fn parse_scalar_literal_by_infered_type<S>(
     token: ScalarToken<'_>,
     span: Span,
-    schema: &SchemaType<S>,
+    schema: Option<&SchemaType<S>>,
 ) -> ParseResult<InputValue<S>>
 where
     S: ScalarValue,
 {
+    let Some(schema) = schema else {
+        return match token {
+            ScalarToken::String(_) => <String as ParseScalarValue<S>>::from_str(token),
+            // `Int` literals out of `i32` range are rejected, rather than silently changing their
+            // type or precision.
+            ScalarToken::Int(_) => <i32 as ParseScalarValue<S>>::from_str(token),
+            ScalarToken::Float(_) => <f64 as ParseScalarValue<S>>::from_str(token),
+        }
+        .map(|s| Spanning::new(span, InputValue::Scalar(s)))
+        .map_err(|e| Spanning::new(span, e));
+    };
+
     let result = match token {
         ScalarToken::String(_) => {
             if let Some(MetaType::Scalar(s)) = schema.concrete_type_by_name("String") {
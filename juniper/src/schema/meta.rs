@@ -418,6 +418,8 @@ pub struct Field<S> {
     pub field_type: Type,
     #[doc(hidden)]
     pub deprecation_status: DeprecationStatus,
+    #[doc(hidden)]
+    pub concurrency_limit: Option<usize>,
 }
 
 impl<S> Field<S> {
@@ -461,6 +463,20 @@ impl<S> Field<S> {
         self.deprecation_status = DeprecationStatus::Deprecated(reason.map(Into::into));
         self
     }
+
+    /// Limits the number of this [`Field`]'s list elements and selected subfields being resolved
+    /// concurrently, in addition to the [`RootNode::limit_concurrency()`] of the whole operation.
+    ///
+    /// The `limit` of `0` is treated as `1`.
+    ///
+    /// Overwrites any previously set limit.
+    ///
+    /// [`RootNode::limit_concurrency()`]: crate::RootNode::limit_concurrency
+    #[must_use]
+    pub fn concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit = Some(limit.max(1));
+        self
+    }
 }
 
 /// Metadata for an argument to a field
@@ -1,4 +1,4 @@
-use std::ptr;
+use std::{future::Future, ptr, sync::Arc, time::Duration};
 
 use arcstr::ArcStr;
 use derive_more::with_trait::Display;
@@ -9,9 +9,11 @@ use graphql_parser::schema::Document;
 use crate::{
     GraphQLEnum,
     ast::{Type, TypeModifier},
-    executor::{Context, Registry},
+    executor::{Context, ErrorFormatter, ExecutionTimeout, Registry},
+    parser::ParseLimits,
     schema::meta::{Argument, InterfaceMeta, MetaType, ObjectMeta, PlaceholderMeta, UnionMeta},
     types::{base::GraphQLType, name::Name},
+    usage::FieldUsageCollector,
     value::{DefaultScalarValue, ScalarValue},
 };
 
@@ -44,6 +46,18 @@ pub struct RootNode<
     pub schema: SchemaType<S>,
     #[doc(hidden)]
     pub introspection_disabled: bool,
+    #[doc(hidden)]
+    pub parse_limits: ParseLimits,
+    #[doc(hidden)]
+    pub field_usage_collector: Option<Arc<FieldUsageCollector>>,
+    #[doc(hidden)]
+    pub execution_timeout: Option<ExecutionTimeout>,
+    #[doc(hidden)]
+    pub concurrency_limit: Option<usize>,
+    #[doc(hidden)]
+    pub serial_mutations: bool,
+    #[doc(hidden)]
+    pub error_formatter: Option<Arc<dyn ErrorFormatter<S>>>,
 }
 
 impl<QueryT, MutationT, SubscriptionT>
@@ -109,6 +123,12 @@ where
             mutation_info,
             subscription_info,
             introspection_disabled: false,
+            parse_limits: ParseLimits::default(),
+            field_usage_collector: None,
+            execution_timeout: None,
+            concurrency_limit: None,
+            serial_mutations: false,
+            error_formatter: None,
         }
     }
 
@@ -167,6 +187,266 @@ where
         self
     }
 
+    /// Applies the provided [`ParseLimits`] to documents executed against this [`RootNode`],
+    /// rejecting the ones exceeding them with a [`GraphQLError::ParseError`] before they're
+    /// validated.
+    ///
+    /// By default, no limits are applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use juniper::{
+    /// #     graphql_object, graphql_vars, EmptyMutation, EmptySubscription, GraphQLError,
+    /// #     ParseError, ParseLimits, RootNode,
+    /// # };
+    /// #
+    /// pub struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn some() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    ///     .limit_parsing(ParseLimits::default().with_max_tokens(3));
+    ///
+    /// # // language=GraphQL
+    /// let query = "query { some }";
+    ///
+    /// match juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &()) {
+    ///     Err(GraphQLError::ParseError(err)) => {
+    ///         assert_eq!(err.item, ParseError::TokenLimitExceeded(3));
+    ///     }
+    ///     res => panic!("expected `ParseError`, returned: {res:#?}"),
+    /// }
+    /// ```
+    ///
+    /// [`GraphQLError::ParseError`]: crate::GraphQLError::ParseError
+    pub fn limit_parsing(mut self, limits: ParseLimits) -> Self {
+        self.parse_limits = limits;
+        self
+    }
+
+    /// Records the fields, arguments and enum values resolved by the operations executed against
+    /// this [`RootNode`] into the provided [`FieldUsageCollector`].
+    ///
+    /// By default, no usage is collected.
+    ///
+    /// See [`FieldUsageCollector`] for an example.
+    pub fn collect_field_usage(mut self, collector: Arc<FieldUsageCollector>) -> Self {
+        self.field_usage_collector = Some(collector);
+        self
+    }
+
+    /// Limits the execution time of every query or mutation executed against this [`RootNode`]
+    /// with the provided `timeout`, awaited with the provided `sleep` function (like
+    /// [`tokio::time::sleep()`], for example).
+    ///
+    /// Once the timeout elapses, the fields still being resolved are resolved into `null` with a
+    /// [`FieldError`] at their path, and the partial result is returned. Resolvers may observe
+    /// the deadline via [`Executor::deadline()`] and [`Executor::is_cancelled()`] to stop early.
+    ///
+    /// By default, no timeout is applied.
+    ///
+    /// The `sleep` function is called once per asynchronous execution, and the returned [`Future`]
+    /// is polled along with the execution, so no background thread or runtime-specific timer is
+    /// required by [`juniper`](crate) itself.
+    ///
+    /// > __NOTE__: Synchronous execution cannot interrupt a running resolver, so only checks the
+    /// >           timeout before resolving each field, and never calls the `sleep` function.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// #
+    /// # use juniper::{
+    /// #     graphql_object, graphql_value, graphql_vars, EmptyMutation, EmptySubscription,
+    /// #     RootNode,
+    /// # };
+    /// #
+    /// pub struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn fast() -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     async fn slow() -> Option<bool> {
+    ///         std::future::pending().await
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    ///     .limit_execution_time(Duration::from_millis(10), tokio::time::sleep);
+    ///
+    /// # // language=GraphQL
+    /// let query = "query { fast slow }";
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let (res, errs) = juniper::execute(query, None, &schema, &graphql_vars! {}, &())
+    ///     .await
+    ///     .unwrap();
+    ///
+    /// assert_eq!(res, graphql_value!({"fast": true, "slow": null}));
+    /// assert_eq!(errs[0].path(), ["slow"]);
+    /// assert_eq!(errs[0].error().message(), "Operation timed out");
+    /// # });
+    /// ```
+    ///
+    /// [`Executor::deadline()`]: crate::Executor::deadline
+    /// [`Executor::is_cancelled()`]: crate::Executor::is_cancelled
+    /// [`FieldError`]: crate::FieldError
+    /// [`tokio::time::sleep()`]: https://docs.rs/tokio/latest/tokio/time/fn.sleep.html
+    pub fn limit_execution_time<F>(
+        mut self,
+        timeout: Duration,
+        sleep: impl Fn(Duration) -> F + Send + Sync + 'static,
+    ) -> Self
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.execution_timeout = Some(ExecutionTimeout {
+            duration: timeout,
+            sleep: Arc::new(move |duration| Box::pin(sleep(duration))),
+        });
+        self
+    }
+
+    /// Limits the number of fields and list elements being resolved concurrently by every
+    /// asynchronous execution of a query or a mutation against this [`RootNode`] with the
+    /// provided `limit`.
+    ///
+    /// The limit applies to the whole operation being executed, no matter how many lists and
+    /// selection sets it's spread across. It may be lowered further for the list elements and
+    /// subfields of a particular field with a `#[graphql(concurrency = N)]` attribute argument (or
+    /// a [`Field::concurrency_limit()`] call). The `limit` of `0` is treated as `1`.
+    ///
+    /// By default, all list elements and sibling fields are resolved concurrently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode};
+    /// #
+    /// pub struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     async fn numbers() -> Vec<i32> {
+    ///         (0..10_000).collect()
+    ///     }
+    ///
+    ///     // Resolves at most 2 elements of this list at once.
+    ///     #[graphql(concurrency = 2)]
+    ///     async fn letters() -> Vec<String> {
+    ///         ('a'..='z').map(String::from).collect()
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// let schema =
+    ///     Schema::new(Query, EmptyMutation::new(), EmptySubscription::new()).limit_concurrency(16);
+    /// ```
+    ///
+    /// [`Field::concurrency_limit()`]: crate::meta::Field::concurrency_limit
+    pub fn limit_concurrency(mut self, limit: usize) -> Self {
+        self.concurrency_limit = Some(limit.max(1));
+        self
+    }
+
+    /// Makes the top-level fields of mutations executed against this [`RootNode`] to be resolved
+    /// serially, one after another, as [required by the GraphQL specification][0] for their side
+    /// effects to be applied in order.
+    ///
+    /// By default, top-level mutation fields are resolved concurrently (like any other sibling
+    /// fields), which is faster, but doesn't guarantee the order of their side effects.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Mutation
+    pub fn resolve_mutations_serially(mut self) -> Self {
+        self.serial_mutations = true;
+        self
+    }
+
+    /// Formats the [`ExecutionError`]s happened while executing operations against this
+    /// [`RootNode`] with the provided [`ErrorFormatter`], before they're returned to clients.
+    ///
+    /// The `formatter` sees every [`ExecutionError`] (including the ones of subscription events),
+    /// except the ones constructed out of a [`SafeError`], so may log it and mask its internal
+    /// details.
+    ///
+    /// By default, all [`ExecutionError`]s are returned to clients as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicU64, Ordering};
+    /// #
+    /// # use juniper::{
+    /// #     graphql_object, graphql_value, graphql_vars, EmptyMutation, EmptySubscription,
+    /// #     ExecutionError, FieldError, FieldResult, RootNode, SafeError,
+    /// # };
+    /// #
+    /// #[derive(derive_more::Display)]
+    /// #[display("Not authorized")]
+    /// struct Unauthorized;
+    ///
+    /// impl SafeError for Unauthorized {}
+    ///
+    /// pub struct Query;
+    ///
+    /// #[graphql_object]
+    /// impl Query {
+    ///     fn users() -> FieldResult<Option<String>> {
+    ///         Err("connection to `db.internal:5432` refused".into())
+    ///     }
+    ///
+    ///     fn secrets() -> FieldResult<Option<String>> {
+    ///         Err(FieldError::new_safe(Unauthorized, graphql_value!(null)))
+    ///     }
+    /// }
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+    ///
+    /// static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    ///
+    /// let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+    ///     .format_errors(|err: ExecutionError<_>| {
+    ///         let id = NEXT_ID.fetch_add(1, Ordering::Relaxed).to_string();
+    ///         // Log the original error here, along with its `id` and `path()`.
+    ///         err.map_error(|_| {
+    ///             FieldError::new("Internal server error", graphql_value!({"correlationId": id}))
+    ///         })
+    ///     });
+    ///
+    /// # // language=GraphQL
+    /// let query = "query { users secrets }";
+    ///
+    /// let (res, errs) = juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(res, graphql_value!({"users": null, "secrets": null}));
+    /// assert_eq!(errs[0].error().message(), "Internal server error");
+    /// assert_eq!(errs[0].error().extensions(), &graphql_value!({"correlationId": "1"}));
+    /// assert_eq!(errs[1].error().message(), "Not authorized");
+    /// ```
+    ///
+    /// [`ExecutionError`]: crate::ExecutionError
+    /// [`SafeError`]: crate::SafeError
+    pub fn format_errors(mut self, formatter: impl ErrorFormatter<S> + 'static) -> Self {
+        self.error_formatter = Some(Arc::new(formatter));
+        self
+    }
+
     #[cfg(feature = "schema-language")]
     /// Returns this [`RootNode`] as a [`String`] containing the schema in [SDL (schema definition language)].
     ///
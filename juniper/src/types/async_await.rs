@@ -183,7 +183,7 @@ where
     T::Context: Sync,
     S: ScalarValue + Send + Sync,
 {
-    use futures::stream::{FuturesOrdered, StreamExt as _};
+    use futures::stream::StreamExt as _;
 
     #[enum_derive(Future)]
     enum AsyncValueFuture<F1, F2, FS, IF1, IF2> {
@@ -196,7 +196,7 @@ where
 
     let mut object = Object::with_capacity(selection_set.len());
 
-    let mut async_values = FuturesOrdered::<AsyncValueFuture<_, _, _, _, _>>::new();
+    let mut async_values = Vec::<AsyncValueFuture<_, _, _, _, _>>::new();
 
     let meta_type = executor
         .schema()
@@ -235,6 +235,8 @@ where
                     )
                 });
 
+                executor.record_field_usage(meta_type, f.name.item, f.arguments.as_ref());
+
                 let exec_vars = executor.variables();
 
                 let sub_exec = executor.field_sub_executor(
@@ -260,16 +262,24 @@ where
                 let is_non_null = meta_field.field_type.is_non_null();
 
                 let response_name = response_name.to_string();
-                async_values.push_back(AsyncValueFuture::Field1(async move {
+                async_values.push(AsyncValueFuture::Field1(async move {
                     // TODO: implement custom future type instead of
                     //       two-level boxing.
-                    let res = instance
-                        .resolve_field_async(info, f.name.item, &args, &sub_exec)
+                    let res = sub_exec
+                        .resolve_until_cancelled(instance.resolve_field_async(
+                            info,
+                            f.name.item,
+                            &args,
+                            &sub_exec,
+                        ))
                         .await;
 
                     let value = match res {
                         Ok(Value::Null) if is_non_null => None,
-                        Ok(v) => Some(v),
+                        Ok(v) => {
+                            sub_exec.record_output_usage(meta_field, &v);
+                            Some(v)
+                        }
                         Err(e) => {
                             sub_exec.push_error_at(e, pos);
 
@@ -322,12 +332,12 @@ where
 
                     if let Ok(Value::Object(obj)) = sub_result {
                         for (k, v) in obj {
-                            async_values.push_back(AsyncValueFuture::FragmentSpread(
-                                future::ready(AsyncValue::Field(AsyncField {
+                            async_values.push(AsyncValueFuture::FragmentSpread(future::ready(
+                                AsyncValue::Field(AsyncField {
                                     name: k,
                                     value: Some(v),
-                                })),
-                            ));
+                                }),
+                            )));
                         }
                     } else {
                         if let Err(e) = sub_result {
@@ -340,7 +350,7 @@ where
                         //       because of non-`null` types involved, its error should be
                         //       propagated to the parent field, which is done here by returning
                         //       a `Value::Null`.
-                        async_values.push_back(AsyncValueFuture::Field2(future::ready(
+                        async_values.push(AsyncValueFuture::Field2(future::ready(
                             AsyncValue::Field(AsyncField {
                                 name: String::new(), // doesn't matter here
                                 value: None,
@@ -381,7 +391,7 @@ where
 
                         if let Ok(Value::Object(obj)) = sub_result {
                             for (k, v) in obj {
-                                async_values.push_back(AsyncValueFuture::InlineFragment1(
+                                async_values.push(AsyncValueFuture::InlineFragment1(
                                     future::ready(AsyncValue::Field(AsyncField {
                                         name: k,
                                         value: Some(v),
@@ -399,7 +409,7 @@ where
                             //       because of non-`null` types involved, its error should be
                             //       propagated to the parent field, which is done here by returning
                             //       a `Value::Null`.
-                            async_values.push_back(AsyncValueFuture::Field2(future::ready(
+                            async_values.push(AsyncValueFuture::Field2(future::ready(
                                 AsyncValue::Field(AsyncField {
                                     name: String::new(), // doesn't matter here
                                     value: None,
@@ -408,7 +418,7 @@ where
                         }
                     }
                 } else {
-                    async_values.push_back(AsyncValueFuture::InlineFragment2(async move {
+                    async_values.push(AsyncValueFuture::InlineFragment2(async move {
                         let value = resolve_selection_set_into_async(
                             instance,
                             info,
@@ -423,6 +433,7 @@ where
         }
     }
 
+    let mut async_values = executor.resolve_concurrently(async_values);
     while let Some(item) = async_values.next().await {
         match item {
             AsyncValue::Field(AsyncField { name, value }) => {
@@ -457,6 +457,8 @@ where
                     )
                 });
 
+                executor.record_field_usage(meta_type, f.name.item, f.arguments.as_ref());
+
                 let exec_vars = executor.variables();
 
                 let sub_exec = executor.field_sub_executor(
@@ -466,27 +468,34 @@ where
                     f.selection_set.as_ref().map(|v| &v[..]),
                 );
 
-                let field_result = instance.resolve_field(
-                    info,
-                    f.name.item,
-                    &Arguments::new(
-                        f.arguments.as_ref().map(|m| {
-                            m.item
-                                .iter()
-                                .filter_map(|(k, v)| {
-                                    let val = v.item.clone().into_const(exec_vars)?;
-                                    Some((k.item, Spanning::new(v.span, val)))
-                                })
-                                .collect()
-                        }),
-                        &meta_field.arguments,
-                    ),
-                    &sub_exec,
-                );
+                let field_result = if sub_exec.is_cancelled() {
+                    Err(sub_exec.cancellation_error())
+                } else {
+                    instance.resolve_field(
+                        info,
+                        f.name.item,
+                        &Arguments::new(
+                            f.arguments.as_ref().map(|m| {
+                                m.item
+                                    .iter()
+                                    .filter_map(|(k, v)| {
+                                        let val = v.item.clone().into_const(exec_vars)?;
+                                        Some((k.item, Spanning::new(v.span, val)))
+                                    })
+                                    .collect()
+                            }),
+                            &meta_field.arguments,
+                        ),
+                        &sub_exec,
+                    )
+                };
 
                 match field_result {
                     Ok(Value::Null) if meta_field.field_type.is_non_null() => return false,
-                    Ok(v) => merge_key_into(result, response_name, v),
+                    Ok(v) => {
+                        sub_exec.record_output_usage(meta_field, &v);
+                        merge_key_into(result, response_name, v);
+                    }
                     Err(e) => {
                         sub_exec.push_error_at(e, span.start);
 
@@ -609,7 +618,7 @@ where
     true
 }
 
-pub(super) fn is_excluded<S>(
+pub(crate) fn is_excluded<S>(
     directives: &Option<Vec<Spanning<Directive<S>>>>,
     vars: &Variables<S>,
 ) -> bool
@@ -557,7 +557,7 @@ where
     T::Context: Sync,
     S: ScalarValue + Send + Sync,
 {
-    use futures::stream::{FuturesOrdered, StreamExt as _};
+    use futures::stream::StreamExt as _;
 
     let stop_on_null = executor
         .current_type()
@@ -565,11 +565,12 @@ where
         .expect("Current type is not a list type")
         .is_non_null();
 
-    let mut futures = items
+    let futures = items
         .map(async |it| executor.resolve_into_value_async(info, it).await)
-        .collect::<FuturesOrdered<_>>();
+        .collect::<Vec<_>>();
 
     let mut values = Vec::with_capacity(futures.len());
+    let mut futures = executor.resolve_concurrently(futures);
     while let Some(value) = futures.next().await {
         if stop_on_null && value.is_null() {
             return Ok(value);
@@ -0,0 +1,126 @@
+//! Stable machine-readable codes of GraphQL errors.
+//!
+//! The codes are put into the `code` extension of the [errors][0] returned to clients, so they
+//! can be matched reliably, without parsing error messages.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Errors
+
+use std::fmt::Display;
+
+use crate::{DefaultScalarValue, FieldError, Object, SafeError, ScalarValue, Value};
+
+/// Code of the errors happened while parsing a GraphQL document.
+pub const GRAPHQL_PARSE_FAILED: &str = "GRAPHQL_PARSE_FAILED";
+
+/// Code of the errors happened while validating a GraphQL document against a schema.
+pub const GRAPHQL_VALIDATION_FAILED: &str = "GRAPHQL_VALIDATION_FAILED";
+
+/// Code of the errors caused by invalid values provided by a client (like variables not coercible
+/// to their types, or arguments failing their validators).
+pub const BAD_USER_INPUT: &str = "BAD_USER_INPUT";
+
+/// Code of the errors caused by a request not allowing to pick an operation to execute (like an
+/// unknown operation name, or an operation of a type not supported by a schema).
+pub const OPERATION_RESOLUTION_FAILURE: &str = "OPERATION_RESOLUTION_FAILURE";
+
+/// Code of the errors caused by a request referring to a persisted query unknown to a server.
+pub const PERSISTED_QUERY_NOT_FOUND: &str = "PERSISTED_QUERY_NOT_FOUND";
+
+/// Code of the errors caused by a client exceeding a limit of a server (like a rate limit, or a
+/// limit of concurrently executed operations).
+pub const RATE_LIMITED: &str = "RATE_LIMITED";
+
+/// Error having a stable machine-readable code and typed extensions.
+///
+/// Usually, implemented via `#[derive(`[`GraphQLErrorCode`](macro@crate::GraphQLErrorCode)`)]`,
+/// which also implements the [`IntoFieldError`] trait, converting the error with the
+/// [`into_field_error()`] function.
+///
+/// # Example
+///
+/// ```rust
+/// # use derive_more::with_trait::Display;
+/// # use juniper::{GraphQLErrorCode, Object, ScalarValue, Value, error_code, graphql_value};
+/// #
+/// #[derive(Display)]
+/// #[display("Order `{_0}` not found")]
+/// struct OrderNotFound(i32);
+///
+/// impl<S: ScalarValue> GraphQLErrorCode<S> for OrderNotFound {
+///     fn code(&self) -> &str {
+///         "ORDER_NOT_FOUND"
+///     }
+///
+///     fn extensions(&self) -> Object<S> {
+///         let mut ext = Object::with_capacity(1);
+///         _ = ext.add_field("id", Value::scalar(self.0));
+///         ext
+///     }
+/// }
+///
+/// let err: juniper::FieldError = error_code::into_field_error(OrderNotFound(1));
+///
+/// assert_eq!(err.message(), "Order `1` not found");
+/// assert_eq!(
+///     err.extensions(),
+///     &graphql_value!({"code": "ORDER_NOT_FOUND", "id": 1}),
+/// );
+/// ```
+///
+/// [`IntoFieldError`]: crate::IntoFieldError
+pub trait GraphQLErrorCode<S = DefaultScalarValue> {
+    /// Returns the machine-readable code of this error.
+    ///
+    /// Should remain stable, as clients rely on it.
+    fn code(&self) -> &str;
+
+    /// Returns the typed extensions of this error, to be put along with its [`code()`].
+    ///
+    /// [`code()`]: GraphQLErrorCode::code
+    fn extensions(&self) -> Object<S> {
+        Object::with_capacity(0)
+    }
+}
+
+/// Converts the provided [`GraphQLErrorCode`] `error` into a [`FieldError`], having the [`Display`]
+/// representation of the `error` as its message, and the [`GraphQLErrorCode::code()`] in the
+/// `code` extension, followed by the [`GraphQLErrorCode::extensions()`].
+#[must_use]
+pub fn into_field_error<S, E>(error: E) -> FieldError<S>
+where
+    S: ScalarValue,
+    E: GraphQLErrorCode<S> + Display,
+{
+    let extensions = extensions_of(&error);
+    FieldError::new(error, extensions)
+}
+
+/// Converts the provided [`GraphQLErrorCode`] `error` into a [safe][0] [`FieldError`], the same
+/// way as [`into_field_error()`] does.
+///
+/// [0]: FieldError::new_safe
+#[must_use]
+pub fn into_safe_field_error<S, E>(error: E) -> FieldError<S>
+where
+    S: ScalarValue,
+    E: GraphQLErrorCode<S> + SafeError,
+{
+    let extensions = extensions_of(&error);
+    FieldError::new_safe(error, extensions)
+}
+
+/// Returns the `code` extension of the provided [`GraphQLErrorCode`] `error`, followed by its
+/// [`GraphQLErrorCode::extensions()`].
+fn extensions_of<S, E>(error: &E) -> Value<S>
+where
+    S: ScalarValue,
+    E: GraphQLErrorCode<S> + ?Sized,
+{
+    let extensions = error.extensions();
+    let mut ext = Object::with_capacity(extensions.field_count() + 1);
+    _ = ext.add_field("code", Value::scalar(error.code().to_owned()));
+    for (k, v) in extensions {
+        _ = ext.add_field(k, v);
+    }
+    Value::Object(ext)
+}
@@ -0,0 +1,351 @@
+//! Guards authorizing resolution of [GraphQL fields][1].
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+
+use std::{borrow::Cow, sync::Arc};
+
+use derive_more::with_trait::Display;
+use futures::future::{self, BoxFuture, FutureExt as _};
+
+use crate::{FieldError, GraphQLErrorCode, IntoFieldError, SafeError, ScalarValue, error_code};
+
+/// Guard authorizing resolution of a [GraphQL field][1] in the provided `Ctx`.
+///
+/// Specified via `#[graphql(guard = <expr>)]` attribute argument on [GraphQL objects][2] and their
+/// fields, or on [GraphQL interface][3] fields, the [`Guard`] is checked before calling the field
+/// resolver, and its [`Rejection`] is returned as a [`FieldError`] instead of resolving the field.
+///
+/// [`Guard`]s may be composed with [`Guard::and()`] and [`Guard::or()`] methods.
+///
+/// # Implementing
+///
+/// Synchronous [`Guard`]s may be specified as closures returning a [`Result`]:
+/// ```rust
+/// # use juniper::guard::Rejection;
+/// #
+/// struct Context {
+///     is_admin: bool,
+/// }
+///
+/// impl juniper::Context for Context {}
+///
+/// fn admin(ctx: &Context) -> Result<(), Rejection> {
+///     ctx.is_admin
+///         .then_some(())
+///         .ok_or_else(|| Rejection::new("Admin role is required"))
+/// }
+///
+/// # struct User;
+/// #[juniper::graphql_object(context = Context)]
+/// impl User {
+///     #[graphql(guard = admin)]
+///     fn email(&self) -> &str {
+///         "luke@skywalker.com"
+///     }
+/// }
+/// ```
+///
+/// While asynchronous ones should implement this trait directly:
+/// ```rust
+/// # use juniper::{BoxFuture, guard::{Guard, Rejection}};
+/// #
+/// # struct Context;
+/// # impl juniper::Context for Context {}
+/// # impl Context {
+/// #     async fn has_role(&self, _: &str) -> bool { true }
+/// # }
+/// #
+/// struct Role(&'static str);
+///
+/// impl Guard<Context> for Role {
+///     fn check<'a>(&'a self, ctx: &'a Context) -> BoxFuture<'a, Result<(), Rejection>> {
+///         Box::pin(async move {
+///             if ctx.has_role(self.0).await {
+///                 Ok(())
+///             } else {
+///                 Err(Rejection::new(format!("`{}` role is required", self.0)))
+///             }
+///         })
+///     }
+/// }
+///
+/// # struct User;
+/// #[juniper::graphql_object(context = Context, guard = Role("user"))]
+/// impl User {
+///     #[graphql(guard = Role("admin").or(Role("owner")))]
+///     fn email(&self) -> &str {
+///         "luke@skywalker.com"
+///     }
+/// }
+/// ```
+///
+/// # Synchronous execution
+///
+/// As [`Guard`]s are asynchronous, guarded [GraphQL fields][1] can be resolved asynchronously only,
+/// while resolving them synchronously returns a [`FieldError`].
+///
+/// # Interfaces
+///
+/// [`Guard`]s of [GraphQL interface][3] fields are checked for the fields selected both on the
+/// [GraphQL interface][3] itself and via fragments on its implementers.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+/// [3]: https://spec.graphql.org/October2021#sec-Interfaces
+pub trait Guard<Ctx: ?Sized>: Send + Sync {
+    /// Checks whether a [GraphQL field][1] is allowed to be resolved in the provided `ctx`,
+    /// returning the [`Rejection`] otherwise.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    fn check<'a>(&'a self, ctx: &'a Ctx) -> BoxFuture<'a, Result<(), Rejection>>;
+
+    /// Composes this [`Guard`] with the `other` one, so both of them should pass.
+    ///
+    /// The `other` [`Guard`] is not checked if this one rejects.
+    #[must_use]
+    fn and<G: Guard<Ctx>>(self, other: G) -> And<Self, G>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Composes this [`Guard`] with the `other` one, so any of them should pass.
+    ///
+    /// The `other` [`Guard`] is not checked if this one passes. If both reject, the [`Rejection`]
+    /// of the `other` one is returned.
+    #[must_use]
+    fn or<G: Guard<Ctx>>(self, other: G) -> Or<Self, G>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+}
+
+impl<Ctx, F> Guard<Ctx> for F
+where
+    Ctx: ?Sized,
+    F: Fn(&Ctx) -> Result<(), Rejection> + Send + Sync,
+{
+    fn check<'a>(&'a self, ctx: &'a Ctx) -> BoxFuture<'a, Result<(), Rejection>> {
+        future::ready(self(ctx)).boxed()
+    }
+}
+
+impl<Ctx: ?Sized, G: Guard<Ctx> + ?Sized> Guard<Ctx> for Arc<G> {
+    fn check<'a>(&'a self, ctx: &'a Ctx) -> BoxFuture<'a, Result<(), Rejection>> {
+        (**self).check(ctx)
+    }
+}
+
+/// [`Guard`] passing only if both of the composed [`Guard`]s pass.
+///
+/// Created by the [`Guard::and()`] method.
+#[derive(Clone, Copy, Debug)]
+pub struct And<A, B>(pub A, pub B);
+
+impl<Ctx, A, B> Guard<Ctx> for And<A, B>
+where
+    Ctx: Sync + ?Sized,
+    A: Guard<Ctx>,
+    B: Guard<Ctx>,
+{
+    fn check<'a>(&'a self, ctx: &'a Ctx) -> BoxFuture<'a, Result<(), Rejection>> {
+        async move {
+            self.0.check(ctx).await?;
+            self.1.check(ctx).await
+        }
+        .boxed()
+    }
+}
+
+/// [`Guard`] passing if any of the composed [`Guard`]s passes.
+///
+/// Created by the [`Guard::or()`] method.
+#[derive(Clone, Copy, Debug)]
+pub struct Or<A, B>(pub A, pub B);
+
+impl<Ctx, A, B> Guard<Ctx> for Or<A, B>
+where
+    Ctx: Sync + ?Sized,
+    A: Guard<Ctx>,
+    B: Guard<Ctx>,
+{
+    fn check<'a>(&'a self, ctx: &'a Ctx) -> BoxFuture<'a, Result<(), Rejection>> {
+        async move {
+            if self.0.check(ctx).await.is_ok() {
+                return Ok(());
+            }
+            self.1.check(ctx).await
+        }
+        .boxed()
+    }
+}
+
+/// Rejection of a [`Guard`] to resolve a [GraphQL field][1].
+///
+/// Converts into a [safe][0] [`FieldError`] with its [`code()`] in the `code` extension.
+///
+/// [`code()`]: Rejection::code
+/// [0]: FieldError::new_safe
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display("{message}")]
+pub struct Rejection {
+    /// Message describing this [`Rejection`].
+    message: Cow<'static, str>,
+
+    /// Machine-readable code of this [`Rejection`].
+    code: Cow<'static, str>,
+}
+
+impl Rejection {
+    /// Default [`code()`] of a [`Rejection`].
+    ///
+    /// [`code()`]: Rejection::code
+    pub const DEFAULT_CODE: &'static str = "FORBIDDEN";
+
+    /// Creates a new [`Rejection`] with the provided `message` and the [`DEFAULT_CODE`].
+    ///
+    /// [`DEFAULT_CODE`]: Rejection::DEFAULT_CODE
+    #[must_use]
+    pub fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            message: message.into(),
+            code: Self::DEFAULT_CODE.into(),
+        }
+    }
+
+    /// Replaces the [`code()`] of this [`Rejection`] with the provided one.
+    ///
+    /// [`code()`]: Rejection::code
+    #[must_use]
+    pub fn with_code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+        self.code = code.into();
+        self
+    }
+
+    /// Returns the message describing this [`Rejection`].
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the machine-readable code of this [`Rejection`].
+    #[must_use]
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl Default for Rejection {
+    fn default() -> Self {
+        Self::new("Forbidden")
+    }
+}
+
+impl<S> GraphQLErrorCode<S> for Rejection {
+    fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl SafeError for Rejection {}
+
+impl<S: ScalarValue> IntoFieldError<S> for Rejection {
+    fn into_field_error(self) -> FieldError<S> {
+        error_code::into_safe_field_error(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultScalarValue, FieldError, IntoFieldError as _};
+
+    use super::{Guard, Rejection};
+
+    struct Context {
+        roles: Vec<&'static str>,
+    }
+
+    struct Role(&'static str);
+
+    impl Guard<Context> for Role {
+        fn check<'a>(
+            &'a self,
+            ctx: &'a Context,
+        ) -> futures::future::BoxFuture<'a, Result<(), Rejection>> {
+            Box::pin(async move {
+                if ctx.roles.contains(&self.0) {
+                    Ok(())
+                } else {
+                    Err(Rejection::new(format!("no `{}` role", self.0)))
+                }
+            })
+        }
+    }
+
+    async fn check(guard: &impl Guard<Context>, roles: &[&'static str]) -> Result<(), Rejection> {
+        guard
+            .check(&Context {
+                roles: roles.to_vec(),
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn and() {
+        let guard = Role("user").and(Role("admin"));
+
+        assert_eq!(check(&guard, &["user", "admin"]).await, Ok(()));
+        assert_eq!(
+            check(&guard, &["admin"]).await,
+            Err(Rejection::new("no `user` role")),
+        );
+        assert_eq!(
+            check(&guard, &["user"]).await,
+            Err(Rejection::new("no `admin` role")),
+        );
+    }
+
+    #[tokio::test]
+    async fn or() {
+        let guard = Role("owner").or(Role("admin"));
+
+        assert_eq!(check(&guard, &["owner"]).await, Ok(()));
+        assert_eq!(check(&guard, &["admin"]).await, Ok(()));
+        assert_eq!(
+            check(&guard, &[]).await,
+            Err(Rejection::new("no `admin` role"))
+        );
+    }
+
+    #[tokio::test]
+    async fn closure() {
+        let guard = |ctx: &Context| {
+            (ctx.roles.len() > 1)
+                .then_some(())
+                .ok_or_else(Rejection::default)
+        };
+
+        assert_eq!(
+            check(&guard.and(Role("user")), &["user", "admin"]).await,
+            Ok(())
+        );
+        assert_eq!(check(&guard, &["user"]).await, Err(Rejection::default()));
+    }
+
+    #[test]
+    fn into_field_error() {
+        let err: FieldError<DefaultScalarValue> = Rejection::new("Not allowed")
+            .with_code("UNAUTHENTICATED")
+            .into_field_error();
+
+        assert_eq!(err.message(), "Not allowed");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "UNAUTHENTICATED"}),
+        );
+    }
+}
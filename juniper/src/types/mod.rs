@@ -1,10 +1,15 @@
 pub mod async_await;
 pub mod base;
 pub mod containers;
+pub mod error_code;
+pub mod guard;
 pub mod marker;
 pub mod name;
 pub mod nullable;
 pub mod pointers;
+#[cfg(feature = "relay")]
+pub mod relay;
 pub mod scalars;
 pub mod subscriptions;
 pub mod utilities;
+pub mod validator;
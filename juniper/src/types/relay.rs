@@ -0,0 +1,841 @@
+//! Helpers for [Relay Cursor Connections][1] pagination and [Global Object Identification][2].
+//!
+//! [1]: https://relay.dev/graphql/connections.htm
+//! [2]: https://graphql.org/learn/global-object-identification
+
+use std::{future::Future, ops::Range};
+
+use arcstr::ArcStr;
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use derive_more::with_trait::{Display, Error, From};
+use futures::future;
+
+use crate::{
+    Arguments, BoxFuture, ExecutionResult, Executor, FieldError, FieldResult, GraphQLErrorCode,
+    GraphQLObject, GraphQLType, GraphQLValue, GraphQLValueAsync, ID, IntoFieldError, Registry,
+    SafeError, ScalarValue, Selection, error_code,
+    macros::reflect,
+    marker::{self, IsOutputType},
+    schema::meta::MetaType,
+};
+
+/// Prefix of the payload encoded into opaque cursors by [`offset_to_cursor()`].
+const CURSOR_PREFIX: &str = "cursor:";
+
+/// Encodes the provided `offset` of an [edge][1] into an opaque cursor.
+///
+/// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+#[must_use]
+pub fn offset_to_cursor(offset: usize) -> String {
+    BASE64.encode(format!("{CURSOR_PREFIX}{offset}"))
+}
+
+/// Decodes an offset of an [edge][1] from the provided opaque `cursor`, previously encoded via
+/// [`offset_to_cursor()`].
+///
+/// Returns [`None`] if the `cursor` is malformed.
+///
+/// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+#[must_use]
+pub fn cursor_to_offset(cursor: &str) -> Option<usize> {
+    let payload = String::from_utf8(BASE64.decode(cursor).ok()?).ok()?;
+    payload.strip_prefix(CURSOR_PREFIX)?.parse().ok()
+}
+
+/// [Arguments][1] of a [connection][2] field, paginating its [edges][3].
+///
+/// [1]: https://relay.dev/graphql/connections.htm#sec-Arguments
+/// [2]: https://relay.dev/graphql/connections.htm#sec-Connection-Types
+/// [3]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConnectionArgs {
+    /// Number of [edges][1] to take from the beginning of the paginated range.
+    ///
+    /// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+    pub first: Option<i32>,
+
+    /// Cursor of the [edge][1] to paginate after.
+    ///
+    /// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+    pub after: Option<String>,
+
+    /// Number of [edges][1] to take from the end of the paginated range.
+    ///
+    /// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+    pub last: Option<i32>,
+
+    /// Cursor of the [edge][1] to paginate before.
+    ///
+    /// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+    pub before: Option<String>,
+}
+
+impl ConnectionArgs {
+    /// Creates new [`ConnectionArgs`] out of the provided `first`, `after`, `last` and `before`
+    /// field arguments.
+    #[must_use]
+    pub fn new(
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Self {
+        Self {
+            first,
+            after,
+            last,
+            before,
+        }
+    }
+
+    /// Validates these [`ConnectionArgs`] and applies them to a list of `total` [edges][1],
+    /// returning the [`Range`] of offsets to be returned, as described in the
+    /// [pagination algorithm][2].
+    ///
+    /// Cursors are expected to be encoded via [`offset_to_cursor()`].
+    ///
+    /// # Errors
+    ///
+    /// - If `first` or `last` is negative.
+    /// - If `after` or `before` is not a valid cursor.
+    ///
+    /// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+    /// [2]: https://relay.dev/graphql/connections.htm#sec-Pagination-algorithm
+    pub fn range(&self, total: usize) -> Result<Range<usize>, ConnectionError> {
+        let cursor = |cursor: &Option<String>, arg| {
+            cursor
+                .as_deref()
+                .map(|c| cursor_to_offset(c).ok_or(ConnectionError::InvalidCursor(arg)))
+                .transpose()
+        };
+        let count = |count: Option<i32>, arg| {
+            count
+                .map(|n| usize::try_from(n).map_err(|_| ConnectionError::NegativeCount(arg)))
+                .transpose()
+        };
+
+        let after = cursor(&self.after, "after")?;
+        let before = cursor(&self.before, "before")?;
+        let first = count(self.first, "first")?;
+        let last = count(self.last, "last")?;
+
+        let mut start = after.map_or(0, |n| n.saturating_add(1)).min(total);
+        let mut end = before.map_or(total, |n| n.min(total)).max(start);
+        if let Some(first) = first {
+            end = end.min(start.saturating_add(first));
+        }
+        if let Some(last) = last {
+            start = start.max(end.saturating_sub(last));
+        }
+        Ok(start..end)
+    }
+}
+
+/// Error of applying invalid [`ConnectionArgs`].
+#[derive(Clone, Copy, Debug, Display, Eq, Error, PartialEq)]
+pub enum ConnectionError {
+    /// `first` or `last` argument is negative.
+    #[display("`{_0}` argument must be a non-negative integer")]
+    NegativeCount(#[error(not(source))] &'static str),
+
+    /// `after` or `before` argument is not a valid cursor.
+    #[display("`{_0}` argument is not a valid cursor")]
+    InvalidCursor(#[error(not(source))] &'static str),
+}
+
+impl ConnectionError {
+    /// Code of [`FieldError`]s converted from [`ConnectionError`]s.
+    pub const CODE: &'static str = "INVALID_PAGINATION";
+}
+
+impl<S> GraphQLErrorCode<S> for ConnectionError {
+    fn code(&self) -> &str {
+        Self::CODE
+    }
+}
+
+impl SafeError for ConnectionError {}
+
+impl<S: ScalarValue> IntoFieldError<S> for ConnectionError {
+    fn into_field_error(self) -> FieldError<S> {
+        error_code::into_safe_field_error(self)
+    }
+}
+
+/// [Connection][1] paginating [edges][2] of the `N` nodes.
+///
+/// Exposed in a GraphQL schema as an [object][3] named after the `N` node type with `Connection`
+/// suffix (like `UserConnection`), so may be used with different node types in the same schema.
+///
+/// [1]: https://relay.dev/graphql/connections.htm#sec-Connection-Types
+/// [2]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+/// [3]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Connection<N> {
+    /// [Edges][1] of this [`Connection`].
+    ///
+    /// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+    pub edges: Vec<Edge<N>>,
+
+    /// [`PageInfo`] of this [`Connection`].
+    pub page_info: PageInfo,
+}
+
+impl<N> Connection<N> {
+    /// Creates a new [`Connection`] out of the provided [`Edge`]s and [`PageInfo`].
+    #[must_use]
+    pub fn new(edges: Vec<Edge<N>>, page_info: PageInfo) -> Self {
+        Self { edges, page_info }
+    }
+
+    /// Creates a new [`Connection`] out of the provided `nodes` loaded for the `range` of offsets
+    /// (as returned by [`ConnectionArgs::range()`]) out of `total` nodes.
+    ///
+    /// Cursors of the [`Edge`]s are encoded via [`offset_to_cursor()`].
+    #[must_use]
+    pub fn from_range(
+        nodes: impl IntoIterator<Item = N>,
+        range: Range<usize>,
+        total: usize,
+    ) -> Self {
+        let edges = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(n, node)| Edge::new(node, offset_to_cursor(range.start + n)))
+            .collect::<Vec<_>>();
+        let end = range.start + edges.len();
+        let page_info = PageInfo {
+            has_previous_page: range.start > 0,
+            has_next_page: end < total,
+            start_cursor: edges.first().map(|e| e.cursor.clone()),
+            end_cursor: edges.last().map(|e| e.cursor.clone()),
+        };
+        Self::new(edges, page_info)
+    }
+
+    /// Paginates the provided `nodes` in memory according to the provided [`ConnectionArgs`].
+    ///
+    /// # Errors
+    ///
+    /// If the provided [`ConnectionArgs`] are invalid.
+    pub fn paginate(
+        nodes: impl IntoIterator<Item = N>,
+        args: &ConnectionArgs,
+    ) -> Result<Self, ConnectionError> {
+        let nodes = nodes.into_iter().collect::<Vec<_>>();
+        let range = args.range(nodes.len())?;
+        let total = nodes.len();
+        let page = nodes
+            .into_iter()
+            .skip(range.start)
+            .take(range.end - range.start);
+        Ok(Self::from_range(page, range, total))
+    }
+}
+
+/// [Edge][1] of a [`Connection`], holding a `N` node along with its cursor.
+///
+/// Exposed in a GraphQL schema as an [object][2] named after the `N` node type with `Edge` suffix
+/// (like `UserEdge`).
+///
+/// [1]: https://relay.dev/graphql/connections.htm#sec-Edge-Types
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Edge<N> {
+    /// Node of this [`Edge`].
+    pub node: N,
+
+    /// Opaque cursor of this [`Edge`].
+    pub cursor: String,
+}
+
+impl<N> Edge<N> {
+    /// Creates a new [`Edge`] out of the provided `node` and its `cursor`.
+    #[must_use]
+    pub fn new(node: N, cursor: impl Into<String>) -> Self {
+        Self {
+            node,
+            cursor: cursor.into(),
+        }
+    }
+}
+
+/// Information about pagination in a [`Connection`], as described in the [specification][1].
+///
+/// [1]: https://relay.dev/graphql/connections.htm#sec-undefined.PageInfo
+#[derive(Clone, Debug, Default, Eq, GraphQLObject, PartialEq)]
+pub struct PageInfo {
+    /// Indicates whether more edges exist prior to the set defined by the pagination arguments.
+    pub has_previous_page: bool,
+
+    /// Indicates whether more edges exist following the set defined by the pagination arguments.
+    pub has_next_page: bool,
+
+    /// Cursor of the first edge in the set defined by the pagination arguments.
+    pub start_cursor: Option<String>,
+
+    /// Cursor of the last edge in the set defined by the pagination arguments.
+    pub end_cursor: Option<String>,
+}
+
+impl<N, S> GraphQLType<S> for Connection<N>
+where
+    N: GraphQLType<S>,
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<ArcStr> {
+        N::name(info).map(|n| arcstr::format!("{n}Connection"))
+    }
+
+    fn meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> MetaType<S> {
+        let fields = [
+            registry.field::<Vec<Edge<N>>>(arcstr::literal!("edges"), info),
+            registry.field::<PageInfo>(arcstr::literal!("pageInfo"), &()),
+        ];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<N, S> GraphQLValue<S> for Connection<N>
+where
+    N: GraphQLType<S>,
+    S: ScalarValue,
+{
+    type Context = N::Context;
+    type TypeInfo = N::TypeInfo;
+
+    fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        <Self as GraphQLType<S>>::name(info)
+            .as_deref()
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        _: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match field {
+            "edges" => executor.resolve(info, &self.edges),
+            "pageInfo" => executor.resolve_with_ctx(&(), &self.page_info),
+            _ => Err(err_no_field(field, Self::name(info))),
+        }
+    }
+}
+
+impl<N, S> GraphQLValueAsync<S> for Connection<N>
+where
+    N: GraphQLType<S> + GraphQLValueAsync<S>,
+    N::TypeInfo: Sync,
+    N::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field: &'a str,
+        _: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            match field {
+                "edges" => executor.resolve_async(info, &self.edges).await,
+                "pageInfo" => executor.resolve_with_ctx_async(&(), &self.page_info).await,
+                _ => Err(err_no_field(field, Self::name(info))),
+            }
+        })
+    }
+}
+
+impl<N, S> GraphQLType<S> for Edge<N>
+where
+    N: GraphQLType<S>,
+    S: ScalarValue,
+{
+    fn name(info: &Self::TypeInfo) -> Option<ArcStr> {
+        N::name(info).map(|n| arcstr::format!("{n}Edge"))
+    }
+
+    fn meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> MetaType<S> {
+        let fields = [
+            registry.field::<N>(arcstr::literal!("node"), info),
+            registry.field::<String>(arcstr::literal!("cursor"), &()),
+        ];
+        registry
+            .build_object_type::<Self>(info, &fields)
+            .into_meta()
+    }
+}
+
+impl<N, S> GraphQLValue<S> for Edge<N>
+where
+    N: GraphQLType<S>,
+    S: ScalarValue,
+{
+    type Context = N::Context;
+    type TypeInfo = N::TypeInfo;
+
+    fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, _: &Self::Context, info: &Self::TypeInfo) -> String {
+        <Self as GraphQLType<S>>::name(info)
+            .as_deref()
+            .unwrap_or_default()
+            .into()
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        _: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match field {
+            "node" => executor.resolve(info, &self.node),
+            "cursor" => executor.resolve_with_ctx(&(), &self.cursor),
+            _ => Err(err_no_field(field, Self::name(info))),
+        }
+    }
+}
+
+impl<N, S> GraphQLValueAsync<S> for Edge<N>
+where
+    N: GraphQLType<S> + GraphQLValueAsync<S>,
+    N::TypeInfo: Sync,
+    N::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field: &'a str,
+        _: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            match field {
+                "node" => executor.resolve_async(info, &self.node).await,
+                "cursor" => executor.resolve_with_ctx_async(&(), &self.cursor).await,
+                _ => Err(err_no_field(field, Self::name(info))),
+            }
+        })
+    }
+}
+
+/// Creates a [`FieldError`] about the `field` being absent on the type with the provided `name`.
+fn err_no_field<S>(field: &str, name: Option<ArcStr>) -> FieldError<S> {
+    FieldError::from(format!(
+        "Field `{field}` not found on type `{}`",
+        name.as_deref().unwrap_or_default(),
+    ))
+}
+
+/// Implements marker and reflection traits for a [Relay] object type, generic over its `N` node
+/// type and named after it with the provided `$suffix`.
+///
+/// [Relay]: https://relay.dev/graphql/connections.htm
+macro_rules! impl_node_object_marks {
+    ($ty:ident: $suffix:literal) => {
+        impl<N, S> IsOutputType<S> for $ty<N>
+        where
+            N: IsOutputType<S>,
+            S: ScalarValue,
+        {
+            fn mark() {
+                N::mark()
+            }
+        }
+
+        impl<N, S> marker::GraphQLObject<S> for $ty<N>
+        where
+            N: GraphQLType<S>,
+            S: ScalarValue,
+        {
+        }
+
+        impl<N, S> reflect::BaseType<S> for $ty<N>
+        where
+            N: reflect::BaseType<S>,
+        {
+            const NAME: reflect::Type = crate::const_concat_generic!(N::NAME, $suffix);
+        }
+
+        impl<N, S> reflect::BaseSubTypes<S> for $ty<N>
+        where
+            N: reflect::BaseType<S>,
+        {
+            const NAMES: reflect::Types = &[<Self as reflect::BaseType<S>>::NAME];
+        }
+
+        impl<N, S> reflect::WrappedType<S> for $ty<N> {
+            const VALUE: reflect::WrappedValue = 1;
+        }
+    };
+}
+
+impl_node_object_marks!(Connection: "Connection");
+impl_node_object_marks!(Edge: "Edge");
+
+/// [Global Object Identification][1] of a node, combining its type name with its ID, unique only
+/// among the nodes of that type.
+///
+/// Encoded into an opaque globally unique [`ID`] via [`GlobalId::to_id()`].
+///
+/// [1]: https://graphql.org/learn/global-object-identification
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GlobalId {
+    /// Name of the node type.
+    pub type_name: String,
+
+    /// ID of the node, unique among the nodes of its type.
+    pub id: String,
+}
+
+impl GlobalId {
+    /// Creates a new [`GlobalId`] out of the provided `type_name` and `id` of a node.
+    #[must_use]
+    pub fn new(type_name: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            id: id.into(),
+        }
+    }
+
+    /// Encodes this [`GlobalId`] into an opaque [`ID`].
+    #[must_use]
+    pub fn to_id(&self) -> ID {
+        ID::new(BASE64.encode(format!("{}:{}", self.type_name, self.id)))
+    }
+
+    /// Decodes a [`GlobalId`] from the provided opaque [`ID`], previously encoded via
+    /// [`GlobalId::to_id()`].
+    ///
+    /// Returns [`None`] if the [`ID`] is malformed.
+    #[must_use]
+    pub fn from_id(id: &ID) -> Option<Self> {
+        let payload = String::from_utf8(BASE64.decode(&**id).ok()?).ok()?;
+        let (type_name, id) = payload.split_once(':')?;
+        Some(Self::new(type_name, id))
+    }
+}
+
+impl From<GlobalId> for ID {
+    fn from(id: GlobalId) -> Self {
+        id.to_id()
+    }
+}
+
+/// Node identified by a [`GlobalId`], resolvable via the [`Node`] interface.
+pub trait Identifiable {
+    /// Returns the [`GlobalId`] of this node.
+    fn global_id(&self) -> GlobalId;
+}
+
+/// `Node` [interface][1] of the [Global Object Identification][2], having a single `id: ID!`
+/// field.
+///
+/// The `T` type represents all the possible node types (usually, a [union][3] of them), and
+/// resolves the concrete node. Only the possible types of `T` (not the `T` itself, in case of a
+/// [union][3]) are registered in a GraphQL schema, each of them declaring the [`Node`] in its
+/// `impl` attribute argument (like `#[graphql(impl = relay::Node<AnyNode>)]`) and having an
+/// `id: ID!` field itself.
+///
+/// The `id` field is resolved via [`Identifiable::global_id()`] of the `T`.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Interfaces
+/// [2]: https://graphql.org/learn/global-object-identification
+/// [3]: https://spec.graphql.org/October2021#sec-Unions
+#[derive(Clone, Debug, Eq, From, PartialEq)]
+pub struct Node<T>(pub T);
+
+impl<T, S> GraphQLType<S> for Node<T>
+where
+    T: GraphQLType<S> + Identifiable,
+    S: ScalarValue,
+{
+    fn name(_: &Self::TypeInfo) -> Option<ArcStr> {
+        Some(arcstr::literal!("Node"))
+    }
+
+    fn meta(info: &Self::TypeInfo, registry: &mut Registry<S>) -> MetaType<S> {
+        // Building `MetaType` of a union registers its possible types only, without the union.
+        if !matches!(T::meta(info, registry), MetaType::Union(_)) {
+            registry.get_type::<T>(info);
+        }
+        let fields = [registry.field::<ID>(arcstr::literal!("id"), &())];
+        registry
+            .build_interface_type::<Self>(info, &fields)
+            .description("An object with a globally unique ID.")
+            .into_meta()
+    }
+}
+
+impl<T, S> GraphQLValue<S> for Node<T>
+where
+    T: GraphQLType<S> + Identifiable,
+    S: ScalarValue,
+{
+    type Context = T::Context;
+    type TypeInfo = T::TypeInfo;
+
+    fn type_name(&self, info: &Self::TypeInfo) -> Option<ArcStr> {
+        <Self as GraphQLType<S>>::name(info)
+    }
+
+    fn concrete_type_name(&self, context: &Self::Context, info: &Self::TypeInfo) -> String {
+        self.0.concrete_type_name(context, info)
+    }
+
+    fn resolve_field(
+        &self,
+        info: &Self::TypeInfo,
+        field: &str,
+        _: &Arguments<S>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        match field {
+            "id" => executor.resolve_with_ctx(&(), &self.0.global_id().to_id()),
+            _ => Err(err_no_field(field, Self::name(info))),
+        }
+    }
+
+    fn resolve_into_type(
+        &self,
+        info: &Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&[Selection<S>]>,
+        executor: &Executor<Self::Context, S>,
+    ) -> ExecutionResult<S> {
+        self.0
+            .resolve_into_type(info, type_name, selection_set, executor)
+    }
+}
+
+impl<T, S> GraphQLValueAsync<S> for Node<T>
+where
+    T: GraphQLType<S> + GraphQLValueAsync<S> + Identifiable,
+    T::TypeInfo: Sync,
+    T::Context: Sync,
+    S: ScalarValue + Send + Sync,
+{
+    fn resolve_field_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        field: &'a str,
+        _: &'a Arguments<S>,
+        executor: &'a Executor<Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        Box::pin(async move {
+            match field {
+                "id" => {
+                    executor
+                        .resolve_with_ctx_async(&(), &self.0.global_id().to_id())
+                        .await
+                }
+                _ => Err(err_no_field(field, Self::name(info))),
+            }
+        })
+    }
+
+    fn resolve_into_type_async<'a>(
+        &'a self,
+        info: &'a Self::TypeInfo,
+        type_name: &str,
+        selection_set: Option<&'a [Selection<'a, S>]>,
+        executor: &'a Executor<'a, 'a, Self::Context, S>,
+    ) -> BoxFuture<'a, ExecutionResult<S>> {
+        self.0
+            .resolve_into_type_async(info, type_name, selection_set, executor)
+    }
+}
+
+impl<T, S> IsOutputType<S> for Node<T>
+where
+    T: IsOutputType<S> + Identifiable,
+    S: ScalarValue,
+{
+    fn mark() {
+        T::mark()
+    }
+}
+
+impl<T, S> marker::GraphQLInterface<S> for Node<T>
+where
+    T: GraphQLType<S> + Identifiable,
+    S: ScalarValue,
+{
+}
+
+impl<T, S> reflect::BaseType<S> for Node<T> {
+    const NAME: reflect::Type = "Node";
+}
+
+impl<T, S> reflect::BaseSubTypes<S> for Node<T>
+where
+    T: reflect::BaseSubTypes<S>,
+{
+    const NAMES: reflect::Types = crate::const_concat_names!(&["Node"], T::NAMES);
+}
+
+impl<T, S> reflect::WrappedType<S> for Node<T> {
+    const VALUE: reflect::WrappedValue = 1;
+}
+
+/// Resolves a `node(id:)` field of the [Global Object Identification][1], by fetching the node
+/// identified by the decoded [`GlobalId`].
+///
+/// Resolves to [`None`] if the provided `id` is malformed.
+///
+/// [1]: https://graphql.org/learn/global-object-identification
+pub async fn fetch_node<N, F, Fut>(id: &ID, fetch: F) -> FieldResult<Option<N>>
+where
+    F: FnOnce(GlobalId) -> Fut,
+    Fut: Future<Output = FieldResult<Option<N>>>,
+{
+    match GlobalId::from_id(id) {
+        Some(id) => fetch(id).await,
+        None => Ok(None),
+    }
+}
+
+/// Resolves a `nodes(ids:)` field of the [Global Object Identification][1], by concurrently
+/// fetching the nodes identified by the decoded [`GlobalId`]s, preserving the order of the
+/// provided `ids`.
+///
+/// Malformed `ids` resolve to [`None`].
+///
+/// [1]: https://graphql.org/learn/global-object-identification
+pub async fn fetch_nodes<N, F, Fut>(ids: &[ID], fetch: F) -> FieldResult<Vec<Option<N>>>
+where
+    F: Fn(GlobalId) -> Fut,
+    Fut: Future<Output = FieldResult<Option<N>>>,
+{
+    future::try_join_all(ids.iter().map(|id| fetch_node(id, &fetch))).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultScalarValue, FieldError, ID, IntoFieldError as _};
+
+    use super::{
+        Connection, ConnectionArgs, ConnectionError, GlobalId, cursor_to_offset, offset_to_cursor,
+    };
+
+    fn args(
+        first: Option<i32>,
+        after: Option<usize>,
+        last: Option<i32>,
+        before: Option<usize>,
+    ) -> ConnectionArgs {
+        ConnectionArgs::new(
+            first,
+            after.map(offset_to_cursor),
+            last,
+            before.map(offset_to_cursor),
+        )
+    }
+
+    #[test]
+    fn roundtrips_cursors() {
+        for offset in [0, 1, 42, usize::MAX] {
+            assert_eq!(cursor_to_offset(&offset_to_cursor(offset)), Some(offset));
+        }
+        assert_eq!(cursor_to_offset("not a cursor"), None);
+        assert_eq!(cursor_to_offset(&GlobalId::new("User", "1").to_id()), None);
+    }
+
+    #[test]
+    fn applies_args() {
+        for (args, expected) in [
+            (args(None, None, None, None), 0..10),
+            (args(Some(3), None, None, None), 0..3),
+            (args(Some(3), Some(2), None, None), 3..6),
+            (args(Some(30), Some(8), None, None), 9..10),
+            (args(None, Some(9), None, None), 10..10),
+            (args(None, None, Some(3), None), 7..10),
+            (args(None, None, Some(3), Some(5)), 2..5),
+            (args(None, None, Some(30), Some(2)), 0..2),
+            (args(None, Some(2), None, Some(6)), 3..6),
+            (args(None, Some(6), None, Some(2)), 7..7),
+            (args(Some(4), Some(0), Some(2), Some(8)), 3..5),
+            (args(Some(0), None, None, None), 0..0),
+        ] {
+            assert_eq!(args.range(10), Ok(expected), "{args:?}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_args() {
+        assert_eq!(
+            args(Some(-1), None, None, None).range(10),
+            Err(ConnectionError::NegativeCount("first")),
+        );
+        assert_eq!(
+            args(None, None, Some(-1), None).range(10),
+            Err(ConnectionError::NegativeCount("last")),
+        );
+        assert_eq!(
+            ConnectionArgs::new(None, Some("foo".into()), None, None).range(10),
+            Err(ConnectionError::InvalidCursor("after")),
+        );
+        assert_eq!(
+            ConnectionArgs::new(None, None, None, Some("foo".into())).range(10),
+            Err(ConnectionError::InvalidCursor("before")),
+        );
+
+        let err: FieldError<DefaultScalarValue> =
+            ConnectionError::InvalidCursor("after").into_field_error();
+        assert_eq!(err.message(), "`after` argument is not a valid cursor");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "INVALID_PAGINATION"}),
+        );
+    }
+
+    #[test]
+    fn paginates() {
+        let conn = Connection::paginate(0..10, &args(Some(2), Some(3), None, None)).unwrap();
+
+        assert_eq!(
+            conn.edges.iter().map(|e| e.node).collect::<Vec<_>>(),
+            [4, 5],
+        );
+        assert_eq!(conn.edges[0].cursor, offset_to_cursor(4));
+        assert!(conn.page_info.has_previous_page);
+        assert!(conn.page_info.has_next_page);
+        assert_eq!(conn.page_info.start_cursor, Some(offset_to_cursor(4)));
+        assert_eq!(conn.page_info.end_cursor, Some(offset_to_cursor(5)));
+
+        let conn = Connection::paginate(0..10, &args(None, None, Some(20), None)).unwrap();
+
+        assert_eq!(conn.edges.len(), 10);
+        assert!(!conn.page_info.has_previous_page);
+        assert!(!conn.page_info.has_next_page);
+
+        let conn = Connection::paginate(0..10, &args(Some(0), None, None, None)).unwrap();
+
+        assert!(conn.edges.is_empty());
+        assert_eq!(conn.page_info.start_cursor, None);
+        assert_eq!(conn.page_info.end_cursor, None);
+    }
+
+    #[test]
+    fn roundtrips_global_ids() {
+        let id = GlobalId::new("User", "42:a");
+
+        assert_eq!(GlobalId::from_id(&id.to_id()), Some(id));
+        assert_eq!(GlobalId::from_id(&ID::new("User:42")), None);
+    }
+}
@@ -298,6 +298,8 @@ where
                     })
                     .clone();
 
+                executor.record_field_usage(meta_type, f.name.item, f.arguments.as_ref());
+
                 let exec_vars = executor.variables();
 
                 let sub_exec = executor.field_sub_executor(
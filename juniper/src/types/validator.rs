@@ -0,0 +1,679 @@
+//! Validators of [GraphQL input values][1], checked while converting them into Rust types.
+//!
+//! [1]: https://spec.graphql.org/October2021#sec-Input-Values
+
+use std::{fmt, rc::Rc, sync::Arc};
+
+use arcstr::ArcStr;
+use compact_str::CompactString;
+use derive_more::with_trait::Display;
+
+#[cfg(feature = "regex-validator")]
+#[doc(no_inline)]
+pub use regex::Regex;
+
+use crate::{
+    FieldError, GraphQLErrorCode, ID, Nullable, Object, SafeError, ScalarValue, Value, error_code,
+};
+
+/// Value of a [GraphQL input value][1] being checked by validators.
+///
+/// Absent values (like [`None`] or [`Nullable::ExplicitNull`]) are not checked by validators at
+/// all, so should be rejected by the type itself, if required.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Values
+pub trait Validatable {
+    /// Type of the value being checked by validators.
+    type Value: ?Sized;
+
+    /// Returns the value to be checked by validators, if any.
+    fn validatable(&self) -> Option<&Self::Value>;
+}
+
+impl<T: Validatable + ?Sized> Validatable for &T {
+    type Value = T::Value;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        (**self).validatable()
+    }
+}
+
+impl<T: Validatable + ?Sized> Validatable for Box<T> {
+    type Value = T::Value;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        (**self).validatable()
+    }
+}
+
+impl<T: Validatable + ?Sized> Validatable for Rc<T> {
+    type Value = T::Value;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        (**self).validatable()
+    }
+}
+
+impl<T: Validatable + ?Sized> Validatable for Arc<T> {
+    type Value = T::Value;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        (**self).validatable()
+    }
+}
+
+impl<T: Validatable> Validatable for Option<T> {
+    type Value = T::Value;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        self.as_ref().and_then(T::validatable)
+    }
+}
+
+impl<T: Validatable> Validatable for Nullable<T> {
+    type Value = T::Value;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        match self {
+            Self::Some(v) => v.validatable(),
+            Self::ImplicitNull | Self::ExplicitNull => None,
+        }
+    }
+}
+
+impl<T> Validatable for Vec<T> {
+    type Value = [T];
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        Some(self)
+    }
+}
+
+impl<T> Validatable for [T] {
+    type Value = Self;
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        Some(self)
+    }
+}
+
+impl<T, const N: usize> Validatable for [T; N] {
+    type Value = [T];
+
+    fn validatable(&self) -> Option<&Self::Value> {
+        Some(self)
+    }
+}
+
+macro_rules! impl_validatable_as {
+    ($target:ty => $($ty:ty),+ $(,)?) => {$(
+        impl Validatable for $ty {
+            type Value = $target;
+
+            fn validatable(&self) -> Option<&Self::Value> {
+                Some(self)
+            }
+        }
+    )+};
+}
+
+impl_validatable_as!(str => str, String, ArcStr, CompactString, ID);
+
+macro_rules! impl_validatable_self {
+    ($($ty:ty),+ $(,)?) => {$(
+        impl Validatable for $ty {
+            type Value = Self;
+
+            fn validatable(&self) -> Option<&Self::Value> {
+                Some(self)
+            }
+        }
+    )+};
+}
+
+impl_validatable_self!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+
+/// Checks the provided string `value` to contain at least `min` characters.
+///
+/// # Errors
+///
+/// If the `value` is shorter than `min` characters.
+pub fn min_length<T>(value: &T, min: usize) -> Result<(), String>
+where
+    T: Validatable<Value: AsRef<str>> + ?Sized,
+{
+    match value.validatable() {
+        Some(v) if v.as_ref().chars().count() < min => {
+            Err(format!("must be at least {min} characters long"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the provided string `value` to contain at most `max` characters.
+///
+/// # Errors
+///
+/// If the `value` is longer than `max` characters.
+pub fn max_length<T>(value: &T, max: usize) -> Result<(), String>
+where
+    T: Validatable<Value: AsRef<str>> + ?Sized,
+{
+    match value.validatable() {
+        Some(v) if v.as_ref().chars().count() > max => {
+            Err(format!("must be at most {max} characters long"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the provided `value` to be within the `min` and `max` bounds (inclusive).
+///
+/// # Errors
+///
+/// If the `value` is out of the bounds.
+pub fn range<T, B>(value: &T, min: Option<B>, max: Option<B>) -> Result<(), String>
+where
+    T: Validatable<Value: PartialOrd<B>> + ?Sized,
+    B: fmt::Display,
+{
+    let Some(v) = value.validatable() else {
+        return Ok(());
+    };
+    let below = min.as_ref().is_some_and(|min| v < min);
+    let above = max.as_ref().is_some_and(|max| v > max);
+    if !below && !above {
+        return Ok(());
+    }
+    Err(match (min, max) {
+        (Some(min), Some(max)) => format!("must be between {min} and {max}"),
+        (Some(min), None) => format!("must be at least {min}"),
+        (None, Some(max)) => format!("must be at most {max}"),
+        (None, None) => unreachable!("cannot be out of unspecified bounds"),
+    })
+}
+
+/// Checks the provided string `value` to match the `pattern`.
+///
+/// # Errors
+///
+/// If the `value` doesn't match the `pattern`.
+#[cfg(feature = "regex-validator")]
+pub fn regex<T>(value: &T, pattern: &Regex) -> Result<(), String>
+where
+    T: Validatable<Value: AsRef<str>> + ?Sized,
+{
+    match value.validatable() {
+        Some(v) if !pattern.is_match(v.as_ref()) => {
+            Err(format!("must match `{}` pattern", pattern.as_str()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the provided string `value` to be a valid email address.
+///
+/// Only the basic structure (`local@domain.tld`) is checked, without resolving the domain.
+///
+/// # Errors
+///
+/// If the `value` is not a valid email address.
+pub fn email<T>(value: &T) -> Result<(), String>
+where
+    T: Validatable<Value: AsRef<str>> + ?Sized,
+{
+    let is_valid = |s: &str| {
+        let Some((local, domain)) = s.split_once('@') else {
+            return false;
+        };
+        !local.is_empty()
+            && !s.chars().any(char::is_whitespace)
+            && domain.contains('.')
+            && domain.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+            })
+    };
+
+    match value.validatable() {
+        Some(v) if !is_valid(v.as_ref()) => Err("must be a valid email address".into()),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the provided string `value` to be a valid absolute URL.
+///
+/// # Errors
+///
+/// If the `value` is not a valid URL.
+#[cfg(feature = "url")]
+pub fn url<T>(value: &T) -> Result<(), String>
+where
+    T: Validatable<Value: AsRef<str>> + ?Sized,
+{
+    match value.validatable() {
+        Some(v) if ::url::Url::parse(v.as_ref()).is_err() => Err("must be a valid URL".into()),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the provided list `value` to contain at least `min` items.
+///
+/// # Errors
+///
+/// If the `value` contains less than `min` items.
+pub fn min_items<T, I>(value: &T, min: usize) -> Result<(), String>
+where
+    T: Validatable<Value: AsRef<[I]>> + ?Sized,
+{
+    match value.validatable() {
+        Some(v) if v.as_ref().len() < min => Err(format!("must contain at least {min} items")),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the provided `value` with the `custom` validator function.
+///
+/// In contrast to other validators, the `value` is passed "as is", without unwrapping it via the
+/// [`Validatable`] trait.
+///
+/// # Errors
+///
+/// If the `custom` validator function fails.
+pub fn custom<T, E>(value: &T, custom: impl FnOnce(&T) -> Result<(), E>) -> Result<(), String>
+where
+    T: ?Sized,
+    E: fmt::Display,
+{
+    custom(value).map_err(|e| e.to_string())
+}
+
+/// Failed validation of a [GraphQL input value][1].
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Values
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[display("`{}` {message}", path.join("."))]
+pub struct InputError {
+    /// Path to the invalid [GraphQL input value][1] from the argument or input object field being
+    /// converted.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Input-Values
+    path: Vec<String>,
+
+    /// Message describing this [`InputError`].
+    message: String,
+}
+
+impl InputError {
+    /// Creates a new [`InputError`] at the provided `path` with the provided `message`.
+    #[must_use]
+    pub fn new(path: Vec<String>, message: impl Into<String>) -> Self {
+        Self {
+            path,
+            message: message.into(),
+        }
+    }
+
+    /// Returns path to the invalid [GraphQL input value][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Input-Values
+    #[must_use]
+    pub fn path(&self) -> &[String] {
+        &self.path
+    }
+
+    /// Returns the message describing this [`InputError`].
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Extracts the [`InputError`]s contained in the provided [`FieldError`], if it represents
+    /// failed validation.
+    #[must_use]
+    pub fn from_field_error<S: ScalarValue>(err: &FieldError<S>) -> Option<Vec<Self>> {
+        let ext = err.extensions().as_object_value()?;
+        let code = ext.get_field_value("code")?.as_scalar()?;
+        if code.try_as_str()? != error_code::BAD_USER_INPUT {
+            return None;
+        }
+        ext.get_field_value("errors")?
+            .as_list_value()?
+            .iter()
+            .map(|e| {
+                let e = e.as_object_value()?;
+                let path = e
+                    .get_field_value("path")?
+                    .as_list_value()?
+                    .iter()
+                    .map(|s| s.as_scalar()?.try_as_str().map(Into::into))
+                    .collect::<Option<_>>()?;
+                let message = e.get_field_value("message")?.as_scalar()?.try_as_str()?;
+                Some(Self::new(path, message))
+            })
+            .collect()
+    }
+
+    /// Converts the provided [`InputError`]s into a single [safe][0] [`FieldError`] with the
+    /// [`error_code::BAD_USER_INPUT`] code, containing all of them in its
+    /// [`FieldError::extensions`].
+    ///
+    /// [0]: FieldError::new_safe
+    #[must_use]
+    pub fn into_field_error<S: ScalarValue>(
+        errors: impl IntoIterator<Item = Self>,
+    ) -> FieldError<S> {
+        error_code::into_safe_field_error(InvalidInput(errors.into_iter().collect()))
+    }
+}
+
+/// [`InputError`]s of a single argument or input object, reported as a single [`FieldError`].
+#[derive(Display)]
+#[display("Invalid input")]
+struct InvalidInput(Vec<InputError>);
+
+impl SafeError for InvalidInput {}
+
+impl<S: ScalarValue> GraphQLErrorCode<S> for InvalidInput {
+    fn code(&self) -> &str {
+        error_code::BAD_USER_INPUT
+    }
+
+    fn extensions(&self) -> Object<S> {
+        let errors = self
+            .0
+            .iter()
+            .map(|e| {
+                let mut obj = Object::with_capacity(2);
+                _ = obj.add_field(
+                    "path",
+                    Value::list(e.path.iter().map(|s| Value::scalar(s.clone())).collect()),
+                );
+                _ = obj.add_field("message", Value::scalar(e.message.clone()));
+                Value::Object(obj)
+            })
+            .collect();
+
+        let mut ext = Object::with_capacity(1);
+        _ = ext.add_field("errors", Value::list(errors));
+        ext
+    }
+}
+
+/// Collector of all the errors happened while converting and validating the fields of a
+/// [GraphQL input object][1] or [arguments][2] of a [GraphQL field][3].
+///
+/// Used in code generated by [`GraphQLInputObject`] derive macro and `#[graphql]` attributes of
+/// field arguments, so all the errors are reported to clients at once, rather than the first one
+/// only.
+///
+/// [`GraphQLInputObject`]: derive@crate::GraphQLInputObject
+/// [1]: https://spec.graphql.org/October2021#sec-Input-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Language.Arguments
+/// [3]: https://spec.graphql.org/October2021#sec-Language.Fields
+#[derive(Debug)]
+pub struct InputErrors<S> {
+    /// Collected [`InputError`]s.
+    errors: Vec<InputError>,
+
+    /// First [`FieldError`] not representing a failed validation.
+    ///
+    /// Returned "as is" if no validation has failed.
+    first: Option<FieldError<S>>,
+
+    /// Indicator whether any validation has failed.
+    is_invalid: bool,
+}
+
+impl<S> Default for InputErrors<S> {
+    fn default() -> Self {
+        Self {
+            errors: vec![],
+            first: None,
+            is_invalid: false,
+        }
+    }
+}
+
+impl<S: ScalarValue> InputErrors<S> {
+    /// Creates a new empty [`InputErrors`] collector.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether no errors have been collected yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Collects the error of converting the value of the `field` with the provided name, if any.
+    ///
+    /// [`InputError`]s contained in the [`FieldError`] are collected with their paths prefixed by
+    /// the `field` name.
+    pub fn convert<T>(&mut self, field: &str, res: Result<T, FieldError<S>>) -> Option<T> {
+        let err = match res {
+            Ok(v) => return Some(v),
+            Err(e) => e,
+        };
+        if let Some(errs) = InputError::from_field_error(&err) {
+            self.is_invalid = true;
+            self.errors.extend(errs.into_iter().map(|mut e| {
+                e.path.insert(0, field.into());
+                e
+            }));
+        } else {
+            self.errors
+                .push(InputError::new(vec![field.into()], err.message()));
+            _ = self.first.get_or_insert(err);
+        }
+        None
+    }
+
+    /// Collects the error of converting the value of a `#[graphql(flatten)]`ed struct field, if
+    /// any.
+    ///
+    /// Unlike [`InputErrors::convert()`], the paths of the collected [`InputError`]s are left
+    /// unprefixed, as the flattened fields belong to the same input object.
+    pub fn flatten<T>(&mut self, res: Result<T, FieldError<S>>) -> Option<T> {
+        let err = match res {
+            Ok(v) => return Some(v),
+            Err(e) => e,
+        };
+        if let Some(errs) = InputError::from_field_error(&err) {
+            self.is_invalid = true;
+            self.errors.extend(errs);
+        } else {
+            self.errors.push(InputError::new(vec![], err.message()));
+            _ = self.first.get_or_insert(err);
+        }
+        None
+    }
+
+    /// Collects the failed validation `results` of the `field` with the provided name.
+    ///
+    /// Returns whether all the validation `results` have succeeded.
+    pub fn validate(
+        &mut self,
+        field: &str,
+        results: impl IntoIterator<Item = Result<(), String>>,
+    ) -> bool {
+        let before = self.errors.len();
+        self.errors.extend(
+            results
+                .into_iter()
+                .filter_map(Result::err)
+                .map(|msg| InputError::new(vec![field.into()], msg)),
+        );
+        let is_valid = self.errors.len() == before;
+        self.is_invalid |= !is_valid;
+        is_valid
+    }
+
+    /// Converts the collected errors into a single [`FieldError`].
+    ///
+    /// If no validation has failed, then the first collected [`FieldError`] is returned "as is".
+    #[must_use]
+    pub fn into_field_error(self) -> FieldError<S> {
+        match self.first {
+            Some(e) if !self.is_invalid => e,
+            _ => InputError::into_field_error(self.errors),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DefaultScalarValue, FieldError, Nullable};
+
+    use super::{InputError, InputErrors, custom, email, max_length, min_items, min_length, range};
+
+    #[test]
+    fn validates_length() {
+        assert_eq!(min_length("abc", 3), Ok(()));
+        assert_eq!(
+            min_length("ab", 3),
+            Err("must be at least 3 characters long".into())
+        );
+        assert_eq!(min_length(&None::<String>, 3), Ok(()));
+        assert_eq!(max_length(&Some("ёжик".to_owned()), 4), Ok(()));
+        assert_eq!(
+            max_length(&Nullable::Some("abcde".to_owned()), 4),
+            Err("must be at most 4 characters long".into()),
+        );
+    }
+
+    #[test]
+    fn validates_range() {
+        assert_eq!(range(&5, Some(1), Some(5)), Ok(()));
+        assert_eq!(
+            range(&0, Some(1), Some(5)),
+            Err("must be between 1 and 5".into())
+        );
+        assert_eq!(
+            range(&Some(0.5), Some(1.0), None),
+            Err("must be at least 1".into())
+        );
+        assert_eq!(range(&None::<i32>, None, Some(1)), Ok(()));
+    }
+
+    #[test]
+    fn validates_email() {
+        assert_eq!(email("luke@skywalker.com"), Ok(()));
+        for invalid in [
+            "luke",
+            "@skywalker.com",
+            "luke@skywalker",
+            "lu ke@sky.com",
+            "a@b..c",
+        ] {
+            assert_eq!(
+                email(invalid),
+                Err("must be a valid email address".into()),
+                "{invalid}",
+            );
+        }
+    }
+
+    #[test]
+    fn validates_items() {
+        assert_eq!(min_items(&vec![1, 2], 2), Ok(()));
+        assert_eq!(
+            min_items(&Some(vec![1]), 2),
+            Err("must contain at least 2 items".into()),
+        );
+    }
+
+    #[test]
+    fn validates_custom() {
+        let even = |v: &i32| {
+            if v % 2 == 0 {
+                Ok(())
+            } else {
+                Err("must be even")
+            }
+        };
+
+        assert_eq!(custom(&2, even), Ok(()));
+        assert_eq!(custom(&3, even), Err("must be even".into()));
+    }
+
+    #[test]
+    fn collects_errors() {
+        let mut nested = InputErrors::<DefaultScalarValue>::new();
+        assert!(!nested.validate("name", [min_length("", 1), email("")]));
+        assert!(nested.validate("age", [range(&3, Some(1), None)]));
+
+        let mut errors = InputErrors::new();
+        assert_eq!(
+            errors.convert::<()>("user", Err(nested.into_field_error())),
+            None,
+        );
+        assert_eq!(errors.convert("id", Ok(1)), Some(1));
+        assert_eq!(
+            errors.convert::<()>("kind", Err(FieldError::from("Unknown kind"))),
+            None,
+        );
+
+        let err = errors.into_field_error();
+        assert_eq!(err.message(), "Invalid input");
+        assert!(err.is_safe());
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({
+                "code": "BAD_USER_INPUT",
+                "errors": [{
+                    "path": ["user", "name"],
+                    "message": "must be at least 1 characters long",
+                }, {
+                    "path": ["user", "name"],
+                    "message": "must be a valid email address",
+                }, {
+                    "path": ["kind"],
+                    "message": "Unknown kind",
+                }],
+            }),
+        );
+        assert_eq!(
+            InputError::from_field_error(&err).map(|errs| errs.len()),
+            Some(3),
+        );
+    }
+
+    #[test]
+    fn collects_flattened_errors() {
+        let mut flattened = InputErrors::<DefaultScalarValue>::new();
+        assert!(!flattened.validate("limit", [range(&0, Some(1), None)]));
+
+        let mut errors = InputErrors::new();
+        assert_eq!(
+            errors.flatten::<()>(Err(flattened.into_field_error())),
+            None
+        );
+        assert_eq!(errors.flatten(Ok(1)), Some(1));
+
+        assert_eq!(
+            errors.into_field_error().extensions(),
+            &graphql_value!({
+                "code": "BAD_USER_INPUT",
+                "errors": [{"path": ["limit"], "message": "must be at least 1"}],
+            }),
+        );
+    }
+
+    #[test]
+    fn returns_conversion_error_as_is() {
+        let mut errors = InputErrors::<DefaultScalarValue>::new();
+        assert_eq!(
+            errors.convert::<()>("kind", Err(FieldError::from("Unknown kind"))),
+            None,
+        );
+
+        let err = errors.into_field_error();
+        assert_eq!(err, FieldError::from("Unknown kind"));
+        assert!(!err.is_safe());
+    }
+}
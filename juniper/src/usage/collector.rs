@@ -0,0 +1,551 @@
+//! Collecting usage of schema fields, arguments and enum values during execution.
+
+use std::{
+    collections::BTreeMap,
+    fmt, mem,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use arcstr::ArcStr;
+use derive_more::with_trait::Display;
+use fnv::FnvHashMap;
+use serde::Serialize;
+
+use crate::{
+    ast::{Arguments, InputValue},
+    executor::Variables,
+    parser::Spanning,
+    schema::{
+        meta::{EnumMeta, Field, MetaType},
+        model::SchemaType,
+    },
+    value::{ScalarValue, Value},
+};
+
+/// Collector of the schema coordinates resolved while executing operations against a
+/// [`RootNode`], grouped by operation names.
+///
+/// It's opt-in: a [`RootNode`] records nothing until [`RootNode::collect_field_usage()`] is
+/// called. Once collected, a [`FieldUsageReport`] may tell whether it's safe to remove a
+/// deprecated field, argument or enum value.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// #
+/// # use juniper::{
+/// #     EmptyMutation, EmptySubscription, RootNode, graphql_object, graphql_vars,
+/// #     usage::FieldUsageCollector,
+/// # };
+/// #
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     #[graphql(deprecated = "Use `name`.")]
+///     fn title() -> &'static str {
+///         "Luke"
+///     }
+///
+///     fn name() -> &'static str {
+///         "Luke"
+///     }
+/// }
+///
+/// let collector = Arc::new(FieldUsageCollector::new());
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new())
+///     .collect_field_usage(Arc::clone(&collector));
+///
+/// # // language=GraphQL
+/// let query = "query Legacy { title name }";
+/// juniper::execute_sync(query, None, &schema, &graphql_vars! {}, &()).unwrap();
+///
+/// let report = collector.report();
+/// let deprecated = report.deprecated().collect::<Vec<_>>();
+/// assert_eq!(deprecated, [("Legacy", "Query.title")]);
+/// ```
+///
+/// [`RootNode`]: crate::RootNode
+/// [`RootNode::collect_field_usage()`]: crate::RootNode::collect_field_usage
+#[derive(Debug, Default)]
+pub struct FieldUsageCollector {
+    /// [`FieldUsageReport`] collected so far.
+    report: Mutex<FieldUsageReport>,
+}
+
+impl FieldUsageCollector {
+    /// Creates a new empty [`FieldUsageCollector`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a snapshot of the [`FieldUsageReport`] collected so far.
+    #[must_use]
+    pub fn report(&self) -> FieldUsageReport {
+        self.report.lock().unwrap().clone()
+    }
+
+    /// Returns the [`FieldUsageReport`] collected so far, and resets this [`FieldUsageCollector`].
+    #[must_use]
+    pub fn take_report(&self) -> FieldUsageReport {
+        std::mem::take(&mut *self.report.lock().unwrap())
+    }
+
+    /// Merges the provided [`CoordinateUsage`]s by [schema coordinates][0] into the ones of the
+    /// operation with the provided `name`.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    fn merge<C: fmt::Display>(
+        &self,
+        name: Option<&str>,
+        usages: impl IntoIterator<Item = (C, CoordinateUsage)>,
+    ) {
+        let mut report = self.report.lock().unwrap();
+        let operation = report
+            .operations
+            .entry(name.unwrap_or_default().into())
+            .or_default();
+        for (coordinate, usage) in usages {
+            let total = operation.entry(coordinate.to_string()).or_default();
+            total.count += usage.count;
+            total.deprecated = usage.deprecated;
+        }
+    }
+}
+
+/// Report of a [`FieldUsageCollector`].
+///
+/// Serializes into [JSON] (or any other format supported by [`serde`]) as an object mapping
+/// operation names to objects mapping [schema coordinates][0] to their [`CoordinateUsage`]s.
+///
+/// [JSON]: https://www.json.org
+/// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct FieldUsageReport {
+    /// [`CoordinateUsage`]s by [schema coordinates][0] by operation names.
+    ///
+    /// Anonymous operations are reported under an empty name.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    operations: BTreeMap<String, BTreeMap<String, CoordinateUsage>>,
+}
+
+impl FieldUsageReport {
+    /// Returns the [`CoordinateUsage`]s by [schema coordinates][0] by operation names, in
+    /// lexicographical order.
+    ///
+    /// Anonymous operations are reported under an empty name.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    #[must_use]
+    pub fn operations(&self) -> &BTreeMap<String, BTreeMap<String, CoordinateUsage>> {
+        &self.operations
+    }
+
+    /// Returns the [`CoordinateUsage`] of the provided [schema coordinate][0] by the operation with
+    /// the provided `name` (or an anonymous one, if [`None`]), if it was resolved at all.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    #[must_use]
+    pub fn usage(&self, name: Option<&str>, coordinate: &str) -> Option<CoordinateUsage> {
+        self.operations
+            .get(name.unwrap_or_default())?
+            .get(coordinate)
+            .copied()
+    }
+
+    /// Indicates whether the provided [schema coordinate][0] was resolved by any operation.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    #[must_use]
+    pub fn is_used(&self, coordinate: &str) -> bool {
+        self.operations
+            .values()
+            .any(|coords| coords.contains_key(coordinate))
+    }
+
+    /// Returns an iterator over the operation names and deprecated [schema coordinates][0] they
+    /// resolved.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    pub fn deprecated(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.operations.iter().flat_map(|(name, coords)| {
+            coords
+                .iter()
+                .filter(|(_, usage)| usage.deprecated)
+                .map(move |(coord, _)| (name.as_str(), coord.as_str()))
+        })
+    }
+}
+
+/// Usage of a [schema coordinate][0] in a [`FieldUsageReport`].
+///
+/// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct CoordinateUsage {
+    /// Number of times the [schema coordinate][0] was resolved.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    pub count: u64,
+
+    /// Indicator whether the [schema coordinate][0] is deprecated.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    pub deprecated: bool,
+}
+
+/// [Schema coordinate][0] resolved by an operation, made of the names shared with its schema (so
+/// cloning them doesn't allocate).
+///
+/// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+#[derive(Clone, Debug, Display, Eq, Hash, PartialEq)]
+enum Coordinate {
+    /// Field of a type, like `Type.field`.
+    #[display("{_0}.{_1}")]
+    Field(ArcStr, ArcStr),
+
+    /// Argument of a field, like `Type.field(arg:)`.
+    #[display("{_0}.{_1}({_2}:)")]
+    Argument(ArcStr, ArcStr, ArcStr),
+
+    /// Value of an enum, like `Enum.VALUE`.
+    #[display("{_0}.{_1}")]
+    EnumValue(ArcStr, ArcStr),
+}
+
+/// [`CoordinateUsage`]s by [`Coordinate`]s of a single operation.
+type Usages = FnvHashMap<Coordinate, CoordinateUsage>;
+
+/// Counts a single usage of the provided [`Coordinate`] in the provided [`Usages`].
+fn count(usages: &mut Usages, coordinate: Coordinate, deprecated: bool) {
+    let usage = usages.entry(coordinate).or_default();
+    usage.count += 1;
+    usage.deprecated = deprecated;
+}
+
+/// [`FieldUsageCollector`] bound to the operation being executed.
+///
+/// Buffers the usage of the operation (shared by all its [`Executor`]s), and merges it into the
+/// [`FieldUsageCollector`] once the operation is done, so the shared [`FieldUsageCollector`] is
+/// locked once per operation rather than once per field.
+///
+/// [`Executor`]: crate::Executor
+#[derive(Clone, Debug)]
+pub(crate) struct FieldUsageRecorder<'a>(Arc<OperationUsage<'a>>);
+
+/// Usage of the operation being executed, buffered by a [`FieldUsageRecorder`].
+#[derive(Debug)]
+struct OperationUsage<'a> {
+    /// [`FieldUsageCollector`] to merge into.
+    collector: &'a FieldUsageCollector,
+
+    /// Name of the operation being executed.
+    operation: Option<&'a str>,
+
+    /// [`Usages`] buffered so far.
+    usages: Mutex<Usages>,
+}
+
+impl Drop for OperationUsage<'_> {
+    fn drop(&mut self) {
+        let usages = self
+            .usages
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+        self.collector.merge(self.operation, mem::take(usages));
+    }
+}
+
+impl<'a> FieldUsageRecorder<'a> {
+    /// Binds the provided [`FieldUsageCollector`] to the operation with the provided name.
+    pub(crate) fn new(collector: &'a FieldUsageCollector, operation: Option<&'a str>) -> Self {
+        Self(Arc::new(OperationUsage {
+            collector,
+            operation,
+            usages: Mutex::default(),
+        }))
+    }
+
+    /// Records the resolution of the field with the provided name of the provided `parent` type,
+    /// along with the `arguments` it's been called with and the enum values they contain.
+    pub(crate) fn record_field<S: ScalarValue>(
+        &self,
+        schema: &SchemaType<S>,
+        parent: &MetaType<S>,
+        field_name: &str,
+        arguments: Option<&Spanning<Arguments<'_, S>>>,
+        variables: &Variables<S>,
+    ) {
+        let (Some(parent), Some(field)) = (parent.name(), parent.field_by_name(field_name)) else {
+            return;
+        };
+        let mut usages = self.0.usages.lock().unwrap();
+        count(
+            &mut usages,
+            Coordinate::Field(parent.clone(), field.name.clone()),
+            field.deprecation_status.is_deprecated(),
+        );
+
+        for (name, value) in arguments.into_iter().flat_map(|args| args.item.iter()) {
+            let Some(arg) = field
+                .arguments
+                .iter()
+                .flatten()
+                .find(|arg| arg.name == name.item)
+            else {
+                continue;
+            };
+            count(
+                &mut usages,
+                Coordinate::Argument(parent.clone(), field.name.clone(), arg.name.clone()),
+                arg.deprecation_status.is_deprecated(),
+            );
+            input_enum_values(
+                schema,
+                arg.arg_type.innermost_name(),
+                &value.item,
+                variables,
+                &mut usages,
+            );
+        }
+    }
+
+    /// Records the enum values contained in the provided resolved `value` of the provided `field`.
+    pub(crate) fn record_output<S: ScalarValue>(
+        &self,
+        schema: &SchemaType<S>,
+        field: &Field<S>,
+        value: &Value<S>,
+    ) {
+        if let Some(MetaType::Enum(meta)) =
+            schema.concrete_type_by_name(field.field_type.innermost_name())
+        {
+            output_enum_values(meta, value, &mut self.0.usages.lock().unwrap());
+        }
+    }
+}
+
+/// Counts the enum values contained in the provided `value` of the provided input type.
+fn input_enum_values<S: ScalarValue>(
+    schema: &SchemaType<S>,
+    type_name: &str,
+    value: &InputValue<S>,
+    variables: &Variables<S>,
+    usages: &mut Usages,
+) {
+    match value {
+        InputValue::Variable(name) => {
+            if let Some(value) = variables.get(name) {
+                input_enum_values(schema, type_name, value, variables, usages);
+            }
+        }
+        InputValue::List(items) => {
+            for item in items {
+                input_enum_values(schema, type_name, &item.item, variables, usages);
+            }
+        }
+        InputValue::Object(fields) => {
+            let Some(ty) = schema.concrete_type_by_name(type_name) else {
+                return;
+            };
+            for (name, value) in fields {
+                if let Some(field) = ty.input_field_by_name(&name.item) {
+                    let field_type = field.arg_type.innermost_name();
+                    input_enum_values(schema, field_type, &value.item, variables, usages);
+                }
+            }
+        }
+        InputValue::Enum(_) | InputValue::Scalar(_) => {
+            let Some(MetaType::Enum(meta)) = schema.concrete_type_by_name(type_name) else {
+                return;
+            };
+            // Enum values provided via variables are represented as strings.
+            if let Some(name) = value
+                .as_enum_value()
+                .or_else(|| value.as_scalar()?.try_as_str())
+            {
+                enum_value(meta, name, usages);
+            }
+        }
+        InputValue::Null => {}
+    }
+}
+
+/// Counts the values of the provided enum contained in the provided resolved `value`.
+fn output_enum_values<S: ScalarValue>(meta: &EnumMeta<S>, value: &Value<S>, usages: &mut Usages) {
+    match value {
+        Value::List(items) => {
+            for item in items {
+                output_enum_values(meta, item, usages);
+            }
+        }
+        Value::Scalar(s) => {
+            if let Some(name) = s.try_as_str() {
+                enum_value(meta, name, usages);
+            }
+        }
+        Value::Null | Value::Object(_) => {}
+    }
+}
+
+/// Counts the value with the provided `name` of the provided enum.
+fn enum_value<S>(meta: &EnumMeta<S>, name: &str, usages: &mut Usages) {
+    if let Some(v) = meta.values.iter().find(|v| v.name == name) {
+        count(
+            usages,
+            Coordinate::EnumValue(meta.name.clone(), v.name.clone()),
+            v.deprecation_status.is_deprecated(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        EmptyMutation, EmptySubscription, GraphQLEnum, GraphQLInputObject, RootNode, execute,
+        execute_sync, graphql_object,
+    };
+
+    use super::{CoordinateUsage, FieldUsageCollector};
+
+    #[derive(Clone, Copy, GraphQLEnum)]
+    enum Color {
+        Red,
+        #[graphql(deprecated = "Use `RED`.")]
+        Crimson,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Filter {
+        colors: Vec<Color>,
+    }
+
+    struct Item;
+
+    #[graphql_object]
+    impl Item {
+        fn id() -> i32 {
+            1
+        }
+
+        #[graphql(deprecated = "Use `id`.")]
+        fn legacy_id() -> i32 {
+            1
+        }
+
+        fn colors() -> Vec<Option<Color>> {
+            vec![Some(Color::Crimson), None, Some(Color::Crimson)]
+        }
+    }
+
+    struct Query;
+
+    #[graphql_object]
+    impl Query {
+        fn items(
+            filter: Option<Filter>,
+            #[graphql(deprecated = "Use `filter`.")] color: Option<Color>,
+        ) -> Vec<Item> {
+            _ = (filter, color);
+            vec![Item, Item]
+        }
+    }
+
+    type Schema = RootNode<Query, EmptyMutation, EmptySubscription>;
+
+    fn usage(count: u64, deprecated: bool) -> Option<CoordinateUsage> {
+        Some(CoordinateUsage { count, deprecated })
+    }
+
+    #[tokio::test]
+    async fn collects_per_operation() {
+        let collector = Arc::new(FieldUsageCollector::new());
+        let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .collect_field_usage(Arc::clone(&collector));
+
+        // language=GraphQL
+        let query = r#"
+            query Modern($colors: [Color!]!) { items(filter: {colors: $colors}) { id } }
+            query Legacy { items(color: CRIMSON) { legacyId id __typename } }
+        "#;
+        let vars = graphql_vars! {"colors": ["RED"]};
+        execute(query, Some("Modern"), &schema, &vars, &())
+            .await
+            .unwrap();
+        execute_sync(query, Some("Legacy"), &schema, &graphql_vars! {}, &()).unwrap();
+
+        let report = collector.take_report();
+
+        assert_eq!(report.usage(Some("Modern"), "Query.items"), usage(1, false));
+        assert_eq!(
+            report.usage(Some("Modern"), "Query.items(filter:)"),
+            usage(1, false),
+        );
+        assert_eq!(report.usage(Some("Modern"), "Color.RED"), usage(1, false));
+        assert_eq!(report.usage(Some("Modern"), "Item.id"), usage(2, false));
+        assert_eq!(report.usage(Some("Legacy"), "Item.id"), usage(2, false));
+        assert_eq!(report.usage(Some("Legacy"), "Item.__typename"), None);
+        assert_eq!(
+            report.deprecated().collect::<Vec<_>>(),
+            [
+                ("Legacy", "Color.CRIMSON"),
+                ("Legacy", "Item.legacyId"),
+                ("Legacy", "Query.items(color:)"),
+            ],
+        );
+        assert!(report.is_used("Color.RED"));
+        assert!(!report.is_used("Item.__typename"));
+        assert_eq!(collector.report(), Default::default());
+    }
+
+    #[test]
+    fn collects_output_enum_values() {
+        let collector = Arc::new(FieldUsageCollector::new());
+        let schema = Schema::new(Query, EmptyMutation::new(), EmptySubscription::new())
+            .collect_field_usage(Arc::clone(&collector));
+
+        // language=GraphQL
+        let query = "query Output { items { colors } }";
+        execute_sync(query, None, &schema, &graphql_vars! {}, &()).unwrap();
+
+        let report = collector.take_report();
+
+        assert_eq!(report.usage(Some("Output"), "Item.colors"), usage(2, false));
+        assert_eq!(
+            report.usage(Some("Output"), "Color.CRIMSON"),
+            usage(4, true)
+        );
+        assert_eq!(report.usage(Some("Output"), "Color.RED"), None);
+    }
+
+    #[test]
+    fn serializes_into_json() {
+        let collector = FieldUsageCollector::new();
+        collector.merge(
+            None,
+            [
+                ("Query.items", usage(1, false).unwrap()),
+                ("Item.legacyId", usage(1, true).unwrap()),
+            ],
+        );
+        collector.merge(Some("Named"), [("Query.items", usage(1, false).unwrap())]);
+
+        assert_eq!(
+            serde_json::to_value(collector.report()).unwrap(),
+            serde_json::json!({
+                "": {
+                    "Item.legacyId": {"count": 1, "deprecated": true},
+                    "Query.items": {"count": 1, "deprecated": false},
+                },
+                "Named": {
+                    "Query.items": {"count": 1, "deprecated": false},
+                },
+            }),
+        );
+    }
+}
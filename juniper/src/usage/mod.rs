@@ -0,0 +1,11 @@
+//! Helpers for reporting how GraphQL operations use a schema (for metrics, usage reporting,
+//! persisted queries registries, etc.).
+
+mod collector;
+mod signature;
+
+pub(crate) use self::collector::FieldUsageRecorder;
+pub use self::{
+    collector::{CoordinateUsage, FieldUsageCollector, FieldUsageReport},
+    signature::OperationSignature,
+};
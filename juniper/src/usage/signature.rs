@@ -0,0 +1,511 @@
+//! [Apollo-style][0] operation signatures.
+//!
+//! [0]: https://www.apollographql.com/docs/graphos/metrics/operation-signatures
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+use crate::{
+    GraphQLError,
+    ast::{
+        Arguments, Definition, Directive, Document, Field, Fragment, FragmentSpread,
+        InlineFragment, InputValue, Operation, OperationType, PrintStyle, Selection,
+        VariablesDefinition, print_document,
+    },
+    executor::get_operation,
+    parser::Spanning,
+    schema::{
+        meta::{Argument, MetaType},
+        model::SchemaType,
+    },
+    value::ScalarValue,
+};
+
+/// [Apollo-style][0] signature of a GraphQL operation, along with the schema coordinates it
+/// references.
+///
+/// The signature is the operation printed in a normalized form, so the operations differing only
+/// in formatting, literal values, aliases, or order of fields and arguments have the same one:
+/// - only the operation itself and the fragments it uses (sorted by name) are kept;
+/// - `Int` and `Float` literals are replaced with `0`, `String` ones with `""`, lists with `[]`
+///   and input objects with `{}` (variables, enum values, `Boolean`s and `null`s are kept);
+/// - aliases and descriptions are removed;
+/// - selections are sorted (fields first, then fragment spreads, then inline fragments) by name,
+///   while arguments, directives and variables are sorted by name;
+/// - insignificant whitespace is removed (as in [`PrintStyle::Minified`]).
+///
+/// # Example
+///
+/// ```rust
+/// # use juniper::{
+/// #     DefaultScalarValue, EmptyMutation, EmptySubscription, GraphQLEnum, RootNode,
+/// #     graphql_object, parser::parse_document_source, usage::OperationSignature,
+/// # };
+/// #
+/// #[derive(Clone, Copy, GraphQLEnum)]
+/// enum Episode {
+///     Empire,
+///     Jedi,
+/// }
+///
+/// struct Query;
+///
+/// #[graphql_object]
+/// impl Query {
+///     fn hero(episode: Episode, limit: i32) -> &'static str {
+///         "Luke"
+///     }
+/// }
+///
+/// let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+/// let document = parse_document_source::<DefaultScalarValue>(
+///     "query Hero { luke: hero(limit: 3, episode: EMPIRE) }",
+///     &schema.schema,
+/// )
+/// .unwrap();
+///
+/// let signature = OperationSignature::new(&document, None, &schema.schema).unwrap();
+///
+/// assert_eq!(signature.signature(), "query Hero{hero(episode:EMPIRE,limit:0)}");
+/// assert_eq!(
+///     signature.coordinates().iter().collect::<Vec<_>>(),
+///     [
+///         "Episode.EMPIRE",
+///         "Query.hero",
+///         "Query.hero(episode:)",
+///         "Query.hero(limit:)",
+///     ],
+/// );
+/// ```
+///
+/// [0]: https://www.apollographql.com/docs/graphos/metrics/operation-signatures
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationSignature {
+    /// Normalized source text of the operation.
+    signature: String,
+
+    /// [Schema coordinates][0] referenced by the operation.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    coordinates: BTreeSet<String>,
+}
+
+impl OperationSignature {
+    /// Computes the [`OperationSignature`] of the operation with the provided `operation_name` (or
+    /// the only one, if [`None`]) in the provided [`Document`], resolving the types it references
+    /// in the provided [`SchemaType`].
+    ///
+    /// # Errors
+    ///
+    /// If the operation cannot be found in the [`Document`].
+    pub fn new<S: ScalarValue>(
+        document: &Document<'_, S>,
+        operation_name: Option<&str>,
+        schema: &SchemaType<S>,
+    ) -> Result<Self, GraphQLError> {
+        let operation = get_operation(document, operation_name)?;
+
+        let mut normalizer = Normalizer {
+            schema,
+            fragments: document
+                .iter()
+                .filter_map(|def| match def {
+                    Definition::Fragment(f) => Some((f.item.name.item, &f.item)),
+                    Definition::Operation(_) => None,
+                })
+                .collect(),
+            used_fragments: BTreeMap::new(),
+            coordinates: BTreeSet::new(),
+        };
+        let operation = normalizer.operation(&operation.item);
+
+        let mut normalized = vec![Definition::Operation(Spanning::unlocated(operation))];
+        normalized.extend(
+            normalizer
+                .used_fragments
+                .into_values()
+                .flatten()
+                .map(|f| Definition::Fragment(Spanning::unlocated(f))),
+        );
+
+        Ok(Self {
+            signature: print_document(&normalized, PrintStyle::Minified),
+            coordinates: normalizer.coordinates,
+        })
+    }
+
+    /// Returns the normalized source text of the operation.
+    #[must_use]
+    pub fn signature(&self) -> &str {
+        &self.signature
+    }
+
+    /// Returns the [schema coordinates][0] referenced by the operation, in lexicographical order.
+    ///
+    /// Includes the referenced fields (like `Type.field`), their arguments (like
+    /// `Type.field(arg:)`), input object fields (like `Input.field`) and enum values (like
+    /// `Enum.VALUE`). Fields and types unknown to the schema are omitted.
+    ///
+    /// [0]: https://spec.graphql.org/draft#sec-Schema-Coordinates
+    #[must_use]
+    pub fn coordinates(&self) -> &BTreeSet<String> {
+        &self.coordinates
+    }
+}
+
+impl fmt::Display for OperationSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.signature)
+    }
+}
+
+/// Normalizer of an [`Operation`] and the [`Fragment`]s it uses, collecting the schema coordinates
+/// they reference.
+struct Normalizer<'a, 'd, S> {
+    /// [`SchemaType`] to resolve the referenced types in.
+    schema: &'a SchemaType<S>,
+
+    /// All the [`Fragment`]s of the [`Document`] by their names.
+    fragments: HashMap<&'d str, &'a Fragment<'d, S>>,
+
+    /// Normalized [`Fragment`]s used by the [`Operation`] by their names.
+    ///
+    /// [`None`] if the [`Fragment`] is being normalized at the moment, or is unknown.
+    used_fragments: BTreeMap<&'d str, Option<Fragment<'d, S>>>,
+
+    /// Collected schema coordinates.
+    coordinates: BTreeSet<String>,
+}
+
+impl<'a, 'd, S: ScalarValue> Normalizer<'a, 'd, S> {
+    fn operation(&mut self, op: &Operation<'d, S>) -> Operation<'d, S> {
+        let root = match op.operation_type {
+            OperationType::Query => Some(self.schema.concrete_query_type()),
+            OperationType::Mutation => self.schema.concrete_mutation_type(),
+            OperationType::Subscription => self.schema.concrete_subscription_type(),
+        };
+        Operation {
+            description: None,
+            operation_type: op.operation_type,
+            name: op.name,
+            variables_definition: op.variables_definition.as_ref().map(|vars| {
+                let mut items = vars
+                    .item
+                    .items
+                    .iter()
+                    .map(|(name, var)| {
+                        let mut var = var.clone();
+                        var.description = None;
+                        if let Some(default) = &mut var.default_value {
+                            let ty = self.concrete_type(var.var_type.item.innermost_name());
+                            default.item = self.value(&default.item, ty);
+                        }
+                        var.directives = self.directives(var.directives.as_deref());
+                        (*name, var)
+                    })
+                    .collect::<Vec<_>>();
+                items.sort_by_key(|(name, _)| name.item);
+                Spanning::new(vars.span, VariablesDefinition { items })
+            }),
+            directives: self.directives(op.directives.as_deref()),
+            selection_set: self.selection_set(&op.selection_set, root),
+        }
+    }
+
+    fn fragment(&mut self, name: &'d str) {
+        if self.used_fragments.contains_key(name) {
+            return;
+        }
+        self.used_fragments.insert(name, None);
+        let Some(frag) = self.fragments.get(name).copied() else {
+            return;
+        };
+        let ty = self.concrete_type(frag.type_condition.item);
+        let normalized = Fragment {
+            name: frag.name,
+            description: None,
+            type_condition: frag.type_condition,
+            directives: self.directives(frag.directives.as_deref()),
+            selection_set: self.selection_set(&frag.selection_set, ty),
+        };
+        self.used_fragments.insert(name, Some(normalized));
+    }
+
+    fn selection_set(
+        &mut self,
+        selection_set: &[Selection<'d, S>],
+        parent: Option<&'a MetaType<S>>,
+    ) -> Vec<Selection<'d, S>> {
+        let mut normalized = selection_set
+            .iter()
+            .map(|selection| {
+                let (kind, selection) = match selection {
+                    Selection::Field(field) => {
+                        let normalized = self.field(&field.item, parent);
+                        (0, Selection::Field(Spanning::new(field.span, normalized)))
+                    }
+                    Selection::FragmentSpread(spread) => {
+                        self.fragment(spread.item.name.item);
+                        let normalized = FragmentSpread {
+                            name: spread.item.name,
+                            directives: self.directives(spread.item.directives.as_deref()),
+                        };
+                        (
+                            1,
+                            Selection::FragmentSpread(Spanning::new(spread.span, normalized)),
+                        )
+                    }
+                    Selection::InlineFragment(frag) => {
+                        let ty = frag
+                            .item
+                            .type_condition
+                            .map_or(parent, |t| self.concrete_type(t.item));
+                        let normalized = InlineFragment {
+                            type_condition: frag.item.type_condition,
+                            directives: self.directives(frag.item.directives.as_deref()),
+                            selection_set: self.selection_set(&frag.item.selection_set, ty),
+                        };
+                        (
+                            2,
+                            Selection::InlineFragment(Spanning::new(frag.span, normalized)),
+                        )
+                    }
+                };
+                ((kind, selection.to_string()), selection)
+            })
+            .collect::<Vec<_>>();
+        normalized.sort_by(|(a, _), (b, _)| a.cmp(b));
+        normalized.into_iter().map(|(_, s)| s).collect()
+    }
+
+    fn field(&mut self, field: &Field<'d, S>, parent: Option<&'a MetaType<S>>) -> Field<'d, S> {
+        let parent_name = parent.and_then(MetaType::name);
+        let meta_field = parent.and_then(|p| p.field_by_name(field.name.item));
+        if let (Some(parent_name), Some(_)) = (parent_name, meta_field) {
+            self.coordinates
+                .insert(format!("{parent_name}.{}", field.name.item));
+        }
+
+        let arguments = field.arguments.as_ref().map(|args| {
+            let normalized = self.arguments(
+                &args.item,
+                meta_field.and_then(|f| f.arguments.as_deref()),
+                |arg| parent_name.map(|p| format!("{p}.{}({arg}:)", field.name.item)),
+            );
+            Spanning::new(args.span, normalized)
+        });
+        let ty = meta_field.and_then(|f| self.concrete_type(f.field_type.innermost_name()));
+
+        Field {
+            alias: None,
+            name: field.name,
+            arguments,
+            directives: self.directives(field.directives.as_deref()),
+            selection_set: field
+                .selection_set
+                .as_ref()
+                .map(|set| self.selection_set(set, ty)),
+        }
+    }
+
+    fn directives(
+        &mut self,
+        directives: Option<&[Spanning<Directive<'d, S>>]>,
+    ) -> Option<Vec<Spanning<Directive<'d, S>>>> {
+        let mut normalized = directives?
+            .iter()
+            .map(|dir| {
+                let meta_args = self
+                    .schema
+                    .directive_by_name(dir.item.name.item)
+                    .map(|d| d.arguments.as_slice());
+                let arguments = dir.item.arguments.as_ref().map(|args| {
+                    let normalized = self.arguments(&args.item, meta_args, |_| None);
+                    Spanning::new(args.span, normalized)
+                });
+                Spanning::new(
+                    dir.span,
+                    Directive {
+                        name: dir.item.name,
+                        arguments,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        normalized.sort_by_key(|dir| dir.item.name.item);
+        Some(normalized)
+    }
+
+    /// Normalizes the provided [`Arguments`], collecting the coordinates of the known ones, as
+    /// returned by the provided `coordinate` function.
+    fn arguments(
+        &mut self,
+        args: &Arguments<'d, S>,
+        meta_args: Option<&'a [Argument<S>]>,
+        coordinate: impl Fn(&str) -> Option<String>,
+    ) -> Arguments<'d, S> {
+        let mut items = args
+            .items
+            .iter()
+            .map(|(name, value)| {
+                let meta_arg = meta_args.and_then(|args| args.iter().find(|a| a.name == name.item));
+                if meta_arg.is_some() {
+                    self.coordinates.extend(coordinate(name.item));
+                }
+                let ty = meta_arg.and_then(|a| self.concrete_type(a.arg_type.innermost_name()));
+                (
+                    *name,
+                    Spanning::new(value.span, self.value(&value.item, ty)),
+                )
+            })
+            .collect::<Vec<_>>();
+        items.sort_by_key(|(name, _)| name.item);
+        Arguments { items }
+    }
+
+    /// Replaces literals in the provided [`InputValue`] with placeholders, collecting the
+    /// coordinates of the enum values and input object fields it contains.
+    fn value(&mut self, value: &InputValue<S>, ty: Option<&'a MetaType<S>>) -> InputValue<S> {
+        match value {
+            InputValue::Null | InputValue::Variable(_) => value.clone(),
+            InputValue::Scalar(s) => {
+                if s.try_to_bool().is_some() {
+                    value.clone()
+                } else if s.try_as_str().is_some() {
+                    InputValue::scalar(String::new())
+                } else {
+                    InputValue::scalar(0)
+                }
+            }
+            InputValue::Enum(v) => {
+                if let Some(MetaType::Enum(meta)) = ty {
+                    self.coordinates.insert(format!("{}.{v}", meta.name));
+                }
+                value.clone()
+            }
+            InputValue::List(items) => {
+                for item in items {
+                    self.value(&item.item, ty);
+                }
+                InputValue::List(vec![])
+            }
+            InputValue::Object(fields) => {
+                for (name, value) in fields {
+                    let meta_field = ty.and_then(|t| t.input_field_by_name(&name.item));
+                    if let (Some(ty_name), Some(_)) = (ty.and_then(MetaType::name), meta_field) {
+                        self.coordinates.insert(format!("{ty_name}.{}", name.item));
+                    }
+                    let field_ty =
+                        meta_field.and_then(|f| self.concrete_type(f.arg_type.innermost_name()));
+                    self.value(&value.item, field_ty);
+                }
+                InputValue::Object(vec![])
+            }
+        }
+    }
+
+    /// Returns the concrete [`MetaType`] with the provided `name`, if any.
+    fn concrete_type(&self, name: &str) -> Option<&'a MetaType<S>> {
+        self.schema.concrete_type_by_name(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parser::parse_document_source,
+        schema::model::RootNode,
+        tests::fixtures::starwars::schema::{Database, Query},
+        types::scalars::{EmptyMutation, EmptySubscription},
+        value::DefaultScalarValue,
+    };
+
+    use super::OperationSignature;
+
+    fn signature(query: &str, operation_name: Option<&str>) -> OperationSignature {
+        let schema = RootNode::new(
+            Query,
+            EmptyMutation::<Database>::new(),
+            EmptySubscription::<Database>::new(),
+        );
+        let document = parse_document_source::<DefaultScalarValue>(query, &schema.schema).unwrap();
+        OperationSignature::new(&document, operation_name, &schema.schema).unwrap()
+    }
+
+    #[test]
+    fn normalizes() {
+        // language=GraphQL
+        let query = r#"
+            fragment Unused on Droid { primaryFunction }
+
+            "Description."
+            query Heroes($ep: Episode = JEDI, $id: String!) {
+                human(id: $id) { ...Names homePlanet }
+                droid(id: "2001") { ... on Droid { primaryFunction } id }
+                hero(episode: EMPIRE) @include(if: true) { appearsIn }
+                luke: human(id: "1000") @skip(if: false) { ...Names }
+            }
+
+            fragment Names on Character { name friends { name } }
+        "#;
+        // language=GraphQL
+        let reformatted = r#"
+            query Heroes($id: String!, $ep: Episode = JEDI) {
+                human(id: "1001") @skip(if: false) { ...Names }
+                hero(episode: EMPIRE) @include(if: true) { appearsIn }
+                droid(id: "42") { id, ... on Droid { primaryFunction } }
+                leia: human(id: $id) { homePlanet, ...Names }
+            }
+            fragment Names on Character { friends { name }, name }
+        "#;
+
+        let sig = signature(query, Some("Heroes"));
+
+        assert_eq!(
+            sig.signature(),
+            "query Heroes($ep:Episode=JEDI,$id:String!){\
+             droid(id:\"\"){id ...on Droid{primaryFunction}} \
+             hero(episode:EMPIRE)@include(if:true){appearsIn} \
+             human(id:\"\")@skip(if:false){...Names} \
+             human(id:$id){homePlanet ...Names}\
+             } \
+             fragment Names on Character{friends{name} name}",
+        );
+        assert_eq!(sig, signature(reformatted, None));
+        assert_eq!(
+            sig.coordinates().iter().collect::<Vec<_>>(),
+            [
+                "Character.appearsIn",
+                "Character.friends",
+                "Character.name",
+                "Droid.id",
+                "Droid.primaryFunction",
+                "Episode.EMPIRE",
+                "Episode.JEDI",
+                "Human.homePlanet",
+                "Query.droid",
+                "Query.droid(id:)",
+                "Query.hero",
+                "Query.hero(episode:)",
+                "Query.human",
+                "Query.human(id:)",
+            ],
+        );
+    }
+
+    #[test]
+    fn replaces_literals() {
+        let sig = signature(
+            r#"{ hero(episode: null) { name } human(id: "1", extra: ["x", {a: JEDI}]) { id } }"#,
+            None,
+        );
+
+        assert_eq!(
+            sig.signature(),
+            "{hero(episode:null){name} human(extra:[],id:\"\"){id}}",
+        );
+        assert!(!sig.coordinates().contains("Query.human(extra:)"));
+    }
+}
@@ -5,6 +5,7 @@ use itertools::Itertools as _;
 
 use crate::{
     ast::{BorrowedType, Definition, Document},
+    error_code,
     parser::SourcePosition,
     schema::{meta::MetaType, model::SchemaType},
 };
@@ -15,6 +16,7 @@ use crate::{
 pub struct RuleError {
     locations: Vec<SourcePosition>,
     message: String,
+    code: &'static str,
 }
 
 #[doc(hidden)]
@@ -35,9 +37,17 @@ impl RuleError {
         Self {
             message: message.into(),
             locations: locations.to_vec(),
+            code: error_code::GRAPHQL_VALIDATION_FAILED,
         }
     }
 
+    #[doc(hidden)]
+    #[must_use]
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = code;
+        self
+    }
+
     /// Access the message for a validation error
     pub fn message(&self) -> &str {
         &self.message
@@ -50,6 +60,17 @@ impl RuleError {
     pub fn locations(&self) -> &[SourcePosition] {
         &self.locations
     }
+
+    /// Returns the stable machine-readable [code][0] of this validation error.
+    ///
+    /// [`error_code::GRAPHQL_VALIDATION_FAILED`] for the errors of validating a document, and
+    /// [`error_code::BAD_USER_INPUT`] for the errors of coercing the provided variables.
+    ///
+    /// [0]: error_code
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
 }
 
 impl<'a, S: Debug> ValidatorContext<'a, S> {
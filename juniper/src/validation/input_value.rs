@@ -4,6 +4,7 @@ use derive_more::with_trait::Display;
 
 use crate::{
     ast::{InputValue, Operation, VariablesDefinition},
+    error_code,
     executor::Variables,
     parser::{SourcePosition, Spanning},
     schema::{
@@ -64,13 +65,16 @@ fn validate_var_defs<S>(
                 let ct = schema.make_type(&def.var_type.item);
 
                 if def.var_type.item.is_non_null() && is_absent_or_null(values.get(name.item)) {
-                    errors.push(RuleError::new(
-                        &format!(
-                            r#"Variable "${}" of required type "{}" was not provided."#,
-                            name.item, def.var_type.item,
-                        ),
-                        &[name.span.start],
-                    ));
+                    errors.push(
+                        RuleError::new(
+                            &format!(
+                                r#"Variable "${}" of required type "{}" was not provided."#,
+                                name.item, def.var_type.item,
+                            ),
+                            &[name.span.start],
+                        )
+                        .with_code(error_code::BAD_USER_INPUT),
+                    );
                 } else if let Some(v) = values.get(name.item) {
                     errors.append(&mut unify_value(
                         name.item,
@@ -369,4 +373,5 @@ fn unification_error(
         &format!(r#"Variable "${var_name}" got invalid value. {path}{message}."#),
         &[*var_pos],
     )
+    .with_code(error_code::BAD_USER_INPUT)
 }
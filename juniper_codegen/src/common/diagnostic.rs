@@ -13,6 +13,8 @@ pub(crate) const SPEC_URL: &str = "https://spec.graphql.org/October2021";
 pub(crate) enum Scope {
     #[display("enum")]
     EnumDerive,
+    #[display("error")]
+    ErrorCodeDerive,
     #[display("input object")]
     InputObjectDerive,
     #[display("interface")]
@@ -39,6 +41,7 @@ impl Scope {
     pub(crate) fn spec_section(&self) -> &str {
         match self {
             Self::EnumDerive => "#sec-Enums",
+            Self::ErrorCodeDerive => "#sec-Errors",
             Self::InputObjectDerive => "#sec-Input-Objects",
             Self::InterfaceAttr | Self::InterfaceDerive => "#sec-Interfaces",
             Self::ObjectAttr | Self::ObjectDerive => "#sec-Objects",
@@ -6,7 +6,7 @@
 use std::mem;
 
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned};
 use syn::{
     ext::IdentExt as _,
     parse::{Parse, ParseStream},
@@ -20,7 +20,7 @@ use crate::common::{
         ParseBufferExt as _, TypeExt as _,
         attr::{OptionExt as _, err},
     },
-    path_eq_single, rename, scalar,
+    path_eq_single, rename, scalar, validator,
 };
 
 /// Available metadata (arguments) behind `#[graphql]` attribute placed on a
@@ -61,6 +61,17 @@ pub(crate) struct Attr {
     /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
     pub(crate) default: Option<SpanContainer<default::Value>>,
 
+    /// Explicitly specified function computing a default value of this [GraphQL argument][1] from
+    /// the [`Context`] at execution time.
+    ///
+    /// If specified, then this [GraphQL argument][1] is registered as a `null`able one without a
+    /// static [default value][2].
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
+    pub(crate) default_with: Option<SpanContainer<syn::Expr>>,
+
     /// Explicitly specified marker indicating that this method argument doesn't
     /// represent a [GraphQL argument][1], but is a [`Context`] being injected
     /// into a [GraphQL field][2] resolving function.
@@ -84,6 +95,11 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) executor: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified [`validator::Validators`] of this [GraphQL argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) validators: validator::Validators,
 }
 
 impl Parse for Attr {
@@ -122,6 +138,13 @@ impl Parse for Attr {
                         .replace(SpanContainer::new(ident.span(), Some(val.span()), val))
                         .none_or_else(|_| err::dup_arg(&ident))?
                 }
+                "default_with" => {
+                    input.parse::<token::Eq>()?;
+                    let func = input.parse::<syn::Expr>()?;
+                    out.default_with
+                        .replace(SpanContainer::new(ident.span(), Some(func.span()), func))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 "ctx" | "context" | "Context" => {
                     let span = ident.span();
                     out.context
@@ -135,7 +158,9 @@ impl Parse for Attr {
                         .none_or_else(|_| err::dup_arg(span))?
                 }
                 name => {
-                    return Err(err::unknown_arg(&ident, name));
+                    if !out.validators.parse_arg(&ident, input)? {
+                        return Err(err::unknown_arg(&ident, name));
+                    }
                 }
             }
             input.try_parse::<token::Comma>()?;
@@ -153,8 +178,10 @@ impl Attr {
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
             default: try_merge_opt!(default: self, another),
+            default_with: try_merge_opt!(default_with: self, another),
             context: try_merge_opt!(context: self, another),
             executor: try_merge_opt!(executor: self, another),
+            validators: self.validators.try_merge(another.validators)?,
         })
     }
 
@@ -170,7 +197,9 @@ impl Attr {
                 || attr.description.is_some()
                 || attr.deprecated.is_some()
                 || attr.default.is_some()
+                || attr.default_with.is_some()
                 || attr.executor.is_some()
+                || attr.validators.span().is_some()
             {
                 return Err(syn::Error::new(
                     context.span(),
@@ -184,7 +213,9 @@ impl Attr {
                 || attr.description.is_some()
                 || attr.deprecated.is_some()
                 || attr.default.is_some()
+                || attr.default_with.is_some()
                 || attr.context.is_some()
+                || attr.validators.span().is_some()
             {
                 return Err(syn::Error::new(
                     executor.span(),
@@ -193,6 +224,13 @@ impl Attr {
             }
         }
 
+        if let (Some(default), Some(_)) = (&attr.default, &attr.default_with) {
+            return Err(syn::Error::new(
+                default.span_ident(),
+                "`default` attribute argument is not composable with `default_with` one",
+            ));
+        }
+
         if attr.deprecated.is_none() && attr.context.is_none() && attr.executor.is_none() {
             attr.deprecated = deprecation::Directive::parse_from_deprecated_attr(attrs)?;
         }
@@ -212,6 +250,12 @@ impl Attr {
         if let Some(span) = &self.default {
             return Err(Self::err_disallowed(&span, "default"));
         }
+        if let Some(span) = &self.default_with {
+            return Err(Self::err_disallowed(&span, "default_with"));
+        }
+        if let Some(span) = self.validators.span() {
+            return Err(syn::Error::new(span, "validators are not allowed here"));
+        }
         Ok(())
     }
 
@@ -256,11 +300,41 @@ pub(crate) struct OnField {
     /// [2]: https://spec.graphql.org/October2021#sec-Required-Arguments
     pub(crate) default: Option<default::Value>,
 
+    /// Function computing a default value of this [GraphQL field argument][1] from the
+    /// [`Context`] at execution time.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) default_with: Option<syn::Expr>,
+
     /// [Deprecation][2] of this [GraphQL field argument][1] to put into GraphQL schema.
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
     /// [2]: https://spec.graphql.org/October2021#sec-Deprecation
     pub(crate) deprecated: Option<deprecation::Directive>,
+
+    /// [`validator::Validators`] of this [GraphQL field argument][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) validators: validator::Validators,
+}
+
+impl OnField {
+    /// Returns generated code of the [`WrappedValue`] of this [GraphQL field argument][1], used
+    /// for reflection.
+    ///
+    /// [`WrappedValue`]: juniper::macros::reflect::WrappedValue
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    #[must_use]
+    pub(crate) fn wrapped_value_tokens(&self, scalar: &scalar::Type) -> TokenStream {
+        let ty = &self.ty;
+        let val = quote! { <#ty as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE };
+        if self.default_with.is_some() {
+            quote! { ::juniper::macros::reflect::nullable(#val) }
+        } else {
+            val
+        }
+    }
 }
 
 /// Possible kinds of Rust method arguments for code generation.
@@ -316,18 +390,20 @@ impl OnMethod {
         field_name: &str,
     ) -> Option<TokenStream> {
         let arg = self.as_regular()?;
-        (arg.deprecated.is_some() && arg.default.is_none()).then(|| {
-            let arg_ty = &arg.ty;
-            let arg_name = &arg.name;
-            quote_spanned! { arg_ty.span() =>
-                ::juniper::assert_field_arg_deprecable!(
-                    #ty,
-                    #const_scalar,
-                    #field_name,
-                    #arg_name,
-                );
-            }
-        })
+        (arg.deprecated.is_some() && arg.default.is_none() && arg.default_with.is_none()).then(
+            || {
+                let arg_ty = &arg.ty;
+                let arg_name = &arg.name;
+                quote_spanned! { arg_ty.span() =>
+                    ::juniper::assert_field_arg_deprecable!(
+                        #ty,
+                        #const_scalar,
+                        #field_name,
+                        #arg_name,
+                    );
+                }
+            },
+        )
     }
 
     /// Returns generated code for the [`marker::IsOutputType::mark`] method, which performs static
@@ -361,6 +437,10 @@ impl OnMethod {
             quote_spanned! { val.span() =>
                 .arg_with_default::<#ty>(::juniper::arcstr::literal!(#name), &#val, info)
             }
+        } else if arg.default_with.is_some() {
+            quote! {
+                .arg_with_computed_default::<#ty>(::juniper::arcstr::literal!(#name), info)
+            }
         } else {
             quote! {
                 .arg::<#ty>(::juniper::arcstr::literal!(#name), info)
@@ -371,50 +451,102 @@ impl OnMethod {
     }
 
     /// Returns generated code for the [`GraphQLValue::resolve_field`] method,
-    /// which provides the value of this [`OnMethod`] argument to be passed into
-    /// a trait method call.
+    /// which provides the values of the provided [`OnMethod`] `arguments` to be
+    /// passed into a trait method call.
+    ///
+    /// Returns the code converting and validating the values of all the
+    /// regular `arguments` into variables named with the provided `prefix`
+    /// first, which collects all their errors and returns them as a single
+    /// [`FieldError`], along with the expressions of all the `arguments` to be
+    /// passed into the call.
     ///
+    /// [`FieldError`]: juniper::FieldError
     /// [`GraphQLValue::resolve_field`]: juniper::GraphQLValue::resolve_field
     #[must_use]
-    pub(crate) fn method_resolve_field_tokens(
-        &self,
+    pub(crate) fn method_resolve_field_tokens<'a>(
+        arguments: impl IntoIterator<Item = &'a Self>,
         scalar: &scalar::Type,
         for_async: bool,
-    ) -> TokenStream {
-        match self {
-            Self::Regular(arg) => {
-                let (name, ty) = (&arg.name, &arg.ty);
-                let err_text = format!("Missing argument `{name}`: {{}}");
-
-                let arg = quote! {
-                    args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
-                        <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
-                            .map_err(|e| {
-                                ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
-                                    .map_message(|m| format!(#err_text, m))
-                            })
-                    }, ::core::result::Result::Ok))
-                };
-                if for_async {
-                    quote! {
-                        match #arg {
-                            ::core::result::Result::Ok(v) => v,
-                            ::core::result::Result::Err(e) => return ::std::boxed::Box::pin(async {
-                                ::core::result::Result::Err(e)
-                            }),
+        prefix: &str,
+    ) -> (TokenStream, Vec<TokenStream>) {
+        let mut converts = vec![];
+        let mut idents = vec![];
+
+        let values = arguments
+            .into_iter()
+            .enumerate()
+            .map(|(i, arg)| match arg {
+                Self::Regular(arg) => {
+                    let (name, ty) = (&arg.name, &arg.ty);
+                    let err_text = format!("Missing argument `{name}`: {{}}");
+
+                    let validate = arg.validators.filter_tokens(name);
+
+                    let value = if let Some(func) = &arg.default_with {
+                        quote_spanned! { func.span() =>
+                            match args.get_input_value(#name).map(|v| &v.item) {
+                                ::core::option::Option::Some(v) if !v.is_null() => v
+                                    .convert::<#ty>()
+                                    .map_err(
+                                        ::juniper::IntoFieldError::<#scalar>::into_field_error,
+                                    ),
+                                _ => {
+                                    let default_with = #func;
+                                    ::core::result::Result::Ok(default_with(executor.context()))
+                                }
+                            }
                         }
-                    }
-                } else {
-                    quote! { #arg? }
+                    } else {
+                        quote! {
+                            args.get::<#ty>(#name).and_then(|opt| opt.map_or_else(|| {
+                                <#ty as ::juniper::FromInputValue<#scalar>>::from_implicit_null()
+                                    .map_err(|e| {
+                                        ::juniper::IntoFieldError::<#scalar>::into_field_error(e)
+                                            .map_message(|m| format!(#err_text, m))
+                                    })
+                            }, ::core::result::Result::Ok))
+                        }
+                    };
+
+                    let ident = format_ident!("{prefix}{i}");
+                    converts.push(quote! {
+                        let #ident = errors.convert::<#ty>(#name, #value) #validate;
+                    });
+                    idents.push(ident.clone());
+                    quote! { #ident }
                 }
-            }
 
-            Self::Context(_) => quote! {
-                ::juniper::FromContext::from(executor.context())
-            },
+                Self::Context(_) => quote! {
+                    ::juniper::FromContext::from(executor.context())
+                },
+
+                Self::Executor => quote! { &executor },
+            })
+            .collect();
 
-            Self::Executor => quote! { &executor },
+        if idents.is_empty() {
+            return (quote! {}, values);
         }
+
+        let err = if for_async {
+            quote! {
+                let e = errors.into_field_error();
+                return ::std::boxed::Box::pin(async { ::core::result::Result::Err(e) });
+            }
+        } else {
+            quote! {
+                return ::core::result::Result::Err(errors.into_field_error());
+            }
+        };
+        let convert = quote! {
+            let mut errors = ::juniper::validator::InputErrors::<#scalar>::new();
+            #( #converts )*
+            let ( #( ::core::option::Option::Some(#idents), )* ) = ( #( #idents, )* ) else {
+                #err
+            };
+        };
+
+        (convert, values)
     }
 
     /// Parses an [`OnMethod`] argument from the given Rust method argument
@@ -493,7 +625,9 @@ impl OnMethod {
             ty: argument.ty.as_ref().clone(),
             description: attr.description.map(SpanContainer::into_inner),
             default: attr.default.map(SpanContainer::into_inner),
+            default_with: attr.default_with.map(SpanContainer::into_inner),
             deprecated: attr.deprecated.map(SpanContainer::into_inner),
+            validators: attr.validators,
         })))
     }
 }
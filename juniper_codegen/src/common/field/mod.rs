@@ -6,7 +6,7 @@
 pub(crate) mod arg;
 
 use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
     parse::{Parse, ParseStream},
     parse_quote,
@@ -62,6 +62,49 @@ pub(crate) struct Attr {
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified function filtering items of the [`Stream`] returned
+    /// by the [GraphQL subscription][2] field resolver, before resolving them
+    /// as this [GraphQL field][1].
+    ///
+    /// The function receives a reference to the [`Stream`] item, references to
+    /// the [GraphQL arguments][3] of this [GraphQL field][1] (in their order of
+    /// declaration) and a reference to the [`Context`], and returns a [`bool`]
+    /// whether the item should be resolved and emitted.
+    ///
+    /// Applicable to [GraphQL subscription][2] fields only.
+    ///
+    /// [`Context`]: juniper::Context
+    /// [`Stream`]: futures::Stream
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Subscription
+    /// [3]: https://spec.graphql.org/October2021#sec-Language.Arguments
+    pub(crate) filter: Option<SpanContainer<syn::ExprPath>>,
+
+    /// Explicitly specified [`Guard`] authorizing resolution of this
+    /// [GraphQL field][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) guard: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified maximum number of list elements and sibling fields to be resolved
+    /// concurrently when resolving this [GraphQL field][1] asynchronously.
+    ///
+    /// If [`None`], then the limit of the `RootNode` is used, if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) concurrency: Option<SpanContainer<syn::LitInt>>,
+
+    /// Explicitly specified marker indicating that the [GraphQL fields][1] of this struct field
+    /// type should be spliced into the parent [GraphQL object][2] instead of representing a
+    /// single [GraphQL field][1].
+    ///
+    /// Applicable to `#[derive(GraphQLObject)]` struct fields only.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) flatten: Option<SpanContainer<syn::Ident>>,
 }
 
 impl Parse for Attr {
@@ -98,6 +141,41 @@ impl Parse for Attr {
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "filter" => {
+                    input.parse::<token::Eq>()?;
+                    let filter = input.parse::<syn::ExprPath>()?;
+                    out.filter
+                        .replace(SpanContainer::new(
+                            ident.span(),
+                            Some(filter.span()),
+                            filter,
+                        ))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<syn::Expr>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "concurrency" => {
+                    input.parse::<token::Eq>()?;
+                    let limit = input.parse::<syn::LitInt>()?;
+                    if limit.base10_parse::<usize>()? == 0 {
+                        return Err(syn::Error::new(
+                            limit.span(),
+                            "`concurrency` attribute argument should be greater than 0",
+                        ));
+                    }
+                    out.concurrency
+                        .replace(SpanContainer::new(ident.span(), Some(limit.span()), limit))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
                 name => {
                     return Err(err::unknown_arg(&ident, name));
                 }
@@ -117,6 +195,10 @@ impl Attr {
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            filter: try_merge_opt!(filter: self, another),
+            guard: try_merge_opt!(guard: self, another),
+            concurrency: try_merge_opt!(concurrency: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
         })
     }
 
@@ -130,7 +212,14 @@ impl Attr {
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
         if let Some(ignore) = &attr.ignore {
-            if attr.name.is_some() || attr.description.is_some() || attr.deprecated.is_some() {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.filter.is_some()
+                || attr.guard.is_some()
+                || attr.concurrency.is_some()
+                || attr.flatten.is_some()
+            {
                 return Err(syn::Error::new(
                     ignore.span(),
                     "`ignore` attribute argument is not composable with any other arguments",
@@ -138,6 +227,22 @@ impl Attr {
             }
         }
 
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.filter.is_some()
+                || attr.guard.is_some()
+                || attr.concurrency.is_some()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+            return Ok(attr);
+        }
+
         if attr.description.is_none() {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
@@ -148,6 +253,34 @@ impl Attr {
 
         Ok(attr)
     }
+
+    /// Checks whether this [`Attr`] doesn't contain arguments applicable to
+    /// [GraphQL subscription][1] fields only.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Subscription
+    pub(crate) fn ensure_no_subscription_arguments(&self) -> syn::Result<()> {
+        if let Some(filter) = &self.filter {
+            return Err(syn::Error::new(
+                filter.span_ident(),
+                "`filter` attribute argument is applicable to `#[graphql_subscription]` \
+                 fields only",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks whether this [`Attr`] doesn't contain the `flatten` argument, applicable to
+    /// `#[derive(GraphQLObject)]` struct fields only.
+    pub(crate) fn ensure_no_flatten(&self) -> syn::Result<()> {
+        if let Some(flatten) = &self.flatten {
+            return Err(syn::Error::new(
+                flatten.span_ident(),
+                "`flatten` attribute argument is applicable to `#[derive(GraphQLObject)]` struct \
+                 fields only",
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Representation of a [GraphQL field][1] for code generation.
@@ -204,6 +337,27 @@ pub(crate) struct Definition {
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) is_async: bool,
+
+    /// Function filtering items of the [`Stream`] returned by the
+    /// [GraphQL subscription][2] field resolver, before resolving them as this
+    /// [GraphQL field][1].
+    ///
+    /// [`Stream`]: futures::Stream
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Subscription
+    pub(crate) filter: Option<syn::ExprPath>,
+
+    /// [`Guard`] authorizing resolution of this [GraphQL field][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) guard: Option<syn::Expr>,
+
+    /// Maximum number of list elements and sibling fields to be resolved concurrently when
+    /// resolving this [GraphQL field][1] asynchronously.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) concurrency: Option<syn::LitInt>,
 }
 
 impl Definition {
@@ -216,6 +370,18 @@ impl Definition {
         self.arguments.is_some()
     }
 
+    /// Prepends the provided [`Guard`] of the whole type to the [`Guard`] of
+    /// this [GraphQL field][1], so it's checked first.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) fn prepend_guard(&mut self, guard: &syn::Expr) {
+        self.guard = Some(match self.guard.take() {
+            Some(own) => parse_quote! { ::juniper::guard::And(#guard, #own) },
+            None => guard.clone(),
+        });
+    }
+
     /// Returns generated code that errors about unknown [GraphQL field][1]
     /// tried to be resolved in the [`GraphQLValue::resolve_field`] method.
     ///
@@ -236,6 +402,76 @@ impl Definition {
         }
     }
 
+    /// Returns generated code panicking about this [GraphQL field][1] being
+    /// resolved synchronously, while it can be resolved asynchronously only.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn method_resolve_field_panic_async_tokens(
+        &self,
+        scalar: &scalar::Type,
+    ) -> TokenStream {
+        let name = &self.name;
+        quote! {
+            ::core::panic!(
+                "Tried to resolve async field `{}` on type `{}` with a sync resolver",
+                #name,
+                <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+            );
+        }
+    }
+
+    /// Returns generated code erroring about the guarded [GraphQL field][1]
+    /// with the provided `name` being resolved synchronously, while its
+    /// [`Guard`] can be checked asynchronously only.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn method_resolve_field_err_guarded_sync_tokens(
+        name: impl ToTokens,
+        scalar: &scalar::Type,
+    ) -> TokenStream {
+        quote! {
+            return ::core::result::Result::Err(::juniper::FieldError::from(::std::format!(
+                "Tried to resolve guarded field `{}` on type `{}` with a sync resolver",
+                #name,
+                <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+            )))
+        }
+    }
+
+    /// Returns generated code checking the [`Guard`] of this [GraphQL field][1]
+    /// (if any) inside an `async` block, and returning its [`Rejection`] as a
+    /// [`FieldError`].
+    ///
+    /// [`FieldError`]: juniper::FieldError
+    /// [`Guard`]: juniper::Guard
+    /// [`Rejection`]: juniper::guard::Rejection
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn method_check_guard_tokens(&self, scalar: &scalar::Type) -> Option<TokenStream> {
+        self.guard
+            .as_ref()
+            .map(|guard| Self::check_guard_tokens(guard, scalar))
+    }
+
+    /// Returns generated code checking the provided [`Guard`] inside an `async`
+    /// block, and returning its [`Rejection`] as a [`FieldError`].
+    ///
+    /// [`FieldError`]: juniper::FieldError
+    /// [`Guard`]: juniper::Guard
+    /// [`Rejection`]: juniper::guard::Rejection
+    #[must_use]
+    pub(crate) fn check_guard_tokens(guard: &syn::Expr, scalar: &scalar::Type) -> TokenStream {
+        quote_spanned! { guard.span() =>
+            let guard = #guard;
+            ::juniper::Guard::<Self::Context>::check(&guard, executor.context())
+                .await
+                .map_err(::juniper::IntoFieldError::<#scalar>::into_field_error)?;
+        }
+    }
+
     /// Returns generated code for the [`marker::IsOutputType::mark`] method,
     /// which performs static checks for this [GraphQL field][1].
     ///
@@ -292,6 +528,9 @@ impl Definition {
 
         let description = &self.description;
         let deprecated = &self.deprecated;
+        let concurrency = self.concurrency.as_ref().map(|limit| {
+            quote! { .concurrency_limit(#limit) }
+        });
 
         let args = self
             .arguments
@@ -304,9 +543,41 @@ impl Definition {
                 #( #args )*
                 #description
                 #deprecated
+                #concurrency
         }
     }
 
+    /// Returns generated code for the [`GraphQLValue::resolve_field`] method,
+    /// which calls the method representing this [GraphQL field][1], or
+    /// references the struct field otherwise.
+    ///
+    /// Returns the code converting and validating the values of this
+    /// [GraphQL field][1] arguments, which should precede the call, along with
+    /// the call itself.
+    ///
+    /// [`GraphQLValue::resolve_field`]: juniper::GraphQLValue::resolve_field
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    #[must_use]
+    pub(crate) fn method_resolve_field_call_tokens(
+        &self,
+        scalar: &scalar::Type,
+        for_async: bool,
+    ) -> (TokenStream, TokenStream) {
+        let ident = &self.ident;
+
+        let Some(arguments) = &self.arguments else {
+            return (quote! {}, quote! { &self.#ident });
+        };
+
+        let (convert, args) =
+            MethodArgument::method_resolve_field_tokens(arguments, scalar, for_async, "arg");
+        let rcv = self.has_receiver.then(|| {
+            quote! { self, }
+        });
+
+        (convert, quote! { Self::#ident(#rcv #( #args ),*) })
+    }
+
     /// Returns generated code for the
     /// [`GraphQLSubscriptionValue::resolve_field_into_stream`][0] method, which
     /// resolves this [GraphQL field][1] as [subscription][2].
@@ -319,35 +590,49 @@ impl Definition {
         &self,
         scalar: &scalar::Type,
     ) -> TokenStream {
-        let (name, mut ty, ident) = (&self.name, self.ty.clone(), &self.ident);
-
-        let mut fut = if self.is_method() {
-            let args = self
-                .arguments
-                .as_ref()
-                .unwrap()
-                .iter()
-                .map(|arg| arg.method_resolve_field_tokens(scalar, false));
-
-            let rcv = self.has_receiver.then(|| {
-                quote! { self, }
-            });
-
-            quote! { Self::#ident(#rcv #( #args ),*) }
-        } else {
+        let (name, mut ty) = (&self.name, self.ty.clone());
+
+        let (args, mut fut) = self.method_resolve_field_call_tokens(scalar, false);
+        if !self.is_method() {
             ty = parse_quote! { _ };
-            quote! { &self.#ident }
-        };
+        }
         if !self.is_async {
             fut = quote! { ::juniper::futures::future::ready(#fut) };
         }
 
+        let guard = self.method_check_guard_tokens(scalar);
+
+        let filter = self.filter.as_ref().map(|filter| {
+            let (filter_args, arg_idents) = MethodArgument::method_resolve_field_tokens(
+                self.arguments
+                    .iter()
+                    .flatten()
+                    .filter(|arg| arg.as_regular().is_some()),
+                scalar,
+                false,
+                "filter_arg",
+            );
+
+            quote! {
+                #filter_args
+                let filter_ctx = executor.context();
+                let res = ::juniper::futures::StreamExt::filter(res, move |item| {
+                    ::juniper::futures::future::ready(
+                        #filter(item, #( &#arg_idents, )* filter_ctx),
+                    )
+                });
+            }
+        });
+
         quote! {
             #name => {
                 ::juniper::futures::FutureExt::boxed(async move {
+                    #guard
+                    #args
                     let res: #ty = #fut.await;
                     let res = ::juniper::IntoFieldResult::<_, #scalar>::into_result(res)?;
                     let executor = executor.as_owned_executor();
+                    #filter
                     let stream = ::juniper::futures::StreamExt::then(res, move |res| {
                         let executor = executor.clone();
                         let res2: ::juniper::FieldResult<_, #scalar> =
@@ -9,7 +9,9 @@ pub(crate) mod generate;
 pub(crate) mod parse;
 pub(crate) mod rename;
 pub(crate) mod scalar;
+pub(crate) mod serde;
 mod span_container;
+pub(crate) mod validator;
 
 use std::slice;
 
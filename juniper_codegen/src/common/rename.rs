@@ -22,6 +22,15 @@ pub(crate) enum Policy {
 
     /// Rename in `SCREAMING_SNAKE_CASE` style.
     ScreamingSnakeCase,
+
+    /// Rename in `PascalCase` style.
+    PascalCase,
+
+    /// Rename in `lowercase` style.
+    LowerCase,
+
+    /// Rename in `UPPERCASE` style.
+    UpperCase,
 }
 
 impl Policy {
@@ -32,12 +41,15 @@ impl Policy {
             Self::CamelCase => to_camel_case(name),
             Self::SnakeCase => to_snake_case(name, false),
             Self::ScreamingSnakeCase => to_snake_case(name, true),
+            Self::PascalCase => to_pascal_case(name),
+            Self::LowerCase => name.to_lowercase(),
+            Self::UpperCase => name.to_uppercase(),
         }
     }
 }
 
 impl FromStr for Policy {
-    type Err = ();
+    type Err = &'static str;
 
     fn from_str(rule: &str) -> Result<Self, Self::Err> {
         match rule {
@@ -45,7 +57,14 @@ impl FromStr for Policy {
             "camelCase" => Ok(Self::CamelCase),
             "snake_case" => Ok(Self::SnakeCase),
             "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
-            _ => Err(()),
+            "PascalCase" => Ok(Self::PascalCase),
+            "lowercase" => Ok(Self::LowerCase),
+            "UPPERCASE" => Ok(Self::UpperCase),
+            "kebab-case" | "SCREAMING-KEBAB-CASE" => Err(
+                "kebab case renaming policies are not supported, as `-` is not allowed in GraphQL \
+                 names, use `snake_case` or `SCREAMING_SNAKE_CASE` instead",
+            ),
+            _ => Err("unknown renaming policy"),
         }
     }
 }
@@ -54,8 +73,7 @@ impl TryFrom<syn::LitStr> for Policy {
     type Error = syn::Error;
 
     fn try_from(lit: syn::LitStr) -> syn::Result<Self> {
-        Self::from_str(&lit.value())
-            .map_err(|_| syn::Error::new(lit.span(), "unknown renaming policy"))
+        Self::from_str(&lit.value()).map_err(|e| syn::Error::new(lit.span(), e))
     }
 }
 
@@ -102,6 +120,15 @@ fn to_camel_case(s: &str) -> String {
     dest
 }
 
+fn to_pascal_case(s: &str) -> String {
+    let camel = to_camel_case(s);
+    let mut chars = camel.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
 fn to_snake_case(s: &str, upper: bool) -> String {
     let mut last_lower = false;
     let mut out = String::new();
@@ -153,6 +180,26 @@ mod to_camel_case_tests {
     }
 }
 
+#[cfg(test)]
+mod to_pascal_case_tests {
+    use super::to_pascal_case;
+
+    #[test]
+    fn pascal() {
+        for (input, expected) in [
+            ("test", "Test"),
+            ("_test", "Test"),
+            ("first_second", "FirstSecond"),
+            ("a_b_c", "ABC"),
+            ("NewHope", "NewHope"),
+            ("a", "A"),
+            ("", ""),
+        ] {
+            assert_eq!(to_pascal_case(input), expected);
+        }
+    }
+}
+
 #[cfg(test)]
 mod to_snake_case_tests {
     use super::to_snake_case;
@@ -0,0 +1,204 @@
+//! Common functions, definitions and extensions for parsing `#[serde(...)]` attributes, honored
+//! by code generation when `#[graphql(serde)]` attribute argument is specified.
+
+use proc_macro2::Span;
+use syn::{meta::ParseNestedMeta, spanned::Spanned as _, token};
+
+use crate::common::{SpanContainer, diagnostic, filter_attrs, rename};
+
+/// Direction of `serde` (de)serialization, which `#[serde(...)]` attributes are honored for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Direction {
+    /// Serialization, honored by GraphQL output types.
+    Serialize,
+
+    /// Deserialization, honored by GraphQL input types.
+    Deserialize,
+
+    /// Both serialization and deserialization, honored by GraphQL types being input and output
+    /// ones at the same time (like [GraphQL enums][0]).
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    Both,
+}
+
+/// `#[serde(...)]` attribute arguments affecting GraphQL naming, placed on a Rust type
+/// definition, its field or variant.
+#[derive(Debug, Default)]
+pub(crate) struct Attr {
+    /// Name specified via `#[serde(rename = "...")]` attribute argument.
+    pub(crate) rename: Option<SpanContainer<String>>,
+
+    /// [`rename::Policy`] specified via `#[serde(rename_all = "...")]` attribute argument.
+    pub(crate) rename_all: Option<SpanContainer<rename::Policy>>,
+
+    /// Marker specified via `#[serde(skip)]` attribute argument (or `skip_serializing` and
+    /// `skip_deserializing` ones, according to the [`Direction`]).
+    pub(crate) skip: Option<Span>,
+}
+
+impl Attr {
+    /// Parses an [`Attr`] from the `#[serde(...)]` attributes among the provided
+    /// [`syn::Attribute`]s, honoring them for the provided [`Direction`].
+    ///
+    /// Returns an empty [`Attr`] if no [`Direction`] is provided, meaning that `#[serde(...)]`
+    /// attributes should not be honored at all.
+    ///
+    /// # Errors
+    ///
+    /// If the relevant `#[serde(...)]` attribute arguments have incorrect format, or cannot be
+    /// represented in GraphQL.
+    pub(crate) fn from_attrs(
+        attrs: &[syn::Attribute],
+        direction: Option<Direction>,
+    ) -> syn::Result<Self> {
+        let mut out = Self::default();
+        let Some(direction) = direction else {
+            return Ok(out);
+        };
+        for attr in filter_attrs("serde", attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if let Some(name) = parse_directional(&meta, direction)? {
+                        out.rename = Some(SpanContainer::new(
+                            meta.path.span(),
+                            Some(name.span()),
+                            name.value(),
+                        ));
+                    }
+                } else if meta.path.is_ident("rename_all") {
+                    if let Some(policy) = parse_directional(&meta, direction)? {
+                        out.rename_all = Some(SpanContainer::new(
+                            meta.path.span(),
+                            Some(policy.span()),
+                            policy.try_into()?,
+                        ));
+                    }
+                } else if meta.path.is_ident("skip")
+                    || (direction == Direction::Serialize && meta.path.is_ident("skip_serializing"))
+                    || (direction == Direction::Deserialize
+                        && meta.path.is_ident("skip_deserializing"))
+                {
+                    out.skip = Some(meta.path.span());
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+
+    /// Returns the name specified via `#[serde(rename = "...")]` attribute argument, if any,
+    /// emitting an error in the provided [`diagnostic::Scope`] if it's not a valid
+    /// [GraphQL name][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Names
+    pub(crate) fn checked_rename(&self, scope: diagnostic::Scope) -> Option<SpanContainer<String>> {
+        let rename = self.rename.clone()?;
+        if !is_valid_name(&rename) {
+            scope.emit_custom(
+                rename.span_joined(),
+                format!(
+                    "`{}` name specified via `serde` is not a valid GraphQL name",
+                    *rename,
+                ),
+            );
+        }
+        Some(rename)
+    }
+}
+
+/// Applies the provided [`rename::Policy`] to the given Rust enum variant `name` in the same
+/// manner `serde` does.
+pub(crate) fn rename_variant(policy: rename::Policy, name: &str) -> String {
+    match policy {
+        rename::Policy::None | rename::Policy::LowerCase | rename::Policy::UpperCase => {
+            policy.apply(name)
+        }
+        _ => policy.apply(&rename::Policy::SnakeCase.apply(name)),
+    }
+}
+
+/// Checks whether the provided `name` is a valid [GraphQL name][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Names
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+}
+
+/// Parses a value of `#[serde(...)]` attribute argument, specified either as `arg = "..."` or as
+/// `arg(serialize = "...", deserialize = "...")`, according to the provided [`Direction`].
+///
+/// # Errors
+///
+/// If the [`Direction::Both`] is provided, while different values are specified for
+/// serialization and deserialization.
+fn parse_directional(
+    meta: &ParseNestedMeta<'_>,
+    direction: Direction,
+) -> syn::Result<Option<syn::LitStr>> {
+    if meta.input.peek(token::Eq) {
+        return meta.value()?.parse().map(Some);
+    }
+
+    let (mut ser, mut de) = (None, None);
+    meta.parse_nested_meta(|nested| {
+        if nested.path.is_ident("serialize") {
+            ser = Some(nested.value()?.parse::<syn::LitStr>()?);
+        } else if nested.path.is_ident("deserialize") {
+            de = Some(nested.value()?.parse::<syn::LitStr>()?);
+        } else {
+            return Err(nested.error("expected `serialize` or `deserialize`"));
+        }
+        Ok(())
+    })?;
+    Ok(match direction {
+        Direction::Serialize => ser,
+        Direction::Deserialize => de,
+        Direction::Both => {
+            if ser.as_ref().map(syn::LitStr::value) != de.as_ref().map(syn::LitStr::value) {
+                return Err(syn::Error::new(
+                    meta.path.span(),
+                    "different `serde` naming for serialization and deserialization cannot be \
+                     represented in GraphQL",
+                ));
+            }
+            ser
+        }
+    })
+}
+
+/// Skips the value of the irrelevant `#[serde(...)]` attribute argument, if any.
+fn skip_meta(meta: &ParseNestedMeta<'_>) -> syn::Result<()> {
+    if meta.input.peek(token::Eq) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta(&nested))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod rename_variant_tests {
+    use super::{rename, rename_variant};
+
+    #[test]
+    fn serde_compatible() {
+        for (policy, expected) in [
+            (rename::Policy::None, "NewHope"),
+            (rename::Policy::CamelCase, "newHope"),
+            (rename::Policy::SnakeCase, "new_hope"),
+            (rename::Policy::ScreamingSnakeCase, "NEW_HOPE"),
+            (rename::Policy::PascalCase, "NewHope"),
+            (rename::Policy::LowerCase, "newhope"),
+            (rename::Policy::UpperCase, "NEWHOPE"),
+        ] {
+            assert_eq!(rename_variant(policy, "NewHope"), expected);
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! Common functions, definitions and extensions for parsing and code generation
+//! of [GraphQL input values][0] validators.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Input-Values
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    token,
+};
+
+use crate::common::{
+    SpanContainer,
+    parse::{
+        ParseBufferExt as _,
+        attr::{OptionExt as _, err},
+    },
+};
+
+/// Validators of a [GraphQL input value][0], specified via `#[graphql]` attribute arguments.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Input-Values
+#[derive(Debug, Default)]
+pub(crate) struct Validators {
+    /// Minimum number of characters in a string value.
+    min_length: Option<SpanContainer<syn::LitInt>>,
+
+    /// Maximum number of characters in a string value.
+    max_length: Option<SpanContainer<syn::LitInt>>,
+
+    /// Inclusive bounds of a value.
+    range: Option<SpanContainer<Range>>,
+
+    /// Pattern to match a string value with.
+    regex: Option<SpanContainer<syn::LitStr>>,
+
+    /// Indicator whether a string value should be a valid email address.
+    email: Option<SpanContainer<syn::Ident>>,
+
+    /// Indicator whether a string value should be a valid URL.
+    url: Option<SpanContainer<syn::Ident>>,
+
+    /// Minimum number of items in a list value.
+    min_items: Option<SpanContainer<syn::LitInt>>,
+
+    /// Custom validator function.
+    custom: Option<SpanContainer<syn::Expr>>,
+}
+
+impl Validators {
+    /// Parses the validator `#[graphql]` attribute argument named by the provided `ident`, if it
+    /// represents the one.
+    ///
+    /// Returns `false` if the `ident` doesn't name any validator.
+    pub(crate) fn parse_arg(
+        &mut self,
+        ident: &syn::Ident,
+        input: ParseStream<'_>,
+    ) -> syn::Result<bool> {
+        fn lit<T: Parse + Spanned>(
+            ident: &syn::Ident,
+            input: ParseStream<'_>,
+        ) -> syn::Result<SpanContainer<T>> {
+            input.parse::<token::Eq>()?;
+            let val = input.parse::<T>()?;
+            Ok(SpanContainer::new(ident.span(), Some(val.span()), val))
+        }
+
+        match ident.to_string().as_str() {
+            "min_length" => self
+                .min_length
+                .replace(lit(ident, input)?)
+                .none_or_else(|_| err::dup_arg(ident))?,
+            "max_length" => self
+                .max_length
+                .replace(lit(ident, input)?)
+                .none_or_else(|_| err::dup_arg(ident))?,
+            "min_items" => self
+                .min_items
+                .replace(lit(ident, input)?)
+                .none_or_else(|_| err::dup_arg(ident))?,
+            "regex" => {
+                let pattern = lit::<syn::LitStr>(ident, input)?;
+                if let Err(e) = regex::Regex::new(&pattern.value()) {
+                    // Syntax errors are rendered in multiple lines, with the reason in the last one.
+                    let e = e.to_string();
+                    let reason = e.lines().last().unwrap_or_default();
+                    let reason = reason.strip_prefix("error: ").unwrap_or(reason);
+                    return Err(syn::Error::new(
+                        (*pattern).span(),
+                        format!("invalid `regex` validator pattern: {reason}"),
+                    ));
+                }
+                self.regex
+                    .replace(pattern)
+                    .none_or_else(|_| err::dup_arg(ident))?
+            }
+            "custom" => self
+                .custom
+                .replace(lit(ident, input)?)
+                .none_or_else(|_| err::dup_arg(ident))?,
+            "range" => {
+                let content;
+                let paren = parenthesized!(content in input);
+                let range = Range::parse(&content, paren.span.join())?;
+                self.range
+                    .replace(SpanContainer::new(
+                        ident.span(),
+                        Some(paren.span.join()),
+                        range,
+                    ))
+                    .none_or_else(|_| err::dup_arg(ident))?
+            }
+            "email" => self
+                .email
+                .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                .none_or_else(|_| err::dup_arg(ident))?,
+            "url" => self
+                .url
+                .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                .none_or_else(|_| err::dup_arg(ident))?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Tries to merge two [`Validators`] into a single one, reporting about duplicates, if any.
+    pub(crate) fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            min_length: try_merge_opt!(min_length: self, another),
+            max_length: try_merge_opt!(max_length: self, another),
+            range: try_merge_opt!(range: self, another),
+            regex: try_merge_opt!(regex: self, another),
+            email: try_merge_opt!(email: self, another),
+            url: try_merge_opt!(url: self, another),
+            min_items: try_merge_opt!(min_items: self, another),
+            custom: try_merge_opt!(custom: self, another),
+        })
+    }
+
+    /// Returns [`Span`] of the first specified validator, if any.
+    #[must_use]
+    pub(crate) fn span(&self) -> Option<Span> {
+        [
+            self.min_length.as_ref().map(SpanContainer::span_ident),
+            self.max_length.as_ref().map(SpanContainer::span_ident),
+            self.range.as_ref().map(SpanContainer::span_ident),
+            self.regex.as_ref().map(SpanContainer::span_ident),
+            self.email.as_ref().map(SpanContainer::span_ident),
+            self.url.as_ref().map(SpanContainer::span_ident),
+            self.min_items.as_ref().map(SpanContainer::span_ident),
+            self.custom.as_ref().map(SpanContainer::span_ident),
+        ]
+        .into_iter()
+        .flatten()
+        .next()
+    }
+
+    /// Returns generated code filtering the [`Option`]al value by these [`Validators`], collecting
+    /// their errors for the input value with the provided `name` into the `errors` variable of
+    /// `::juniper::validator::InputErrors` type.
+    ///
+    /// Returns [`None`] if no validators are specified.
+    #[must_use]
+    pub(crate) fn filter_tokens(&self, name: &str) -> Option<TokenStream> {
+        let v = quote! { v };
+        let checks = [
+            self.min_length.as_ref().map(|min| {
+                quote! { ::juniper::validator::min_length(#v, #min) }
+            }),
+            self.max_length.as_ref().map(|max| {
+                quote! { ::juniper::validator::max_length(#v, #max) }
+            }),
+            self.range.as_ref().map(|range| {
+                let bound = |b: &Option<syn::Expr>| match b {
+                    Some(expr) => quote! { ::core::option::Option::Some(#expr) },
+                    None => quote! { ::core::option::Option::None },
+                };
+                let (min, max) = (bound(&range.min), bound(&range.max));
+                quote! { ::juniper::validator::range(#v, #min, #max) }
+            }),
+            self.regex.as_ref().map(|pattern| {
+                quote! {
+                    ::juniper::validator::regex(#v, {
+                        static REGEX: ::std::sync::LazyLock<::juniper::validator::Regex> =
+                            ::std::sync::LazyLock::new(|| {
+                                ::juniper::validator::Regex::new(#pattern)
+                                    .expect("`regex` validator pattern is checked by macro")
+                            });
+                        &*REGEX
+                    })
+                }
+            }),
+            self.email
+                .as_ref()
+                .map(|_| quote! { ::juniper::validator::email(#v) }),
+            self.url
+                .as_ref()
+                .map(|_| quote! { ::juniper::validator::url(#v) }),
+            self.min_items.as_ref().map(|min| {
+                quote! { ::juniper::validator::min_items(#v, #min) }
+            }),
+            self.custom.as_ref().map(|f| {
+                quote! { ::juniper::validator::custom(#v, #f) }
+            }),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        (!checks.is_empty()).then(|| {
+            quote! {
+                .filter(|#v| errors.validate(#name, [#( #checks ),*]))
+            }
+        })
+    }
+}
+
+/// Inclusive bounds of the `range` validator.
+#[derive(Debug)]
+struct Range {
+    /// Lower bound of this [`Range`].
+    min: Option<syn::Expr>,
+
+    /// Upper bound of this [`Range`].
+    max: Option<syn::Expr>,
+}
+
+impl Range {
+    /// Parses [`Range`] from the provided parenthesized `input`, reporting errors at the provided
+    /// `span`.
+    fn parse(input: ParseStream<'_>, span: Span) -> syn::Result<Self> {
+        let mut out = Self {
+            min: None,
+            max: None,
+        };
+        while !input.is_empty() {
+            let ident = input.parse::<syn::Ident>()?;
+            let bound = match ident.to_string().as_str() {
+                "min" => &mut out.min,
+                "max" => &mut out.max,
+                name => return Err(err::unknown_arg(&ident, name)),
+            };
+            input.parse::<token::Eq>()?;
+            bound
+                .replace(input.parse::<syn::Expr>()?)
+                .none_or_else(|_| err::dup_arg(&ident))?;
+            input.try_parse::<token::Comma>()?;
+        }
+        if out.min.is_none() && out.max.is_none() {
+            return Err(syn::Error::new(
+                span,
+                "`range` validator should have at least one of `min` or `max` bounds",
+            ));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod filter_tokens_test {
+    use proc_macro2::TokenStream;
+    use quote::quote;
+    use syn::{
+        parse::{ParseStream, Parser as _},
+        token,
+    };
+
+    use crate::common::parse::{ParseBufferExt as _, attr::err};
+
+    use super::Validators;
+
+    fn parse(tokens: TokenStream) -> syn::Result<Validators> {
+        (|input: ParseStream<'_>| {
+            let mut out = Validators::default();
+            while !input.is_empty() {
+                let ident = input.parse::<syn::Ident>()?;
+                if !out.parse_arg(&ident, input)? {
+                    return Err(err::unknown_arg(&ident, &ident.to_string()));
+                }
+                input.try_parse::<token::Comma>()?;
+            }
+            Ok(out)
+        })
+        .parse2(tokens)
+    }
+
+    #[test]
+    fn generates_nothing_without_validators() {
+        assert!(parse(quote! {}).unwrap().filter_tokens("name").is_none());
+    }
+
+    #[test]
+    fn generates_validators() {
+        let validators = parse(quote! {
+            min_length = 1, email, range(max = 10), custom = check,
+        })
+        .unwrap();
+
+        assert_eq!(
+            validators.filter_tokens("name").unwrap().to_string(),
+            quote! {
+                .filter(|v| errors.validate("name", [
+                    ::juniper::validator::min_length(v, 1),
+                    ::juniper::validator::range(
+                        v,
+                        ::core::option::Option::None,
+                        ::core::option::Option::Some(10)
+                    ),
+                    ::juniper::validator::email(v),
+                    ::juniper::validator::custom(v, check)
+                ]))
+            }
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn errors_on_empty_range() {
+        let err = parse(quote! { range() }).map(drop).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`range` validator should have at least one of `min` or `max` bounds",
+        );
+    }
+
+    #[test]
+    fn errors_on_invalid_regex() {
+        let err = parse(quote! { regex = "[a-z" }).map(drop).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "invalid `regex` validator pattern: unclosed character class",
+        );
+    }
+
+    #[test]
+    fn errors_on_duplicates() {
+        let err = parse(quote! { email, email }).map(drop).unwrap_err();
+
+        assert_eq!(err.to_string(), "duplicated attribute argument found");
+    }
+}
@@ -6,7 +6,7 @@ use proc_macro2::TokenStream;
 use quote::ToTokens as _;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
-use crate::common::{SpanContainer, diagnostic, rename, scalar};
+use crate::common::{SpanContainer, diagnostic, rename, scalar, serde};
 
 use super::{ContainerAttr, Definition, ValueDefinition, VariantAttr};
 
@@ -16,7 +16,12 @@ const ERR: diagnostic::Scope = diagnostic::Scope::EnumDerive;
 /// Expands `#[derive(GraphQLEnum)]` macro into generated code.
 pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
     let ast = syn::parse2::<syn::DeriveInput>(input)?;
-    let attr = ContainerAttr::from_attrs("graphql", &ast.attrs)?;
+    let mut attr = ContainerAttr::from_attrs("graphql", &ast.attrs)?;
+    let serde = attr.serde.is_some().then_some(serde::Direction::Both);
+    let serde_attr = serde::Attr::from_attrs(&ast.attrs, serde)?;
+    if attr.name.is_none() {
+        attr.name = serde_attr.checked_rename(ERR);
+    }
 
     let syn::Data::Enum(data) = &ast.data else {
         return Err(ERR.custom_error(ast.span(), "can only be derived on enums"));
@@ -25,13 +30,18 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
     let mut has_ignored_variants = false;
     let renaming = attr
         .rename_values
+        .or(serde_attr.rename_all)
         .map(SpanContainer::into_inner)
-        .unwrap_or(rename::Policy::ScreamingSnakeCase);
+        .unwrap_or(if serde.is_some() {
+            rename::Policy::None
+        } else {
+            rename::Policy::ScreamingSnakeCase
+        });
     let values = data
         .variants
         .iter()
         .filter_map(|v| {
-            parse_value(v, renaming).or_else(|| {
+            parse_value(v, renaming, serde).or_else(|| {
                 has_ignored_variants = true;
                 None
             })
@@ -95,14 +105,24 @@ pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 /// Parses a [`ValueDefinition`] from the given Rust enum variant definition.
 ///
 /// Returns [`None`] if the parsing fails, or the enum variant is ignored.
-fn parse_value(v: &syn::Variant, renaming: rename::Policy) -> Option<ValueDefinition> {
-    let attr = VariantAttr::from_attrs("graphql", &v.attrs)
+fn parse_value(
+    v: &syn::Variant,
+    renaming: rename::Policy,
+    serde: Option<serde::Direction>,
+) -> Option<ValueDefinition> {
+    let mut attr = VariantAttr::from_attrs("graphql", &v.attrs)
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    let serde_attr = serde::Attr::from_attrs(&v.attrs, serde)
         .map_err(diagnostic::emit_error)
         .ok()?;
 
-    if attr.ignore.is_some() {
+    if attr.ignore.is_some() || serde_attr.skip.is_some() {
         return None;
     }
+    if attr.name.is_none() {
+        attr.name = serde_attr.checked_rename(ERR);
+    }
 
     if !v.fields.is_empty() {
         err_variant_with_fields(&v.fields)?;
@@ -111,7 +131,14 @@ fn parse_value(v: &syn::Variant, renaming: rename::Policy) -> Option<ValueDefini
     let name = attr
         .name
         .map_or_else(
-            || renaming.apply(&v.ident.unraw().to_string()),
+            || {
+                let ident = v.ident.unraw().to_string();
+                if serde.is_some() {
+                    serde::rename_variant(renaming, &ident)
+                } else {
+                    renaming.apply(&ident)
+                }
+            },
             SpanContainer::into_inner,
         )
         .into_boxed_str();
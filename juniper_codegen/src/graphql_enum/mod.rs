@@ -75,6 +75,12 @@ struct ContainerAttr {
     /// [1]: https://spec.graphql.org/October2021#EnumValuesDefinition
     rename_values: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified marker indicating that `#[serde(rename, rename_all, skip)]`
+    /// attributes should be honored for naming this [GraphQL enum][0] and its values.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Enums
+    serde: Option<SpanContainer<syn::Ident>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -129,6 +135,10 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "serde" => out
+                    .serde
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -152,6 +162,7 @@ impl ContainerAttr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_values: try_merge_opt!(rename_values: self, another),
+            serde: try_merge_opt!(serde: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
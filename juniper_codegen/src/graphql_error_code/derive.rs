@@ -0,0 +1,118 @@
+//! Code generation for `#[derive(GraphQLErrorCode)]` macro.
+
+use proc_macro2::TokenStream;
+use quote::ToTokens as _;
+use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
+
+use crate::common::{SpanContainer, diagnostic, rename, scalar};
+
+use super::{Attr, Definition, VariantDefinition, field_extension};
+
+/// [`diagnostic::Scope`] of errors for `#[derive(GraphQLErrorCode)]` macro.
+const ERR: diagnostic::Scope = diagnostic::Scope::ErrorCodeDerive;
+
+/// Expands `#[derive(GraphQLErrorCode)]` macro into generated code.
+pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input)?;
+    let mut attr = Attr::from_attrs("graphql", &ast.attrs)?;
+
+    let variants = match &ast.data {
+        syn::Data::Enum(data) => {
+            if let Some(code) = &attr.code {
+                return Err(ERR.custom_error(
+                    code.span_ident(),
+                    "`code` attribute argument is applicable to enum variants and structs only",
+                ));
+            }
+            if data.variants.is_empty() {
+                return Err(ERR.custom_error(ast.span(), "expected at least 1 enum variant"));
+            }
+            data.variants
+                .iter()
+                .filter_map(|v| {
+                    let attr = Attr::from_attrs("graphql", &v.attrs)
+                        .map_err(diagnostic::emit_error)
+                        .ok()?;
+                    if let Some(scalar) = &attr.scalar {
+                        ERR.emit_custom(
+                            scalar.span_ident(),
+                            "`scalar` attribute argument is not applicable to enum variants",
+                        );
+                        return None;
+                    }
+                    if let Some(safe) = &attr.safe {
+                        ERR.emit_custom(
+                            safe.span_ident(),
+                            "`safe` attribute argument is not applicable to enum variants",
+                        );
+                        return None;
+                    }
+                    let ident = &v.ident;
+                    parse_variant(parse_quote! { Self::#ident }, ident, attr.code, &v.fields)
+                })
+                .collect()
+        }
+        syn::Data::Struct(data) => parse_variant(
+            parse_quote! { Self },
+            &ast.ident,
+            attr.code.take(),
+            &data.fields,
+        )
+        .into_iter()
+        .collect(),
+        syn::Data::Union(_) => {
+            return Err(ERR.custom_error(ast.span(), "can only be derived on enums and structs"));
+        }
+    };
+
+    diagnostic::abort_if_dirty();
+
+    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+
+    let definition = Definition {
+        ident: ast.ident,
+        generics: ast.generics,
+        scalar,
+        variants,
+        safe: attr.safe.is_some(),
+    };
+
+    Ok(definition.into_token_stream())
+}
+
+/// Parses a [`VariantDefinition`] from the given Rust struct or enum variant definition, with the
+/// provided explicitly specified `code`, if any.
+///
+/// Returns [`None`] if the parsing fails.
+fn parse_variant(
+    path: syn::Path,
+    ident: &syn::Ident,
+    code: Option<SpanContainer<syn::LitStr>>,
+    fields: &syn::Fields,
+) -> Option<VariantDefinition> {
+    let extensions = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(n, f)| field_extension(n, f).map_err(diagnostic::emit_error).ok()?)
+        .collect::<Vec<_>>();
+    for ext in &extensions {
+        if ext.name == "code" {
+            ERR.emit_custom(
+                ext.member.span(),
+                "`code` extension is reserved for the code of the error",
+            );
+            return None;
+        }
+    }
+
+    let code = code.map_or_else(
+        || rename::Policy::ScreamingSnakeCase.apply(&ident.unraw().to_string()),
+        |code| SpanContainer::into_inner(code).value(),
+    );
+
+    Some(VariantDefinition {
+        path,
+        code,
+        extensions,
+    })
+}
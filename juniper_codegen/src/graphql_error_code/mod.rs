@@ -0,0 +1,463 @@
+//! Code generation for [GraphQL errors][0] with stable machine-readable codes.
+//!
+//! [0]: https://spec.graphql.org/October2021#sec-Errors
+
+pub(crate) mod derive;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    ext::IdentExt as _,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned as _,
+    token,
+};
+
+use crate::common::{
+    SpanContainer, filter_attrs,
+    parse::{
+        ParseBufferExt as _,
+        attr::{OptionExt as _, err},
+    },
+    rename, scalar,
+};
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust enum or struct definition,
+/// or on a Rust enum variant, when generating code for a [GraphQL error][0] code.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+#[derive(Debug, Default)]
+struct Attr {
+    /// Explicitly specified code of this [GraphQL error][0].
+    ///
+    /// If [`None`], then the `SCREAMING_SNAKE_CASE`d name of the Rust struct or enum variant will
+    /// be used by default.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    code: Option<SpanContainer<syn::LitStr>>,
+
+    /// Explicitly specified type (or type parameter with its bounds) of [`ScalarValue`] to
+    /// represent the extensions of this [GraphQL error][0] with.
+    ///
+    /// If [`None`], then generated code will be generic over any [`ScalarValue`] type.
+    ///
+    /// Applicable to the whole Rust enum or struct only.
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    scalar: Option<SpanContainer<scalar::AttrValue>>,
+
+    /// Explicitly specified marker indicating that the messages of this [GraphQL error][0] are
+    /// safe to be exposed to clients as is, bypassing the [`ErrorFormatter`].
+    ///
+    /// Applicable to the whole Rust enum or struct only.
+    ///
+    /// [`ErrorFormatter`]: juniper::ErrorFormatter
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    safe: Option<SpanContainer<syn::Ident>>,
+}
+
+impl Parse for Attr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "code" => {
+                    input.parse::<token::Eq>()?;
+                    let code = input.parse::<syn::LitStr>()?;
+                    if code.value().is_empty() {
+                        return Err(syn::Error::new(
+                            code.span(),
+                            "`code` attribute argument should not be empty",
+                        ));
+                    }
+                    out.code
+                        .replace(SpanContainer::new(ident.span(), Some(code.span()), code))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "scalar" | "Scalar" | "ScalarValue" => {
+                    input.parse::<token::Eq>()?;
+                    let scl = input.parse::<scalar::AttrValue>()?;
+                    out.scalar
+                        .replace(SpanContainer::new(ident.span(), Some(scl.span()), scl))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "safe" => out
+                    .safe
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl Attr {
+    /// Tries to merge two [`Attr`]s into a single one, reporting about duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            code: try_merge_opt!(code: self, another),
+            scalar: try_merge_opt!(scalar: self, another),
+            safe: try_merge_opt!(safe: self, another),
+        })
+    }
+
+    /// Parses [`Attr`] from the given multiple `name`d [`syn::Attribute`]s placed on a Rust enum
+    /// or struct definition, or on a Rust enum variant.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))
+    }
+}
+
+/// Available arguments behind `#[graphql]` attribute placed on a Rust struct field, when
+/// generating code for a [GraphQL error][0] code.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+#[derive(Debug, Default)]
+struct FieldAttr {
+    /// Explicitly specified name of the extension this field is represented with.
+    ///
+    /// If [`None`], then the `camelCased` Rust field name will be used by default.
+    name: Option<SpanContainer<syn::LitStr>>,
+
+    /// Explicitly specified marker for the Rust struct field to be omitted from the extensions.
+    ignore: Option<SpanContainer<syn::Ident>>,
+}
+
+impl Parse for FieldAttr {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut out = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse_any_ident()?;
+            match ident.to_string().as_str() {
+                "name" => {
+                    input.parse::<token::Eq>()?;
+                    let name = input.parse::<syn::LitStr>()?;
+                    out.name
+                        .replace(SpanContainer::new(ident.span(), Some(name.span()), name))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "ignore" | "skip" => out
+                    .ignore
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
+                name => {
+                    return Err(err::unknown_arg(&ident, name));
+                }
+            }
+            input.try_parse::<token::Comma>()?;
+        }
+        Ok(out)
+    }
+}
+
+impl FieldAttr {
+    /// Tries to merge two [`FieldAttr`]s into a single one, reporting about duplicates, if any.
+    fn try_merge(self, mut another: Self) -> syn::Result<Self> {
+        Ok(Self {
+            name: try_merge_opt!(name: self, another),
+            ignore: try_merge_opt!(ignore: self, another),
+        })
+    }
+
+    /// Parses [`FieldAttr`] from the given multiple `name`d [`syn::Attribute`]s placed on a Rust
+    /// struct field.
+    fn from_attrs(name: &str, attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        filter_attrs(name, attrs)
+            .map(|attr| attr.parse_args())
+            .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))
+    }
+}
+
+/// Representation of a Rust struct field put into the extensions of a [GraphQL error][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+#[derive(Debug)]
+struct ExtensionDefinition {
+    /// Rust struct field this extension is represented by.
+    member: syn::Member,
+
+    /// Rust type of the field this extension is represented by.
+    ty: syn::Type,
+
+    /// Name of this extension.
+    name: String,
+}
+
+/// Representation of a Rust struct or enum variant, having a distinct code of a
+/// [GraphQL error][0].
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+#[derive(Debug)]
+struct VariantDefinition {
+    /// Path to match this Rust struct or enum variant with (like `Self` or `Self::Variant`).
+    path: syn::Path,
+
+    /// Code of the [GraphQL error][0] represented by this Rust struct or enum variant.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    code: String,
+
+    /// Extensions of the [GraphQL error][0] represented by this Rust struct or enum variant.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    extensions: Vec<ExtensionDefinition>,
+}
+
+impl VariantDefinition {
+    /// Returns generated pattern destructuring this Rust struct or enum variant into the
+    /// bindings of its [`ExtensionDefinition`]s.
+    fn pattern(&self) -> TokenStream {
+        let path = &self.path;
+        let bindings = self.extensions.iter().enumerate().map(|(n, e)| {
+            let member = &e.member;
+            let var = format_ident!("__ext{n}");
+            quote! { #member: #var }
+        });
+        quote! { #path { #( #bindings, )* .. } }
+    }
+}
+
+/// Definition of a Rust enum or struct, representing [GraphQL errors][0] with stable codes, for
+/// code generation.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+struct Definition {
+    /// [`syn::Ident`] of the Rust enum or struct behind the [GraphQL errors][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    ident: syn::Ident,
+
+    /// [`syn::Generics`] of the Rust enum or struct behind the [GraphQL errors][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    generics: syn::Generics,
+
+    /// [`ScalarValue`] parametrization to generate code with for the [GraphQL errors][0].
+    ///
+    /// [`ScalarValue`]: juniper::ScalarValue
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    scalar: scalar::Type,
+
+    /// [`VariantDefinition`]s of the Rust enum or struct behind the [GraphQL errors][0].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    variants: Vec<VariantDefinition>,
+
+    /// Indicator whether the [GraphQL errors][0] are [`SafeError`]s, bypassing the
+    /// [`ErrorFormatter`].
+    ///
+    /// [`ErrorFormatter`]: juniper::ErrorFormatter
+    /// [`SafeError`]: juniper::SafeError
+    /// [0]: https://spec.graphql.org/October2021#sec-Errors
+    safe: bool,
+}
+
+impl ToTokens for Definition {
+    fn to_tokens(&self, into: &mut TokenStream) {
+        self.impl_graphql_error_code_tokens().to_tokens(into);
+        self.impl_safe_error_tokens().to_tokens(into);
+        self.impl_into_field_error_tokens().to_tokens(into);
+    }
+}
+
+impl Definition {
+    /// Returns prepared [`syn::Generics`] for [`GraphQLErrorCode`] and [`IntoFieldError`] trait
+    /// implementations.
+    ///
+    /// [`GraphQLErrorCode`]: juniper::GraphQLErrorCode
+    /// [`IntoFieldError`]: juniper::IntoFieldError
+    #[must_use]
+    fn impl_generics(&self) -> syn::Generics {
+        let mut generics = self.generics.clone();
+
+        let scalar = &self.scalar;
+        if scalar.is_implicit_generic() {
+            generics.params.push(parse_quote! { #scalar });
+        }
+        if scalar.is_generic() {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #scalar: ::juniper::ScalarValue });
+        }
+        if let Some(bound) = scalar.bounds() {
+            generics.make_where_clause().predicates.push(bound);
+        }
+
+        for ext in self.variants.iter().flat_map(|v| &v.extensions) {
+            let ty = &ext.ty;
+            generics.make_where_clause().predicates.push(parse_quote! {
+                #ty: ::juniper::IntoValue<#scalar> + ::core::clone::Clone
+            });
+        }
+
+        generics
+    }
+
+    /// Returns generated code implementing [`GraphQLErrorCode`] trait for this Rust enum or
+    /// struct.
+    ///
+    /// [`GraphQLErrorCode`]: juniper::GraphQLErrorCode
+    #[must_use]
+    fn impl_graphql_error_code_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let scalar = &self.scalar;
+
+        let generics = self.impl_generics();
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let code_arms = self.variants.iter().map(|v| {
+            let path = &v.path;
+            let code = &v.code;
+            quote! { #path { .. } => #code, }
+        });
+
+        let extensions_arms = self.variants.iter().map(|v| {
+            let pattern = v.pattern();
+            let count = v.extensions.len();
+            let fields = v.extensions.iter().enumerate().map(|(n, e)| {
+                let name = &e.name;
+                let var = format_ident!("__ext{n}");
+                quote! {
+                    _ = extensions.add_field(
+                        #name,
+                        ::juniper::IntoValue::<#scalar>::into_value(
+                            ::core::clone::Clone::clone(#var),
+                        ),
+                    );
+                }
+            });
+            quote! {
+                #pattern => {
+                    let mut extensions = ::juniper::Object::with_capacity(#count);
+                    #( #fields )*
+                    extensions
+                }
+            }
+        });
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::GraphQLErrorCode<#scalar>
+                for #ident #ty_generics
+                #where_clause
+            {
+                fn code(&self) -> &str {
+                    match self {
+                        #( #code_arms )*
+                    }
+                }
+
+                fn extensions(&self) -> ::juniper::Object<#scalar> {
+                    match self {
+                        #( #extensions_arms )*
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns generated code implementing [`SafeError`] trait for this Rust enum or struct, if
+    /// it's marked as safe.
+    ///
+    /// [`SafeError`]: juniper::SafeError
+    #[must_use]
+    fn impl_safe_error_tokens(&self) -> TokenStream {
+        if !self.safe {
+            return TokenStream::new();
+        }
+
+        let ident = &self.ident;
+
+        let mut generics = self.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::core::fmt::Display });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::SafeError for #ident #ty_generics #where_clause {}
+        }
+    }
+
+    /// Returns generated code implementing [`IntoFieldError`] trait for this Rust enum or
+    /// struct.
+    ///
+    /// [`IntoFieldError`]: juniper::IntoFieldError
+    #[must_use]
+    fn impl_into_field_error_tokens(&self) -> TokenStream {
+        let ident = &self.ident;
+        let scalar = &self.scalar;
+
+        let mut generics = self.impl_generics();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::core::fmt::Display });
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+
+        let convert = if self.safe {
+            format_ident!("into_safe_field_error")
+        } else {
+            format_ident!("into_field_error")
+        };
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::juniper::IntoFieldError<#scalar>
+                for #ident #ty_generics
+                #where_clause
+            {
+                fn into_field_error(self) -> ::juniper::FieldError<#scalar> {
+                    ::juniper::error_code::#convert(self)
+                }
+            }
+        }
+    }
+}
+
+/// Returns the [`syn::Member`] and the name of the provided Rust struct `field` to be put into
+/// [GraphQL error][0] extensions, if it should be.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+fn field_extension(n: usize, field: &syn::Field) -> syn::Result<Option<ExtensionDefinition>> {
+    let attr = FieldAttr::from_attrs("graphql", &field.attrs)?;
+    if attr.ignore.is_some() {
+        if let Some(name) = &attr.name {
+            return Err(syn::Error::new(
+                name.span_ident(),
+                "`name` attribute argument is not composable with `ignore`",
+            ));
+        }
+        return Ok(None);
+    }
+    let Some(ident) = &field.ident else {
+        // Unnamed fields are put into extensions only when named explicitly.
+        return Ok(attr.name.map(|name| ExtensionDefinition {
+            member: syn::Member::from(n),
+            ty: field.ty.clone(),
+            name: name.into_inner().value(),
+        }));
+    };
+    Ok(Some(ExtensionDefinition {
+        member: syn::Member::Named(ident.clone()),
+        ty: field.ty.clone(),
+        name: attr.name.map_or_else(
+            || rename::Policy::CamelCase.apply(&ident.unraw().to_string()),
+            |name| name.into_inner().value(),
+        ),
+    }))
+}
@@ -6,7 +6,7 @@ use proc_macro2::TokenStream;
 use quote::ToTokens as _;
 use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned};
 
-use crate::common::{SpanContainer, diagnostic, rename, scalar};
+use crate::common::{SpanContainer, diagnostic, rename, scalar, serde};
 
 use super::{ContainerAttr, Definition, FieldAttr, FieldDefinition};
 
@@ -16,19 +16,32 @@ const ERR: diagnostic::Scope = diagnostic::Scope::InputObjectDerive;
 /// Expands `#[derive(GraphQLInputObject)]` macro placed on a struct or an enum.
 pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
     let ast = syn::parse2::<syn::DeriveInput>(input)?;
-    let attr = ContainerAttr::from_attrs("graphql", &ast.attrs)?;
+    let mut attr = ContainerAttr::from_attrs("graphql", &ast.attrs)?;
+    let serde = attr
+        .serde
+        .is_some()
+        .then_some(serde::Direction::Deserialize);
+    let serde_attr = serde::Attr::from_attrs(&ast.attrs, serde)?;
+    if attr.name.is_none() {
+        attr.name = serde_attr.checked_rename(ERR);
+    }
 
     let renaming = attr
         .rename_fields
+        .or(serde_attr.rename_all)
         .map(SpanContainer::into_inner)
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or(if serde.is_some() {
+            rename::Policy::None
+        } else {
+            rename::Policy::CamelCase
+        });
     let is_internal = attr.is_internal;
     let (fields, fields_span) = match &ast.data {
         syn::Data::Struct(data) => {
             let fields = data
                 .fields
                 .iter()
-                .filter_map(|f| parse_struct_field(f, renaming, is_internal))
+                .filter_map(|f| parse_struct_field(f, renaming, serde, is_internal))
                 .collect::<Vec<_>>();
             (fields, data.fields.span())
         }
@@ -36,7 +49,7 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
             let fields = data
                 .variants
                 .iter()
-                .filter_map(|v| parse_enum_variant(v, renaming, is_internal))
+                .filter_map(|v| parse_enum_variant(v, renaming, serde, is_internal))
                 .collect::<Vec<_>>();
             (fields, data.variants.span())
         }
@@ -51,8 +64,12 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
         return Err(ERR.custom_error(fields_span, "expected at least 1 non-ignored field"));
     }
 
-    let unique_fields = fields.iter().map(|v| &v.name).collect::<HashSet<_>>();
-    if unique_fields.len() != fields.len() {
+    let named_fields = fields.iter().filter(|f| !f.flattened);
+    let unique_fields = named_fields
+        .clone()
+        .map(|v| &v.name)
+        .collect::<HashSet<_>>();
+    if unique_fields.len() != named_fields.count() {
         return Err(ERR.custom_error(fields_span, "expected all fields to have unique names"));
     }
 
@@ -99,11 +116,18 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 fn parse_struct_field(
     f: &syn::Field,
     renaming: rename::Policy,
+    serde: Option<serde::Direction>,
     is_internal: bool,
 ) -> Option<FieldDefinition> {
-    let field_attr = FieldAttr::from_attrs("graphql", &f.attrs)
+    let mut field_attr = FieldAttr::from_attrs("graphql", &f.attrs)
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    let serde_attr = serde::Attr::from_attrs(&f.attrs, serde)
         .map_err(diagnostic::emit_error)
         .ok()?;
+    if field_attr.name.is_none() {
+        field_attr.name = serde_attr.checked_rename(ERR);
+    }
 
     let ident = f.ident.as_ref().or_else(|| err_unnamed_field(f))?;
 
@@ -125,7 +149,9 @@ fn parse_struct_field(
         name,
         description: field_attr.description.map(SpanContainer::into_inner),
         deprecated: field_attr.deprecated.map(SpanContainer::into_inner),
-        ignored: field_attr.ignore.is_some(),
+        ignored: field_attr.ignore.is_some() || serde_attr.skip.is_some(),
+        flattened: field_attr.flatten.is_some(),
+        validators: field_attr.validators,
     })
 }
 
@@ -135,6 +161,7 @@ fn parse_struct_field(
 fn parse_enum_variant(
     v: &syn::Variant,
     renaming: rename::Policy,
+    serde: Option<serde::Direction>,
     is_internal: bool,
 ) -> Option<FieldDefinition> {
     if v.fields.len() != 1 || !matches!(v.fields, syn::Fields::Unnamed(_)) {
@@ -145,11 +172,24 @@ fn parse_enum_variant(
         );
     }
 
-    let field_attr = FieldAttr::from_attrs("graphql", &v.attrs)
+    let mut field_attr = FieldAttr::from_attrs("graphql", &v.attrs)
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    let serde_attr = serde::Attr::from_attrs(&v.attrs, serde)
         .map_err(diagnostic::emit_error)
         .ok()?;
+    if field_attr.name.is_none() {
+        field_attr.name = serde_attr.checked_rename(ERR);
+    }
+
+    if let Some(flatten) = &field_attr.flatten {
+        ERR.emit_custom(
+            flatten.span_ident(),
+            "field cannot be flattened in `@oneOf` input object",
+        );
+    }
 
-    let ignored = field_attr.ignore.is_some();
+    let ignored = field_attr.ignore.is_some() || serde_attr.skip.is_some();
     if let Some(default) = &field_attr.default {
         ERR.emit_custom(
             default.span_ident(),
@@ -169,6 +209,9 @@ fn parse_enum_variant(
         .map_or_else(
             || {
                 let mut name = ident.unraw().to_string();
+                if serde.is_some() {
+                    return serde::rename_variant(renaming, &name);
+                }
                 if renaming != rename::Policy::None {
                     // Make naming similar to struct fields before applying further renaming.
                     name = rename::Policy::SnakeCase.apply(&ident.unraw().to_string());
@@ -192,6 +235,8 @@ fn parse_enum_variant(
         description: field_attr.description.map(SpanContainer::into_inner),
         deprecated: field_attr.deprecated.map(SpanContainer::into_inner),
         ignored,
+        flattened: false,
+        validators: field_attr.validators,
     })
 }
 
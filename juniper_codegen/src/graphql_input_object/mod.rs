@@ -4,7 +4,7 @@
 
 pub(crate) mod derive;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, format_ident, quote, quote_spanned};
 use std::iter;
 use syn::{
@@ -21,7 +21,7 @@ use crate::common::{
         GenericsExt as _, ParseBufferExt as _,
         attr::{OptionExt as _, err},
     },
-    rename, scalar,
+    rename, scalar, validator,
 };
 
 /// Available arguments behind `#[graphql]` attribute placed on a Rust struct
@@ -76,6 +76,12 @@ struct ContainerAttr {
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified marker indicating that `#[serde(rename, rename_all, skip)]`
+    /// attributes should be honored for naming this [GraphQL input object][0] and its fields.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    serde: Option<SpanContainer<syn::Ident>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     is_internal: bool,
@@ -130,6 +136,10 @@ impl Parse for ContainerAttr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "serde" => out
+                    .serde
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -153,6 +163,7 @@ impl ContainerAttr {
             context: try_merge_opt!(context: self, another),
             scalar: try_merge_opt!(scalar: self, another),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            serde: try_merge_opt!(serde: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -224,6 +235,19 @@ struct FieldAttr {
     /// [`default`]: Self::default
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     ignore: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified marker for the Rust struct field to have the [fields][1] of its
+    /// [GraphQL input object][0] type spliced into the parent [GraphQL input object][0], instead
+    /// of representing a single [field][1].
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    flatten: Option<SpanContainer<syn::Ident>>,
+
+    /// Explicitly specified [`validator::Validators`] of this [GraphQL input object field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    validators: validator::Validators,
 }
 
 impl Parse for FieldAttr {
@@ -270,8 +294,14 @@ impl Parse for FieldAttr {
                     .ignore
                     .replace(SpanContainer::new(ident.span(), None, ident.clone()))
                     .none_or_else(|_| err::dup_arg(&ident))?,
+                "flatten" => out
+                    .flatten
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 name => {
-                    return Err(err::unknown_arg(&ident, name));
+                    if !out.validators.parse_arg(&ident, input)? {
+                        return Err(err::unknown_arg(&ident, name));
+                    }
                 }
             }
             input.try_parse::<token::Comma>()?;
@@ -290,6 +320,8 @@ impl FieldAttr {
             description: try_merge_opt!(description: self, another),
             deprecated: try_merge_opt!(deprecated: self, another),
             ignore: try_merge_opt!(ignore: self, another),
+            flatten: try_merge_opt!(flatten: self, another),
+            validators: self.validators.try_merge(another.validators)?,
         })
     }
 
@@ -300,6 +332,22 @@ impl FieldAttr {
             .map(|attr| attr.parse_args())
             .try_fold(Self::default(), |prev, curr| prev.try_merge(curr?))?;
 
+        if let Some(flatten) = &attr.flatten {
+            if attr.name.is_some()
+                || attr.default.is_some()
+                || attr.description.is_some()
+                || attr.deprecated.is_some()
+                || attr.ignore.is_some()
+                || attr.validators.span().is_some()
+            {
+                return Err(syn::Error::new(
+                    flatten.span(),
+                    "`flatten` attribute argument is not composable with any other arguments",
+                ));
+            }
+            return Ok(attr);
+        }
+
         if attr.description.is_none() {
             attr.description = Description::parse_from_doc_attrs(attrs)?;
         }
@@ -308,6 +356,13 @@ impl FieldAttr {
             attr.deprecated = deprecation::Directive::parse_from_deprecated_attr(attrs)?;
         }
 
+        if let (Some(_), Some(span)) = (&attr.ignore, attr.validators.span()) {
+            return Err(syn::Error::new(
+                span,
+                "validators are not composable with `ignore` attribute argument",
+            ));
+        }
+
         Ok(attr)
     }
 }
@@ -366,6 +421,19 @@ struct FieldDefinition {
     /// [`default`]: Self::default
     /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
     ignored: bool,
+
+    /// Indicator whether the Rust struct field behind this [GraphQL input object field][1] is
+    /// flattened, so the [fields][1] of its [GraphQL input object][0] type are spliced into the
+    /// parent one.
+    ///
+    /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    flattened: bool,
+
+    /// [`validator::Validators`] of this [GraphQL input object field][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#InputValueDefinition
+    validators: validator::Validators,
 }
 
 /// Representation of [GraphQL input object][0] for code generation.
@@ -476,6 +544,20 @@ impl Definition {
             }
         });
 
+        let assert_flattened_fields = self.fields.iter().any(|f| f.flattened).then(|| {
+            let fields = self
+                .fields
+                .iter()
+                .filter(|f| !f.ignored && !f.flattened)
+                .map(|f| &f.name);
+            let flattened_tys = self.fields.iter().filter(|f| f.flattened).map(|f| &f.ty);
+            quote! {
+                ::juniper::assert_flattened_fields!(
+                    #ident #ty_generics, #const_scalar, &[#( #fields ),*], #( #flattened_tys ),*
+                );
+            }
+        });
+
         quote! {
             #[automatically_derived]
             impl #impl_generics ::juniper::marker::IsInputType<#scalar>
@@ -484,6 +566,7 @@ impl Definition {
             {
                 fn mark() {
                     #( #assert_fields_input_values )*
+                    #assert_flattened_fields
                 }
             }
         }
@@ -508,11 +591,13 @@ impl Definition {
 
         let one_of = self.is_one_of.then(|| quote! { .one_of() });
 
+        let flattened_tys = self.fields.iter().filter(|f| f.flattened).map(|f| &f.ty);
+
         let fields = self.fields.iter().filter_map(|f| {
             let ty = &f.ty;
             let name = &f.name;
 
-            (!f.ignored).then(|| {
+            (!f.ignored && !f.flattened).then(|| {
                 let arg = if let Some(default) = &f.default {
                     quote! {
                         .arg_with_default::<#ty>(
@@ -546,6 +631,12 @@ impl Definition {
                     registry: &mut ::juniper::Registry<#scalar>,
                 ) -> ::juniper::meta::MetaType<#scalar> {
                     let fields = [#( #fields ),*];
+                    #( let fields = [
+                        &fields[..],
+                        &::juniper::macros::helper::flattened_input_fields::<#flattened_tys, #scalar>(
+                            info, registry,
+                        )[..],
+                    ].concat(); )*
                     registry
                         .build_input_object_type::<#ident #ty_generics>(info, &fields)
                         #description
@@ -640,15 +731,24 @@ impl Definition {
 
             let arms = variants.iter().enumerate().map(|(n, v)| {
                 let variant_ident = &v.ident;
+                let name = &v.name;
+                let validate = v.validators.filter_tokens(name);
 
                 let pre_none_pats = iter::repeat_n(&none_pat, n);
                 let post_none_pats = iter::repeat_n(&none_pat, variants.len() - n - 1);
 
                 quote! {
                     (#( #pre_none_pats, )* #some_pat, #( #post_none_pats, )*) => {
+                        let mut errors = ::juniper::validator::InputErrors::<#scalar>::new();
                         Self::#variant_ident(
-                            ::juniper::FromInputValue::<#scalar>::from_input_value(v)
-                                .map_err(::juniper::IntoFieldError::into_field_error)?
+                            errors
+                                .convert(
+                                    #name,
+                                    ::juniper::FromInputValue::<#scalar>::from_input_value(v)
+                                        .map_err(::juniper::IntoFieldError::into_field_error),
+                                )
+                                #validate
+                                .ok_or_else(|| errors.into_field_error())?
                         )
                     }
                 }
@@ -663,45 +763,75 @@ impl Definition {
                 }
             }
         } else {
-            let fields = self.fields.iter().map(|f| {
-                let ident = &f.ident;
+            // Call site span is required to omit triggering lints (like `deprecated` one) in the
+            // user code.
+            let var = |f: &FieldDefinition| {
+                format_ident!("field_{}", f.ident.unraw(), span = Span::call_site())
+            };
 
-                let construct = if f.ignored {
-                    f.default.as_ref().map_or_else(
-                        || {
-                            let expr = default::Value::default();
-                            quote! { #expr }
-                        },
-                        |expr| quote! { #expr },
-                    )
-                } else {
-                    let name = &f.name;
+            let fields = self
+                .fields
+                .iter()
+                .filter(|f| !f.ignored)
+                .collect::<Vec<_>>();
+            let vars = fields.iter().map(|f| var(f)).collect::<Vec<_>>();
+
+            let convert = fields.iter().zip(&vars).map(|(f, var)| {
+                let (name, ty) = (&f.name, &f.ty);
+
+                if f.flattened {
+                    return quote! {
+                        let #var = errors.flatten::<#ty>(
+                            ::juniper::FromInputValue::<#scalar>::from_input_value(value)
+                                .map_err(::juniper::IntoFieldError::into_field_error),
+                        );
+                    };
+                }
 
-                    let fallback = f.default.as_ref().map_or_else(
-                        || {
-                            quote! {
-                                ::juniper::FromInputValue::<#scalar>::from_implicit_null()
-                                    .map_err(::juniper::IntoFieldError::into_field_error)?
-                            }
-                        },
-                        |expr| quote! { #expr },
-                    );
+                let validate = f.validators.filter_tokens(name);
 
-                    quote! {
-                        match obj.get(#name) {
+                let fallback = f.default.as_ref().map_or_else(
+                    || {
+                        quote! {
+                            ::juniper::FromInputValue::<#scalar>::from_implicit_null()
+                                .map_err(::juniper::IntoFieldError::into_field_error)
+                        }
+                    },
+                    |expr| quote! { ::core::result::Result::Ok(#expr) },
+                );
+
+                quote! {
+                    let #var = errors
+                        .convert::<#ty>(#name, match obj.get(#name) {
                             ::core::option::Option::Some(v) => {
                                 ::juniper::FromInputValue::<#scalar>::from_input_value(v)
-                                    .map_err(::juniper::IntoFieldError::into_field_error)?
+                                    .map_err(::juniper::IntoFieldError::into_field_error)
                             }
-                            ::core::option::Option::None => { #fallback }
-                        }
-                    }
-                };
+                            ::core::option::Option::None => #fallback,
+                        })
+                        #validate;
+                }
+            });
 
-                quote! { #ident: #construct, }
+            let construct = self.fields.iter().map(|f| {
+                let ident = &f.ident;
+                if f.ignored {
+                    let expr = f.default.clone().unwrap_or_default();
+                    quote! { #ident: #expr, }
+                } else {
+                    let var = var(f);
+                    quote! { #ident: #var, }
+                }
             });
 
-            quote! { Self { #( #fields )* } }
+            quote! {{
+                let mut errors = ::juniper::validator::InputErrors::<#scalar>::new();
+                #( #convert )*
+                match (#( #vars, )*) {
+                    (#( ::core::option::Option::Some(#vars), )*) => Self { #( #construct )* },
+                    _ => return ::core::result::Result::Err(errors.into_field_error()),
+                }
+            }}
         };
 
         quote! {
@@ -740,26 +870,40 @@ impl Definition {
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
-        let fields = self.fields.iter().filter(|&f| !f.ignored).map(|f| {
-            let ident = &f.ident;
-            let name = &f.name;
+        let has_flattened = self.fields.iter().any(|f| f.flattened);
 
-            let value_expr = if self.is_one_of {
-                quote! {
-                    if let Self::#ident(v) = self {
-                        ::core::option::Option::Some(v)
-                    } else {
-                        ::core::option::Option::None
+        let fields = self
+            .fields
+            .iter()
+            .filter(|&f| !f.ignored && !f.flattened)
+            .map(|f| {
+                let ident = &f.ident;
+                let name = &f.name;
+                // Flattened fields are keyed by owned `String`s, so the own ones should be too.
+                let name = if has_flattened {
+                    quote! { ::std::string::String::from(#name) }
+                } else {
+                    quote! { #name }
+                };
+
+                let value_expr = if self.is_one_of {
+                    quote! {
+                        if let Self::#ident(v) = self {
+                            ::core::option::Option::Some(v)
+                        } else {
+                            ::core::option::Option::None
+                        }
                     }
+                } else {
+                    quote! { self.#ident }
+                };
+
+                quote! {
+                    (#name, ::juniper::ToInputValue::to_input_value(&#value_expr))
                 }
-            } else {
-                quote! { self.#ident }
-            };
+            });
 
-            quote! {
-                (#name, ::juniper::ToInputValue::to_input_value(&#value_expr))
-            }
-        });
+        let flattened_idents = self.fields.iter().filter(|f| f.flattened).map(|f| &f.ident);
 
         quote! {
             #[automatically_derived]
@@ -768,7 +912,13 @@ impl Definition {
             {
                 fn to_input_value(&self) -> ::juniper::InputValue<#scalar> {
                     ::juniper::InputValue::object(
-                        ::core::iter::IntoIterator::into_iter([#( #fields ),*]).collect()
+                        ::core::iter::IntoIterator::into_iter([#( #fields ),*])
+                            #( .chain(::juniper::macros::helper::flattened_input_value(
+                                ::juniper::ToInputValue::<#scalar>::to_input_value(
+                                    &self.#flattened_idents,
+                                ),
+                            )) )*
+                            .collect()
                     )
                 }
             }
@@ -792,7 +942,11 @@ impl Definition {
         let (impl_generics, _, where_clause) = generics.split_for_impl();
         let (_, ty_generics, _) = self.generics.split_for_impl();
 
-        let fields_names = self.fields.iter().filter(|f| !f.ignored).map(|f| &f.name);
+        let fields_names = self
+            .fields
+            .iter()
+            .filter(|f| !f.ignored && !f.flattened)
+            .map(|f| &f.name);
 
         quote! {
             #[automatically_derived]
@@ -846,7 +1000,7 @@ impl Definition {
 
         self.fields
             .iter()
-            .filter(|f| !f.ignored)
+            .filter(|f| !f.ignored && !f.flattened)
             .map(|field| {
                 let field_name = &field.name;
                 let mut field_ty = field.ty.clone();
@@ -168,6 +168,13 @@ fn parse_trait_method(
         return None;
     }
 
+    attr.ensure_no_subscription_arguments()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    attr.ensure_no_flatten()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+
     if method.default.is_some() {
         return err_default_impl_block(&method.default);
     }
@@ -212,6 +219,9 @@ fn parse_trait_method(
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        filter: None,
+        guard: attr.guard.map(SpanContainer::into_inner),
+        concurrency: attr.concurrency.map(SpanContainer::into_inner),
     })
 }
 
@@ -350,6 +360,13 @@ fn parse_struct_field(
         return None;
     }
 
+    attr.ensure_no_subscription_arguments()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    attr.ensure_no_flatten()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+
     let name = attr
         .name
         .as_ref()
@@ -377,6 +394,9 @@ fn parse_struct_field(
         arguments: None,
         has_receiver: false,
         is_async: false,
+        filter: None,
+        guard: attr.guard.map(SpanContainer::into_inner),
+        concurrency: attr.concurrency.map(SpanContainer::into_inner),
     })
 }
 
@@ -123,6 +123,13 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         return None;
     }
 
+    attr.ensure_no_subscription_arguments()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    attr.ensure_no_flatten()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+
     let name = attr
         .name
         .as_ref()
@@ -150,5 +157,8 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         arguments: None,
         has_receiver: false,
         is_async: false,
+        filter: None,
+        guard: attr.guard.map(SpanContainer::into_inner),
+        concurrency: attr.concurrency.map(SpanContainer::into_inner),
     })
 }
@@ -782,6 +782,7 @@ impl Definition {
         let downcast_check = self.method_concrete_type_name_tokens();
 
         let downcast = self.method_resolve_into_type_tokens();
+        let selection_set = self.selection_set_arg_ident();
 
         quote! {
             #[allow(deprecated)]
@@ -824,7 +825,7 @@ impl Definition {
                     &self,
                     info: &Self::TypeInfo,
                     type_name: &::core::primitive::str,
-                    _: ::core::option::Option<&[::juniper::Selection<'_, #scalar>]>,
+                    #selection_set: ::core::option::Option<&[::juniper::Selection<'_, #scalar>]>,
                     executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     #downcast
@@ -863,6 +864,7 @@ impl Definition {
             field::Definition::method_resolve_field_err_no_field_tokens(scalar, trait_name);
 
         let downcast = self.method_resolve_into_type_async_tokens();
+        let selection_set = self.selection_set_arg_ident();
 
         quote! {
             #[allow(deprecated, non_snake_case)]
@@ -887,7 +889,7 @@ impl Definition {
                     &'b self,
                     info: &'b Self::TypeInfo,
                     type_name: &::core::primitive::str,
-                    _: ::core::option::Option<&'b [::juniper::Selection<'b, #scalar>]>,
+                    #selection_set: ::core::option::Option<&'b [::juniper::Selection<'b, #scalar>]>,
                     executor: &'b ::juniper::Executor<'b, 'b, Self::Context, #scalar>
                 ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
                     #downcast
@@ -985,15 +987,23 @@ impl Definition {
                 let mut return_ty = field.ty.clone();
                 generics.replace_type_with_defaults(&mut return_ty);
 
-                let (args_tys, args_names): (Vec<_>, Vec<_>) = field
+                let arguments = field
                     .arguments
                     .iter()
                     .flat_map(|vec| vec.iter())
                     .filter_map(|arg| match arg {
-                        field::MethodArgument::Regular(arg) => Some((&arg.ty, &arg.name)),
+                        field::MethodArgument::Regular(arg) => {
+                            let (name, ty) = (&arg.name, &arg.ty);
+                            let wrapped_value = arg.wrapped_value_tokens(scalar);
+                            Some(quote! {(
+                                #name,
+                                <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
+                                #wrapped_value,
+                            )})
+                        }
                         _ => None,
                     })
-                    .unzip();
+                    .collect::<Vec<_>>();
 
                 quote! {
                     #[allow(non_snake_case)]
@@ -1014,11 +1024,7 @@ impl Definition {
                             ::juniper::macros::reflect::Name,
                             ::juniper::macros::reflect::Type,
                             ::juniper::macros::reflect::WrappedValue,
-                        )] = &[#( (
-                            #args_names,
-                            <#args_tys as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
-                            <#args_tys as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE,
-                        ) ),*];
+                        )] = &[#(#arguments,)*];
                     }
                 }
             })
@@ -1069,6 +1075,31 @@ impl Definition {
                     quote! { _ => unreachable!() }
                 });
 
+                let resolve = if field.guard.is_some() {
+                    field::Definition::method_resolve_field_err_guarded_sync_tokens(
+                        field_name, scalar,
+                    )
+                } else {
+                    quote_spanned! { field.ident.span() =>
+                        match self {
+                            #( #ty::#implemented_for_idents(v) => {
+                                ::juniper::assert_field!(
+                                    #ty #ty_const_generics,
+                                    #const_implemented_for,
+                                    #const_scalar,
+                                    #field_name,
+                                );
+
+                                <_ as ::juniper::macros::reflect::Field::<
+                                    #scalar,
+                                    { ::juniper::macros::reflect::fnv1a128(#field_name) },
+                                >>::call(v, info, args, executor)
+                            } )*
+                            #unreachable_arm
+                        }
+                    }
+                };
+
                 quote_spanned! { field.ident.span() =>
                     #[allow(non_snake_case)]
                     #[automatically_derived]
@@ -1082,22 +1113,7 @@ impl Definition {
                             args: &::juniper::Arguments<'_, #scalar>,
                             executor: &::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::ExecutionResult<#scalar> {
-                            match self {
-                                #( #ty::#implemented_for_idents(v) => {
-                                    ::juniper::assert_field!(
-                                        #ty #ty_const_generics,
-                                        #const_implemented_for,
-                                        #const_scalar,
-                                        #field_name,
-                                    );
-
-                                    <_ as ::juniper::macros::reflect::Field::<
-                                        #scalar,
-                                        { ::juniper::macros::reflect::fnv1a128(#field_name) },
-                                    >>::call(v, info, args, executor)
-                                } )*
-                                #unreachable_arm
-                            }
+                            #resolve
                         }
                     }
                 }
@@ -1149,6 +1165,33 @@ impl Definition {
                     quote! { _ => unreachable!() }
                 });
 
+                let mut resolve = quote_spanned! { field.ident.span() =>
+                    match self {
+                        #( #ty::#implemented_for_idents(v) => {
+                            ::juniper::assert_field!(
+                                #ty #ty_const_generics,
+                                #const_implemented_for,
+                                #const_scalar,
+                                #field_name,
+                            );
+
+                            <_ as ::juniper::macros::reflect::AsyncField<
+                                #scalar,
+                                { ::juniper::macros::reflect::fnv1a128(#field_name) },
+                            >>::call(v, info, args, executor)
+                        } )*
+                        #unreachable_arm
+                    }
+                };
+                if let Some(guard) = field.method_check_guard_tokens(scalar) {
+                    resolve = quote! {
+                        ::std::boxed::Box::pin(async move {
+                            #guard
+                            #resolve.await
+                        })
+                    };
+                }
+
                 quote_spanned! { field.ident.span() =>
                     #[allow(non_snake_case)]
                     #[automatically_derived]
@@ -1162,22 +1205,7 @@ impl Definition {
                             args: &'b ::juniper::Arguments<'_, #scalar>,
                             executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
-                            match self {
-                                #( #ty::#implemented_for_idents(v) => {
-                                    ::juniper::assert_field!(
-                                        #ty #ty_const_generics,
-                                        #const_implemented_for,
-                                        #const_scalar,
-                                        #field_name,
-                                    );
-
-                                    <_ as ::juniper::macros::reflect::AsyncField<
-                                        #scalar,
-                                        { ::juniper::macros::reflect::fnv1a128(#field_name) },
-                                    >>::call(v, info, args, executor)
-                                } )*
-                                #unreachable_arm
-                            }
+                            #resolve
                         }
                     }
                 }
@@ -1225,8 +1253,13 @@ impl Definition {
     /// downcasts this enum into its underlying [`implementers`][1] type
     /// asynchronously.
     ///
+    /// [`Guard`]s of this [GraphQL interface][2] fields, selected via fragments
+    /// on the [`implementers`][1], are checked before downcasting.
+    ///
+    /// [`Guard`]: juniper::Guard
     /// [0]: juniper::GraphQLValueAsync::resolve_into_type_async
     /// [1]: Self::implementers
+    /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     #[must_use]
     fn method_resolve_into_type_async_tokens(&self) -> TokenStream {
         let resolving_code = generate::async_resolving_code(None);
@@ -1246,11 +1279,39 @@ impl Definition {
                 quote! { _ => unreachable!(), }
             });
 
-        quote! {
+        let downcast = quote! {
             match self {
                 #( #match_arms )*
                 #non_exhaustive_match_arm
             }
+        };
+
+        let scalar = &self.scalar;
+        let guards = self
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let name = &f.name;
+                f.method_check_guard_tokens(scalar).map(|guard| {
+                    quote! {
+                        if ::juniper::macros::helper::selects_field(selection_set, #name, executor) {
+                            #guard
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        if guards.is_empty() {
+            downcast
+        } else {
+            quote! {
+                ::std::boxed::Box::pin(async move {
+                    #( #guards )*
+                    let fut: ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> =
+                        #downcast;
+                    fut.await
+                })
+            }
         }
     }
 
@@ -1258,8 +1319,14 @@ impl Definition {
     /// method, which resolves this enum into its underlying
     /// [`implementers`][1] type synchronously.
     ///
+    /// Errors if any guarded field of this [GraphQL interface][2] is selected
+    /// via fragments on the [`implementers`][1], as its [`Guard`] can be
+    /// checked asynchronously only.
+    ///
+    /// [`Guard`]: juniper::Guard
     /// [0]: juniper::GraphQLValue::resolve_into_type
     /// [1]: Self::implementers
+    /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
     #[must_use]
     fn method_resolve_into_type_tokens(&self) -> TokenStream {
         let resolving_code = generate::sync_resolving_code();
@@ -1277,7 +1344,19 @@ impl Definition {
                 quote! { _ => unreachable!(), }
             });
 
+        let guarded_errs = self.fields.iter().filter(|f| f.guard.is_some()).map(|f| {
+            let name = &f.name;
+            let err =
+                field::Definition::method_resolve_field_err_guarded_sync_tokens(name, &self.scalar);
+            quote! {
+                if ::juniper::macros::helper::selects_field(selection_set, #name, executor) {
+                    #err
+                }
+            }
+        });
+
         quote! {
+            #( #guarded_errs )*
             match self {
                 #( #match_arms )*
                 #non_exhaustive_match_arm
@@ -1285,6 +1364,24 @@ impl Definition {
         }
     }
 
+    /// Returns identifier of the `selection_set` argument of the
+    /// [`GraphQLValue::resolve_into_type`][0] and
+    /// [`GraphQLValueAsync::resolve_into_type_async`][1] methods, being used
+    /// only for checking [`Guard`]s of this [GraphQL interface][2] fields.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [0]: juniper::GraphQLValue::resolve_into_type
+    /// [1]: juniper::GraphQLValueAsync::resolve_into_type_async
+    /// [2]: https://spec.graphql.org/October2021#sec-Interfaces
+    #[must_use]
+    fn selection_set_arg_ident(&self) -> syn::Ident {
+        if self.fields.iter().any(|f| f.guard.is_some()) {
+            format_ident!("selection_set")
+        } else {
+            format_ident!("_selection_set")
+        }
+    }
+
     /// Returns trait generics replaced with the default values for usage in a
     /// `const` context.
     #[must_use]
@@ -12,7 +12,7 @@ use crate::common::{
     path_eq_single, rename, scalar,
 };
 
-use super::{Attr, Definition, Query};
+use super::{Attr, Definition, Query, check_name_template};
 
 /// [`diagnostic::Scope`] of errors for `#[graphql_object]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ObjectAttr;
@@ -45,6 +45,8 @@ where
     Definition<Operation>: ToTokens,
     Operation: 'static,
 {
+    attr.ensure_no_serde()?;
+
     let type_span = ast.self_ty.span();
     let type_ident = ast.self_ty.topmost_ident().ok_or_else(|| {
         ERR.custom_error(type_span, "could not determine ident for the `impl` type")
@@ -63,6 +65,9 @@ where
                 .unwrap_or_else(|| type_ident.span()),
         );
     }
+    if let Some(name) = &attr.name {
+        check_name_template(name, name.span_joined(), &ast.generics, ERR);
+    }
 
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
@@ -75,7 +80,7 @@ where
         .unwrap_or(rename::Policy::CamelCase);
 
     let async_only = TypeId::of::<Operation>() != TypeId::of::<Query>();
-    let fields: Vec<_> = ast
+    let mut fields: Vec<_> = ast
         .items
         .iter_mut()
         .filter_map(|item| {
@@ -98,6 +103,12 @@ where
 
     diagnostic::abort_if_dirty();
 
+    if let Some(guard) = &attr.guard {
+        for field in &mut fields {
+            field.prepend_guard(guard);
+        }
+    }
+
     let context = attr
         .context
         .as_deref()
@@ -121,6 +132,7 @@ where
         context,
         scalar,
         fields,
+        flattened: vec![],
         interfaces: attr
             .interfaces
             .iter()
@@ -162,6 +174,15 @@ fn parse_field(
         return None;
     }
 
+    if !async_only {
+        attr.ensure_no_subscription_arguments()
+            .map_err(diagnostic::emit_error)
+            .ok()?;
+    }
+    attr.ensure_no_flatten()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+
     if async_only && method.sig.asyncness.is_none() {
         return err_no_sync_resolvers(&method.sig);
     }
@@ -226,6 +247,9 @@ fn parse_field(
         arguments: Some(arguments),
         has_receiver: method.sig.receiver().is_some(),
         is_async: method.sig.asyncness.is_some(),
+        filter: attr.filter.map(SpanContainer::into_inner),
+        guard: attr.guard.map(SpanContainer::into_inner),
+        concurrency: attr.concurrency.map(SpanContainer::into_inner),
     })
 }
 
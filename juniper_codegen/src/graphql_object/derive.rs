@@ -11,10 +11,10 @@ use crate::common::{
     diagnostic::{self, ResultExt as _},
     field,
     parse::TypeExt as _,
-    rename, scalar,
+    rename, scalar, serde,
 };
 
-use super::{Attr, Definition, Query};
+use super::{Attr, Definition, FlattenedField, Query, check_name_template};
 
 /// [`diagnostic::Scope`] of errors for `#[derive(GraphQLObject)]` macro.
 const ERR: diagnostic::Scope = diagnostic::Scope::ObjectDerive;
@@ -33,7 +33,12 @@ pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
 /// Expands into generated code a `#[derive(GraphQLObject)]` macro placed on a
 /// Rust struct.
 fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
-    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+    let mut attr = Attr::from_attrs("graphql", &ast.attrs)?;
+    let serde = attr.serde.is_some().then_some(serde::Direction::Serialize);
+    let serde_attr = serde::Attr::from_attrs(&ast.attrs, serde)?;
+    if attr.name.is_none() {
+        attr.name = serde_attr.checked_rename(ERR);
+    }
 
     let struct_span = ast.span();
     let struct_ident = ast.ident;
@@ -54,6 +59,9 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
                 .unwrap_or_else(|| struct_ident.span()),
         );
     }
+    if let Some(name) = &attr.name {
+        check_name_template(name, name.span_joined(), &ast.generics, ERR);
+    }
 
     let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
 
@@ -62,16 +70,21 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
     let renaming = attr
         .rename_fields
         .as_deref()
+        .or(serde_attr.rename_all.as_deref())
         .copied()
-        .unwrap_or(rename::Policy::CamelCase);
+        .unwrap_or(if serde.is_some() {
+            rename::Policy::None
+        } else {
+            rename::Policy::CamelCase
+        });
 
-    let mut fields = vec![];
+    let (mut fields, mut flattened) = (vec![], vec![]);
     if let syn::Data::Struct(data) = &ast.data {
         if let syn::Fields::Named(fs) = &data.fields {
             fields = fs
                 .named
                 .iter()
-                .filter_map(|f| parse_field(f, &renaming))
+                .filter_map(|f| parse_field(f, &renaming, serde, &mut flattened))
                 .collect();
         } else {
             ERR.emit_custom(struct_span, "only named fields are allowed");
@@ -80,7 +93,7 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
     diagnostic::abort_if_dirty();
 
-    if fields.is_empty() {
+    if fields.is_empty() && flattened.is_empty() {
         ERR.emit_custom(struct_span, "must have at least one field");
     }
     if !field::all_different(&fields) {
@@ -89,6 +102,15 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
     diagnostic::abort_if_dirty();
 
+    if let Some(guard) = &attr.guard {
+        for field in &mut fields {
+            field.prepend_guard(guard);
+        }
+        for field in &mut flattened {
+            field.guard = Some(guard.as_ref().clone());
+        }
+    }
+
     Ok(Definition {
         name,
         ty,
@@ -100,6 +122,7 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
             .unwrap_or_else(|| parse_quote! { () }),
         scalar,
         fields,
+        flattened,
         interfaces: attr
             .interfaces
             .iter()
@@ -111,17 +134,45 @@ fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
 
 /// Parses a [`field::Definition`] from the given Rust struct [`syn::Field`].
 ///
-/// Returns [`None`] if parsing fails, or the struct field is ignored.
+/// Returns [`None`] if parsing fails, or the struct field is ignored or flattened. Flattened
+/// struct fields are pushed into the provided `flattened` ones instead.
 #[must_use]
-fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::Definition> {
-    let attr = field::Attr::from_attrs("graphql", &field.attrs)
+fn parse_field(
+    field: &syn::Field,
+    renaming: &rename::Policy,
+    serde: Option<serde::Direction>,
+    flattened: &mut Vec<FlattenedField>,
+) -> Option<field::Definition> {
+    let mut attr = field::Attr::from_attrs("graphql", &field.attrs)
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+    let serde_attr = serde::Attr::from_attrs(&field.attrs, serde)
         .map_err(diagnostic::emit_error)
         .ok()?;
 
-    if attr.ignore.is_some() {
+    if attr.ignore.is_some() || serde_attr.skip.is_some() {
+        return None;
+    }
+    if attr.name.is_none() {
+        attr.name = serde_attr.checked_rename(ERR).map(|name| {
+            let lit = syn::LitStr::new(&name, name.span_joined());
+            SpanContainer::new(name.span_ident(), Some(lit.span()), lit)
+        });
+    }
+
+    if attr.flatten.is_some() {
+        flattened.push(FlattenedField {
+            member: syn::Member::Named(field.ident.clone().unwrap()),
+            ty: field.ty.unparenthesized().clone(),
+            guard: None,
+        });
         return None;
     }
 
+    attr.ensure_no_subscription_arguments()
+        .map_err(diagnostic::emit_error)
+        .ok()?;
+
     let field_ident = field.ident.as_ref().unwrap();
 
     let name = attr
@@ -151,5 +202,8 @@ fn parse_field(field: &syn::Field, renaming: &rename::Policy) -> Option<field::D
         arguments: None,
         has_receiver: false,
         is_async: false,
+        filter: None,
+        guard: attr.guard.map(SpanContainer::into_inner),
+        concurrency: attr.concurrency.map(SpanContainer::into_inner),
     })
 }
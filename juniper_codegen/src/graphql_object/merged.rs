@@ -0,0 +1,116 @@
+//! Code generation for `#[derive(GraphQLMergedObject)]` macro.
+
+use std::marker::PhantomData;
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{ext::IdentExt as _, parse_quote, spanned::Spanned as _};
+
+use crate::common::{
+    SpanContainer,
+    diagnostic::{self, ResultExt as _},
+    filter_attrs,
+    parse::TypeExt as _,
+    scalar,
+};
+
+use super::{Attr, Definition, FlattenedField, Query, check_name_template};
+
+/// [`diagnostic::Scope`] of errors for `#[derive(GraphQLMergedObject)]` macro.
+const ERR: diagnostic::Scope = diagnostic::Scope::ObjectDerive;
+
+/// Expands `#[derive(GraphQLMergedObject)]` macro into generated code.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let ast = syn::parse2::<syn::DeriveInput>(input).unwrap_or_abort();
+
+    match &ast.data {
+        syn::Data::Struct(_) => expand_struct(ast),
+        _ => Err(ERR.custom_error(ast.span(), "can only be derived for structs")),
+    }
+    .map(ToTokens::into_token_stream)
+}
+
+/// Expands into generated code a `#[derive(GraphQLMergedObject)]` macro placed on a Rust struct,
+/// merging the [GraphQL objects][1] of all its fields into a single one.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+fn expand_struct(ast: syn::DeriveInput) -> syn::Result<Definition<Query>> {
+    let attr = Attr::from_attrs("graphql", &ast.attrs)?;
+    attr.ensure_no_serde()?;
+
+    let struct_span = ast.span();
+    let struct_ident = ast.ident;
+
+    let (_, struct_generics, _) = ast.generics.split_for_impl();
+    let ty = parse_quote! { #struct_ident #struct_generics };
+
+    let name = attr
+        .name
+        .clone()
+        .map(SpanContainer::into_inner)
+        .unwrap_or_else(|| struct_ident.unraw().to_string());
+    if !attr.is_internal && name.starts_with("__") {
+        ERR.no_double_underscore(
+            attr.name
+                .as_ref()
+                .map(SpanContainer::span_ident)
+                .unwrap_or_else(|| struct_ident.span()),
+        );
+    }
+    if let Some(name) = &attr.name {
+        check_name_template(name, name.span_joined(), &ast.generics, ERR);
+    }
+
+    let scalar = scalar::Type::parse(attr.scalar.as_deref(), &ast.generics);
+
+    diagnostic::abort_if_dirty();
+
+    let mut flattened = vec![];
+    if let syn::Data::Struct(data) = &ast.data {
+        for (n, field) in data.fields.iter().enumerate() {
+            if let Some(a) = filter_attrs("graphql", &field.attrs).next() {
+                ERR.emit_custom(
+                    a.span(),
+                    "merged object fields don't support `#[graphql]` attribute",
+                );
+                continue;
+            }
+            flattened.push(FlattenedField {
+                member: field
+                    .ident
+                    .clone()
+                    .map_or_else(|| syn::Member::from(n), syn::Member::Named),
+                ty: field.ty.unparenthesized().clone(),
+                guard: attr.guard.as_deref().cloned(),
+            });
+        }
+    }
+
+    diagnostic::abort_if_dirty();
+
+    if flattened.is_empty() {
+        ERR.emit_custom(struct_span, "must have at least one merged object");
+    }
+
+    diagnostic::abort_if_dirty();
+
+    Ok(Definition {
+        name,
+        ty,
+        generics: ast.generics,
+        description: attr.description.map(SpanContainer::into_inner),
+        context: attr
+            .context
+            .map(SpanContainer::into_inner)
+            .unwrap_or_else(|| parse_quote! { () }),
+        scalar,
+        fields: vec![],
+        flattened,
+        interfaces: attr
+            .interfaces
+            .iter()
+            .map(|ty| ty.as_ref().clone())
+            .collect(),
+        _operation: PhantomData,
+    })
+}
@@ -4,11 +4,12 @@
 
 pub mod attr;
 pub mod derive;
+pub mod merged;
 
 use std::{any::TypeId, collections::HashSet, marker::PhantomData};
 
-use proc_macro2::TokenStream;
-use quote::{ToTokens, format_ident, quote};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::{
     ext::IdentExt as _,
     parse::{Parse, ParseStream},
@@ -18,7 +19,7 @@ use syn::{
 };
 
 use crate::common::{
-    AttrNames, Description, SpanContainer, field, filter_attrs, generate,
+    AttrNames, Description, SpanContainer, diagnostic, field, filter_attrs, generate,
     parse::{
         GenericsExt as _, ParseBufferExt as _, TypeExt,
         attr::{OptionExt as _, err},
@@ -87,6 +88,23 @@ pub(crate) struct Attr {
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
     pub(crate) rename_fields: Option<SpanContainer<rename::Policy>>,
 
+    /// Explicitly specified [`Guard`] authorizing resolution of all the fields
+    /// of this [GraphQL object][1] type.
+    ///
+    /// Checked before the [`Guard`]s of the fields themselves, if any.
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) guard: Option<SpanContainer<syn::Expr>>,
+
+    /// Explicitly specified marker indicating that `#[serde(rename, rename_all, skip)]`
+    /// attributes should be honored for naming this [GraphQL object][1] type and its fields.
+    ///
+    /// Applicable to `#[derive(GraphQLObject)]` macro only.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) serde: Option<SpanContainer<syn::Ident>>,
+
     /// Indicator whether the generated code is intended to be used only inside
     /// the [`juniper`] library.
     pub(crate) is_internal: bool,
@@ -153,6 +171,17 @@ impl Parse for Attr {
                         ))
                         .none_or_else(|_| err::dup_arg(&ident))?;
                 }
+                "guard" => {
+                    input.parse::<token::Eq>()?;
+                    let guard = input.parse::<syn::Expr>()?;
+                    out.guard
+                        .replace(SpanContainer::new(ident.span(), Some(guard.span()), guard))
+                        .none_or_else(|_| err::dup_arg(&ident))?
+                }
+                "serde" => out
+                    .serde
+                    .replace(SpanContainer::new(ident.span(), None, ident.clone()))
+                    .none_or_else(|_| err::dup_arg(&ident))?,
                 "internal" => {
                     out.is_internal = true;
                 }
@@ -177,6 +206,8 @@ impl Attr {
             scalar: try_merge_opt!(scalar: self, another),
             interfaces: try_merge_hashset!(interfaces: self, another => span_joined),
             rename_fields: try_merge_opt!(rename_fields: self, another),
+            guard: try_merge_opt!(guard: self, another),
+            serde: try_merge_opt!(serde: self, another),
             is_internal: self.is_internal || another.is_internal,
         })
     }
@@ -194,6 +225,18 @@ impl Attr {
 
         Ok(attr)
     }
+
+    /// Checks whether this [`Attr`] doesn't contain the `serde` argument, applicable to
+    /// `#[derive(GraphQLObject)]` macro only.
+    pub(crate) fn ensure_no_serde(&self) -> syn::Result<()> {
+        if let Some(serde) = &self.serde {
+            return Err(syn::Error::new(
+                serde.span_ident(),
+                "`serde` attribute argument is applicable to `#[derive(GraphQLObject)]` macro only",
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Definition of [GraphQL object][1] for code generation.
@@ -246,6 +289,13 @@ pub(crate) struct Definition<Operation: ?Sized> {
     /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
     pub(crate) fields: Vec<field::Definition>,
 
+    /// Rust struct fields of this [GraphQL object][1], whose [GraphQL fields][2] are spliced into
+    /// it via `#[graphql(flatten)]` attribute argument.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    /// [2]: https://spec.graphql.org/October2021#sec-Language.Fields
+    pub(crate) flattened: Vec<FlattenedField>,
+
     /// [GraphQL interfaces][2] implemented by this [GraphQL object][1].
     ///
     /// [1]: https://spec.graphql.org/October2021#sec-Objects
@@ -262,6 +312,102 @@ pub(crate) struct Definition<Operation: ?Sized> {
     pub(crate) _operation: PhantomData<Box<Operation>>,
 }
 
+/// Representation of a Rust struct field, whose [GraphQL fields][1] are spliced into its
+/// [GraphQL object][2] via `#[graphql(flatten)]` attribute argument.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+/// [2]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Debug)]
+pub(crate) struct FlattenedField {
+    /// [`syn::Member`] of this Rust struct field, either named or unnamed.
+    pub(crate) member: syn::Member,
+
+    /// Rust type of this struct field, representing a [GraphQL object][2] itself.
+    ///
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) ty: syn::Type,
+
+    /// [`Guard`] of the whole [GraphQL object][2] to be checked before resolving any of the
+    /// flattened [GraphQL fields][1].
+    ///
+    /// [`Guard`]: juniper::Guard
+    /// [1]: https://spec.graphql.org/October2021#sec-Language.Fields
+    /// [2]: https://spec.graphql.org/October2021#sec-Objects
+    pub(crate) guard: Option<syn::Expr>,
+}
+
+/// Segment of a [GraphQL object][1] name template (like `{T}Page`), naming a generic Rust type
+/// after its type parameters.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+#[derive(Clone, Copy, Debug)]
+enum NameSegment<'a> {
+    /// Literal part of the name.
+    Literal(&'a str),
+
+    /// `{T}` placeholder, substituted with the name of the `T` type parameter.
+    Param(&'a str),
+}
+
+/// Parses the provided [GraphQL object][1] `name` into [`NameSegment`]s.
+///
+/// # Errors
+///
+/// If the `name` contains unbalanced braces.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+fn parse_name_template(name: &str) -> Result<Vec<NameSegment<'_>>, &'static str> {
+    let mut segments = vec![];
+    let mut rest = name;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err("unmatched `}` in name template");
+        }
+        if start > 0 {
+            segments.push(NameSegment::Literal(&rest[..start]));
+        }
+        rest = &rest[(start + 1)..];
+        let end = rest
+            .find(['{', '}'])
+            .filter(|&i| rest[i..].starts_with('}'))
+            .ok_or("unclosed `{` in name template")?;
+        segments.push(NameSegment::Param(&rest[..end]));
+        rest = &rest[(end + 1)..];
+    }
+    if !rest.is_empty() {
+        segments.push(NameSegment::Literal(rest));
+    }
+    Ok(segments)
+}
+
+/// Checks the placeholders of the provided [GraphQL object][1] `name` template to reference type
+/// parameters of the provided `generics` only, emitting errors otherwise.
+///
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+pub(crate) fn check_name_template(
+    name: &str,
+    span: Span,
+    generics: &syn::Generics,
+    scope: diagnostic::Scope,
+) {
+    match parse_name_template(name) {
+        Ok(segments) => {
+            for segment in segments {
+                let NameSegment::Param(param) = segment else {
+                    continue;
+                };
+                if !generics.type_params().any(|p| p.ident == param) {
+                    scope.emit_custom(
+                        span,
+                        format!("`{param}` in name template is not a type parameter"),
+                    );
+                }
+            }
+        }
+        Err(e) => scope.emit_custom(span, e),
+    }
+}
+
 impl<Operation: ?Sized + 'static> Definition<Operation> {
     /// Returns prepared [`syn::Generics::split_for_impl`] for [`GraphQLType`]
     /// trait (and similar) implementation of this [GraphQL object][1].
@@ -289,6 +435,12 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         if let Some(bound) = scalar.bounds() {
             generics.make_where_clause().predicates.push(bound);
         }
+        for param in self.name_params() {
+            let predicates = &mut generics.make_where_clause().predicates;
+            predicates
+                .push(parse_quote! { #param: ::juniper::GraphQLType<#scalar, TypeInfo = ()> });
+            predicates.push(parse_quote! { #param: ::juniper::macros::reflect::BaseType<#scalar> });
+        }
 
         if for_async {
             let self_ty = if self.generics.lifetimes().next().is_some() {
@@ -324,6 +476,100 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         (quote! { #impl_generics }, where_clause.cloned())
     }
 
+    /// Returns type parameters referenced in the name template of this [GraphQL object][1] (like
+    /// `{T}Page`), if any.
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn name_params(&self) -> Vec<syn::Ident> {
+        parse_name_template(&self.name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|segment| match segment {
+                NameSegment::Param(param) => Some(format_ident!("{param}")),
+                NameSegment::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Returns generated code of a `&'static str` `const` expression, evaluating to the name of
+    /// this [GraphQL object][1] for static reflection, with the placeholders of its name template
+    /// (if any) substituted by the [`BaseType::NAME`]s of the referenced type parameters.
+    ///
+    /// [`BaseType::NAME`]: juniper::macros::reflect::BaseType::NAME
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn name_const_tokens(&self) -> TokenStream {
+        let name = &self.name;
+        let scalar = &self.scalar;
+
+        if self.name_params().is_empty() {
+            return quote! { #name };
+        }
+        let segments = parse_name_template(name)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|segment| match segment {
+                NameSegment::Literal(lit) => quote! { #lit },
+                NameSegment::Param(param) => {
+                    let param = format_ident!("{param}");
+                    quote! { <#param as ::juniper::macros::reflect::BaseType<#scalar>>::NAME }
+                }
+            });
+        quote! { ::juniper::const_concat_generic!(#( #segments ),*) }
+    }
+
+    /// Returns generated code of an `Option<ArcStr>` expression (returning early on [`None`]),
+    /// building the name of this [GraphQL object][1] at runtime, with the placeholders of its name
+    /// template (if any) substituted by the [`GraphQLType::name()`]s of the referenced type
+    /// parameters.
+    ///
+    /// [`GraphQLType::name()`]: juniper::GraphQLType::name
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    fn name_runtime_tokens(&self) -> TokenStream {
+        let name = &self.name;
+        let scalar = &self.scalar;
+
+        if self.name_params().is_empty() {
+            return quote! { ::core::option::Option::Some(::juniper::arcstr::literal!(#name)) };
+        }
+        let (mut fmt, mut args) = (String::new(), vec![]);
+        for segment in parse_name_template(name).unwrap_or_default() {
+            match segment {
+                NameSegment::Literal(lit) => fmt.push_str(lit),
+                NameSegment::Param(param) => {
+                    let param = format_ident!("{param}");
+                    fmt.push_str("{}");
+                    args.push(quote! { <#param as ::juniper::GraphQLType<#scalar>>::name(&())? });
+                }
+            }
+        }
+        quote! {
+            ::core::option::Option::Some(::juniper::arcstr::format!(#fmt, #( #args ),*))
+        }
+    }
+
+    /// Returns generated code of a `&str` expression, evaluating to the name of this
+    /// [GraphQL object][1].
+    ///
+    /// [1]: https://spec.graphql.org/October2021#sec-Objects
+    #[must_use]
+    pub(crate) fn name_tokens(&self) -> TokenStream {
+        let name = &self.name;
+        let scalar = &self.scalar;
+
+        if self.name_params().is_empty() {
+            quote! { #name }
+        } else {
+            quote! {
+                <Self as ::juniper::GraphQLType<#scalar>>::name(&())
+                    .as_deref()
+                    .unwrap_or_default()
+            }
+        }
+    }
+
     /// Returns generated code implementing [`marker::IsOutputType`] trait for
     /// this [GraphQL object][1].
     ///
@@ -377,10 +623,20 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
     #[must_use]
     pub(crate) fn impl_reflection_traits_tokens(&self) -> TokenStream {
         let scalar = &self.scalar;
-        let name = &self.name;
+        let name = self.name_const_tokens();
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
         let fields = self.fields.iter().map(|f| &f.name);
+        let mut fields_names = quote! { &[#(#fields),*] };
+        if !self.flattened.is_empty() {
+            let flattened_tys = self.flattened.iter().map(|f| &f.ty);
+            fields_names = quote! {
+                ::juniper::const_concat_names!(
+                    #fields_names,
+                    #( <#flattened_tys as ::juniper::macros::reflect::Fields<#scalar>>::NAMES, )*
+                )
+            };
+        }
         let interfaces = self.interfaces.iter();
 
         quote! {
@@ -423,7 +679,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                 for #ty
                 #where_clause
             {
-                const NAMES: ::juniper::macros::reflect::Names = &[#(#fields),*];
+                const NAMES: ::juniper::macros::reflect::Names = #fields_names;
             }
         }
     }
@@ -440,7 +696,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
 
-        let name = &self.name;
+        let name = self.name_runtime_tokens();
         let description = &self.description;
 
         let extract_stream_type = TypeId::of::<Operation>() != TypeId::of::<Query>();
@@ -448,6 +704,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             .fields
             .iter()
             .map(|f| f.method_meta_tokens(extract_stream_type.then_some(scalar)));
+        let flattened_tys = self.flattened.iter().map(|f| &f.ty);
 
         // Sorting is required to preserve/guarantee the order of interfaces registered in schema.
         let mut interface_tys: Vec<_> = self.interfaces.iter().collect();
@@ -468,7 +725,7 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
             impl #impl_generics ::juniper::GraphQLType<#scalar> for #ty #where_clause
             {
                 fn name(_ : &Self::TypeInfo) -> ::core::option::Option<::juniper::ArcStr> {
-                    ::core::option::Option::Some(::juniper::arcstr::literal!(#name))
+                    #name
                 }
 
                 fn meta(
@@ -478,6 +735,12 @@ impl<Operation: ?Sized + 'static> Definition<Operation> {
                     let fields = [
                         #( #fields_meta, )*
                     ];
+                    #( let fields = [
+                        &fields[..],
+                        &::juniper::macros::helper::flattened_fields::<#flattened_tys, #scalar>(
+                            info, registry,
+                        )[..],
+                    ].concat(); )*
                     registry.build_object_type::<#ty>(info, &fields)
                         #description
                         #interfaces
@@ -535,6 +798,22 @@ impl Definition<Query> {
             ty
         });
 
+        let flattened_marks = self.flattened.iter().map(|f| {
+            let flattened_ty = &f.ty;
+            quote_spanned! { flattened_ty.span() =>
+                <#flattened_ty as ::juniper::marker::GraphQLObject<#scalar>>::mark();
+            }
+        });
+        let assert_flattened_fields = (!self.flattened.is_empty()).then(|| {
+            let fields = self.fields.iter().map(|f| &f.name);
+            let flattened_tys = self.flattened.iter().map(|f| &f.ty);
+            quote! {
+                ::juniper::assert_flattened_fields!(
+                    #ty, #const_scalar, &[#( #fields ),*], #( #flattened_tys ),*
+                );
+            }
+        });
+
         // TODO: Make it work by repeating `sa::assert_type_ne_all!` expansion,
         //       but considering generics.
         //let interface_tys: Vec<_> = self.interfaces.iter().collect();
@@ -548,9 +827,11 @@ impl Definition<Query> {
             {
                 fn mark() {
                     #( <#interface_tys as ::juniper::marker::GraphQLInterface<#scalar>>::mark(); )*
+                    #( #flattened_marks )*
                     ::juniper::assert_implemented_for!(
                         #const_scalar, #ty, #(#const_interface_tys),*
                     );
+                    #assert_flattened_fields
                 }
             }
         }
@@ -581,10 +862,11 @@ impl Definition<Query> {
                     .filter_map(|arg| match arg {
                         field::MethodArgument::Regular(arg) => {
                             let (name, ty) = (&arg.name, &arg.ty);
+                            let wrapped_value = arg.wrapped_value_tokens(scalar);
                             Some(quote! {(
                                 #name,
                                 <#ty as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
-                                <#ty as ::juniper::macros::reflect::WrappedType<#scalar>>::VALUE,
+                                #wrapped_value,
                             )})
                         }
                         field::MethodArgument::Executor | field::MethodArgument::Context(_) => None,
@@ -630,38 +912,22 @@ impl Definition<Query> {
         self.fields
             .iter()
             .map(|field| {
-                let (name, mut res_ty, ident) = (&field.name, field.ty.clone(), &field.ident);
+                let (name, mut res_ty) = (&field.name, field.ty.clone());
 
                 let resolve = if field.is_async {
-                    quote! {
-                        ::core::panic!(
-                             "Tried to resolve async field `{}` on type `{}` with a sync resolver",
-                             #name,
-                             <Self as ::juniper::macros::reflect::BaseType<#scalar>>::NAME,
-                         );
-                    }
+                    field.method_resolve_field_panic_async_tokens(scalar)
+                } else if field.guard.is_some() {
+                    field::Definition::method_resolve_field_err_guarded_sync_tokens(name, scalar)
                 } else {
-                    let res = if field.is_method() {
-                        let args = field
-                            .arguments
-                            .as_ref()
-                            .unwrap()
-                            .iter()
-                            .map(|arg| arg.method_resolve_field_tokens(scalar, false));
-
-                        let rcv = field.has_receiver.then(|| {
-                            quote! { self, }
-                        });
-
-                        quote! { Self::#ident(#rcv #( #args ),*) }
-                    } else {
+                    let (args, res) = field.method_resolve_field_call_tokens(scalar, false);
+                    if !field.is_method() {
                         res_ty = parse_quote! { _ };
-                        quote! { &self.#ident }
-                    };
+                    }
 
                     let resolving_code = generate::sync_resolving_code();
 
                     quote! {
+                        #args
                         let res: #res_ty = #res;
                         #resolving_code
                     }
@@ -703,30 +969,38 @@ impl Definition<Query> {
         self.fields
             .iter()
             .map(|field| {
-                let (name, mut res_ty, ident) = (&field.name, field.ty.clone(), &field.ident);
-
-                let mut res = if field.is_method() {
-                    let args = field
-                        .arguments
-                        .as_ref()
-                        .unwrap()
-                        .iter()
-                        .map(|arg| arg.method_resolve_field_tokens(scalar, true));
+                let (name, mut res_ty) = (&field.name, field.ty.clone());
 
-                    let rcv = field.has_receiver.then(|| {
-                        quote! { self, }
-                    });
+                let guard = field.method_check_guard_tokens(scalar);
 
-                    quote! { Self::#ident(#rcv #( #args ),*) }
-                } else {
+                // Arguments are converted inside the `async` block after checking the `Guard`, so
+                // their errors are returned from this block rather than from the method.
+                let (args, mut res) =
+                    field.method_resolve_field_call_tokens(scalar, guard.is_none());
+                if !field.is_method() {
                     res_ty = parse_quote! { _ };
-                    quote! { &self.#ident }
-                };
+                }
                 if !field.is_async {
                     res = quote! { ::juniper::futures::future::ready(#res) };
                 }
 
                 let resolving_code = generate::async_resolving_code(Some(&res_ty));
+                let resolve = if let Some(guard) = guard {
+                    quote! {
+                        ::std::boxed::Box::pin(async move {
+                            #guard
+                            #args
+                            let fut = #res;
+                            #resolving_code.await
+                        })
+                    }
+                } else {
+                    quote! {
+                        #args
+                        let fut = #res;
+                        #resolving_code
+                    }
+                };
 
                 quote! {
                     #[allow(deprecated, non_snake_case)]
@@ -743,8 +1017,7 @@ impl Definition<Query> {
                             args: &'b ::juniper::Arguments<'_, #scalar>,
                             executor: &'b ::juniper::Executor<'_, '_, Self::Context, #scalar>,
                         ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
-                            let fut = #res;
-                            #resolving_code
+                            #resolve
                         }
                     }
                 }
@@ -766,7 +1039,7 @@ impl Definition<Query> {
         let ty = &self.ty;
         let ty_name = ty.to_token_stream().to_string();
 
-        let name = &self.name;
+        let name = self.name_tokens();
 
         let fields_resolvers = self.fields.iter().map(|f| {
             let name = &f.name;
@@ -779,6 +1052,29 @@ impl Definition<Query> {
                 }
             }
         });
+        let flattened_resolvers = self.flattened.iter().map(|f| {
+            let (member, flattened_ty) = (&f.member, &f.ty);
+            let resolve = if f.guard.is_some() {
+                field::Definition::method_resolve_field_err_guarded_sync_tokens(
+                    quote! { field },
+                    scalar,
+                )
+            } else {
+                quote! {
+                    ::juniper::GraphQLValue::<#scalar>::resolve_field(
+                        &self.#member, info, field, args, executor,
+                    )
+                }
+            };
+            quote! {
+                _ if ::juniper::macros::reflect::str_exists_in_arr(
+                    field,
+                    <#flattened_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                ) => {
+                    #resolve
+                }
+            }
+        });
 
         let no_field_err =
             field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
@@ -807,6 +1103,7 @@ impl Definition<Query> {
                 ) -> ::juniper::ExecutionResult<#scalar> {
                     match field {
                         #( #fields_resolvers )*
+                        #( #flattened_resolvers )*
                         _ => #no_field_err,
                     }
                 }
@@ -846,6 +1143,33 @@ impl Definition<Query> {
                 }
             }
         });
+        let flattened_resolvers = self.flattened.iter().map(|f| {
+            let (member, flattened_ty) = (&f.member, &f.ty);
+            let resolve = quote! {
+                ::juniper::GraphQLValueAsync::<#scalar>::resolve_field_async(
+                    &self.#member, info, field, args, executor,
+                )
+            };
+            let resolve = if let Some(guard) = &f.guard {
+                let guard = field::Definition::check_guard_tokens(guard, scalar);
+                quote! {
+                    ::std::boxed::Box::pin(async move {
+                        #guard
+                        #resolve.await
+                    })
+                }
+            } else {
+                resolve
+            };
+            quote! {
+                _ if ::juniper::macros::reflect::str_exists_in_arr(
+                    field,
+                    <#flattened_ty as ::juniper::macros::reflect::Fields<#scalar>>::NAMES,
+                ) => {
+                    #resolve
+                }
+            }
+        });
 
         let no_field_err =
             field::Definition::method_resolve_field_err_no_field_tokens(scalar, &ty_name);
@@ -864,6 +1188,7 @@ impl Definition<Query> {
                 ) -> ::juniper::BoxFuture<'b, ::juniper::ExecutionResult<#scalar>> {
                     match field {
                         #( #fields_resolvers )*
+                        #( #flattened_resolvers )*
                         _ => ::std::boxed::Box::pin(async move { #no_field_err }),
                     }
                 }
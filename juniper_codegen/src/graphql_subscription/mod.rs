@@ -40,7 +40,7 @@ impl Definition<Subscription> {
         let (impl_generics, where_clause) = self.impl_generics(false);
         let ty = &self.ty;
 
-        let name = &self.name;
+        let name = self.name_tokens();
 
         quote! {
             #[automatically_derived]
@@ -104,6 +104,7 @@ macro_rules! try_merge_hashset {
 
 mod common;
 mod graphql_enum;
+mod graphql_error_code;
 mod graphql_input_object;
 mod graphql_interface;
 mod graphql_object;
@@ -214,7 +215,8 @@ use self::common::diagnostic::{self, ResultExt as _};
 ///
 /// However, if you need for some reason another naming convention, it's possible to do so by using
 /// the `rename_all` attribute's argument. At the moment, it supports the following policies only:
-/// `SCREAMING_SNAKE_CASE`, `snake_case`, `camelCase`, `none` (disables any renaming).
+/// `SCREAMING_SNAKE_CASE`, `snake_case`, `camelCase`, `PascalCase`, `lowercase`, `UPPERCASE`,
+/// `none` (disables any renaming).
 ///
 /// ```rust
 /// # use juniper::{GraphQLInputObject, ID};
@@ -234,6 +236,29 @@ use self::common::diagnostic::{self, ResultExt as _};
 /// }
 /// ```
 ///
+/// # `serde` naming
+///
+/// To keep the [GraphQL input object][0] naming consistent with the `serde` deserialization one
+/// without duplicating it, specify the `serde` attribute's argument. This way, the
+/// `#[serde(rename = "...")]`, `#[serde(rename_all = "...")]` and `#[serde(skip)]` (or
+/// `#[serde(skip_deserializing)]`) attributes are honored, unless overridden with `#[graphql]`
+/// ones. Without `#[serde(rename_all = "...")]`, the [fields][1] are not renamed, just like in
+/// `serde`.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// # use serde::Deserialize;
+/// #
+/// #[derive(Deserialize, GraphQLInputObject)]
+/// #[graphql(serde)]
+/// #[serde(rename = "Point", rename_all = "SCREAMING_SNAKE_CASE")]
+/// struct Point2D {
+///     x: f64,       // will be `X` in GraphQL schema
+///     #[serde(rename = "yCoord")]
+///     y_coord: f64, // will be `yCoord` in GraphQL schema
+/// }
+/// ```
+///
 /// # Ignoring fields
 ///
 /// To omit exposing a Rust struct field (or an enum variant) in a GraphQL schema, use the `ignore`
@@ -274,7 +299,56 @@ use self::common::diagnostic::{self, ResultExt as _};
 /// }
 /// ```
 ///
+/// # Flattening struct fields
+///
+/// To splice the fields of another [GraphQL input object][0] into this one (rather than accepting
+/// it as a single field), use the `flatten` attribute's argument directly on the struct field of
+/// that [GraphQL input object][0] type. Collisions of the resulting field names are reported at
+/// compile time.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct Pagination {
+///     first: i32,
+///     after: Option<String>,
+/// }
+///
+/// #[derive(GraphQLInputObject)]
+/// struct UsersFilter {
+///     name: Option<String>,
+///     // Accepts `{ name: "Luke", first: 5 }` GraphQL input value.
+///     #[graphql(flatten)]
+///     pagination: Pagination,
+/// }
+/// ```
+///
+/// # Validation
+///
+/// To validate the value of a struct field (or an enum variant) while converting it from an input,
+/// use the `min_length`, `max_length`, `range(min = ..., max = ...)`, `regex` (requires
+/// `regex-validator` feature, with the pattern being checked at compile time), `email`, `url`
+/// (requires `url` feature), `min_items` or `custom = <function>` attribute's arguments directly on
+/// it. All the failed validations are collected into a single [`FieldError`], along with the paths
+/// to the invalid values.
+///
+/// ```rust
+/// # use juniper::GraphQLInputObject;
+/// #
+/// #[derive(GraphQLInputObject)]
+/// struct NewUser {
+///     #[graphql(min_length = 3, max_length = 32)]
+///     login: String,
+///     #[graphql(email)]
+///     email: Option<String>,
+///     #[graphql(range(min = 18, max = 150))]
+///     age: i32,
+/// }
+/// ```
+///
 /// [`@oneOf`]: https://spec.graphql.org/September2025#sec--oneOf
+/// [`FieldError`]: juniper::FieldError
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [0]: https://spec.graphql.org/October2021#sec-Input-Objects
 /// [1]: https://spec.graphql.org/October2021#InputFieldsDefinition
@@ -355,7 +429,7 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// However, if you need for some reason another naming convention, it's
 /// possible to do so by using the `rename_all` attribute's argument. At the
 /// moment, it supports the following policies only: `SCREAMING_SNAKE_CASE`, `snake_case`,
-/// `camelCase`, `none` (disables any renaming).
+/// `camelCase`, `PascalCase`, `lowercase`, `UPPERCASE`, `none` (disables any renaming).
 ///
 /// ```rust
 /// # use juniper::GraphQLEnum;
@@ -369,6 +443,30 @@ pub fn derive_input_object(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # `serde` naming
+///
+/// To keep the [GraphQL enum][0] naming consistent with the `serde` one without duplicating it,
+/// specify the `serde` attribute's argument. This way, the `#[serde(rename = "...")]`,
+/// `#[serde(rename_all = "...")]` and `#[serde(skip)]` attributes are honored, unless overridden
+/// with `#[graphql]` ones. Without `#[serde(rename_all = "...")]`, the [values][1] are not
+/// renamed, just like in `serde`.
+///
+/// ```rust
+/// # use juniper::GraphQLEnum;
+/// # use serde::{Deserialize, Serialize};
+/// #
+/// #[derive(Deserialize, GraphQLEnum, Serialize)]
+/// #[graphql(serde)]
+/// #[serde(rename_all = "camelCase")]
+/// enum Episode {
+///     NewHope, // will be `newHope` in GraphQL schema
+///     #[serde(rename = "empire")]
+///     EmpireStrikesBack,
+///     #[serde(skip)]
+///     Jedi,    // won't be present in GraphQL schema
+/// }
+/// ```
+///
 /// # Ignoring enum variants
 ///
 /// To omit exposing a Rust enum variant in a GraphQL schema, use the `ignore`
@@ -421,6 +519,76 @@ pub fn derive_enum(input: TokenStream) -> TokenStream {
     })
 }
 
+/// `#[derive(GraphQLErrorCode)]` macro for deriving stable machine-readable codes and typed
+/// extensions of [GraphQL errors][0] for a Rust enum or struct.
+///
+/// Implements `GraphQLErrorCode` trait, and `IntoFieldError` trait converting the type into a
+/// `FieldError` with its `Display` implementation as the message and its code in the `code`
+/// extension.
+///
+/// By default, the code is the `SCREAMING_SNAKE_CASE`d name of the Rust struct or enum variant,
+/// and may be specified explicitly via `#[graphql(code = "...")]` attribute argument.
+///
+/// Named fields are put into the extensions under their `camelCased` names (which may be
+/// specified explicitly via `#[graphql(name = "...")]` attribute argument), unless ignored via
+/// `#[graphql(ignore)]` (or `#[graphql(skip)]`) attribute argument. Unnamed fields are put into
+/// the extensions only when named explicitly. Types of the fields must implement `IntoValue` and
+/// `Clone` traits.
+///
+/// ```rust
+/// # use derive_more::with_trait::Display;
+/// # use juniper::{GraphQLErrorCode, IntoFieldError as _, graphql_value};
+/// #
+/// #[derive(Display, GraphQLErrorCode)]
+/// enum OrderError {
+///     #[display("Order `{id}` not found")]
+///     NotFound { id: i32 },
+///
+///     #[display("Too many orders, retry later")]
+///     #[graphql(code = "RATE_LIMITED")]
+///     TooManyRequests {
+///         retry_after_secs: i32,
+///         #[graphql(skip)]
+///         client_ip: String,
+///     },
+/// }
+///
+/// let err = OrderError::TooManyRequests {
+///     retry_after_secs: 30,
+///     client_ip: "127.0.0.1".into(),
+/// }
+/// .into_field_error();
+///
+/// assert_eq!(err.message(), "Too many orders, retry later");
+/// assert_eq!(
+///     err.extensions(),
+///     &graphql_value!({"code": "RATE_LIMITED", "retryAfterSecs": 30}),
+/// );
+/// # let _: juniper::FieldError = err;
+/// ```
+///
+/// # Custom `ScalarValue`
+///
+/// By default, `#[derive(GraphQLErrorCode)]` macro generates code, which is generic over a
+/// `ScalarValue` type. This can be changed with `scalar` attribute argument placed on the whole
+/// Rust enum or struct.
+///
+/// # Safe errors
+///
+/// To return the errors to clients as is, bypassing the `ErrorFormatter` of a `RootNode`, the
+/// whole Rust enum or struct should be marked with `#[graphql(safe)]` attribute argument, which
+/// implements `SafeError` trait for it.
+///
+/// [0]: https://spec.graphql.org/October2021#sec-Errors
+#[proc_macro_derive(GraphQLErrorCode, attributes(graphql))]
+pub fn derive_error_code(input: TokenStream) -> TokenStream {
+    diagnostic::entry_point(|| {
+        graphql_error_code::derive::expand(input.into())
+            .unwrap_or_abort()
+            .into()
+    })
+}
+
 /// `#[derive(GraphQLScalar)]` macro for deriving a [GraphQL scalar][0] implementation.
 ///
 /// # Transparent delegation
@@ -1064,7 +1232,9 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// `deprecated` attribute's argument, or with the regular Rust `#[deprecated]` attribute.
 ///
 /// The default value of a field argument may be specified with a `default` attribute argument (if
-/// no exact value is specified then [`Default::default`] is used).
+/// no exact value is specified then [`Default::default`] is used). Alternatively, it may be
+/// computed from the context at execution time with a `default_with = <function>` attribute
+/// argument (such argument becomes `null`able and has no default value in GraphQL schema).
 ///
 /// ```rust
 /// # use juniper::graphql_interface;
@@ -1247,7 +1417,7 @@ pub fn derive_scalar_value(input: TokenStream) -> TokenStream {
 /// However, if you need for some reason apply another naming convention, it's
 /// possible to do by using `rename_all` attribute's argument. At the moment it
 /// supports the following policies only: `SCREAMING_SNAKE_CASE`, `snake_case`, `camelCase`,
-/// `none` (disables any renaming).
+/// `PascalCase`, `lowercase`, `UPPERCASE`, `none` (disables any renaming).
 ///
 /// ```rust
 /// # use juniper::{graphql_interface, graphql_object};
@@ -1564,7 +1734,7 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// However, if you need for some reason apply another naming convention, it's
 /// possible to do by using `rename_all` attribute's argument. At the moment it
 /// supports the following policies only: `SCREAMING_SNAKE_CASE`, `snake_case`, `camelCase`,
-/// `none` (disables any renaming).
+/// `PascalCase`, `lowercase`, `UPPERCASE`, `none` (disables any renaming).
 ///
 /// ```
 /// # use juniper::GraphQLObject;
@@ -1578,6 +1748,28 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # `serde` naming
+///
+/// To keep the [GraphQL object][1] naming consistent with the `serde` serialization one without
+/// duplicating it, specify the `serde` attribute's argument. This way, the
+/// `#[serde(rename = "...")]`, `#[serde(rename_all = "...")]` and `#[serde(skip)]` (or
+/// `#[serde(skip_serializing)]`) attributes are honored, unless overridden with `#[graphql]` ones.
+/// Without `#[serde(rename_all = "...")]`, the fields are not renamed, just like in `serde`.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// # use serde::Serialize;
+/// #
+/// #[derive(GraphQLObject, Serialize)]
+/// #[graphql(serde)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Human {
+///     home_planet: String,   // will be `homePlanet` in GraphQL schema
+///     #[serde(skip)]
+///     password_hash: String, // won't be present in GraphQL schema
+/// }
+/// ```
+///
 /// # Ignoring struct fields
 ///
 /// To omit exposing a struct field in the GraphQL schema, use an `ignore`
@@ -1597,6 +1789,37 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// # Flattening struct fields
+///
+/// To splice the fields of another [GraphQL object][1] into this one (rather
+/// than exposing it as a single field), use a `flatten` attribute's argument
+/// directly on the struct field of that [GraphQL object][1] type. The flattened
+/// type should have the same `context` and is not registered in the GraphQL
+/// schema on its own. Collisions of the resulting field names are reported at
+/// compile time.
+///
+/// ```
+/// # use juniper::GraphQLObject;
+/// #
+/// #[derive(GraphQLObject)]
+/// struct Audit {
+///     created_at: i32,
+///     created_by: String,
+/// }
+///
+/// #[derive(GraphQLObject)]
+/// struct Human {
+///     id: String,
+///     // Exposes `createdAt` and `createdBy` fields on `Human`.
+///     #[graphql(flatten)]
+///     audit: Audit,
+/// }
+/// ```
+///
+/// > __NOTE:__ Flattened fields don't take part in the compile-time checks of
+/// >           the implemented [GraphQL interfaces][2], so can't be used to
+/// >           implement their fields.
+///
 /// # Custom `ScalarValue`
 ///
 /// By default, `#[derive(GraphQLObject)]` macro generates code, which is
@@ -1625,6 +1848,7 @@ pub fn derive_interface(body: TokenStream) -> TokenStream {
 ///
 /// [`ScalarValue`]: juniper::ScalarValue
 /// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: https://spec.graphql.org/October2021#sec-Interfaces
 #[proc_macro_derive(GraphQLObject, attributes(graphql))]
 pub fn derive_object(body: TokenStream) -> TokenStream {
     diagnostic::entry_point(|| {
@@ -1634,6 +1858,70 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
     })
 }
 
+/// `#[derive(GraphQLMergedObject)]` macro for deriving a [GraphQL object][1]
+/// merging the fields of several other [GraphQL objects][1].
+///
+/// Each field of the struct (either named or unnamed) should be a
+/// [GraphQL object][1] type itself (like the ones generated by the
+/// [`#[graphql_object]`][2] macro), having the same `context` and [`ScalarValue`]
+/// parametrization. Its fields are spliced into the merged [GraphQL object][1],
+/// while resolving is delegated to the owning part. Collisions of the field
+/// names between parts are reported at compile time.
+///
+/// This is useful to split a large root query or mutation type into the ones
+/// owned by separate domain modules:
+/// ```
+/// # use juniper::{
+/// #     graphql_object, EmptyMutation, EmptySubscription, GraphQLMergedObject, RootNode,
+/// # };
+/// #
+/// struct UserQuery;
+///
+/// #[graphql_object]
+/// impl UserQuery {
+///     fn user_count() -> i32 {
+///         42
+///     }
+/// }
+///
+/// struct PostQuery;
+///
+/// #[graphql_object]
+/// impl PostQuery {
+///     fn post_count() -> i32 {
+///         7
+///     }
+/// }
+///
+/// // Exposes both `userCount` and `postCount` fields.
+/// #[derive(GraphQLMergedObject)]
+/// #[graphql(name = "Query")]
+/// struct Query(UserQuery, PostQuery);
+///
+/// let schema = RootNode::new(
+///     Query(UserQuery, PostQuery),
+///     EmptyMutation::<()>::new(),
+///     EmptySubscription::<()>::new(),
+/// );
+/// ```
+///
+/// The same `name`, `description`, `context`, `scalar`, `impl` and `guard`
+/// attribute's arguments as for the [`#[derive(GraphQLObject)]`][3] macro may
+/// be specified on the struct.
+///
+/// [`ScalarValue`]: juniper::ScalarValue
+/// [1]: https://spec.graphql.org/October2021#sec-Objects
+/// [2]: macro@graphql_object
+/// [3]: derive@GraphQLObject
+#[proc_macro_derive(GraphQLMergedObject, attributes(graphql))]
+pub fn derive_merged_object(body: TokenStream) -> TokenStream {
+    diagnostic::entry_point(|| {
+        self::graphql_object::merged::expand(body.into())
+            .unwrap_or_abort()
+            .into()
+    })
+}
+
 /// `#[graphql_object]` macro for generating a [GraphQL object][1]
 /// implementation for structs with computable field resolvers (declared via
 /// a regular Rust `impl` block).
@@ -1731,7 +2019,13 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///
 /// The default value of a field argument may be specified with a `default`
 /// attribute argument (if no exact value is specified then [`Default::default`]
-/// is used).
+/// is used). Alternatively, it may be computed from the context at execution
+/// time with a `default_with = <function>` attribute argument, accepting a
+/// reference to the context (such argument becomes `null`able and has no
+/// default value in GraphQL schema, so clients are not misled).
+///
+/// A field argument may be validated with the same validator attribute's arguments (like
+/// `min_length` or `range(min = 1)`), as the ones supported by [`GraphQLInputObject`] fields.
 ///
 /// ```
 /// # use juniper::graphql_object;
@@ -1755,6 +2049,8 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///         // You may specify default values.
 ///         // A default can be any valid expression that yields the right type.
 ///         #[graphql(default = 5)]
+///         // You may also validate the provided values.
+///         #[graphql(range(min = 0, max = 10))]
 ///         num: i32,
 ///     ) -> &str {
 ///         "Don't use me!"
@@ -1795,7 +2091,7 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 /// However, if you need for some reason apply another naming convention, it's
 /// possible to do by using `rename_all` attribute's argument. At the moment it
 /// supports the following policies only: `SCREAMING_SNAKE_CASE`, `snake_case`, `camelCase`,
-/// `none` (disables any renaming).
+/// `PascalCase`, `lowercase`, `UPPERCASE`, `none` (disables any renaming).
 ///
 /// ```
 /// # use juniper::graphql_object;
@@ -1955,6 +2251,7 @@ pub fn derive_object(body: TokenStream) -> TokenStream {
 ///
 /// [`Context`]: juniper::Context
 /// [`Executor`]: juniper::Executor
+/// [`GraphQLInputObject`]: derive@GraphQLInputObject
 /// [`GraphQLType`]: juniper::GraphQLType
 /// [`GraphQLValue`]: juniper::GraphQLValue
 /// [`ScalarValue`]: juniper::ScalarValue
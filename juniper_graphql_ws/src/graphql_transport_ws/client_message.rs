@@ -1,7 +1,8 @@
+use derive_more::with_trait::From;
 use juniper::Variables;
 use serde::Deserialize;
 
-use crate::util::default_for_null;
+use crate::util::{default_for_null, non_empty_batch};
 
 /// The payload for a client's "start" message. This triggers execution of a query, mutation, or
 /// subscription.
@@ -24,6 +25,27 @@ pub struct SubscribePayload<S> {
     pub extensions: Variables<S>,
 }
 
+/// The payload for a client's "subscribe" message, being either a single operation or a batch of
+/// them.
+///
+/// Batches are accepted only if [`ConnectionConfig::batching`] is enabled, and may contain queries
+/// and mutations only.
+///
+/// [`ConnectionConfig::batching`]: crate::ConnectionConfig::batching
+#[derive(Debug, Deserialize, From, PartialEq)]
+#[serde(bound(deserialize = "S: Deserialize<'de>"))]
+#[serde(untagged)]
+pub enum BatchSubscribePayload<S> {
+    /// A single operation.
+    Single(SubscribePayload<S>),
+
+    /// A batch of operations.
+    ///
+    /// Empty batch is considered as invalid value, so cannot be deserialized.
+    #[serde(deserialize_with = "non_empty_batch")]
+    Batch(Vec<SubscribePayload<S>>),
+}
+
 /// ClientMessage defines the message types that clients can send.
 #[derive(Debug, Deserialize, PartialEq)]
 #[serde(bound(deserialize = "S: Deserialize<'de>"))]
@@ -55,8 +77,8 @@ pub enum ClientMessage<S> {
         /// in-flight operations with the same id, the message will cause an error.
         id: String,
 
-        /// The query, variables, and operation name.
-        payload: SubscribePayload<S>,
+        /// The query, variables, and operation name (or a batch of them).
+        payload: BatchSubscribePayload<S>,
     },
     /// Indicates that the client has stopped listening and wants to complete the subscription.
     Complete {
@@ -98,7 +120,8 @@ mod test {
                     variables: graphql_vars! {"foo": "bar"},
                     operation_name: Some("MyQuery".into()),
                     extensions: Default::default(),
-                },
+                }
+                .into(),
             },
             serde_json::from_str(
                 r#"{"type": "subscribe", "id": "foo", "payload": {
@@ -120,7 +143,8 @@ mod test {
                     variables: graphql_vars! {},
                     operation_name: None,
                     extensions: Default::default(),
-                },
+                }
+                .into(),
             },
             serde_json::from_str(
                 r#"{"type": "subscribe", "id": "foo", "payload": {
@@ -130,6 +154,40 @@ mod test {
             .unwrap(),
         );
 
+        assert_eq!(
+            ClientMessage::Subscribe {
+                id: "foo".into(),
+                payload: BatchSubscribePayload::Batch(vec![
+                    SubscribePayload {
+                        query: "{ __typename }".into(),
+                        variables: graphql_vars! {},
+                        operation_name: None,
+                        extensions: Default::default(),
+                    },
+                    SubscribePayload {
+                        query: "mutation { __typename }".into(),
+                        variables: graphql_vars! {},
+                        operation_name: None,
+                        extensions: Default::default(),
+                    },
+                ]),
+            },
+            serde_json::from_str(
+                r#"{"type": "subscribe", "id": "foo", "payload": [
+                {"query": "{ __typename }"},
+                {"query": "mutation { __typename }"}
+            ]}"#
+            )
+            .unwrap(),
+        );
+
+        assert!(
+            serde_json::from_str::<ClientMessage>(
+                r#"{"type": "subscribe", "id": "foo", "payload": []}"#
+            )
+            .is_err(),
+        );
+
         assert_eq!(
             ClientMessage::Complete { id: "foo".into() },
             serde_json::from_str(r#"{"type": "complete", "id": "foo"}"#).unwrap(),
@@ -14,31 +14,49 @@ mod client_message;
 mod server_message;
 
 use std::{
-    collections::HashMap, convert::Infallible, error::Error, marker::PhantomPinned,
-    panic::AssertUnwindSafe, pin::Pin, sync::Arc, time::Duration,
+    collections::HashMap,
+    convert::Infallible,
+    error::Error,
+    marker::PhantomPinned,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
 use derive_more::with_trait::From;
 use juniper::{
-    GraphQLError, RuleError, ScalarValue, Value,
+    GraphQLError, Object, Resolved, ScalarValue, Value, error_code,
     futures::{
         Sink, Stream,
         channel::oneshot,
-        future::{self, BoxFuture, Either, FutureExt as _, TryFutureExt as _},
+        future::{self, BoxFuture, Either, FutureExt as _},
         stream::{self, BoxStream, SelectAll, StreamExt as _},
         task::{Context, Poll, Waker},
     },
+    http::GraphQLResponse,
 };
 use tokio::{sync::Notify, time};
 
-use super::{ConnectionConfig, Init, Schema};
+use juniper_subscriptions::{Replay, operation_fingerprint};
+
+use super::{
+    ConnectionConfig, ConnectionLimit, Init, ReplayBuffer, Schema,
+    limit::{Buffered, ConnectionPermit, RateLimiter},
+    util::{CompletableReplay, last_event_id, with_event_id},
+};
 
 pub use self::{
-    client_message::{ClientMessage, SubscribePayload},
-    server_message::{ErrorPayload, NextPayload, ServerMessage},
+    client_message::{BatchSubscribePayload, ClientMessage, SubscribePayload},
+    server_message::{BatchNextPayload, ErrorPayload, NextPayload, ServerMessage},
 };
 
 struct ExecutionParams<S: Schema> {
+    /// Indicator whether the operation has been completed by the client.
+    completed: Arc<AtomicBool>,
     subscribe_payload: SubscribePayload<S::ScalarValue>,
     config: ConnectionConfig<S::Context, S::ScalarValue>,
     schema: S,
@@ -77,15 +95,20 @@ impl<S: ScalarValue + Send> Output<S> {
     }
 }
 
+#[expect(clippy::large_enum_variant, reason = "not boxed to avoid allocations")]
 enum ConnectionState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
     /// PreInit is the state before a ConnectionInit message has been accepted.
     PreInit { init: I, schema: S },
     /// Active is the state after a ConnectionInit message has been accepted.
     Active {
         config: ConnectionConfig<S::Context, S::ScalarValue>,
-        stoppers: HashMap<String, oneshot::Sender<()>>,
+        /// Stoppers of the in-flight operations, along with the number of operations (batch
+        /// entries) each of them counts as.
+        stoppers: HashMap<String, (oneshot::Sender<()>, usize)>,
+        rate_limiter: RateLimiter,
         ping: Arc<Notify>,
         schema: S,
+        _permit: Option<ConnectionPermit>,
     },
     /// Terminated is the state after a ConnectionInit message has been rejected.
     Terminated,
@@ -102,6 +125,26 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
             Self::PreInit { init, schema } => match msg {
                 ClientMessage::ConnectionInit { payload } => match init.init(payload).await {
                     Ok(config) => {
+                        let permit = match config
+                            .connection_limit
+                            .as_ref()
+                            .map(ConnectionLimit::acquire)
+                        {
+                            Some(None) => {
+                                // Too many connections. We must close this one.
+                                return (
+                                    Self::Terminated,
+                                    Output::Close {
+                                        code: 1013,
+                                        message: "Too many connections".into(),
+                                    }
+                                    .into_stream()
+                                    .boxed(),
+                                );
+                            }
+                            permit => permit.flatten(),
+                        };
+
                         let keep_alive_interval = config.keep_alive.interval;
                         let keep_alive_timeout = config.keep_alive.timeout;
 
@@ -147,10 +190,12 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
 
                         (
                             Self::Active {
+                                rate_limiter: RateLimiter::new(config.subscribe_rate_limit),
                                 config,
                                 stoppers: HashMap::new(),
                                 ping,
                                 schema,
+                                _permit: permit,
                             },
                             s,
                         )
@@ -183,13 +228,21 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
             Self::Active {
                 config,
                 mut stoppers,
+                mut rate_limiter,
                 ping,
                 schema,
+                _permit,
             } => {
                 let reactions = match msg {
                     ClientMessage::Subscribe { id, payload } => {
                         // Prune stoppers which streams are already completed or canceled.
-                        stoppers.retain(|_, tx| !tx.is_canceled());
+                        stoppers.retain(|_, (tx, _)| !tx.is_canceled());
+
+                        // Every entry of a batch counts as a separate operation.
+                        let operations = match &payload {
+                            BatchSubscribePayload::Single(_) => 1,
+                            BatchSubscribePayload::Batch(payloads) => payloads.len(),
+                        };
 
                         if stoppers.contains_key(&id) {
                             // We already have an operation with this id. We must close the connection.
@@ -199,17 +252,48 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                             }
                             .into_stream()
                             .boxed()
+                        } else if matches!(payload, BatchSubscribePayload::Batch(_))
+                            && !config.batching
+                        {
+                            // Batching is not enabled. Just send back an error.
+                            stream::iter(vec![
+                                Output::Message(ServerMessage::Error {
+                                    id: id.clone(),
+                                    payload: GraphQLError::Rejected {
+                                        message: "Batched operations are not allowed.",
+                                        code: error_code::OPERATION_RESOLUTION_FAILURE,
+                                    }
+                                    .into(),
+                                }),
+                                Output::Message(ServerMessage::Complete { id }),
+                            ])
+                            .boxed()
                         } else if config.max_in_flight_operations > 0
-                            && stoppers.len() >= config.max_in_flight_operations
+                            && stoppers.values().map(|(_, n)| n).sum::<usize>() + operations
+                                > config.max_in_flight_operations
                         {
-                            // Too many in-flight operations. Just send back a validation error.
+                            // Too many in-flight operations. Just send back an error.
+                            stream::iter(vec![
+                                Output::Message(ServerMessage::Error {
+                                    id: id.clone(),
+                                    payload: GraphQLError::Rejected {
+                                        message: "Too many in-flight operations.",
+                                        code: error_code::RATE_LIMITED,
+                                    }
+                                    .into(),
+                                }),
+                                Output::Message(ServerMessage::Complete { id }),
+                            ])
+                            .boxed()
+                        } else if !rate_limiter.check(operations) {
+                            // Too many operations started recently. Just send back an error.
                             stream::iter(vec![
                                 Output::Message(ServerMessage::Error {
                                     id: id.clone(),
-                                    payload: GraphQLError::from(RuleError::new(
-                                        "Too many in-flight operations.",
-                                        &[],
-                                    ))
+                                    payload: GraphQLError::Rejected {
+                                        message: "Too many operations started.",
+                                        code: error_code::RATE_LIMITED,
+                                    }
                                     .into(),
                                 }),
                                 Output::Message(ServerMessage::Complete { id }),
@@ -218,30 +302,75 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                         } else {
                             // Create a channel that we can use to cancel the operation.
                             let (tx, rx) = oneshot::channel::<()>();
-                            stoppers.insert(id.clone(), tx);
+                            stoppers.insert(id.clone(), (tx, operations));
+                            let completed = Arc::new(AtomicBool::new(false));
 
                             // Create the operation stream. This stream will emit Next and Error
                             // messages, but will not emit Complete – that part is up to us.
-                            let s = Self::start(
-                                id.clone(),
-                                ExecutionParams {
-                                    subscribe_payload: payload,
-                                    config: config.clone(),
-                                    schema: schema.clone(),
-                                },
-                            )
-                            .into_stream()
-                            .flatten();
+                            let s = match payload {
+                                BatchSubscribePayload::Single(payload) => Self::start(
+                                    id.clone(),
+                                    ExecutionParams {
+                                        completed: completed.clone(),
+                                        subscribe_payload: payload,
+                                        config: config.clone(),
+                                        schema: schema.clone(),
+                                    },
+                                )
+                                .into_stream()
+                                .flatten()
+                                .boxed(),
+                                BatchSubscribePayload::Batch(payloads) => Self::start_batch(
+                                    id.clone(),
+                                    payloads,
+                                    config.clone(),
+                                    schema.clone(),
+                                )
+                                .into_stream()
+                                .boxed(),
+                            };
+
+                            // Decouple the stream from the client consuming it, if required.
+                            let s = if let Some(buffer) = config.outgoing_buffer {
+                                let id = id.clone();
+                                buffer
+                                    .wrap(s)
+                                    .map(move |item| match item {
+                                        Buffered::Item(item) => item,
+                                        Buffered::Overflow => {
+                                            Output::Message(ServerMessage::Error {
+                                                id: id.clone(),
+                                                payload: GraphQLError::Rejected {
+                                                    message: "Too many pending operation results.",
+                                                    code: error_code::RATE_LIMITED,
+                                                }
+                                                .into(),
+                                            })
+                                        }
+                                    })
+                                    .boxed()
+                            } else {
+                                s
+                            };
 
                             // Combine this with our oneshot channel so that the stream ends if the
-                            // oneshot is ever fired.
-                            let s = stream::unfold((rx, s.boxed()), async |(rx, mut s)| {
-                                let next = match future::select(rx, s.next()).await {
-                                    Either::Left(_) => None,
-                                    Either::Right((r, rx)) => r.map(|r| (r, rx)),
-                                };
-                                next.map(|(r, rx)| (r, (rx, s)))
-                            });
+                            // oneshot is ever fired. Firing it explicitly means that the client has
+                            // completed the operation.
+                            let s = stream::unfold(
+                                (rx, s, completed),
+                                async |(rx, mut s, completed)| {
+                                    let next = match future::select(rx, s.next()).await {
+                                        Either::Left((stopped, _)) => {
+                                            if stopped.is_ok() {
+                                                completed.store(true, Ordering::Release);
+                                            }
+                                            None
+                                        }
+                                        Either::Right((r, rx)) => r.map(|r| (r, rx)),
+                                    };
+                                    next.map(|(r, rx)| (r, (rx, s, completed)))
+                                },
+                            );
 
                             // Once the stream ends, send the Complete message.
                             let s = s.chain(
@@ -252,7 +381,9 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                         }
                     }
                     ClientMessage::Complete { id } => {
-                        stoppers.remove(&id);
+                        if let Some((tx, _)) = stoppers.remove(&id) {
+                            _ = tx.send(());
+                        }
                         stream::empty().boxed()
                     }
                     ClientMessage::Ping { .. } => {
@@ -265,8 +396,10 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                     Self::Active {
                         config,
                         stoppers,
+                        rate_limiter,
                         ping,
                         schema,
+                        _permit,
                     },
                     reactions,
                 )
@@ -277,64 +410,42 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
 
     async fn start(
         id: String,
-        params: ExecutionParams<S>,
+        mut params: ExecutionParams<S>,
     ) -> BoxStream<'static, Output<S::ScalarValue>> {
-        // TODO: This could be made more efficient if `juniper` exposed
-        //       functionality to allow us to parse and validate the query,
-        //       determine whether it's a subscription, and then execute it.
-        //       For now, the query gets parsed and validated twice.
+        let replay_buffer = params.config.replay.clone().map(|replay| {
+            let payload = &params.subscribe_payload;
+            let fingerprint = operation_fingerprint(
+                &payload.query,
+                payload.operation_name.as_deref(),
+                &payload.variables,
+            );
+            (replay.buffer, replay.identity, fingerprint)
+        });
+        if let Some(last_event_id) = last_event_id(&params.subscribe_payload.extensions) {
+            if let Some(replay) =
+                replay_buffer
+                    .as_ref()
+                    .and_then(|(buffer, identity, fingerprint)| {
+                        buffer.resume(identity, fingerprint, last_event_id)
+                    })
+            {
+                return Self::replayed(id, replay, params.completed.clone());
+            }
+            if let Some(resume_handler) = params.config.resume_handler.clone() {
+                resume_handler(&mut params.config.context, last_event_id);
+            }
+        }
 
         let params = Arc::new(params);
 
-        let fut = juniper::execute(
-            &params.subscribe_payload.query,
-            params.subscribe_payload.operation_name.as_deref(),
-            params.schema.root_node(),
-            &params.subscribe_payload.variables,
-            &params.config.context,
-        )
-        .map_ok(|(data, errors)| {
-            Output::Message(ServerMessage::Next {
-                id: id.clone(),
-                payload: NextPayload { data, errors },
-            })
-            .into_stream()
-            .left_stream()
-        })
-        .unwrap_or_else(|e| {
-            if matches!(e, GraphQLError::IsSubscription) {
-                SubscriptionStart::new(id.clone(), params.clone()).right_stream()
-            } else {
-                Output::Message(ServerMessage::Error {
-                    id: id.clone(),
-                    payload: ErrorPayload::new(Box::new(params.clone()), e),
-                })
-                .into_stream()
-                .left_stream()
-            }
-        });
+        let start = OperationStart::new(id.clone(), params.clone());
+        let stream = if let Some((buffer, identity, fingerprint)) = replay_buffer {
+            let completed = params.completed.clone();
+            Self::recorded(id.clone(), start, buffer, identity, fingerprint, completed)
+        } else {
+            start.boxed()
+        };
         if let Some(panic_handler) = params.config.panic_handler.as_ref().map(Arc::clone) {
-            let stream = AssertUnwindSafe(fut)
-                .catch_unwind()
-                .await
-                .unwrap_or_else(|e| {
-                    if let Some(e) = panic_handler(e, &params.config.context) {
-                        Output::Message(ServerMessage::Next {
-                            id: id.clone(),
-                            payload: NextPayload {
-                                data: Value::null(),
-                                errors: vec![e],
-                            },
-                        })
-                    } else {
-                        Output::Close {
-                            code: 1000,
-                            message: "Operation execution panicked".into(),
-                        }
-                    }
-                    .into_stream()
-                    .left_stream()
-                });
             AssertUnwindSafe(stream)
                 .catch_unwind()
                 .map(move |res| match res {
@@ -346,35 +457,183 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                                 payload: NextPayload {
                                     data: Value::null(),
                                     errors: vec![e],
+                                    extensions: None,
                                 },
                             })
                         } else {
                             Output::Close {
                                 code: 1000,
-                                message: "Subscription execution panicked".into(),
+                                message: "Operation execution panicked".into(),
                             }
                         }
                     }
                 })
                 .boxed()
         } else {
-            fut.await.boxed()
+            stream
+        }
+    }
+}
+
+impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
+    /// Records results of the provided operation into the [`ReplayBuffer`], so it may be
+    /// resumed later, if the operation is a subscription.
+    ///
+    /// Results of queries and mutations, and errors of starting the subscription are emitted as
+    /// is, without being recorded.
+    fn recorded(
+        id: String,
+        start: OperationStart<S>,
+        buffer: ReplayBuffer<NextPayload<S::ScalarValue>>,
+        identity: String,
+        fingerprint: String,
+        completed: Arc<AtomicBool>,
+    ) -> BoxStream<'static, Output<S::ScalarValue>> {
+        let mut start = Box::pin(start);
+        stream::once(async move {
+            match start.next().await {
+                Some(Output::Message(ServerMessage::Next { payload, .. }))
+                    if start.is_subscribed() =>
+                {
+                    let payloads = stream::once(future::ready(payload))
+                        .chain(start.filter_map(|output| {
+                            future::ready(match output {
+                                Output::Message(ServerMessage::Next { payload, .. }) => {
+                                    Some(payload)
+                                }
+                                _ => None,
+                            })
+                        }))
+                        .boxed();
+                    Self::replayed(
+                        id,
+                        buffer.record(identity, fingerprint, payloads),
+                        completed,
+                    )
+                }
+                output => stream::iter(output).chain(start).boxed(),
+            }
+        })
+        .flatten()
+        .boxed()
+    }
+
+    /// Emits results of the subscription recorded into a [`ReplayBuffer`] along with their
+    /// [`EventId`]s.
+    ///
+    /// [`EventId`]: crate::EventId
+    fn replayed(
+        id: String,
+        replay: Replay<NextPayload<S::ScalarValue>>,
+        completed: Arc<AtomicBool>,
+    ) -> BoxStream<'static, Output<S::ScalarValue>> {
+        CompletableReplay::new(replay, completed)
+            .map(move |(event_id, payload)| {
+                Output::Message(ServerMessage::Next {
+                    id: id.clone(),
+                    payload: with_event_id(payload, event_id),
+                })
+            })
+            .boxed()
+    }
+
+    async fn start_batch(
+        id: String,
+        payloads: Vec<SubscribePayload<S::ScalarValue>>,
+        config: ConnectionConfig<S::Context, S::ScalarValue>,
+        schema: S,
+    ) -> Output<S::ScalarValue> {
+        // Entries are executed one after another, so mutations are applied in the requested
+        // order.
+        let fut = async {
+            let mut results = Vec::with_capacity(payloads.len());
+            for payload in &payloads {
+                let started = Instant::now();
+                let result = juniper::execute(
+                    &payload.query,
+                    payload.operation_name.as_deref(),
+                    schema.root_node(),
+                    &payload.variables,
+                    &config.context,
+                )
+                .await;
+                let extensions = config
+                    .execution_metadata
+                    .then(|| result.as_ref().ok())
+                    .flatten()
+                    .map(|(data, _)| execution_metadata(started.elapsed(), data));
+                results.push(BatchNextPayload {
+                    response: GraphQLResponse::from_result(result),
+                    extensions,
+                });
+            }
+            results
+        };
+        if let Some(panic_handler) = config.panic_handler.as_ref() {
+            match AssertUnwindSafe(fut).catch_unwind().await {
+                Ok(payload) => Output::Message(ServerMessage::NextBatch { id, payload }),
+                Err(e) => {
+                    if let Some(e) = panic_handler(e, &config.context) {
+                        Output::Message(ServerMessage::Next {
+                            id,
+                            payload: NextPayload {
+                                data: Value::null(),
+                                errors: vec![e],
+                                extensions: None,
+                            },
+                        })
+                    } else {
+                        Output::Close {
+                            code: 1000,
+                            message: "Operation execution panicked".into(),
+                        }
+                    }
+                }
+            }
+        } else {
+            Output::Message(ServerMessage::NextBatch {
+                id,
+                payload: fut.await,
+            })
+        }
+    }
+}
+
+/// Builds execution metadata of the provided operation result, as described by the
+/// [`ConnectionConfig::execution_metadata`].
+fn execution_metadata<S: ScalarValue>(duration: Duration, data: &Value<S>) -> Value<S> {
+    fn result_fields<S>(value: &Value<S>) -> usize {
+        match value {
+            Value::Null | Value::Scalar(_) => 0,
+            Value::List(l) => l.iter().map(result_fields).sum(),
+            Value::Object(o) => o.iter().map(|(_, v)| 1 + result_fields(v)).sum(),
         }
     }
+
+    let mut execution = Object::with_capacity(2);
+    execution.add_field("durationMs", Value::scalar(duration.as_secs_f64() * 1000.0));
+    execution.add_field(
+        "resultFields",
+        Value::scalar(i32::try_from(result_fields(data)).unwrap_or(i32::MAX)),
+    );
+    Value::object(
+        [("execution", Value::object(execution))]
+            .into_iter()
+            .collect(),
+    )
 }
 
-/// SubscriptionStartState is the state for a subscription operation.
-enum SubscriptionStartState<S: Schema> {
+/// OperationStartState is the state for an operation.
+enum OperationStartState<S: Schema> {
     /// Init is the start before being polled for the first time.
     Init { id: String },
-    /// ResolvingIntoStream is the state after being polled for the first time. In this state,
-    /// we're parsing, validating, and getting the actual event stream.
-    ResolvingIntoStream {
+    /// Resolving is the state after being polled for the first time. In this state, we're
+    /// parsing, validating, and either executing the query or mutation, or getting the actual
+    /// event stream of the subscription.
+    Resolving {
         id: String,
-        future: BoxFuture<
-            'static,
-            Result<juniper_subscriptions::Connection<'static, S::ScalarValue>, GraphQLError>,
-        >,
+        started: Instant,
+        future: BoxFuture<'static, Result<Resolved<'static, S::ScalarValue>, GraphQLError>>,
     },
     /// Streaming is the state after we've successfully obtained the event stream for the
     /// subscription. In this state, we're just forwarding events back to the client.
@@ -386,45 +645,56 @@ enum SubscriptionStartState<S: Schema> {
     Terminated,
 }
 
-/// SubscriptionStart is the stream for a subscription operation.
-struct SubscriptionStart<S: Schema> {
+/// OperationStart is the stream for an operation, parsed and validated only once regardless of
+/// whether it's a subscription.
+struct OperationStart<S: Schema> {
     params: Arc<ExecutionParams<S>>,
-    state: SubscriptionStartState<S>,
+    state: OperationStartState<S>,
+    /// Indicator whether the operation has turned out to be a subscription.
+    subscribed: bool,
     _marker: PhantomPinned,
 }
 
-impl<S: Schema> SubscriptionStart<S> {
+impl<S: Schema> OperationStart<S> {
     fn new(id: String, params: Arc<ExecutionParams<S>>) -> Self {
         Self {
             params,
-            state: SubscriptionStartState::Init { id },
+            state: OperationStartState::Init { id },
+            subscribed: false,
             _marker: PhantomPinned,
         }
     }
+
+    /// Indicates whether the operation has turned out to be a subscription.
+    fn is_subscribed(&self) -> bool {
+        self.subscribed
+    }
 }
 
-impl<S: Schema> Stream for SubscriptionStart<S> {
+impl<S: Schema> Stream for OperationStart<S> {
     type Item = Output<S::ScalarValue>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
-        let (params, state) = unsafe {
+        let (params, state, subscribed) = unsafe {
             // XXX: The execution parameters are referenced by state and must not be modified.
             // Modifying state is fine though.
             let inner = self.get_unchecked_mut();
-            (&inner.params, &mut inner.state)
+            (&inner.params, &mut inner.state, &mut inner.subscribed)
         };
 
         loop {
             match state {
-                SubscriptionStartState::Init { id } => {
-                    // XXX: resolve_into_stream returns a Future that references the execution
-                    // parameters, and the returned stream also references them. We can guarantee
-                    // that everything has the same lifetime in this self-referential struct.
+                OperationStartState::Init { id } => {
+                    // XXX: execute_or_resolve_into_stream returns a Future that references the
+                    // execution parameters, and the returned stream also references them. We can
+                    // guarantee that everything has the same lifetime in this self-referential
+                    // struct.
                     let params = Arc::as_ptr(params);
-                    *state = SubscriptionStartState::ResolvingIntoStream {
+                    *state = OperationStartState::Resolving {
                         id: id.clone(),
+                        started: Instant::now(),
                         future: unsafe {
-                            juniper::resolve_into_stream(
+                            juniper::execute_or_resolve_into_stream(
                                 &(*params).subscribe_payload.query,
                                 (*params).subscribe_payload.operation_name.as_deref(),
                                 (*params).schema.root_node(),
@@ -436,32 +706,48 @@ impl<S: Schema> Stream for SubscriptionStart<S> {
                                 &(&(*params).config).context,
                             )
                         }
-                        .map_ok(|(stream, errors)| {
-                            juniper_subscriptions::Connection::from_stream(stream, errors)
-                        })
                         .boxed(),
                     };
                 }
-                SubscriptionStartState::ResolvingIntoStream { id, future } => {
-                    match future.as_mut().poll(cx) {
-                        Poll::Ready(r) => match r {
-                            Ok(stream) => {
-                                *state = SubscriptionStartState::Streaming {
-                                    id: id.clone(),
-                                    stream,
-                                }
-                            }
-                            Err(e) => {
-                                return Poll::Ready(Some(Output::Message(ServerMessage::Error {
-                                    id: id.clone(),
-                                    payload: ErrorPayload::new(Box::new(params.clone()), e),
-                                })));
-                            }
-                        },
-                        Poll::Pending => return Poll::Pending,
+                OperationStartState::Resolving {
+                    id,
+                    started,
+                    future,
+                } => match future.as_mut().poll(cx) {
+                    Poll::Ready(Ok(Resolved::Executed(data, errors))) => {
+                        let id = id.clone();
+                        let extensions = params
+                            .config
+                            .execution_metadata
+                            .then(|| execution_metadata(started.elapsed(), &data));
+                        *state = OperationStartState::Terminated;
+                        return Poll::Ready(Some(Output::Message(ServerMessage::Next {
+                            id,
+                            payload: NextPayload {
+                                data,
+                                errors,
+                                extensions,
+                            },
+                        })));
                     }
-                }
-                SubscriptionStartState::Streaming { id, stream } => {
+                    Poll::Ready(Ok(Resolved::Subscribed(stream, errors))) => {
+                        *subscribed = true;
+                        *state = OperationStartState::Streaming {
+                            id: id.clone(),
+                            stream: juniper_subscriptions::Connection::from_stream(stream, errors),
+                        };
+                    }
+                    Poll::Ready(Err(e)) => {
+                        let id = id.clone();
+                        *state = OperationStartState::Terminated;
+                        return Poll::Ready(Some(Output::Message(ServerMessage::Error {
+                            id,
+                            payload: ErrorPayload::new(Box::new(params.clone()), e),
+                        })));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                OperationStartState::Streaming { id, stream } => {
                     match Pin::new(stream).poll_next(cx) {
                         Poll::Ready(Some(output)) => {
                             return Poll::Ready(Some(Output::Message(ServerMessage::Next {
@@ -469,22 +755,24 @@ impl<S: Schema> Stream for SubscriptionStart<S> {
                                 payload: NextPayload {
                                     data: output.data,
                                     errors: output.errors,
+                                    extensions: None,
                                 },
                             })));
                         }
                         Poll::Ready(None) => {
-                            *state = SubscriptionStartState::Terminated;
+                            *state = OperationStartState::Terminated;
                             return Poll::Ready(None);
                         }
                         Poll::Pending => return Poll::Pending,
                     }
                 }
-                SubscriptionStartState::Terminated => return Poll::Ready(None),
+                OperationStartState::Terminated => return Poll::Ready(None),
             }
         }
     }
 }
 
+#[expect(clippy::large_enum_variant, reason = "not boxed to avoid allocations")]
 enum ConnectionSinkState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
     Ready {
         state: ConnectionState<S, I>,
@@ -672,7 +960,7 @@ mod test {
 
     use juniper::{
         DefaultScalarValue, EmptyMutation, FieldError, FieldResult, GraphQLError, RootNode,
-        Variables,
+        ScalarValue as _, Value, Variables,
         futures::{
             future::{self, FutureExt as _},
             sink::SinkExt,
@@ -682,7 +970,10 @@ mod test {
         parser::{ParseError, Spanning},
     };
 
-    use super::{Connection, ConnectionConfig, NextPayload, Output, SubscribePayload};
+    use super::{
+        super::{ConnectionLimit, EventId, OverflowPolicy, ReplayBuffer},
+        BatchSubscribePayload, Connection, ConnectionConfig, NextPayload, Output, SubscribePayload,
+    };
 
     #[derive(Clone, Copy)]
     struct Context(i32);
@@ -722,6 +1013,11 @@ mod test {
                 .boxed()
         }
 
+        /// numbers emits numbers from 1 to 5 at once, then completes.
+        async fn numbers(_context: &Context) -> BoxStream<'static, FieldResult<i32>> {
+            stream::iter((1..=5).map(Ok)).boxed()
+        }
+
         /// error emits an error once, then never emits anything else.
         async fn error(_context: &Context) -> BoxStream<'static, FieldResult<i32>> {
             stream::once(future::ready(Err(FieldError::new(
@@ -769,7 +1065,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -780,6 +1077,7 @@ mod test {
                 payload: NextPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             }),
             conn.next().await.unwrap()
@@ -805,7 +1103,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -846,7 +1145,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -857,6 +1157,7 @@ mod test {
                 payload: NextPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             }),
             conn.next().await.unwrap()
@@ -869,7 +1170,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -880,6 +1182,7 @@ mod test {
                 payload: NextPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             }),
             conn.next().await.unwrap()
@@ -965,7 +1268,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -977,24 +1281,92 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
 
         match conn.next().await.unwrap() {
-            Output::Message(ServerMessage::Error { id, .. }) => {
-                assert_eq!(id, "bar");
+            Output::Message(msg @ ServerMessage::Error { .. }) => {
+                assert_eq!(
+                    serde_json::to_value(msg).unwrap(),
+                    serde_json::json!({
+                        "type": "error",
+                        "id": "bar",
+                        "payload": [{
+                            "message": "Too many in-flight operations.",
+                            "extensions": {"code": "RATE_LIMITED"},
+                        }],
+                    }),
+                );
             }
             msg => panic!("expected error, got: {msg:?}"),
         }
     }
 
     #[tokio::test]
-    async fn test_parse_error() {
+    async fn test_connection_limit() {
+        let limit = ConnectionLimit::new(1);
+        let new_conn = || {
+            Connection::new(
+                new_test_schema(),
+                ConnectionConfig::new(Context(1))
+                    .with_keep_alive_interval(Duration::from_secs(0))
+                    .with_connection_limit(limit.clone()),
+            )
+        };
+
+        let mut first = new_conn();
+        first
+            .send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            first.next().await.unwrap()
+        );
+
+        let mut second = new_conn();
+        second
+            .send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            Output::Close {
+                code: 1013,
+                message: "Too many connections".into(),
+            },
+            second.next().await.unwrap(),
+        );
+        assert_eq!(limit.active(), 1);
+
+        drop(first);
+
+        let mut third = new_conn();
+        third
+            .send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            third.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_rate_limit() {
         let mut conn = Connection::new(
             new_test_schema(),
-            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_subscribe_rate_limit(1, Duration::from_secs(10000)),
         );
 
         conn.send(ClientMessage::ConnectionInit {
@@ -1011,37 +1383,60 @@ mod test {
         conn.send(ClientMessage::Subscribe {
             id: "foo".into(),
             payload: SubscribePayload {
-                query: "asd".into(),
+                query: "{context}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::Next {
+                id: "foo".into(),
+                payload: NextPayload {
+                    data: graphql_value!({"context": 1}),
+                    errors: vec![],
+                    extensions: None,
+                },
+            }),
+            conn.next().await.unwrap()
+        );
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(ClientMessage::Subscribe {
+            id: "bar".into(),
+            payload: SubscribePayload {
+                query: "{context}".into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+                extensions: Default::default(),
+            }
+            .into(),
         })
         .await
         .unwrap();
 
         match conn.next().await.unwrap() {
-            Output::Message(ServerMessage::Error { id, payload }) => {
-                assert_eq!(id, "foo");
-                match payload.graphql_error() {
-                    GraphQLError::ParseError(Spanning {
-                        item: ParseError::UnexpectedToken(token),
-                        ..
-                    }) => assert_eq!(token, "asd"),
-                    p => panic!("expected graphql parse error, got: {p:?}"),
-                }
+            Output::Message(ServerMessage::Error { id, .. }) => {
+                assert_eq!(id, "bar");
             }
             msg => panic!("expected error, got: {msg:?}"),
         }
     }
 
     #[tokio::test]
-    async fn test_keep_alive_interval() {
+    async fn test_outgoing_buffer_overflow() {
         let mut conn = Connection::new(
             new_test_schema(),
             ConnectionConfig::new(Context(1))
-                .with_keep_alive_interval(Duration::from_millis(20))
-                .with_keep_alive_timeout(Duration::from_secs(0)),
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_outgoing_buffer(2, OverflowPolicy::CloseOperation),
         );
 
         conn.send(ClientMessage::ConnectionInit {
@@ -1055,19 +1450,584 @@ mod test {
             conn.next().await.unwrap()
         );
 
-        for _ in 0..10 {
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: SubscribePayload {
+                query: "subscription Foo {numbers}".into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+                extensions: Default::default(),
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+
+        // Let the operation overflow its buffer before consuming anything.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        for n in 1..=2 {
             assert_eq!(
-                Output::Message(ServerMessage::Pong),
+                Output::Message(ServerMessage::Next {
+                    id: "foo".into(),
+                    payload: NextPayload {
+                        data: graphql_value!({"numbers": n}),
+                        errors: vec![],
+                        extensions: None,
+                    },
+                }),
                 conn.next().await.unwrap()
             );
         }
-    }
-
-    #[tokio::test]
-    async fn test_keep_alive_timeout() {
-        let mut conn = Connection::new(
-            new_test_schema(),
-            ConnectionConfig::new(Context(1))
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Error { id, .. }) => {
+                assert_eq!(id, "foo");
+            }
+            msg => panic!("expected error, got: {msg:?}"),
+        }
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_parse_error() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: SubscribePayload {
+                query: "asd".into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+                extensions: Default::default(),
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Error { id, payload }) => {
+                assert_eq!(id, "foo");
+                match payload.graphql_error() {
+                    GraphQLError::ParseError(Spanning {
+                        item: ParseError::UnexpectedToken(token),
+                        ..
+                    }) => assert_eq!(token, "asd"),
+                    p => panic!("expected graphql parse error, got: {p:?}"),
+                }
+            }
+            msg => panic!("expected error, got: {msg:?}"),
+        }
+    }
+
+    fn subscribe_payload(query: &str) -> SubscribePayload<DefaultScalarValue> {
+        SubscribePayload {
+            query: query.into(),
+            variables: graphql_vars! {},
+            operation_name: None,
+            extensions: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_not_allowed() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1)).with_keep_alive_interval(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: BatchSubscribePayload::Batch(vec![
+                subscribe_payload("{context}"),
+                subscribe_payload("{context}"),
+            ]),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Error { id, .. }) => {
+                assert_eq!(id, "foo");
+            }
+            msg => panic!("expected error, got: {msg:?}"),
+        }
+
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_batching(),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: BatchSubscribePayload::Batch(vec![
+                subscribe_payload("{context}"),
+                subscribe_payload("{unknown}"),
+            ]),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(msg @ ServerMessage::NextBatch { .. }) => {
+                assert_eq!(
+                    serde_json::to_value(msg).unwrap(),
+                    serde_json::json!({
+                        "type": "next",
+                        "id": "foo",
+                        "payload": [
+                            {"data": {"context": 1}},
+                            {"errors": [{
+                                "message": "Unknown field \"unknown\" on type \"Query\"",
+                                "locations": [{"line": 1, "column": 2}],
+                                "extensions": {"code": "GRAPHQL_VALIDATION_FAILED"},
+                            }]},
+                        ],
+                    }),
+                );
+            }
+            msg => panic!("expected batch results, got: {msg:?}"),
+        }
+
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_counts_each_operation() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_max_in_flight_operations(2)
+                .with_subscribe_rate_limit(3, Duration::from_secs(10000))
+                .with_batching(),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        // Exceeds the in-flight operations limit, despite being a single message.
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: BatchSubscribePayload::Batch(vec![
+                subscribe_payload("{context}"),
+                subscribe_payload("{context}"),
+                subscribe_payload("{context}"),
+            ]),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Error { id, .. }) => assert_eq!(id, "foo"),
+            msg => panic!("expected error, got: {msg:?}"),
+        }
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(ClientMessage::Subscribe {
+            id: "bar".into(),
+            payload: BatchSubscribePayload::Batch(vec![
+                subscribe_payload("{context}"),
+                subscribe_payload("{context}"),
+            ]),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::NextBatch { id, payload }) => {
+                assert_eq!(id, "bar");
+                assert_eq!(payload.len(), 2);
+            }
+            msg => panic!("expected batch results, got: {msg:?}"),
+        }
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "bar".into() }),
+            conn.next().await.unwrap()
+        );
+
+        // Both operations of the previous batch count against the rate limit.
+        conn.send(ClientMessage::Subscribe {
+            id: "baz".into(),
+            payload: BatchSubscribePayload::Batch(vec![
+                subscribe_payload("{context}"),
+                subscribe_payload("{context}"),
+            ]),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Error { id, .. }) => assert_eq!(id, "baz"),
+            msg => panic!("expected error, got: {msg:?}"),
+        }
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "baz".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execution_metadata() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_execution_metadata(),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: subscribe_payload("{context}").into(),
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Next { id, payload }) => {
+                assert_eq!(id, "foo");
+                assert_eq!(payload.data, graphql_value!({"context": 1}));
+                let extensions = payload.extensions.expect("no execution metadata");
+                let execution = extensions
+                    .as_object_value()
+                    .and_then(|o| o.get_field_value("execution"))
+                    .and_then(|v| v.as_object_value())
+                    .expect("no `execution` metadata");
+                assert_eq!(
+                    execution.get_field_value("resultFields"),
+                    Some(&graphql_value!(1)),
+                );
+                assert!(execution.get_field_value("durationMs").is_some());
+            }
+            msg => panic!("expected next, got: {msg:?}"),
+        }
+
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    type TestConnection = Connection<
+        Arc<RootNode<Query, EmptyMutation<Context>, Subscription>>,
+        ConnectionConfig<Context, DefaultScalarValue>,
+    >;
+
+    /// Establishes a new [`Connection`] with the provided `context`, making its subscriptions
+    /// resumable from the provided [`ReplayBuffer`] by the clients with the same `identity`.
+    async fn resumable_connection(
+        buffer: &ReplayBuffer<NextPayload<DefaultScalarValue>>,
+        identity: &str,
+        context: i32,
+    ) -> TestConnection {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(context))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_replay_buffer(buffer.clone(), identity),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        conn
+    }
+
+    /// Subscribes to the provided `query` with the provided `lastEventId`.
+    async fn subscribe(conn: &mut TestConnection, query: &str, last_event_id: Option<EventId>) {
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: SubscribePayload {
+                extensions: last_event_id
+                    .map(|id| graphql_vars! {"lastEventId": (id.to_string())})
+                    .unwrap_or_default(),
+                ..subscribe_payload(query)
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+    }
+
+    /// Receives the next subscription result, returning its [`EventId`] and data.
+    async fn next_event(conn: &mut TestConnection) -> (EventId, Value) {
+        match conn.next().await.unwrap() {
+            Output::Message(ServerMessage::Next { id, payload }) => {
+                assert_eq!(id, "foo");
+                let event_id = payload
+                    .extensions
+                    .as_ref()
+                    .and_then(|e| e.as_object_value())
+                    .and_then(|o| o.get_field_value("eventId"))
+                    .and_then(|v| v.as_scalar())
+                    .and_then(|s| s.try_as_str())
+                    .expect("no `eventId`")
+                    .parse()
+                    .unwrap();
+                (event_id, payload.data)
+            }
+            msg => panic!("expected next, got: {msg:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_subscription() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        subscribe(&mut conn, "subscription Foo {numbers}", None).await;
+
+        let mut events = vec![];
+        for n in 1..=5 {
+            let (event_id, data) = next_event(&mut conn).await;
+            assert_eq!(event_id.seq(), n);
+            assert_eq!(data, graphql_value!({"numbers": (n as i32)}));
+            events.push(event_id);
+        }
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        subscribe(&mut conn, "subscription Foo {numbers}", Some(events[2])).await;
+
+        for n in 4..=5 {
+            let (event_id, data) = next_event(&mut conn).await;
+            assert_eq!(event_id, events[n - 1]);
+            assert_eq!(data, graphql_value!({"numbers": (n as i32)}));
+        }
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resume_subscription_of_another_client() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        subscribe(&mut conn, "subscription Foo {context}", None).await;
+        let (first, _) = next_event(&mut conn).await;
+
+        drop(conn);
+
+        // Subscriptions of other clients are not replayed, but executed anew.
+        let mut conn = resumable_connection(&buffer, "bob", 2).await;
+        let before_first = format!("{:x}:0", first.stream()).parse().unwrap();
+        subscribe(&mut conn, "subscription Foo {context}", Some(before_first)).await;
+        let (started, data) = next_event(&mut conn).await;
+        assert_ne!(started.stream(), first.stream());
+        assert_eq!(data, graphql_value!({"context": 2}));
+    }
+
+    #[tokio::test]
+    async fn test_resume_completed_subscription() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        subscribe(&mut conn, "subscription Foo {context}", None).await;
+        let (first, _) = next_event(&mut conn).await;
+
+        conn.send(ClientMessage::Complete { id: "foo".into() })
+            .await
+            .unwrap();
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap()
+        );
+
+        drop(conn);
+
+        // Completed subscriptions are released, so executed anew.
+        let mut conn = resumable_connection(&buffer, "alice", 2).await;
+        let before_first = format!("{:x}:0", first.stream()).parse().unwrap();
+        subscribe(&mut conn, "subscription Foo {context}", Some(before_first)).await;
+        let (started, data) = next_event(&mut conn).await;
+        assert_ne!(started.stream(), first.stream());
+        assert_eq!(data, graphql_value!({"context": 2}));
+    }
+
+    #[tokio::test]
+    async fn test_resume_handler() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_replay_buffer(ReplayBuffer::new(10, Duration::from_secs(10)), "alice")
+                .with_resume_handler(|ctx: &mut Context, last_event_id: EventId| {
+                    ctx.0 = last_event_id.seq().try_into().unwrap();
+                }),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        // The event is unknown to the buffer, so the subscription is started anew.
+        conn.send(ClientMessage::Subscribe {
+            id: "foo".into(),
+            payload: SubscribePayload {
+                extensions: graphql_vars! {"lastEventId": "42:7"},
+                ..subscribe_payload("subscription Foo {context}")
+            }
+            .into(),
+        })
+        .await
+        .unwrap();
+
+        let (event_id, data) = next_event(&mut conn).await;
+        assert_eq!(event_id.seq(), 1);
+        assert_eq!(data, graphql_value!({"context": 7}));
+    }
+
+    #[tokio::test]
+    async fn test_query_is_not_recorded() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+
+        subscribe(&mut conn, "{context}", None).await;
+
+        assert_eq!(
+            Output::Message(ServerMessage::Next {
+                id: "foo".into(),
+                payload: NextPayload {
+                    data: graphql_value!({"context": 1}),
+                    errors: vec![],
+                    extensions: None,
+                },
+            }),
+            conn.next().await.unwrap(),
+        );
+        assert_eq!(
+            Output::Message(ServerMessage::Complete { id: "foo".into() }),
+            conn.next().await.unwrap(),
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_interval() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_millis(20))
+                .with_keep_alive_timeout(Duration::from_secs(0)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            Output::Message(ServerMessage::ConnectionAck),
+            conn.next().await.unwrap()
+        );
+
+        for _ in 0..10 {
+            assert_eq!(
+                Output::Message(ServerMessage::Pong),
+                conn.next().await.unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_timeout() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
                 .with_keep_alive_interval(Duration::from_millis(0))
                 .with_keep_alive_timeout(Duration::from_millis(20)),
         );
@@ -1113,7 +2073,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -1129,6 +2090,7 @@ mod test {
                 payload: NextPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             }),
             conn.next().await.unwrap()
@@ -1160,7 +2122,8 @@ mod test {
                 variables: graphql_vars! {},
                 operation_name: None,
                 extensions: Default::default(),
-            },
+            }
+            .into(),
         })
         .await
         .unwrap();
@@ -1168,7 +2131,7 @@ mod test {
         match conn.next().await.unwrap() {
             Output::Message(ServerMessage::Next {
                 id,
-                payload: NextPayload { data, errors },
+                payload: NextPayload { data, errors, .. },
             }) => {
                 assert_eq!(id, "foo");
                 assert_eq!(data, graphql_value!({ "error": null }));
@@ -1,9 +1,31 @@
+use juniper::{ScalarValue, Value, http::GraphQLResponse};
 use serde::Serialize;
 
 pub use crate::server_message::{ErrorPayload, NextPayload};
 
+/// Payload to be send after execution of a single operation of a batch.
+///
+/// Unlike [`NextPayload`], contains the whole [`GraphQLResponse`], so errors happened before
+/// execution (such as validation errors) are reported for each operation separately.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(bound = "S: ScalarValue")]
+pub struct BatchNextPayload<S> {
+    /// Result of the operation execution.
+    #[serde(flatten)]
+    pub response: GraphQLResponse<S>,
+
+    /// Additional information about the execution, if any.
+    ///
+    /// See [`ConnectionConfig::execution_metadata`] for details.
+    ///
+    /// [`ConnectionConfig::execution_metadata`]: crate::ConnectionConfig::execution_metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value<S>>,
+}
+
 /// ServerMessage defines the message types that servers can send.
 #[derive(Debug, PartialEq, Serialize)]
+#[serde(bound = "S: ScalarValue")]
 #[serde(rename_all = "snake_case")]
 #[serde(tag = "type")]
 pub enum ServerMessage<S> {
@@ -20,6 +42,15 @@ pub enum ServerMessage<S> {
         /// The data and errors that occurred during execution.
         payload: NextPayload<S>,
     },
+    /// Contains the results of a batch of queries and mutations, in the order they were requested.
+    #[serde(rename = "next")]
+    NextBatch {
+        /// The id of the batch that the data is for.
+        id: String,
+
+        /// The results of every operation in the batch.
+        payload: Vec<BatchNextPayload<S>>,
+    },
     /// Error contains an error that occurs before execution, such as validation errors.
     Error {
         /// The id of the operation that triggered this error.
@@ -37,7 +68,7 @@ pub enum ServerMessage<S> {
 
 #[cfg(test)]
 mod test {
-    use juniper::{DefaultScalarValue, GraphQLError, graphql_value};
+    use juniper::{DefaultScalarValue, GraphQLError, graphql_value, http::GraphQLResponse};
 
     use super::*;
 
@@ -61,19 +92,43 @@ mod test {
                 payload: NextPayload {
                     data: graphql_value!(null),
                     errors: vec![],
+                    extensions: None,
                 },
             })
             .unwrap(),
             r#"{"type":"next","id":"foo","payload":{"data":null}}"#,
         );
 
+        assert_eq!(
+            serde_json::to_string(&ServerMessage::NextBatch {
+                id: "foo".into(),
+                payload: vec![
+                    BatchNextPayload {
+                        response: GraphQLResponse::from_result(Ok((
+                            graphql_value!({"foo": 1}),
+                            vec![],
+                        ))),
+                        extensions: Some(graphql_value!({"bar": 2})),
+                    },
+                    BatchNextPayload {
+                        response: GraphQLResponse::from_result(Err(
+                            GraphQLError::UnknownOperationName,
+                        )),
+                        extensions: None,
+                    },
+                ],
+            })
+            .unwrap(),
+            r#"{"type":"next","id":"foo","payload":[{"data":{"foo":1},"extensions":{"bar":2}},{"errors":[{"message":"Unknown operation","extensions":{"code":"OPERATION_RESOLUTION_FAILURE"}}]}]}"#,
+        );
+
         assert_eq!(
             serde_json::to_string(&ServerMessage::Error {
                 id: "foo".into(),
                 payload: GraphQLError::UnknownOperationName.into(),
             })
             .unwrap(),
-            r#"{"type":"error","id":"foo","payload":[{"message":"Unknown operation"}]}"#,
+            r#"{"type":"error","id":"foo","payload":[{"message":"Unknown operation","extensions":{"code":"OPERATION_RESOLUTION_FAILURE"}}]}"#,
         );
 
         assert_eq!(
@@ -18,6 +18,10 @@ pub struct StartPayload<S> {
 
     /// The optional operation name (required if the document contains multiple operations).
     pub operation_name: Option<String>,
+
+    /// The optional extension data.
+    #[serde(default, deserialize_with = "default_for_null")]
+    pub extensions: Variables<S>,
 }
 
 /// ClientMessage defines the message types that clients can send.
@@ -83,6 +87,7 @@ mod test {
                     query: "query MyQuery { __typename }".into(),
                     variables: graphql_vars! {"foo": "bar"},
                     operation_name: Some("MyQuery".into()),
+                    extensions: Default::default(),
                 },
             },
             serde_json::from_str(
@@ -104,6 +109,7 @@ mod test {
                     query: "query MyQuery { __typename }".into(),
                     variables: graphql_vars! {},
                     operation_name: None,
+                    extensions: Default::default(),
                 },
             },
             serde_json::from_str(
@@ -134,6 +140,7 @@ mod test {
             query: "query".into(),
             variables: graphql_vars! {},
             operation_name: None,
+            extensions: Default::default(),
         };
 
         assert_eq!(expected, payload);
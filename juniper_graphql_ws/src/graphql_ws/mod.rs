@@ -16,22 +16,37 @@ mod client_message;
 mod server_message;
 
 use std::{
-    collections::HashMap, convert::Infallible, error::Error, marker::PhantomPinned,
-    panic::AssertUnwindSafe, pin::Pin, sync::Arc, time::Duration,
+    collections::HashMap,
+    convert::Infallible,
+    error::Error,
+    marker::PhantomPinned,
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
 };
 
 use juniper::{
-    GraphQLError, RuleError, Value,
+    GraphQLError, Resolved, Value, error_code,
     futures::{
         Sink, Stream,
         channel::oneshot,
-        future::{self, BoxFuture, Either, FutureExt as _, TryFutureExt as _},
+        future::{self, BoxFuture, Either, FutureExt as _},
         stream::{self, BoxStream, SelectAll, StreamExt as _},
         task::{Context, Poll, Waker},
     },
 };
 
-use super::{ConnectionConfig, Init, Schema};
+use juniper_subscriptions::{Replay, operation_fingerprint};
+
+use super::{
+    ConnectionConfig, ConnectionLimit, Init, ReplayBuffer, Schema,
+    limit::{Buffered, ConnectionPermit, RateLimiter},
+    util::{CompletableReplay, last_event_id, with_event_id},
+};
 
 pub use self::{
     client_message::{ClientMessage, StartPayload},
@@ -39,6 +54,8 @@ pub use self::{
 };
 
 struct ExecutionParams<S: Schema> {
+    /// Indicator whether the operation has been completed by the client.
+    completed: Arc<AtomicBool>,
     start_payload: StartPayload<S::ScalarValue>,
     config: ConnectionConfig<S::Context, S::ScalarValue>,
     schema: S,
@@ -56,6 +73,7 @@ impl<S: Schema> Reaction<S> {
     }
 }
 
+#[expect(clippy::large_enum_variant, reason = "not boxed to avoid allocations")]
 enum ConnectionState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
     /// PreInit is the state before a ConnectionInit message has been accepted.
     PreInit { init: I, schema: S },
@@ -63,7 +81,9 @@ enum ConnectionState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
     Active {
         config: ConnectionConfig<S::Context, S::ScalarValue>,
         stoppers: HashMap<String, oneshot::Sender<()>>,
+        rate_limiter: RateLimiter,
         schema: S,
+        _permit: Option<ConnectionPermit>,
     },
     /// Terminated is the state after a ConnectionInit message has been rejected.
     Terminated,
@@ -84,6 +104,29 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
             Self::PreInit { init, schema } => match msg {
                 ClientMessage::ConnectionInit { payload } => match init.init(payload).await {
                     Ok(config) => {
+                        let permit = match config
+                            .connection_limit
+                            .as_ref()
+                            .map(ConnectionLimit::acquire)
+                        {
+                            Some(None) => {
+                                // Too many connections. We must close this one.
+                                return (
+                                    Self::Terminated,
+                                    stream::iter(vec![
+                                        Reaction::ServerMessage(ServerMessage::ConnectionError {
+                                            payload: ConnectionErrorPayload {
+                                                message: "Too many connections".into(),
+                                            },
+                                        }),
+                                        Reaction::EndStream,
+                                    ])
+                                    .boxed(),
+                                );
+                            }
+                            permit => permit.flatten(),
+                        };
+
                         let keep_alive_interval = config.keep_alive.interval;
 
                         let s = stream::iter(vec![Reaction::ServerMessage(
@@ -110,9 +153,11 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
 
                         (
                             Self::Active {
+                                rate_limiter: RateLimiter::new(config.subscribe_rate_limit),
                                 config,
                                 stoppers: HashMap::new(),
                                 schema,
+                                _permit: permit,
                             },
                             s,
                         )
@@ -135,7 +180,9 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
             Self::Active {
                 config,
                 mut stoppers,
+                mut rate_limiter,
                 schema,
+                _permit,
             } => {
                 let reactions = match msg {
                     ClientMessage::Start { id, payload } => {
@@ -149,14 +196,28 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                         } else if config.max_in_flight_operations > 0
                             && stoppers.len() >= config.max_in_flight_operations
                         {
-                            // Too many in-flight operations. Just send back a validation error.
+                            // Too many in-flight operations. Just send back an error.
+                            stream::iter(vec![
+                                Reaction::ServerMessage(ServerMessage::Error {
+                                    id: id.clone(),
+                                    payload: GraphQLError::Rejected {
+                                        message: "Too many in-flight operations.",
+                                        code: error_code::RATE_LIMITED,
+                                    }
+                                    .into(),
+                                }),
+                                Reaction::ServerMessage(ServerMessage::Complete { id }),
+                            ])
+                            .boxed()
+                        } else if !rate_limiter.check(1) {
+                            // Too many operations started recently. Just send back an error.
                             stream::iter(vec![
                                 Reaction::ServerMessage(ServerMessage::Error {
                                     id: id.clone(),
-                                    payload: GraphQLError::from(RuleError::new(
-                                        "Too many in-flight operations.",
-                                        &[],
-                                    ))
+                                    payload: GraphQLError::Rejected {
+                                        message: "Too many operations started.",
+                                        code: error_code::RATE_LIMITED,
+                                    }
                                     .into(),
                                 }),
                                 Reaction::ServerMessage(ServerMessage::Complete { id }),
@@ -166,12 +227,14 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                             // Create a channel that we can use to cancel the operation.
                             let (tx, rx) = oneshot::channel::<()>();
                             stoppers.insert(id.clone(), tx);
+                            let completed = Arc::new(AtomicBool::new(false));
 
                             // Create the operation stream. This stream will emit Data and Error
                             // messages, but will not emit Complete – that part is up to us.
                             let s = Self::start(
                                 id.clone(),
                                 ExecutionParams {
+                                    completed: completed.clone(),
                                     start_payload: payload,
                                     config: config.clone(),
                                     schema: schema.clone(),
@@ -180,15 +243,47 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                             .into_stream()
                             .flatten();
 
+                            // Decouple the stream from the client consuming it, if required.
+                            let s = if let Some(buffer) = config.outgoing_buffer {
+                                let id = id.clone();
+                                buffer
+                                    .wrap(s.boxed())
+                                    .map(move |item| match item {
+                                        Buffered::Item(item) => item,
+                                        Buffered::Overflow => {
+                                            Reaction::ServerMessage(ServerMessage::Error {
+                                                id: id.clone(),
+                                                payload: GraphQLError::Rejected {
+                                                    message: "Too many pending operation results.",
+                                                    code: error_code::RATE_LIMITED,
+                                                }
+                                                .into(),
+                                            })
+                                        }
+                                    })
+                                    .boxed()
+                            } else {
+                                s.boxed()
+                            };
+
                             // Combine this with our oneshot channel so that the stream ends if the
-                            // oneshot is ever fired.
-                            let s = stream::unfold((rx, s.boxed()), async |(rx, mut s)| {
-                                let next = match future::select(rx, s.next()).await {
-                                    Either::Left(_) => None,
-                                    Either::Right((r, rx)) => r.map(|r| (r, rx)),
-                                };
-                                next.map(|(r, rx)| (r, (rx, s)))
-                            });
+                            // oneshot is ever fired. Firing it explicitly means that the client has
+                            // completed the operation.
+                            let s = stream::unfold(
+                                (rx, s, completed),
+                                async |(rx, mut s, completed)| {
+                                    let next = match future::select(rx, s.next()).await {
+                                        Either::Left((stopped, _)) => {
+                                            if stopped.is_ok() {
+                                                completed.store(true, Ordering::Release);
+                                            }
+                                            None
+                                        }
+                                        Either::Right((r, rx)) => r.map(|r| (r, rx)),
+                                    };
+                                    next.map(|(r, rx)| (r, (rx, s, completed)))
+                                },
+                            );
 
                             // Once the stream ends, send the Complete message.
                             let s = s.chain(
@@ -200,7 +295,9 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                         }
                     }
                     ClientMessage::Stop { id } => {
-                        stoppers.remove(&id);
+                        if let Some(tx) = stoppers.remove(&id) {
+                            _ = tx.send(());
+                        }
                         stream::empty().boxed()
                     }
                     _ => stream::empty().boxed(),
@@ -209,7 +306,9 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                     Self::Active {
                         config,
                         stoppers,
+                        rate_limiter,
                         schema,
+                        _permit,
                     },
                     reactions,
                 )
@@ -218,60 +317,41 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
         }
     }
 
-    async fn start(id: String, params: ExecutionParams<S>) -> BoxStream<'static, Reaction<S>> {
-        // TODO: This could be made more efficient if `juniper` exposed
-        //       functionality to allow us to parse and validate the query,
-        //       determine whether it's a subscription, and then execute it.
-        //       For now, the query gets parsed and validated twice.
+    async fn start(id: String, mut params: ExecutionParams<S>) -> BoxStream<'static, Reaction<S>> {
+        let replay_buffer = params.config.replay.clone().map(|replay| {
+            let payload = &params.start_payload;
+            let fingerprint = operation_fingerprint(
+                &payload.query,
+                payload.operation_name.as_deref(),
+                &payload.variables,
+            );
+            (replay.buffer, replay.identity, fingerprint)
+        });
+        if let Some(last_event_id) = last_event_id(&params.start_payload.extensions) {
+            if let Some(replay) =
+                replay_buffer
+                    .as_ref()
+                    .and_then(|(buffer, identity, fingerprint)| {
+                        buffer.resume(identity, fingerprint, last_event_id)
+                    })
+            {
+                return Self::replayed(id, replay, params.completed.clone());
+            }
+            if let Some(resume_handler) = params.config.resume_handler.clone() {
+                resume_handler(&mut params.config.context, last_event_id);
+            }
+        }
 
         let params = Arc::new(params);
 
-        let fut = juniper::execute(
-            &params.start_payload.query,
-            params.start_payload.operation_name.as_deref(),
-            params.schema.root_node(),
-            &params.start_payload.variables,
-            &params.config.context,
-        )
-        .map_ok(|(data, errors)| {
-            Reaction::ServerMessage(ServerMessage::Data {
-                id: id.clone(),
-                payload: DataPayload { data, errors },
-            })
-            .into_stream()
-            .left_stream()
-        })
-        .unwrap_or_else(|e| {
-            if matches!(e, GraphQLError::IsSubscription) {
-                SubscriptionStart::new(id.clone(), params.clone()).right_stream()
-            } else {
-                Reaction::ServerMessage(ServerMessage::Error {
-                    id: id.clone(),
-                    payload: ErrorPayload::new(Box::new(params.clone()), e),
-                })
-                .into_stream()
-                .left_stream()
-            }
-        });
+        let start = OperationStart::new(id.clone(), params.clone());
+        let stream = if let Some((buffer, identity, fingerprint)) = replay_buffer {
+            let completed = params.completed.clone();
+            Self::recorded(id.clone(), start, buffer, identity, fingerprint, completed)
+        } else {
+            start.boxed()
+        };
         if let Some(panic_handler) = params.config.panic_handler.as_ref().map(Arc::clone) {
-            let stream = AssertUnwindSafe(fut)
-                .catch_unwind()
-                .await
-                .unwrap_or_else(|e| {
-                    if let Some(e) = panic_handler(e, &params.config.context) {
-                        Reaction::ServerMessage(ServerMessage::Data {
-                            id: id.clone(),
-                            payload: DataPayload {
-                                data: Value::null(),
-                                errors: vec![e],
-                            },
-                        })
-                    } else {
-                        Reaction::EndStream
-                    }
-                    .into_stream()
-                    .left_stream()
-                });
             AssertUnwindSafe(stream)
                 .catch_unwind()
                 .map(move |res| match res {
@@ -283,6 +363,7 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                                 payload: DataPayload {
                                     data: Value::null(),
                                     errors: vec![e],
+                                    extensions: None,
                                 },
                             })
                         } else {
@@ -292,23 +373,82 @@ impl<S: Schema, I: Init<S::ScalarValue, S::Context>> ConnectionState<S, I> {
                 })
                 .boxed()
         } else {
-            fut.await.boxed()
+            stream
         }
     }
+
+    /// Records results of the provided operation into the [`ReplayBuffer`], so it may be
+    /// resumed later, if the operation is a subscription.
+    ///
+    /// Results of queries and mutations, and errors of starting the subscription are emitted as
+    /// is, without being recorded.
+    fn recorded(
+        id: String,
+        start: OperationStart<S>,
+        buffer: ReplayBuffer<DataPayload<S::ScalarValue>>,
+        identity: String,
+        fingerprint: String,
+        completed: Arc<AtomicBool>,
+    ) -> BoxStream<'static, Reaction<S>> {
+        let mut start = Box::pin(start);
+        stream::once(async move {
+            match start.next().await {
+                Some(Reaction::ServerMessage(ServerMessage::Data { payload, .. }))
+                    if start.is_subscribed() =>
+                {
+                    let payloads = stream::once(future::ready(payload))
+                        .chain(start.filter_map(|reaction| {
+                            future::ready(match reaction {
+                                Reaction::ServerMessage(ServerMessage::Data {
+                                    payload, ..
+                                }) => Some(payload),
+                                _ => None,
+                            })
+                        }))
+                        .boxed();
+                    Self::replayed(
+                        id,
+                        buffer.record(identity, fingerprint, payloads),
+                        completed,
+                    )
+                }
+                reaction => stream::iter(reaction).chain(start).boxed(),
+            }
+        })
+        .flatten()
+        .boxed()
+    }
+
+    /// Emits results of the subscription recorded into a [`ReplayBuffer`] along with their
+    /// [`EventId`]s.
+    ///
+    /// [`EventId`]: crate::EventId
+    fn replayed(
+        id: String,
+        replay: Replay<DataPayload<S::ScalarValue>>,
+        completed: Arc<AtomicBool>,
+    ) -> BoxStream<'static, Reaction<S>> {
+        CompletableReplay::new(replay, completed)
+            .map(move |(event_id, payload)| {
+                Reaction::ServerMessage(ServerMessage::Data {
+                    id: id.clone(),
+                    payload: with_event_id(payload, event_id),
+                })
+            })
+            .boxed()
+    }
 }
 
-/// SubscriptionStartState is the state for a subscription operation.
-enum SubscriptionStartState<S: Schema> {
+/// OperationStartState is the state for an operation.
+enum OperationStartState<S: Schema> {
     /// Init is the start before being polled for the first time.
     Init { id: String },
-    /// ResolvingIntoStream is the state after being polled for the first time. In this state,
-    /// we're parsing, validating, and getting the actual event stream.
-    ResolvingIntoStream {
+    /// Resolving is the state after being polled for the first time. In this state, we're
+    /// parsing, validating, and either executing the query or mutation, or getting the actual
+    /// event stream of the subscription.
+    Resolving {
         id: String,
-        future: BoxFuture<
-            'static,
-            Result<juniper_subscriptions::Connection<'static, S::ScalarValue>, GraphQLError>,
-        >,
+        future: BoxFuture<'static, Result<Resolved<'static, S::ScalarValue>, GraphQLError>>,
     },
     /// Streaming is the state after we've successfully obtained the event stream for the
     /// subscription. In this state, we're just forwarding events back to the client.
@@ -320,45 +460,55 @@ enum SubscriptionStartState<S: Schema> {
     Terminated,
 }
 
-/// SubscriptionStart is the stream for a subscription operation.
-struct SubscriptionStart<S: Schema> {
+/// OperationStart is the stream for an operation, parsed and validated only once regardless of
+/// whether it's a subscription.
+struct OperationStart<S: Schema> {
     params: Arc<ExecutionParams<S>>,
-    state: SubscriptionStartState<S>,
+    state: OperationStartState<S>,
+    /// Indicator whether the operation has turned out to be a subscription.
+    subscribed: bool,
     _marker: PhantomPinned,
 }
 
-impl<S: Schema> SubscriptionStart<S> {
+impl<S: Schema> OperationStart<S> {
     fn new(id: String, params: Arc<ExecutionParams<S>>) -> Self {
         Self {
             params,
-            state: SubscriptionStartState::Init { id },
+            state: OperationStartState::Init { id },
+            subscribed: false,
             _marker: PhantomPinned,
         }
     }
+
+    /// Indicates whether the operation has turned out to be a subscription.
+    fn is_subscribed(&self) -> bool {
+        self.subscribed
+    }
 }
 
-impl<S: Schema> Stream for SubscriptionStart<S> {
+impl<S: Schema> Stream for OperationStart<S> {
     type Item = Reaction<S>;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
-        let (params, state) = unsafe {
+        let (params, state, subscribed) = unsafe {
             // XXX: The execution parameters are referenced by state and must not be modified.
             // Modifying state is fine though.
             let inner = self.get_unchecked_mut();
-            (&inner.params, &mut inner.state)
+            (&inner.params, &mut inner.state, &mut inner.subscribed)
         };
 
         loop {
             match state {
-                SubscriptionStartState::Init { id } => {
-                    // XXX: resolve_into_stream returns a Future that references the execution
-                    // parameters, and the returned stream also references them. We can guarantee
-                    // that everything has the same lifetime in this self-referential struct.
+                OperationStartState::Init { id } => {
+                    // XXX: execute_or_resolve_into_stream returns a Future that references the
+                    // execution parameters, and the returned stream also references them. We can
+                    // guarantee that everything has the same lifetime in this self-referential
+                    // struct.
                     let params = Arc::as_ptr(params);
-                    *state = SubscriptionStartState::ResolvingIntoStream {
+                    *state = OperationStartState::Resolving {
                         id: id.clone(),
                         future: unsafe {
-                            juniper::resolve_into_stream(
+                            juniper::execute_or_resolve_into_stream(
                                 &(*params).start_payload.query,
                                 (*params).start_payload.operation_name.as_deref(),
                                 (*params).schema.root_node(),
@@ -370,34 +520,40 @@ impl<S: Schema> Stream for SubscriptionStart<S> {
                                 &(&(*params).config).context,
                             )
                         }
-                        .map_ok(|(stream, errors)| {
-                            juniper_subscriptions::Connection::from_stream(stream, errors)
-                        })
                         .boxed(),
                     };
                 }
-                SubscriptionStartState::ResolvingIntoStream { id, future } => {
-                    match future.as_mut().poll(cx) {
-                        Poll::Ready(r) => match r {
-                            Ok(stream) => {
-                                *state = SubscriptionStartState::Streaming {
-                                    id: id.clone(),
-                                    stream,
-                                }
-                            }
-                            Err(e) => {
-                                return Poll::Ready(Some(Reaction::ServerMessage(
-                                    ServerMessage::Error {
-                                        id: id.clone(),
-                                        payload: ErrorPayload::new(Box::new(params.clone()), e),
-                                    },
-                                )));
-                            }
-                        },
-                        Poll::Pending => return Poll::Pending,
+                OperationStartState::Resolving { id, future } => match future.as_mut().poll(cx) {
+                    Poll::Ready(Ok(Resolved::Executed(data, errors))) => {
+                        let id = id.clone();
+                        *state = OperationStartState::Terminated;
+                        return Poll::Ready(Some(Reaction::ServerMessage(ServerMessage::Data {
+                            id,
+                            payload: DataPayload {
+                                data,
+                                errors,
+                                extensions: None,
+                            },
+                        })));
                     }
-                }
-                SubscriptionStartState::Streaming { id, stream } => match Pin::new(stream)
+                    Poll::Ready(Ok(Resolved::Subscribed(stream, errors))) => {
+                        *subscribed = true;
+                        *state = OperationStartState::Streaming {
+                            id: id.clone(),
+                            stream: juniper_subscriptions::Connection::from_stream(stream, errors),
+                        };
+                    }
+                    Poll::Ready(Err(e)) => {
+                        let id = id.clone();
+                        *state = OperationStartState::Terminated;
+                        return Poll::Ready(Some(Reaction::ServerMessage(ServerMessage::Error {
+                            id,
+                            payload: ErrorPayload::new(Box::new(params.clone()), e),
+                        })));
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                OperationStartState::Streaming { id, stream } => match Pin::new(stream)
                     .poll_next(cx)
                 {
                     Poll::Ready(Some(output)) => {
@@ -406,21 +562,23 @@ impl<S: Schema> Stream for SubscriptionStart<S> {
                             payload: DataPayload {
                                 data: output.data,
                                 errors: output.errors,
+                                extensions: None,
                             },
                         })));
                     }
                     Poll::Ready(None) => {
-                        *state = SubscriptionStartState::Terminated;
+                        *state = OperationStartState::Terminated;
                         return Poll::Ready(None);
                     }
                     Poll::Pending => return Poll::Pending,
                 },
-                SubscriptionStartState::Terminated => return Poll::Ready(None),
+                OperationStartState::Terminated => return Poll::Ready(None),
             }
         }
     }
 }
 
+#[expect(clippy::large_enum_variant, reason = "not boxed to avoid allocations")]
 enum ConnectionSinkState<S: Schema, I: Init<S::ScalarValue, S::Context>> {
     Ready {
         state: ConnectionState<S, I>,
@@ -571,7 +729,7 @@ mod test {
 
     use juniper::{
         DefaultScalarValue, EmptyMutation, FieldError, FieldResult, GraphQLError, RootNode,
-        Variables,
+        ScalarValue as _, Value, Variables,
         futures::{
             future::{self, FutureExt as _},
             sink::SinkExt,
@@ -581,7 +739,11 @@ mod test {
         parser::{ParseError, Spanning},
     };
 
-    use super::{Connection, ConnectionConfig, ConnectionErrorPayload, DataPayload, StartPayload};
+    use super::{
+        super::{EventId, ReplayBuffer},
+        Connection, ConnectionConfig, ConnectionErrorPayload, ConnectionLimit, DataPayload,
+        StartPayload,
+    };
 
     #[derive(Clone, Copy)]
     struct Context(i32);
@@ -664,6 +826,7 @@ mod test {
                 query: "{context}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -675,6 +838,7 @@ mod test {
                 payload: DataPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             },
             conn.next().await.unwrap()
@@ -707,6 +871,7 @@ mod test {
                 query: "subscription Foo {context}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -718,6 +883,7 @@ mod test {
                 payload: DataPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             },
             conn.next().await.unwrap()
@@ -729,6 +895,7 @@ mod test {
                 query: "subscription Bar {context}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -740,6 +907,7 @@ mod test {
                 payload: DataPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             },
             conn.next().await.unwrap()
@@ -755,6 +923,137 @@ mod test {
         );
     }
 
+    type TestConnection = Connection<
+        Arc<RootNode<Query, EmptyMutation<Context>, Subscription>>,
+        ConnectionConfig<Context, DefaultScalarValue>,
+    >;
+
+    /// Establishes a new [`Connection`] with the provided `context`, making its subscriptions
+    /// resumable from the provided [`ReplayBuffer`] by the clients with the same `identity`.
+    async fn resumable_connection(
+        buffer: &ReplayBuffer<DataPayload<DefaultScalarValue>>,
+        identity: &str,
+        context: i32,
+    ) -> TestConnection {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(context))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_replay_buffer(buffer.clone(), identity),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(ServerMessage::ConnectionAck, conn.next().await.unwrap());
+
+        conn
+    }
+
+    /// Starts the `context` subscription with the provided `lastEventId`, returning the
+    /// [`EventId`] and the data of its first result.
+    async fn start_context_subscription(
+        conn: &mut TestConnection,
+        last_event_id: Option<EventId>,
+    ) -> (EventId, Value) {
+        conn.send(ClientMessage::Start {
+            id: "foo".into(),
+            payload: StartPayload {
+                query: "subscription Foo {context}".into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+                extensions: last_event_id
+                    .map(|id| graphql_vars! {"lastEventId": (id.to_string())})
+                    .unwrap_or_default(),
+            },
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            ServerMessage::Data { id, payload } => {
+                assert_eq!(id, "foo");
+                let event_id = payload
+                    .extensions
+                    .as_ref()
+                    .and_then(|e| e.as_object_value())
+                    .and_then(|o| o.get_field_value("eventId"))
+                    .and_then(|v| v.as_scalar())
+                    .and_then(|s| s.try_as_str())
+                    .expect("no `eventId`")
+                    .parse()
+                    .unwrap();
+                (event_id, payload.data)
+            }
+            msg => panic!("expected data, got: {msg:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_subscription() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        let (first, data) = start_context_subscription(&mut conn, None).await;
+        assert_eq!(first.seq(), 1);
+        assert_eq!(data, graphql_value!({"context": 1}));
+
+        drop(conn);
+
+        // Resuming from before the first event replays it, instead of executing the subscription
+        // with the new context.
+        let mut conn = resumable_connection(&buffer, "alice", 2).await;
+        let before_first = format!("{:x}:0", first.stream()).parse().unwrap();
+        let (replayed, data) = start_context_subscription(&mut conn, Some(before_first)).await;
+        assert_eq!(replayed, first);
+        assert_eq!(data, graphql_value!({"context": 1}));
+    }
+
+    #[tokio::test]
+    async fn test_resume_subscription_of_another_client() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        let (first, _) = start_context_subscription(&mut conn, None).await;
+
+        drop(conn);
+
+        // Subscriptions of other clients are not replayed, but executed anew.
+        let mut conn = resumable_connection(&buffer, "bob", 2).await;
+        let before_first = format!("{:x}:0", first.stream()).parse().unwrap();
+        let (started, data) = start_context_subscription(&mut conn, Some(before_first)).await;
+        assert_ne!(started.stream(), first.stream());
+        assert_eq!(data, graphql_value!({"context": 2}));
+    }
+
+    #[tokio::test]
+    async fn test_resume_stopped_subscription() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut conn = resumable_connection(&buffer, "alice", 1).await;
+        let (first, _) = start_context_subscription(&mut conn, None).await;
+
+        conn.send(ClientMessage::Stop { id: "foo".into() })
+            .await
+            .unwrap();
+        assert_eq!(
+            ServerMessage::Complete { id: "foo".into() },
+            conn.next().await.unwrap()
+        );
+
+        drop(conn);
+
+        // Stopped subscriptions are released, so executed anew.
+        let mut conn = resumable_connection(&buffer, "alice", 2).await;
+        let before_first = format!("{:x}:0", first.stream()).parse().unwrap();
+        let (started, data) = start_context_subscription(&mut conn, Some(before_first)).await;
+        assert_ne!(started.stream(), first.stream());
+        assert_eq!(data, graphql_value!({"context": 2}));
+    }
+
     #[tokio::test]
     async fn test_init_params_ok() {
         let mut conn = Connection::new(new_test_schema(), async |params: Variables| {
@@ -817,6 +1116,7 @@ mod test {
                 query: "subscription Foo {never}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -828,6 +1128,114 @@ mod test {
                 query: "subscription Bar {never}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
+            },
+        })
+        .await
+        .unwrap();
+
+        match conn.next().await.unwrap() {
+            ServerMessage::Error { id, .. } => {
+                assert_eq!(id, "bar");
+            }
+            msg => panic!("expected error, got: {msg:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connection_limit() {
+        let limit = ConnectionLimit::new(1);
+        let new_conn = || {
+            Connection::new(
+                new_test_schema(),
+                ConnectionConfig::new(Context(1))
+                    .with_keep_alive_interval(Duration::from_secs(0))
+                    .with_connection_limit(limit.clone()),
+            )
+        };
+
+        let mut first = new_conn();
+        first
+            .send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        assert_eq!(ServerMessage::ConnectionAck, first.next().await.unwrap());
+
+        let mut second = new_conn();
+        second
+            .send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            ServerMessage::ConnectionError {
+                payload: ConnectionErrorPayload {
+                    message: "Too many connections".into(),
+                },
+            },
+            second.next().await.unwrap(),
+        );
+
+        drop(first);
+
+        let mut third = new_conn();
+        third
+            .send(ClientMessage::ConnectionInit {
+                payload: graphql_vars! {},
+            })
+            .await
+            .unwrap();
+        assert_eq!(ServerMessage::ConnectionAck, third.next().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_rate_limit() {
+        let mut conn = Connection::new(
+            new_test_schema(),
+            ConnectionConfig::new(Context(1))
+                .with_keep_alive_interval(Duration::from_secs(0))
+                .with_subscribe_rate_limit(1, Duration::from_secs(10000)),
+        );
+
+        conn.send(ClientMessage::ConnectionInit {
+            payload: graphql_vars! {},
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(ServerMessage::ConnectionAck, conn.next().await.unwrap());
+
+        conn.send(ClientMessage::Start {
+            id: "foo".into(),
+            payload: StartPayload {
+                query: "subscription Foo {never}".into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+                extensions: Default::default(),
+            },
+        })
+        .await
+        .unwrap();
+
+        conn.send(ClientMessage::Stop { id: "foo".into() })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            ServerMessage::Complete { id: "foo".into() },
+            conn.next().await.unwrap(),
+        );
+
+        conn.send(ClientMessage::Start {
+            id: "bar".into(),
+            payload: StartPayload {
+                query: "subscription Bar {never}".into(),
+                variables: graphql_vars! {},
+                operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -862,6 +1270,7 @@ mod test {
                 query: "asd".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -925,6 +1334,7 @@ mod test {
                 query: "{context}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -938,6 +1348,7 @@ mod test {
                 payload: DataPayload {
                     data: graphql_value!({"context": 1}),
                     errors: vec![],
+                    extensions: None,
                 },
             },
             conn.next().await.unwrap()
@@ -965,6 +1376,7 @@ mod test {
                 query: "subscription Foo {error}".into(),
                 variables: graphql_vars! {},
                 operation_name: None,
+                extensions: Default::default(),
             },
         })
         .await
@@ -973,7 +1385,7 @@ mod test {
         match conn.next().await.unwrap() {
             ServerMessage::Data {
                 id,
-                payload: DataPayload { data, errors },
+                payload: DataPayload { data, errors, .. },
             } => {
                 assert_eq!(id, "foo");
                 assert_eq!(data, graphql_value!({ "error": null }));
@@ -84,6 +84,7 @@ mod test {
                 payload: DataPayload {
                     data: graphql_value!(null),
                     errors: vec![],
+                    extensions: None,
                 },
             })
             .unwrap(),
@@ -96,7 +97,7 @@ mod test {
                 payload: GraphQLError::UnknownOperationName.into(),
             })
             .unwrap(),
-            r#"{"type":"error","id":"foo","payload":[{"message":"Unknown operation"}]}"#,
+            r#"{"type":"error","id":"foo","payload":[{"message":"Unknown operation","extensions":{"code":"OPERATION_RESOLUTION_FAILURE"}}]}"#,
         );
 
         assert_eq!(
@@ -11,6 +11,7 @@ compile_error!(
 pub mod graphql_transport_ws;
 #[cfg(feature = "graphql-ws")]
 pub mod graphql_ws;
+mod limit;
 mod schema;
 mod server_message;
 mod util;
@@ -20,7 +21,14 @@ use std::{any::Any, convert::Infallible, error::Error, future, sync::Arc, time::
 use derive_more::with_trait::Debug;
 use juniper::{ExecutionError, ScalarValue, Variables};
 
-pub use self::schema::{ArcSchema, Schema};
+pub use juniper_subscriptions::{EventId, ReplayBuffer};
+
+pub use self::{
+    limit::{ConnectionLimit, OutgoingBuffer, OverflowPolicy, RateLimit},
+    schema::{ArcSchema, Schema},
+};
+
+use self::server_message::NextPayload;
 
 /// ConnectionConfig is used to configure the connection once the client sends the ConnectionInit
 /// message.
@@ -35,12 +43,81 @@ pub struct ConnectionConfig<CtxT, S> {
     /// By default, there is no limit to in-flight operations.
     pub max_in_flight_operations: usize,
 
+    /// Optional [`ConnectionLimit`] shared with other connections of a server.
+    ///
+    /// If it's exceeded, the connection is closed once initialized.
+    /// By default, there is no limit to concurrent connections.
+    pub connection_limit: Option<ConnectionLimit>,
+
+    /// Optional [`RateLimit`] of starting new operations.
+    ///
+    /// If it's exceeded, attempting to start more will result in an error.
+    /// By default, there is no limit to the rate of starting operations.
+    pub subscribe_rate_limit: Option<RateLimit>,
+
+    /// Optional [`OutgoingBuffer`] of every operation's results.
+    ///
+    /// By default, operations are driven only as fast as their results are consumed.
+    pub outgoing_buffer: Option<OutgoingBuffer>,
+
     /// Keep-alive configuration.
     pub keep_alive: KeepAliveConfig,
 
+    #[cfg(feature = "graphql-transport-ws")]
+    /// Indicator whether a batch of queries and mutations is accepted in a single `Subscribe`
+    /// message.
+    ///
+    /// Applicable only for the [new `graphql-transport-ws` GraphQL over WebSocket Protocol][new],
+    /// and does nothing for the [legacy `graphql-ws` GraphQL over WebSocket Protocol][old].
+    ///
+    /// By default, batching is disabled.
+    ///
+    /// [new]: https://github.com/enisdenjo/graphql-ws/blob/v5.14.0/PROTOCOL.md
+    /// [old]: https://github.com/apollographql/subscriptions-transport-ws/blob/v0.11.0/PROTOCOL.md
+    pub batching: bool,
+
+    #[cfg(feature = "graphql-transport-ws")]
+    /// Indicator whether execution metadata is included into `extensions` of query and mutation
+    /// results.
+    ///
+    /// The metadata is placed under the `execution` key, and contains:
+    /// - `durationMs`: time spent on executing the operation, in milliseconds;
+    /// - `resultFields`: number of fields in the operation result (its size, not its complexity).
+    ///
+    /// > __NOTE__: No complexity of the operation is reported, as [`juniper`] doesn't analyze
+    /// >           it. Only the duration and the result size are.
+    ///
+    /// Applicable only for the [new `graphql-transport-ws` GraphQL over WebSocket Protocol][new],
+    /// and does nothing for the [legacy `graphql-ws` GraphQL over WebSocket Protocol][old].
+    ///
+    /// By default, no metadata is included.
+    ///
+    /// [new]: https://github.com/enisdenjo/graphql-ws/blob/v5.14.0/PROTOCOL.md
+    /// [old]: https://github.com/apollographql/subscriptions-transport-ws/blob/v0.11.0/PROTOCOL.md
+    pub execution_metadata: bool,
+
     /// Optional [`PanicHandler`] for panics happened during execution of operations.
     #[debug(ignore)]
     pub panic_handler: Option<Arc<dyn PanicHandler<S, CtxT>>>,
+
+    /// Optional [`ReplayConfig`] making subscriptions resumable.
+    ///
+    /// Once configured, every subscription result carries its [`EventId`] in the `eventId` key of
+    /// its `extensions`. A client may resume a subscription after reconnecting by providing the
+    /// last seen [`EventId`] in the `lastEventId` key of the `extensions` of the subscription
+    /// operation, receiving all the later results still present in the [`ReplayBuffer`]. Only
+    /// connections with the same [identity][0] may resume a subscription, and it stops being
+    /// retained once completed by the client.
+    ///
+    /// By default, subscriptions are not resumable.
+    ///
+    /// [0]: ReplayConfig::identity
+    pub replay: Option<ReplayConfig<S>>,
+
+    /// Optional [`ResumeHandler`] for subscriptions that cannot be resumed from the
+    /// [`ReplayBuffer`] anymore.
+    #[debug(ignore)]
+    pub resume_handler: Option<Arc<dyn ResumeHandler<CtxT>>>,
 }
 
 impl<CtxT, S> ConnectionConfig<CtxT, S> {
@@ -49,8 +126,17 @@ impl<CtxT, S> ConnectionConfig<CtxT, S> {
         Self {
             context,
             max_in_flight_operations: 0,
+            connection_limit: None,
+            subscribe_rate_limit: None,
+            outgoing_buffer: None,
             keep_alive: KeepAliveConfig::default(),
+            #[cfg(feature = "graphql-transport-ws")]
+            batching: false,
+            #[cfg(feature = "graphql-transport-ws")]
+            execution_metadata: false,
             panic_handler: None,
+            replay: None,
+            resume_handler: None,
         }
     }
 
@@ -64,6 +150,39 @@ impl<CtxT, S> ConnectionConfig<CtxT, S> {
         self
     }
 
+    /// Specifies the [`ConnectionLimit`] shared with other connections of a server.
+    ///
+    /// If it's exceeded, the connection is closed once initialized.
+    /// By default, there is no limit to concurrent connections.
+    #[must_use]
+    pub fn with_connection_limit(mut self, limit: ConnectionLimit) -> Self {
+        self.connection_limit = Some(limit);
+        self
+    }
+
+    /// Specifies the maximum number of operations that can be started during the provided
+    /// `interval`.
+    ///
+    /// If this number is exceeded, attempting to start more will result in an error.
+    /// By default, there is no limit to the rate of starting operations.
+    #[must_use]
+    pub fn with_subscribe_rate_limit(mut self, max: usize, interval: Duration) -> Self {
+        self.subscribe_rate_limit = Some(RateLimit { max, interval });
+        self
+    }
+
+    /// Specifies the maximum number of results buffered for every operation, and the
+    /// [`OverflowPolicy`] to apply once it's reached.
+    ///
+    /// See [`OutgoingBuffer`] for details.
+    ///
+    /// By default, operations are driven only as fast as their results are consumed.
+    #[must_use]
+    pub fn with_outgoing_buffer(mut self, capacity: usize, overflow: OverflowPolicy) -> Self {
+        self.outgoing_buffer = Some(OutgoingBuffer { capacity, overflow });
+        self
+    }
+
     /// Specifies the interval at which to send keep-alives.
     ///
     /// Specifying a [`Duration::ZERO`] will disable keep-alives.
@@ -102,6 +221,41 @@ impl<CtxT, S> ConnectionConfig<CtxT, S> {
         self
     }
 
+    #[cfg(feature = "graphql-transport-ws")]
+    /// Enables accepting a batch of queries and mutations in a single `Subscribe` message.
+    ///
+    /// Results of a batch are sent back in a single `Next` message, in the order the operations
+    /// were requested.
+    ///
+    /// Operations of a batch are executed sequentially, in the order they were requested, and each
+    /// of them counts separately against the [`ConnectionConfig::max_in_flight_operations`] and
+    /// the [`ConnectionConfig::subscribe_rate_limit`].
+    ///
+    /// Applicable only for the [new `graphql-transport-ws` GraphQL over WebSocket Protocol][new],
+    /// and does nothing for the [legacy `graphql-ws` GraphQL over WebSocket Protocol][old].
+    ///
+    /// By default, batching is disabled.
+    ///
+    /// [new]: https://github.com/enisdenjo/graphql-ws/blob/v5.14.0/PROTOCOL.md
+    /// [old]: https://github.com/apollographql/subscriptions-transport-ws/blob/v0.11.0/PROTOCOL.md
+    #[must_use]
+    pub fn with_batching(mut self) -> Self {
+        self.batching = true;
+        self
+    }
+
+    #[cfg(feature = "graphql-transport-ws")]
+    /// Enables including execution metadata into `extensions` of query and mutation results.
+    ///
+    /// See [`ConnectionConfig::execution_metadata`] for details.
+    ///
+    /// By default, no metadata is included.
+    #[must_use]
+    pub fn with_execution_metadata(mut self) -> Self {
+        self.execution_metadata = true;
+        self
+    }
+
     /// Specifies the [`PanicHandler`] for panics happened during execution of operations.
     ///
     /// # [`PanicHandler`]'s result
@@ -118,9 +272,58 @@ impl<CtxT, S> ConnectionConfig<CtxT, S> {
         self.panic_handler = Some(Arc::new(panic_handler));
         self
     }
+
+    /// Specifies the [`ReplayBuffer`] shared with other connections of a server, making
+    /// subscriptions resumable only by the clients with the same `identity` (like their
+    /// authenticated user or session).
+    ///
+    /// See [`ConnectionConfig::replay`] for details.
+    ///
+    /// By default, subscriptions are not resumable.
+    #[must_use]
+    pub fn with_replay_buffer(
+        mut self,
+        buffer: ReplayBuffer<NextPayload<S>>,
+        identity: impl Into<String>,
+    ) -> Self {
+        self.replay = Some(ReplayConfig {
+            buffer,
+            identity: identity.into(),
+        });
+        self
+    }
+
+    /// Specifies the [`ResumeHandler`] for subscriptions that cannot be resumed from the
+    /// [`ReplayBuffer`] anymore (or if there is no [`ReplayBuffer`] at all).
+    ///
+    /// The [`ResumeHandler`] is provided with the context of the operation before it's started,
+    /// allowing to expose the last [`EventId`] seen by the client to resolvers.
+    #[must_use]
+    pub fn with_resume_handler(
+        mut self,
+        resume_handler: impl ResumeHandler<CtxT> + 'static,
+    ) -> Self {
+        self.resume_handler = Some(Arc::new(resume_handler));
+        self
+    }
+}
+
+/// Configuration of resumable subscriptions of a connection.
+///
+/// See [`ConnectionConfig::replay`] for details.
+#[derive(Clone, Debug)]
+pub struct ReplayConfig<S> {
+    /// [`ReplayBuffer`] shared with other connections of a server, recording the subscriptions.
+    pub buffer: ReplayBuffer<NextPayload<S>>,
+
+    /// Identity of the client (like its authenticated user or session) the subscriptions of the
+    /// connection are bound to.
+    pub identity: String,
 }
 
-impl<S: ScalarValue, CtxT: Unpin + Send + 'static> Init<S, CtxT> for ConnectionConfig<CtxT, S> {
+impl<S: ScalarValue + Send, CtxT: Unpin + Send + 'static> Init<S, CtxT>
+    for ConnectionConfig<CtxT, S>
+{
     type Error = Infallible;
     type Future = future::Ready<Result<Self, Self::Error>>;
 
@@ -189,6 +392,14 @@ impl<T, S, CtxT> PanicHandler<S, CtxT> for T where
 {
 }
 
+/// Handler of a client resuming a subscription, which cannot be resumed from the [`ReplayBuffer`].
+///
+/// Receives the context of the operation before it's started, and the last [`EventId`] seen by
+/// the client, so the resolvers may continue the subscription from where the client has left.
+pub trait ResumeHandler<CtxT>: Fn(&mut CtxT, EventId) + Send + Sync {}
+
+impl<T, CtxT> ResumeHandler<CtxT> for T where T: Fn(&mut CtxT, EventId) + Send + Sync + ?Sized {}
+
 /// Init defines the requirements for types that can provide connection configurations when
 /// ConnectionInit messages are received. Implementations are provided for `ConnectionConfig` and
 /// closures that meet the requirements.
@@ -0,0 +1,372 @@
+//! Limits applied to connections, their incoming messages and outgoing operation results.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use juniper::futures::{
+    Stream,
+    stream::{BoxStream, StreamExt as _},
+    task::{Context, Poll, Waker},
+};
+use tokio::{task::JoinHandle, time::Instant};
+
+/// Limit of concurrent connections, shared by all the connections it's provided to.
+///
+/// [`Clone`]s of a [`ConnectionLimit`] share the same counter, so the same [`ConnectionLimit`]
+/// should be [`Clone`]d into the [`ConnectionConfig`] of every connection accepted by a server.
+///
+/// [`ConnectionConfig`]: crate::ConnectionConfig
+#[derive(Clone, Debug)]
+pub struct ConnectionLimit {
+    /// Maximum number of concurrently active connections.
+    max: usize,
+
+    /// Number of currently active connections.
+    active: Arc<AtomicUsize>,
+}
+
+impl ConnectionLimit {
+    /// Creates a new [`ConnectionLimit`] allowing at most `max` concurrently active connections.
+    #[must_use]
+    pub fn new(max: usize) -> Self {
+        Self {
+            max,
+            active: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the maximum number of concurrently active connections.
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Returns the number of currently active connections.
+    #[must_use]
+    pub fn active(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    /// Tries to occupy a slot for a new connection.
+    ///
+    /// Returns [`None`] if [`ConnectionLimit::max()`] connections are active already.
+    pub(crate) fn acquire(&self) -> Option<ConnectionPermit> {
+        self.active
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |active| {
+                (active < self.max).then_some(active + 1)
+            })
+            .ok()
+            .map(|_| ConnectionPermit(self.active.clone()))
+    }
+}
+
+/// Slot occupied by an active connection in a [`ConnectionLimit`], released once dropped.
+#[derive(Debug)]
+pub(crate) struct ConnectionPermit(Arc<AtomicUsize>);
+
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Limit of the rate at which a client may start new operations over a connection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Maximum number of operations allowed to be started during a single [`interval`].
+    ///
+    /// [`interval`]: Self::interval
+    pub max: usize,
+
+    /// Time window in which at most [`max`] operations are allowed to be started.
+    ///
+    /// [`max`]: Self::max
+    pub interval: Duration,
+}
+
+/// Fixed-window counter enforcing a [`RateLimit`].
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// [`RateLimit`] to enforce, if any.
+    limit: Option<RateLimit>,
+
+    /// [`Instant`] the current time window has started at.
+    window_start: Instant,
+
+    /// Number of operations started in the current time window.
+    count: usize,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] enforcing the provided [`RateLimit`], if any.
+    pub(crate) fn new(limit: Option<RateLimit>) -> Self {
+        Self {
+            limit,
+            window_start: Instant::now(),
+            count: 0,
+        }
+    }
+
+    /// Registers the start of the provided number of `operations`, returning `false` (and
+    /// registering none of them) if they exceed the [`RateLimit`].
+    pub(crate) fn check(&mut self, operations: usize) -> bool {
+        let Some(limit) = self.limit else {
+            return true;
+        };
+        let now = Instant::now();
+        if now.duration_since(self.window_start) >= limit.interval {
+            self.window_start = now;
+            self.count = 0;
+        }
+        if self.count + operations > limit.max {
+            return false;
+        }
+        self.count += operations;
+        true
+    }
+}
+
+/// Policy of handling results of an operation not fitting into its [`OutgoingBuffer`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OverflowPolicy {
+    /// Drops the oldest buffered result to make room for the new one.
+    #[default]
+    DropOldest,
+
+    /// Drops the new result, keeping the already buffered ones.
+    DropNewest,
+
+    /// Stops the operation, emitting an error to the client.
+    CloseOperation,
+}
+
+/// Bounded buffer of results produced by an operation, but not sent to the client yet.
+///
+/// Once configured, an operation is driven independently from the client consuming its results,
+/// so a slow client can't make the server to buffer them without bound.
+///
+/// # Runtime
+///
+/// Operations are driven by [`tokio::spawn()`]ed tasks, so a [Tokio] runtime is required.
+///
+/// [Tokio]: https://tokio.rs
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OutgoingBuffer {
+    /// Maximum number of results buffered for a single operation.
+    pub capacity: usize,
+
+    /// [`OverflowPolicy`] to apply once the [`capacity`] is reached.
+    ///
+    /// [`capacity`]: Self::capacity
+    pub overflow: OverflowPolicy,
+}
+
+/// Item of a stream wrapped into an [`OutgoingBuffer`].
+#[derive(Debug)]
+pub(crate) enum Buffered<T> {
+    /// Item of the wrapped stream.
+    Item(T),
+
+    /// [`OutgoingBuffer`] has overflowed with the [`OverflowPolicy::CloseOperation`].
+    Overflow,
+}
+
+/// Shared state of a [`BufferedStream`] and the task driving its wrapped stream.
+struct BufferState<T> {
+    /// Buffered items.
+    queue: VecDeque<T>,
+
+    /// Indicator whether the wrapped stream has been overflowed with the
+    /// [`OverflowPolicy::CloseOperation`].
+    overflowed: bool,
+
+    /// Indicator whether the wrapped stream has finished.
+    done: bool,
+
+    /// [`Waker`] of the [`BufferedStream`] waiting for new items.
+    waker: Option<Waker>,
+}
+
+impl<T> BufferState<T> {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// [`Stream`] wrapped into an [`OutgoingBuffer`].
+///
+/// Aborts the task driving the wrapped stream once dropped.
+struct BufferedStream<T> {
+    state: Arc<Mutex<BufferState<T>>>,
+    producer: JoinHandle<()>,
+}
+
+impl<T> Stream for BufferedStream<T> {
+    type Item = Buffered<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(item) = state.queue.pop_front() {
+            Poll::Ready(Some(Buffered::Item(item)))
+        } else if state.overflowed {
+            state.overflowed = false;
+            Poll::Ready(Some(Buffered::Overflow))
+        } else if state.done {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> Drop for BufferedStream<T> {
+    fn drop(&mut self) {
+        self.producer.abort();
+    }
+}
+
+impl OutgoingBuffer {
+    /// Wraps the provided [`Stream`] into this [`OutgoingBuffer`], driving it in a separate task.
+    pub(crate) fn wrap<T: Send + 'static>(
+        self,
+        mut stream: BoxStream<'static, T>,
+    ) -> BoxStream<'static, Buffered<T>> {
+        let state = Arc::new(Mutex::new(BufferState {
+            queue: VecDeque::with_capacity(self.capacity),
+            overflowed: false,
+            done: false,
+            waker: None,
+        }));
+
+        let producer = tokio::spawn({
+            let state = state.clone();
+            async move {
+                while let Some(item) = stream.next().await {
+                    let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+                    if state.queue.len() >= self.capacity {
+                        match self.overflow {
+                            OverflowPolicy::DropOldest => {
+                                _ = state.queue.pop_front();
+                                if self.capacity > 0 {
+                                    state.queue.push_back(item);
+                                }
+                            }
+                            OverflowPolicy::DropNewest => {}
+                            OverflowPolicy::CloseOperation => {
+                                state.overflowed = true;
+                                break;
+                            }
+                        }
+                    } else {
+                        state.queue.push_back(item);
+                    }
+                    state.wake();
+                }
+                let mut state = state.lock().unwrap_or_else(|e| e.into_inner());
+                state.done = true;
+                state.wake();
+            }
+        });
+
+        BufferedStream { state, producer }.boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use juniper::futures::stream::{self, StreamExt as _};
+
+    use super::{
+        Buffered, ConnectionLimit, OutgoingBuffer, OverflowPolicy, RateLimit, RateLimiter,
+    };
+
+    #[test]
+    fn test_connection_limit_releases_slots_on_drop() {
+        let limit = ConnectionLimit::new(2);
+
+        let first = limit.acquire().expect("first slot is free");
+        let second = limit.clone().acquire().expect("second slot is free");
+        assert_eq!(limit.active(), 2);
+        assert!(limit.acquire().is_none(), "limit is exceeded");
+
+        drop(first);
+        assert_eq!(limit.active(), 1);
+        assert!(limit.acquire().is_some(), "slot is released");
+
+        drop(second);
+        assert_eq!(limit.active(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_resets_each_interval() {
+        let mut limiter = RateLimiter::new(Some(RateLimit {
+            max: 2,
+            interval: Duration::from_millis(20),
+        }));
+
+        assert!(limiter.check(1));
+        assert!(!limiter.check(2));
+        assert!(limiter.check(1));
+        assert!(!limiter.check(1));
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert!(limiter.check(2));
+    }
+
+    async fn collect_overflowed(overflow: OverflowPolicy) -> Vec<Option<i32>> {
+        let buffer = OutgoingBuffer {
+            capacity: 2,
+            overflow,
+        };
+        let mut s = buffer.wrap(stream::iter(1..=4).boxed());
+
+        // Let the producer task exhaust the wrapped stream before consuming anything.
+        tokio::task::yield_now().await;
+
+        let mut out = vec![];
+        while let Some(item) = s.next().await {
+            out.push(match item {
+                Buffered::Item(i) => Some(i),
+                Buffered::Overflow => None,
+            });
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn test_buffer_drops_oldest() {
+        assert_eq!(
+            collect_overflowed(OverflowPolicy::DropOldest).await,
+            [Some(3), Some(4)],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buffer_drops_newest() {
+        assert_eq!(
+            collect_overflowed(OverflowPolicy::DropNewest).await,
+            [Some(1), Some(2)],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_buffer_closes_operation() {
+        assert_eq!(
+            collect_overflowed(OverflowPolicy::CloseOperation).await,
+            [Some(1), Some(2), None],
+        );
+    }
+}
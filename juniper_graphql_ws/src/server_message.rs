@@ -12,7 +12,7 @@ use serde::{Serialize, Serializer};
 ///
 /// - For queries and mutations, this is sent to the client once.
 /// - For subscriptions, this is sent for every event in the event [`Stream`].
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NextPayload<S> {
     /// Execution result data.
@@ -24,6 +24,15 @@ pub struct NextPayload<S> {
     /// [`ErrorPayload`].
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<ExecutionError<S>>,
+
+    /// Additional information about the execution, if any.
+    ///
+    /// See [`ConnectionConfig::execution_metadata`] and [`ConnectionConfig::replay`] for details.
+    ///
+    /// [`ConnectionConfig::execution_metadata`]: crate::ConnectionConfig::execution_metadata
+    /// [`ConnectionConfig::replay`]: crate::ConnectionConfig::replay
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value<S>>,
 }
 
 /// Payload for errors that can happen before execution.
@@ -1,5 +1,18 @@
+use std::{
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    task::{Context, Poll},
+};
+
+use juniper::{Object, ScalarValue, Value, Variables, futures::Stream};
+use juniper_subscriptions::Replay;
 use serde::{Deserialize, Deserializer};
 
+use crate::{EventId, server_message::NextPayload};
+
 /// Deserializes `null`able value by placing the [`Default`] value instead of `null`.
 pub(crate) fn default_for_null<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
@@ -8,3 +21,92 @@ where
 {
     Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
 }
+
+/// Deserializes a non-empty batch of values, failing on an empty one.
+#[cfg(feature = "graphql-transport-ws")]
+pub(crate) fn non_empty_batch<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    use serde::de::Error as _;
+
+    let v = Vec::<T>::deserialize(deserializer)?;
+    if v.is_empty() {
+        Err(D::Error::invalid_length(
+            0,
+            &"non-empty batch of operations",
+        ))
+    } else {
+        Ok(v)
+    }
+}
+
+/// Extracts the last [`EventId`] seen by a client from the `lastEventId` key of the provided
+/// operation `extensions`, if any.
+pub(crate) fn last_event_id<S: ScalarValue>(extensions: &Variables<S>) -> Option<EventId> {
+    extensions
+        .get("lastEventId")?
+        .as_scalar()?
+        .try_as_str()?
+        .parse()
+        .ok()
+}
+
+/// Puts the provided [`EventId`] into the `eventId` key of the `extensions` of the provided
+/// [`NextPayload`].
+pub(crate) fn with_event_id<S: ScalarValue>(
+    mut payload: NextPayload<S>,
+    id: EventId,
+) -> NextPayload<S> {
+    let extensions = payload
+        .extensions
+        .get_or_insert_with(|| Value::Object(Object::with_capacity(1)));
+    if let Some(extensions) = extensions.as_mut_object_value() {
+        _ = extensions.add_field("eventId", Value::scalar(id.to_string()));
+    }
+    payload
+}
+
+/// [`Replay`] of a subscription, which is [released][0] once dropped, if its operation has been
+/// completed by the client (rather than the client being disconnected).
+///
+/// [0]: Replay::release
+pub(crate) struct CompletableReplay<T: Send + 'static> {
+    /// Wrapped [`Replay`].
+    replay: Option<Replay<T>>,
+
+    /// Indicator whether the operation has been completed by the client.
+    completed: Arc<AtomicBool>,
+}
+
+impl<T: Send + 'static> CompletableReplay<T> {
+    /// Wraps the provided [`Replay`] to be released once the `completed` indicator is set.
+    pub(crate) fn new(replay: Replay<T>, completed: Arc<AtomicBool>) -> Self {
+        Self {
+            replay: Some(replay),
+            completed,
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> Stream for CompletableReplay<T> {
+    type Item = <Replay<T> as Stream>::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.replay.as_mut() {
+            Some(replay) => Pin::new(replay).poll_next(cx),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl<T: Send + 'static> Drop for CompletableReplay<T> {
+    fn drop(&mut self) {
+        if self.completed.load(Ordering::Acquire) {
+            if let Some(replay) = self.replay.take() {
+                replay.release();
+            }
+        }
+    }
+}
@@ -519,4 +519,79 @@ mod tests {
     async fn test_custom_request_sync_hyper_integration() {
         run_hyper_integration(3003, true, true).await
     }
+
+    #[tokio::test]
+    async fn cancels_execution_on_client_disconnection() {
+        use std::{io::Write as _, net::TcpStream, sync::Mutex};
+
+        use juniper::{CancellationToken, DefaultScalarValue, Executor, graphql_object};
+
+        #[derive(Default)]
+        struct Context {
+            token: Mutex<Option<CancellationToken>>,
+        }
+
+        impl juniper::Context for Context {}
+
+        struct Query;
+
+        #[graphql_object(context = Context, scalar = DefaultScalarValue)]
+        impl Query {
+            async fn never(executor: &Executor<'_, '_, Context>) -> bool {
+                *executor.context().token.lock().unwrap() = executor.cancellation_token().cloned();
+                std::future::pending().await
+            }
+        }
+
+        let addr = SocketAddr::from(([127, 0, 0, 1], 3004));
+        let ctx = Arc::new(Context::default());
+        let root_node = Arc::new(RootNode::new(
+            Query,
+            EmptyMutation::<Context>::new(),
+            EmptySubscription::<Context>::new(),
+        ));
+
+        let listener = TcpListener::bind(addr).await.unwrap();
+        let server = task::spawn({
+            let ctx = ctx.clone();
+            async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                _ =
+                    http1::Builder::new()
+                        .serve_connection(
+                            TokioIo::new(stream),
+                            service_fn(move |req: Request<Incoming>| {
+                                let (root_node, ctx) = (root_node.clone(), ctx.clone());
+                                async move {
+                                    Ok::<_, Infallible>(super::graphql(root_node, ctx, req).await)
+                                }
+                            }),
+                        )
+                        .await;
+            }
+        });
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        write!(
+            client,
+            "GET /graphql?query=%7Bnever%7D HTTP/1.1\r\nHost: 127.0.0.1\r\n\r\n",
+        )
+        .unwrap();
+
+        let token = loop {
+            if let Some(token) = ctx.token.lock().unwrap().clone() {
+                break token;
+            }
+            sleep(Duration::from_millis(10)).await;
+        };
+        assert!(!token.is_cancelled());
+
+        drop(client);
+
+        tokio::time::timeout(Duration::from_secs(5), token.cancelled())
+            .await
+            .expect("client disconnection should cancel the execution");
+
+        server.abort();
+    }
 }
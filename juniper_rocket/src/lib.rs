@@ -13,23 +13,32 @@ mod for_tests_only {
     use futures as _;
 }
 
-use std::{borrow::Cow, io::Cursor};
+use std::{borrow::Cow, convert::Infallible, io::Cursor, sync::Arc};
 
 use derive_more::with_trait::{AsMut, AsRef};
 use juniper::{
     DefaultScalarValue, FieldError, GraphQLSubscriptionType, GraphQLType, GraphQLTypeAsync,
-    InputValue, RootNode, ScalarValue,
+    InputValue, Resolved, RootNode, ScalarValue, Value,
+    futures::{Stream, StreamExt as _, future, stream},
     http::{self, GraphQLBatchRequest},
 };
+use juniper_subscriptions::operation_fingerprint;
 use rocket::{
     Data, Request,
     data::{self, FromData, ToByteUnit},
     form::{DataField, Error, Errors, FromForm, Options, ValueField, error::ErrorKind},
     http::{ContentType, Status},
     outcome::Outcome,
-    response::{self, Responder, Response, content::RawHtml},
+    request::{self as req, FromRequest},
+    response::{
+        self, Responder, Response,
+        content::RawHtml,
+        stream::{Event, EventStream, stream},
+    },
 };
 
+pub use juniper_subscriptions::{EventId, ReplayBuffer};
+
 /// Simple wrapper around an incoming GraphQL request.
 ///
 /// See the [`http`] module for more information. This type can be constructed automatically from
@@ -199,6 +208,323 @@ where
     }
 }
 
+impl<S> GraphQLRequest<S>
+where
+    S: ScalarValue + Send + Sync,
+{
+    /// Asynchronously executes an incoming GraphQL operation, streaming its results as
+    /// [Server-Sent Events][SSE].
+    ///
+    /// Follows the "distinct connections mode" of the [GraphQL over SSE Protocol][0]: every
+    /// execution result is emitted as a `next` event, and once the operation is finished, a
+    /// `complete` event is emitted. Queries and mutations emit a single `next` event, while
+    /// subscriptions emit a `next` event for every event of their [`Stream`].
+    ///
+    /// Batched requests are not supported and result in a single error `next` event.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use juniper::{
+    ///     tests::fixtures::starwars::schema::{Database, Query, Subscription},
+    ///     EmptyMutation, RootNode,
+    /// };
+    /// use rocket::{
+    ///     futures::Stream,
+    ///     response::stream::{Event, EventStream},
+    ///     routes, State,
+    /// };
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation<Database>, Subscription>;
+    ///
+    /// #[rocket::get("/subscriptions?<request..>")]
+    /// fn subscriptions<'r>(
+    ///     db: &State<Database>,
+    ///     request: juniper_rocket::GraphQLRequest,
+    ///     schema: &'r State<Schema>,
+    /// ) -> EventStream<impl Stream<Item = Event> + Send + 'r> {
+    ///     request.subscribe(schema, db.inner().clone())
+    /// }
+    ///
+    /// let rocket = rocket::build()
+    ///     .manage(Database::new())
+    ///     .manage(Schema::new(Query, EmptyMutation::new(), Subscription))
+    ///     .mount("/", routes![subscriptions]);
+    /// ```
+    ///
+    /// [0]: https://github.com/enisdenjo/graphql-sse/blob/v2.5.3/PROTOCOL.md
+    /// [SSE]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+    pub fn subscribe<'r, CtxT, QueryT, MutationT, SubscriptionT>(
+        self,
+        root_node: &'r RootNode<QueryT, MutationT, SubscriptionT, S>,
+        context: CtxT,
+    ) -> EventStream<impl Stream<Item = Event> + Send + 'r>
+    where
+        QueryT: GraphQLTypeAsync<S, Context = CtxT>,
+        QueryT::TypeInfo: Sync,
+        MutationT: GraphQLTypeAsync<S, Context = CtxT>,
+        MutationT::TypeInfo: Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT>,
+        SubscriptionT::TypeInfo: Sync,
+        CtxT: Send + Sync + 'r,
+        S: 'r,
+    {
+        EventStream::from(stream! {
+            let request = match self.0 {
+                GraphQLBatchRequest::Single(request) => request,
+                GraphQLBatchRequest::Batch(_) => {
+                    yield next_event(&http::GraphQLResponse::<S>::error(FieldError::new(
+                        "Batched subscriptions are not supported",
+                        Value::null(),
+                    )));
+                    yield complete_event();
+                    return;
+                }
+            };
+
+            match http::execute_or_resolve_into_stream(&request, root_node, &context).await {
+                Ok(Resolved::Subscribed(stream, errors)) => {
+                    let mut stream = juniper_subscriptions::Connection::from_stream(stream, errors);
+                    while let Some(output) = stream.next().await {
+                        yield next_event(&http::GraphQLResponse::from_result(Ok((
+                            output.data,
+                            output.errors,
+                        ))));
+                    }
+                }
+                Ok(Resolved::Executed(data, errors)) => {
+                    yield next_event(&http::GraphQLResponse::from_result(Ok((data, errors))));
+                }
+                Err(e) => yield next_event(&http::GraphQLResponse::<S>::from_result(Err(e))),
+            }
+
+            yield complete_event();
+        })
+    }
+}
+
+impl<S> GraphQLRequest<S>
+where
+    S: ScalarValue + Send + Sync + 'static,
+{
+    /// Asynchronously executes an incoming GraphQL operation, streaming its results as
+    /// [Server-Sent Events][SSE], which may be resumed by a client after reconnecting.
+    ///
+    /// Behaves the same way as [`GraphQLRequest::subscribe()`], but the `next` events of
+    /// subscriptions are recorded into the provided [`ReplayBuffer`] and carry their [`EventId`]s
+    /// in the `id` field. Once a client reconnects, providing the [`EventId`] of the last event
+    /// it has seen (usually via the `Last-Event-ID` header, see [`LastEventId`]), the
+    /// subscription is resumed, replaying all the later events still present in the
+    /// [`ReplayBuffer`]. If the subscription cannot be resumed, it's executed anew.
+    ///
+    /// Subscriptions are bound to the provided `identity` of the client (like its authenticated
+    /// user or session), so they cannot be resumed by other clients.
+    ///
+    /// As subscriptions are driven independently from their clients, the `root_node` and the
+    /// `context` are required to be owned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::{sync::Arc, time::Duration};
+    ///
+    /// use juniper::{
+    ///     tests::fixtures::starwars::schema::{Database, Query, Subscription},
+    ///     EmptyMutation, RootNode,
+    /// };
+    /// use juniper_rocket::{LastEventId, ReplayBuffer};
+    /// use rocket::{
+    ///     futures::Stream,
+    ///     http::CookieJar,
+    ///     response::stream::{Event, EventStream},
+    ///     routes, State,
+    /// };
+    ///
+    /// type Schema = RootNode<Query, EmptyMutation<Database>, Subscription>;
+    ///
+    /// #[rocket::get("/subscriptions?<request..>")]
+    /// fn subscriptions(
+    ///     db: &State<Database>,
+    ///     request: juniper_rocket::GraphQLRequest,
+    ///     last_event_id: LastEventId,
+    ///     cookies: &CookieJar<'_>,
+    ///     schema: &State<Arc<Schema>>,
+    ///     buffer: &State<ReplayBuffer<String>>,
+    /// ) -> EventStream<impl Stream<Item = Event> + Send + 'static> {
+    ///     // Identity of the authenticated client.
+    ///     let session = cookies.get("session").map(|c| c.value().to_owned());
+    ///     request.subscribe_resumable(
+    ///         schema.inner().clone(),
+    ///         db.inner().clone(),
+    ///         buffer,
+    ///         session.unwrap_or_default(),
+    ///         last_event_id,
+    ///     )
+    /// }
+    ///
+    /// let rocket = rocket::build()
+    ///     .manage(Database::new())
+    ///     .manage(Arc::new(Schema::new(Query, EmptyMutation::new(), Subscription)))
+    ///     .manage(ReplayBuffer::<String>::new(100, Duration::from_secs(30)))
+    ///     .mount("/", routes![subscriptions]);
+    /// ```
+    ///
+    /// [SSE]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+    pub fn subscribe_resumable<CtxT, QueryT, MutationT, SubscriptionT>(
+        self,
+        root_node: Arc<RootNode<QueryT, MutationT, SubscriptionT, S>>,
+        context: CtxT,
+        buffer: &ReplayBuffer<String>,
+        identity: impl Into<String>,
+        last_event_id: impl Into<Option<EventId>>,
+    ) -> EventStream<impl Stream<Item = Event> + Send + 'static>
+    where
+        QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+        QueryT::TypeInfo: Send + Sync,
+        MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send + 'static,
+        MutationT::TypeInfo: Send + Sync,
+        SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send + 'static,
+        SubscriptionT::TypeInfo: Send + Sync,
+        CtxT: Send + Sync + 'static,
+    {
+        let buffer = buffer.clone();
+        let identity = identity.into();
+        let last_event_id = last_event_id.into();
+        EventStream::from(stream! {
+            let request = match self.0 {
+                GraphQLBatchRequest::Single(request) => request,
+                GraphQLBatchRequest::Batch(_) => {
+                    yield next_event(&http::GraphQLResponse::<S>::error(FieldError::new(
+                        "Batched subscriptions are not supported",
+                        Value::null(),
+                    )));
+                    yield complete_event();
+                    return;
+                }
+            };
+
+            let fingerprint = operation_fingerprint(
+                &request.query,
+                request.operation_name.as_deref(),
+                &request.variables(),
+            );
+            let replay = last_event_id.and_then(|id| buffer.resume(&identity, &fingerprint, id));
+            let mut replay = match replay {
+                Some(replay) => replay,
+                None => {
+                    let mut events = operation_events(request, root_node, context).boxed();
+                    match events.next().await {
+                        Some(OperationEvent::Next(data)) => buffer.record(
+                            identity,
+                            fingerprint,
+                            stream::once(future::ready(data))
+                                .chain(events.filter_map(|ev| future::ready(match ev {
+                                    OperationEvent::Next(data) => Some(data),
+                                    OperationEvent::Result(_) => None,
+                                })))
+                                .boxed(),
+                        ),
+                        Some(OperationEvent::Result(data)) => {
+                            yield Event::data(data).event("next");
+                            yield complete_event();
+                            return;
+                        }
+                        None => {
+                            yield complete_event();
+                            return;
+                        }
+                    }
+                }
+            };
+            while let Some((id, data)) = replay.next().await {
+                yield Event::data(data).event("next").id(id.to_string());
+            }
+
+            yield complete_event();
+        })
+    }
+}
+
+/// Event of an operation resolved by [`operation_events()`].
+enum OperationEvent {
+    /// Serialized [`http::GraphQLResponse`] of an executed query or mutation, or of an operation
+    /// failed to be resolved.
+    Result(String),
+
+    /// Serialized [`http::GraphQLResponse`] of a subscription event.
+    Next(String),
+}
+
+/// Resolves the provided `request` into a [`Stream`] of [`OperationEvent`]s, owning everything it
+/// requires.
+///
+/// The operation is parsed and validated only once: queries and mutations are executed, yielding a
+/// single [`OperationEvent::Result`], while subscriptions yield [`OperationEvent::Next`] for each
+/// of their events.
+fn operation_events<S, CtxT, QueryT, MutationT, SubscriptionT>(
+    request: http::GraphQLRequest<S>,
+    root_node: Arc<RootNode<QueryT, MutationT, SubscriptionT, S>>,
+    context: CtxT,
+) -> impl Stream<Item = OperationEvent> + Send
+where
+    S: ScalarValue + Send + Sync,
+    QueryT: GraphQLTypeAsync<S, Context = CtxT> + Send,
+    QueryT::TypeInfo: Send + Sync,
+    MutationT: GraphQLTypeAsync<S, Context = CtxT> + Send,
+    MutationT::TypeInfo: Send + Sync,
+    SubscriptionT: GraphQLSubscriptionType<S, Context = CtxT> + Send,
+    SubscriptionT::TypeInfo: Send + Sync,
+    CtxT: Send + Sync,
+{
+    stream! {
+        match http::execute_or_resolve_into_stream(&request, &*root_node, &context).await {
+            Ok(Resolved::Subscribed(stream, errors)) => {
+                let mut stream = juniper_subscriptions::Connection::from_stream(stream, errors);
+                while let Some(output) = stream.next().await {
+                    let response =
+                        http::GraphQLResponse::from_result(Ok((output.data, output.errors)));
+                    yield OperationEvent::Next(to_json(&response));
+                }
+            }
+            Ok(Resolved::Executed(data, errors)) => {
+                let response = http::GraphQLResponse::from_result(Ok((data, errors)));
+                yield OperationEvent::Result(to_json(&response));
+            }
+            Err(e) => {
+                let response = http::GraphQLResponse::<S>::from_result(Err(e));
+                yield OperationEvent::Result(to_json(&response));
+            }
+        }
+    }
+}
+
+/// Creates a `next` [`Event`] of the [GraphQL over SSE Protocol][0] with the provided
+/// [`http::GraphQLResponse`].
+///
+/// [0]: https://github.com/enisdenjo/graphql-sse/blob/v2.5.3/PROTOCOL.md
+fn next_event<S: ScalarValue>(response: &http::GraphQLResponse<S>) -> Event {
+    Event::data(to_json(response)).event("next")
+}
+
+/// Serializes the provided [`http::GraphQLResponse`] into JSON, replacing it with an error
+/// response if it fails to be serialized (e.g. by a custom [`ScalarValue`]).
+fn to_json<S: ScalarValue>(response: &http::GraphQLResponse<S>) -> String {
+    serde_json::to_string(response).unwrap_or_else(|e| {
+        serde_json::json!({
+            "data": null,
+            "errors": [{"message": format!("Failed to serialize response: {e}")}],
+        })
+        .to_string()
+    })
+}
+
+/// Creates a `complete` [`Event`] of the [GraphQL over SSE Protocol][0].
+///
+/// [0]: https://github.com/enisdenjo/graphql-sse/blob/v2.5.3/PROTOCOL.md
+fn complete_event() -> Event {
+    Event::data("").event("complete")
+}
+
 impl GraphQLResponse {
     /// Constructs an error response outside of the normal execution flow.
     ///
@@ -361,6 +687,34 @@ where
     }
 }
 
+/// [Rocket] request guard extracting the [`EventId`] of the last event seen by a client from the
+/// `Last-Event-ID` header, to be passed into [`GraphQLRequest::subscribe_resumable()`].
+///
+/// Never fails: contains [`None`] if the header is absent or is not a valid [`EventId`].
+///
+/// [Rocket]: https://rocket.rs
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct LastEventId(pub Option<EventId>);
+
+impl From<LastEventId> for Option<EventId> {
+    fn from(id: LastEventId) -> Self {
+        id.0
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for LastEventId {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> req::Outcome<Self, Self::Error> {
+        Outcome::Success(Self(
+            req.headers()
+                .get_one("Last-Event-ID")
+                .and_then(|id| id.trim().parse().ok()),
+        ))
+    }
+}
+
 const BODY_LIMIT: u64 = 1024 * 100;
 
 #[rocket::async_trait]
@@ -4,25 +4,29 @@
 
 use futures::executor;
 use juniper::{
-    EmptyMutation, EmptySubscription, RootNode,
+    EmptyMutation, RootNode,
     http::tests::{HttpIntegration, TestResponse, run_http_test_suite},
-    tests::fixtures::starwars::schema::{Database, Query},
+    tests::fixtures::starwars::schema::{Database, Query, Subscription},
 };
 use juniper_rocket::{GraphQLRequest, GraphQLResponse};
 use rocket::{
-    Build, Rocket, State, get,
+    Build, Rocket, State,
+    futures::Stream,
+    get,
     http::ContentType,
     local::asynchronous::{Client, LocalResponse},
-    post, routes,
+    post,
+    response::stream::{Event, EventStream},
+    routes,
 };
 
-type Schema = RootNode<Query, EmptyMutation<Database>, EmptySubscription<Database>>;
+type Schema = RootNode<Query, EmptyMutation<Database>, Subscription>;
 
 fn bootstrap_rocket() -> Rocket<Build> {
     Rocket::build().manage(Database::new()).manage(Schema::new(
         Query,
         EmptyMutation::<Database>::new(),
-        EmptySubscription::<Database>::new(),
+        Subscription,
     ))
 }
 
@@ -48,6 +52,19 @@ fn make_rocket() -> Rocket<Build> {
     bootstrap_rocket().mount("/", routes![post_handler, get_handler])
 }
 
+#[get("/subscriptions?<request..>")]
+fn subscriptions_handler<'r>(
+    context: &State<Database>,
+    request: GraphQLRequest,
+    schema: &'r State<Schema>,
+) -> EventStream<impl Stream<Item = Event> + Send + 'r> {
+    request.subscribe(schema, context.inner().clone())
+}
+
+fn make_rocket_with_subscriptions() -> Rocket<Build> {
+    make_rocket().mount("/", routes![subscriptions_handler])
+}
+
 #[get("/?<request..>")]
 fn get_handler_sync(
     context: &State<Database>,
@@ -124,6 +141,14 @@ async fn test_rocket_integration() {
     run_http_test_suite(&TestRocketIntegration { client });
 }
 
+#[rocket::async_test]
+async fn test_rocket_integration_with_subscriptions() {
+    let rocket = make_rocket_with_subscriptions();
+    let client = Client::untracked(rocket).await.expect("valid rocket");
+
+    run_http_test_suite(&TestRocketIntegration { client });
+}
+
 #[rocket::async_test]
 async fn test_sync_rocket_integration() {
     let rocket = make_sync_rocket();
@@ -0,0 +1,323 @@
+//! Subscriptions over [Server-Sent Events][SSE] testing for [`juniper`].
+//!
+//! [SSE]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+
+#![expect(unused_crate_dependencies, reason = "single test case")]
+
+use std::{sync::Arc, time::Duration};
+
+use juniper::{
+    EmptyMutation, RootNode,
+    tests::fixtures::starwars::schema::{Database, Query, Subscription},
+};
+use juniper_rocket::{GraphQLRequest, LastEventId, ReplayBuffer};
+use rocket::{
+    Build, Rocket, State,
+    futures::Stream,
+    get,
+    http::{ContentType, Cookie, CookieJar, Header},
+    local::asynchronous::Client,
+    post,
+    response::stream::{Event, EventStream},
+    routes,
+};
+use serde_json::{Value as Json, json};
+
+type Schema = RootNode<Query, EmptyMutation<Database>, Subscription>;
+
+#[get("/?<request..>")]
+fn get_handler<'r>(
+    context: &State<Database>,
+    request: GraphQLRequest,
+    schema: &'r State<Schema>,
+) -> EventStream<impl Stream<Item = Event> + Send + 'r> {
+    request.subscribe(schema, context.inner().clone())
+}
+
+#[post("/", data = "<request>")]
+fn post_handler<'r>(
+    context: &State<Database>,
+    request: GraphQLRequest,
+    schema: &'r State<Schema>,
+) -> EventStream<impl Stream<Item = Event> + Send + 'r> {
+    request.subscribe(schema, context.inner().clone())
+}
+
+#[post("/resumable", data = "<request>")]
+fn resumable_handler(
+    context: &State<Database>,
+    request: GraphQLRequest,
+    last_event_id: LastEventId,
+    cookies: &CookieJar<'_>,
+    schema: &State<Arc<Schema>>,
+    buffer: &State<ReplayBuffer<String>>,
+) -> EventStream<impl Stream<Item = Event> + Send + 'static> {
+    let session = cookies.get("session").map(|c| c.value().to_owned());
+    request.subscribe_resumable(
+        schema.inner().clone(),
+        context.inner().clone(),
+        buffer,
+        session.unwrap_or_default(),
+        last_event_id,
+    )
+}
+
+fn make_rocket() -> Rocket<Build> {
+    Rocket::build()
+        .manage(Database::new())
+        .manage(Schema::new(Query, EmptyMutation::new(), Subscription))
+        .manage(Arc::new(Schema::new(
+            Query,
+            EmptyMutation::new(),
+            Subscription,
+        )))
+        .manage(ReplayBuffer::<String>::new(10, Duration::from_secs(10)))
+        .mount("/", routes![get_handler, post_handler, resumable_handler])
+}
+
+/// Parses the provided [SSE] response body into a list of `(event, data)` pairs.
+///
+/// [SSE]: https://html.spec.whatwg.org/multipage/server-sent-events.html
+fn parse_events(body: &str) -> Vec<(String, Option<Json>)> {
+    body.split("\n\n")
+        .filter(|chunk| !chunk.trim().is_empty())
+        .filter_map(|chunk| {
+            let mut event = None;
+            let mut data = String::new();
+            for line in chunk.lines() {
+                if let Some(name) = line.strip_prefix("event:") {
+                    event = Some(name.trim().to_owned());
+                } else if let Some(line) = line.strip_prefix("data:") {
+                    data.push_str(line.trim());
+                }
+            }
+            event.map(|event| {
+                let data = (!data.is_empty())
+                    .then(|| serde_json::from_str(&data).expect("invalid JSON in `data`"));
+                (event, data)
+            })
+        })
+        .collect()
+}
+
+async fn post_json(body: &str) -> (String, Vec<(String, Option<Json>)>) {
+    let client = Client::untracked(make_rocket())
+        .await
+        .expect("valid rocket");
+    let response = client
+        .post("/")
+        .header(ContentType::JSON)
+        .body(body)
+        .dispatch()
+        .await;
+    let content_type = response
+        .content_type()
+        .expect("no `Content-Type` header from handler")
+        .to_string();
+    let body = response
+        .into_string()
+        .await
+        .expect("no body returned from GraphQL handler");
+    (content_type, parse_events(&body))
+}
+
+#[rocket::async_test]
+async fn test_simple_subscription() {
+    let (content_type, events) =
+        post_json(r#"{"query": "subscription { asyncHuman { id, name, homePlanet } }"}"#).await;
+
+    assert_eq!(content_type, "text/event-stream");
+    assert_eq!(
+        events,
+        [
+            (
+                "next".into(),
+                Some(json!({
+                    "data": {
+                        "asyncHuman": {
+                            "id": "1000",
+                            "name": "Luke Skywalker",
+                            "homePlanet": "Tatooine",
+                        },
+                    },
+                })),
+            ),
+            ("complete".into(), None),
+        ],
+    );
+}
+
+#[rocket::async_test]
+async fn test_get_subscription() {
+    let client = Client::untracked(make_rocket())
+        .await
+        .expect("valid rocket");
+    // subscription { asyncHuman { name } }
+    let response = client
+        .get("/?query=subscription%20%7B%20asyncHuman%20%7B%20name%20%7D%20%7D")
+        .dispatch()
+        .await;
+    let body = response.into_string().await.expect("no body returned");
+
+    assert_eq!(
+        parse_events(&body),
+        [
+            (
+                "next".into(),
+                Some(json!({"data": {"asyncHuman": {"name": "Luke Skywalker"}}})),
+            ),
+            ("complete".into(), None),
+        ],
+    );
+}
+
+#[rocket::async_test]
+async fn test_query() {
+    let (_, events) = post_json(r#"{"query": "{ hero { name } }"}"#).await;
+
+    assert_eq!(
+        events,
+        [
+            (
+                "next".into(),
+                Some(json!({"data": {"hero": {"name": "R2-D2"}}})),
+            ),
+            ("complete".into(), None),
+        ],
+    );
+}
+
+#[rocket::async_test]
+async fn test_invalid_query() {
+    let (_, events) = post_json(r#"{"query": "subscription { asyncHuman }"}"#).await;
+
+    assert_eq!(
+        events,
+        [
+            (
+                "next".into(),
+                Some(json!({
+                    "errors": [{
+                        "message": "Field \"asyncHuman\" of type \"Human!\" must have a selection \
+                                    of subfields. Did you mean \"asyncHuman { ... }\"?",
+                        "locations": [{"line": 1, "column": 16}],
+                        "extensions": {"code": "GRAPHQL_VALIDATION_FAILED"},
+                    }],
+                })),
+            ),
+            ("complete".into(), None),
+        ],
+    );
+}
+
+#[rocket::async_test]
+async fn test_batch_request() {
+    let (_, events) =
+        post_json(r#"[{"query": "{ hero { name } }"}, {"query": "{ hero { name } }"}]"#).await;
+
+    assert_eq!(
+        events,
+        [
+            (
+                "next".into(),
+                Some(json!({
+                    "data": null,
+                    "errors": [{
+                        "message": "Batched subscriptions are not supported",
+                        "locations": [{"line": 1, "column": 1}],
+                        "path": [],
+                    }],
+                })),
+            ),
+            ("complete".into(), None),
+        ],
+    );
+}
+
+#[rocket::async_test]
+async fn test_resumable_subscription() {
+    let client = Client::untracked(make_rocket())
+        .await
+        .expect("valid rocket");
+    let subscribe = async |session: &'static str, last_event_id: Option<String>| {
+        let mut request = client
+            .post("/resumable")
+            .header(ContentType::JSON)
+            .cookie(Cookie::new("session", session))
+            .body(r#"{"query": "subscription { asyncHuman { name } }"}"#);
+        if let Some(id) = last_event_id {
+            request = request.header(Header::new("Last-Event-ID", id));
+        }
+        request
+            .dispatch()
+            .await
+            .into_string()
+            .await
+            .expect("no body returned")
+    };
+    let event_id = |body: &str| {
+        body.lines()
+            .find_map(|line| line.strip_prefix("id:"))
+            .expect("no event ID")
+            .to_owned()
+    };
+    let human = || {
+        (
+            "next".into(),
+            Some(json!({"data": {"asyncHuman": {"name": "Luke Skywalker"}}})),
+        )
+    };
+
+    let body = subscribe("alice", None).await;
+    let id = event_id(&body);
+    let (stream, seq) = id.split_once(':').unwrap();
+    assert_eq!(seq, "1");
+    assert_eq!(parse_events(&body), [human(), ("complete".into(), None)]);
+
+    // All the events have been seen already, so nothing is replayed.
+    let body = subscribe("alice", Some(id.clone())).await;
+    assert_eq!(parse_events(&body), [("complete".into(), None)]);
+
+    let body = subscribe("alice", Some(format!("{stream}:0"))).await;
+    assert_eq!(event_id(&body), id);
+    assert_eq!(parse_events(&body), [human(), ("complete".into(), None)]);
+
+    // Subscriptions of other clients are executed anew.
+    let body = subscribe("bob", Some(format!("{stream}:0"))).await;
+    assert_ne!(event_id(&body), id);
+    assert_eq!(parse_events(&body), [human(), ("complete".into(), None)]);
+
+    // Unknown subscriptions are executed anew.
+    let body = subscribe("alice", Some("42:0".into())).await;
+    assert_ne!(event_id(&body), id);
+    assert_eq!(parse_events(&body), [human(), ("complete".into(), None)]);
+}
+
+#[rocket::async_test]
+async fn test_resumable_query() {
+    let client = Client::untracked(make_rocket())
+        .await
+        .expect("valid rocket");
+    let body = client
+        .post("/resumable")
+        .header(ContentType::JSON)
+        .body(r#"{"query": "{ hero { name } }"}"#)
+        .dispatch()
+        .await
+        .into_string()
+        .await
+        .expect("no body returned");
+
+    // Queries are not recorded, so carry no event IDs.
+    assert!(!body.contains("id:"), "unexpected event ID in: {body}");
+    assert_eq!(
+        parse_events(&body),
+        [
+            (
+                "next".into(),
+                Some(json!({"data": {"hero": {"name": "R2-D2"}}})),
+            ),
+            ("complete".into(), None),
+        ],
+    );
+}
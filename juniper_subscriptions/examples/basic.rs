@@ -9,6 +9,10 @@ use juniper::{
 };
 use juniper_subscriptions::Coordinator;
 
+// Required by the `juniper_subscriptions::ReplayBuffer` only.
+#[cfg(feature = "replay")]
+use getrandom as _;
+
 #[derive(Clone)]
 struct Database;
 
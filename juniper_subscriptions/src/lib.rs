@@ -1,7 +1,11 @@
+#![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(any(doc, test), doc = include_str!("../README.md"))]
 #![cfg_attr(not(any(doc, test)), doc = env!("CARGO_PKG_NAME"))]
 #![warn(missing_docs)]
 
+#[cfg(feature = "replay")]
+mod replay;
+
 use std::{
     pin::Pin,
     task::{self, Poll},
@@ -14,6 +18,9 @@ use juniper::{
     ValuesStream, http::GraphQLRequest,
 };
 
+#[cfg(feature = "replay")]
+pub use self::replay::{EventId, InvalidEventId, Replay, ReplayBuffer, operation_fingerprint};
+
 /// Simple [`SubscriptionCoordinator`] implementation:
 /// - contains the schema
 /// - handles subscription start
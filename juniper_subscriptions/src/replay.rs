@@ -0,0 +1,632 @@
+//! Replaying of subscription events to clients resuming their subscriptions after reconnecting.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    error::Error,
+    fmt,
+    pin::Pin,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    task::{self, Poll, Waker},
+    time::Duration,
+};
+
+use futures::{Stream, StreamExt as _, stream::BoxStream};
+use juniper::{ScalarValue, Variables};
+use tokio::task::AbortHandle;
+
+/// Identifier of an event emitted by a resumable subscription.
+///
+/// Rendered as `<stream>:<seq>`, where `stream` is the random (so unguessable) hexadecimal
+/// identifier of the subscription [`Stream`] the event has been emitted by, and `seq` is the
+/// sequential number of the event in this [`Stream`], starting from `1`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EventId {
+    /// Identifier of the subscription [`Stream`] the event has been emitted by.
+    stream: u128,
+
+    /// Sequential number of the event in its subscription [`Stream`].
+    seq: u64,
+}
+
+impl EventId {
+    /// Returns the identifier of the subscription [`Stream`] this event has been emitted by.
+    #[must_use]
+    pub fn stream(&self) -> u128 {
+        self.stream
+    }
+
+    /// Returns the sequential number of this event in its subscription [`Stream`], starting
+    /// from `1`.
+    #[must_use]
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+}
+
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}:{}", self.stream, self.seq)
+    }
+}
+
+impl FromStr for EventId {
+    type Err = InvalidEventId;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (stream, seq) = s.split_once(':').ok_or(InvalidEventId)?;
+        Ok(Self {
+            stream: u128::from_str_radix(stream, 16).map_err(|_| InvalidEventId)?,
+            seq: seq.parse().map_err(|_| InvalidEventId)?,
+        })
+    }
+}
+
+/// Error of parsing an [`EventId`] from a string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidEventId;
+
+impl fmt::Display for InvalidEventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid event ID, expected `<stream>:<seq>`")
+    }
+}
+
+impl Error for InvalidEventId {}
+
+/// Buffer of the recent events emitted by resumable subscriptions, shared by all the connections
+/// it's provided to.
+///
+/// Every [`record()`]ed subscription [`Stream`] is driven independently from its client, and
+/// keeps being driven for the [`retention()`] duration once the client disconnects, so the events
+/// emitted in between are not lost. Once the client reconnects, it may [`resume()`] the
+/// subscription by providing the [`EventId`] of the last event it has seen, receiving all the
+/// later events still present in the buffer. A subscription [`Replay::release()`]d by its client
+/// stops being driven immediately instead.
+///
+/// Every subscription is bound to the identity of the client it has been [`record()`]ed for, so
+/// it cannot be [`resume()`]d by other clients, even if they know its [`EventId`]s.
+///
+/// [`Clone`]s of a [`ReplayBuffer`] share the same events, so the same [`ReplayBuffer`] should be
+/// [`Clone`]d into every connection accepted by a server.
+///
+/// # Runtime
+///
+/// Subscriptions are driven by [`tokio::spawn()`]ed tasks, so a [Tokio] runtime is required.
+///
+/// [`record()`]: ReplayBuffer::record
+/// [`resume()`]: ReplayBuffer::resume
+/// [`retention()`]: ReplayBuffer::retention
+/// [Tokio]: https://tokio.rs
+pub struct ReplayBuffer<T> {
+    /// Maximum number of the recent events retained for a single subscription.
+    window: usize,
+
+    /// Duration a subscription keeps being driven for after its client disconnects.
+    retention: Duration,
+
+    /// Subscriptions recorded by this [`ReplayBuffer`].
+    registry: Arc<Mutex<Registry<T>>>,
+}
+
+impl<T> Clone for ReplayBuffer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            window: self.window,
+            retention: self.retention,
+            registry: self.registry.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ReplayBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplayBuffer")
+            .field("window", &self.window)
+            .field("retention", &self.retention)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Subscriptions recorded by a [`ReplayBuffer`].
+struct Registry<T> {
+    /// [`Log`]s of the recorded subscriptions.
+    logs: HashMap<u128, Arc<Mutex<Log<T>>>>,
+}
+
+/// Recent events of a single recorded subscription.
+struct Log<T> {
+    /// Identity of the client the subscription has been recorded for.
+    identity: String,
+
+    /// Fingerprint of the operation the subscription has been started by.
+    fingerprint: String,
+
+    /// Retained events along with their sequential numbers.
+    events: VecDeque<(u64, T)>,
+
+    /// Sequential number of the last emitted event.
+    last_seq: u64,
+
+    /// Indicator whether the subscription [`Stream`] has finished.
+    done: bool,
+
+    /// Number of clients currently consuming the events.
+    consumers: usize,
+
+    /// Number of times the events have stopped being consumed by any client.
+    detached: u64,
+
+    /// [`Waker`]s of the clients waiting for new events.
+    wakers: Vec<Waker>,
+
+    /// [`AbortHandle`] of the task driving the subscription [`Stream`].
+    producer: Option<AbortHandle>,
+}
+
+impl<T> Log<T> {
+    fn wake(&mut self) {
+        for waker in self.wakers.drain(..) {
+            waker.wake();
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> ReplayBuffer<T> {
+    /// Creates a new [`ReplayBuffer`] retaining at most `window` recent events of every
+    /// subscription, and driving subscriptions for the `retention` duration after their clients
+    /// disconnect.
+    #[must_use]
+    pub fn new(window: usize, retention: Duration) -> Self {
+        Self {
+            window: window.max(1),
+            retention,
+            registry: Arc::new(Mutex::new(Registry {
+                logs: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Returns the maximum number of the recent events retained for a single subscription.
+    ///
+    /// A client lagging behind for more events misses the older ones.
+    #[must_use]
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the duration a subscription keeps being driven for after its client disconnects.
+    #[must_use]
+    pub fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    /// Records the provided subscription [`Stream`] started by the operation with the provided
+    /// `fingerprint` for the client with the provided `identity`, driving it in a separate task.
+    ///
+    /// The `identity` should identify the client (like its authenticated user or session), so
+    /// the subscription cannot be [`resume()`]d by other clients. The `fingerprint` should
+    /// identify the operation (its document, operation name and variables), so it cannot be
+    /// [`resume()`]d by a different one.
+    ///
+    /// # Panics
+    ///
+    /// If the operating system fails to provide randomness for the identifier of the subscription.
+    ///
+    /// [`resume()`]: ReplayBuffer::resume
+    pub fn record(
+        &self,
+        identity: impl Into<String>,
+        fingerprint: impl Into<String>,
+        mut stream: BoxStream<'static, T>,
+    ) -> Replay<T> {
+        let log = Arc::new(Mutex::new(Log {
+            identity: identity.into(),
+            fingerprint: fingerprint.into(),
+            events: VecDeque::new(),
+            last_seq: 0,
+            done: false,
+            consumers: 1,
+            detached: 0,
+            wakers: vec![],
+            producer: None,
+        }));
+        let stream_id = {
+            let mut registry = self.lock_registry();
+            let id = loop {
+                let id = random_stream_id();
+                if !registry.logs.contains_key(&id) {
+                    break id;
+                }
+            };
+            _ = registry.logs.insert(id, log.clone());
+            id
+        };
+
+        let producer = tokio::spawn({
+            let log = log.clone();
+            let window = self.window;
+            async move {
+                let _guard = DoneGuard(log.clone());
+                while let Some(item) = stream.next().await {
+                    let mut log = lock(&log);
+                    log.last_seq += 1;
+                    let seq = log.last_seq;
+                    log.events.push_back((seq, item));
+                    if log.events.len() > window {
+                        _ = log.events.pop_front();
+                    }
+                    log.wake();
+                }
+            }
+        });
+        let mut guard = lock(&log);
+        // The task might have already finished, so there is nothing to abort anymore.
+        if !guard.done {
+            guard.producer = Some(producer.abort_handle());
+        }
+        drop(guard);
+
+        Replay {
+            buffer: self.clone(),
+            stream: stream_id,
+            log,
+            next_seq: 1,
+            released: false,
+        }
+    }
+
+    /// Resumes the subscription, which the event with the provided [`EventId`] has been emitted
+    /// by, replaying all its later events still present in this [`ReplayBuffer`].
+    ///
+    /// Returns [`None`] if the subscription is not present in this [`ReplayBuffer`] anymore, or
+    /// has been recorded for a client with a different `identity`, or has been started by an
+    /// operation with a different `fingerprint`.
+    #[must_use]
+    pub fn resume(
+        &self,
+        identity: &str,
+        fingerprint: &str,
+        last_event_id: EventId,
+    ) -> Option<Replay<T>> {
+        let log = self
+            .lock_registry()
+            .logs
+            .get(&last_event_id.stream)?
+            .clone();
+        {
+            let mut log = lock(&log);
+            if log.identity != identity || log.fingerprint != fingerprint {
+                return None;
+            }
+            log.consumers += 1;
+        }
+        Some(Replay {
+            buffer: self.clone(),
+            stream: last_event_id.stream,
+            log,
+            next_seq: last_event_id.seq + 1,
+            released: false,
+        })
+    }
+
+    fn lock_registry(&self) -> std::sync::MutexGuard<'_, Registry<T>> {
+        self.registry.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Returns a fingerprint of the operation with the provided document, operation name and
+/// variables, identifying it in a [`ReplayBuffer`].
+///
+/// The `variables` are sorted, so the fingerprint doesn't depend on their order.
+#[must_use]
+pub fn operation_fingerprint<S: ScalarValue>(
+    query: &str,
+    operation_name: Option<&str>,
+    variables: &Variables<S>,
+) -> String {
+    let mut variables = variables
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>();
+    variables.sort();
+    format!(
+        "{}\n{}\n{query}",
+        operation_name.unwrap_or_default(),
+        variables.join(", "),
+    )
+}
+
+/// Generates a new random identifier of a subscription [`Stream`].
+fn random_stream_id() -> u128 {
+    let mut bytes = [0; 16];
+    getrandom::fill(&mut bytes).expect("failed to generate random subscription ID");
+    u128::from_ne_bytes(bytes)
+}
+
+/// Locks the provided [`Log`], ignoring its poisoning.
+fn lock<T>(log: &Mutex<Log<T>>) -> std::sync::MutexGuard<'_, Log<T>> {
+    log.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Guard marking a [`Log`] as done once the task driving its subscription [`Stream`] finishes,
+/// either normally, by panicking or by being aborted.
+struct DoneGuard<T>(Arc<Mutex<Log<T>>>);
+
+impl<T> Drop for DoneGuard<T> {
+    fn drop(&mut self) {
+        let mut log = lock(&self.0);
+        log.done = true;
+        log.producer = None;
+        log.wake();
+    }
+}
+
+/// [`Stream`] of the events of a subscription recorded by a [`ReplayBuffer`], along with their
+/// [`EventId`]s.
+///
+/// Once dropped, the subscription keeps being driven for the [`ReplayBuffer::retention()`]
+/// duration, unless resumed again. Use [`Replay::release()`] once the client completes the
+/// subscription, so it stops being driven immediately.
+pub struct Replay<T: Send + 'static> {
+    /// [`ReplayBuffer`] the subscription is recorded by.
+    buffer: ReplayBuffer<T>,
+
+    /// Identifier of the subscription [`Stream`].
+    stream: u128,
+
+    /// [`Log`] of the subscription.
+    log: Arc<Mutex<Log<T>>>,
+
+    /// Sequential number of the next event to emit.
+    next_seq: u64,
+
+    /// Indicator whether the subscription has been [released](Replay::release) by its client.
+    released: bool,
+}
+
+impl<T: Send + 'static> fmt::Debug for Replay<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Replay")
+            .field("stream", &self.stream)
+            .field("next_seq", &self.next_seq)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + Send + 'static> Stream for Replay<T> {
+    type Item = (EventId, T);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let mut log = lock(&this.log);
+        if let Some((seq, item)) = log.events.iter().find(|(seq, _)| *seq >= this.next_seq) {
+            this.next_seq = seq + 1;
+            let id = EventId {
+                stream: this.stream,
+                seq: *seq,
+            };
+            Poll::Ready(Some((id, item.clone())))
+        } else if log.done {
+            Poll::Ready(None)
+        } else {
+            if !log.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                log.wakers.push(cx.waker().clone());
+            }
+            Poll::Pending
+        }
+    }
+}
+
+impl<T: Send + 'static> Replay<T> {
+    /// Releases the subscription, as its client has completed it.
+    ///
+    /// Unlike dropping, stops driving the subscription [`Stream`] and removes it from the
+    /// [`ReplayBuffer`] immediately, unless it's still consumed by other clients.
+    pub fn release(mut self) {
+        self.released = true;
+    }
+}
+
+impl<T: Send + 'static> Drop for Replay<T> {
+    fn drop(&mut self) {
+        let detached = {
+            let mut log = lock(&self.log);
+            log.consumers -= 1;
+            if log.consumers > 0 {
+                return;
+            }
+            log.detached += 1;
+            log.detached
+        };
+
+        if self.released {
+            return forget(&self.buffer.registry, self.stream, &self.log, detached);
+        }
+        // Outside a runtime, there is no task to wait for retention in, so forget immediately.
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return forget(&self.buffer.registry, self.stream, &self.log, detached);
+        };
+        let (registry, stream, log) = (self.buffer.registry.clone(), self.stream, self.log.clone());
+        let retention = self.buffer.retention;
+        _ = runtime.spawn(async move {
+            tokio::time::sleep(retention).await;
+            forget(&registry, stream, &log, detached);
+        });
+    }
+}
+
+/// Removes the provided [`Log`] from the [`Registry`] and stops driving its subscription
+/// [`Stream`], unless it has been resumed since it was `detached`.
+fn forget<T>(registry: &Mutex<Registry<T>>, stream: u128, log: &Mutex<Log<T>>, detached: u64) {
+    let producer = {
+        let mut log = lock(log);
+        if log.consumers > 0 || log.detached != detached {
+            return;
+        }
+        log.producer.take()
+    };
+    if let Some(producer) = producer {
+        producer.abort();
+    }
+    _ = registry
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .logs
+        .remove(&stream);
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::{StreamExt as _, stream};
+    use juniper::{DefaultScalarValue, Variables, graphql_vars};
+
+    use super::{EventId, InvalidEventId, ReplayBuffer, operation_fingerprint};
+
+    #[test]
+    fn fingerprints_regardless_of_variables_order() {
+        let fingerprint = |variables: &Variables<DefaultScalarValue>| {
+            operation_fingerprint(
+                "query Foo($a: Int, $b: Int) { foo(a: $a, b: $b) }",
+                Some("Foo"),
+                variables,
+            )
+        };
+
+        assert_eq!(
+            fingerprint(&graphql_vars! {"a": 1, "b": 2}),
+            fingerprint(&graphql_vars! {"b": 2, "a": 1}),
+        );
+        assert_ne!(
+            fingerprint(&graphql_vars! {"a": 1, "b": 2}),
+            fingerprint(&graphql_vars! {"a": 2, "b": 1}),
+        );
+    }
+
+    #[test]
+    fn parses_event_id() {
+        let id = "3f:14".parse::<EventId>().unwrap();
+
+        assert_eq!((id.stream(), id.seq()), (0x3f, 14));
+        assert_eq!(id.to_string(), "0000000000000000000000000000003f:14");
+        assert_eq!("3".parse::<EventId>(), Err(InvalidEventId));
+        assert_eq!("3:x".parse::<EventId>(), Err(InvalidEventId));
+        assert_eq!("xyz:1".parse::<EventId>(), Err(InvalidEventId));
+    }
+
+    #[tokio::test]
+    async fn resumes_after_last_event() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let events = buffer
+            .record("alice", "foo", stream::iter(1..=3).boxed())
+            .collect::<Vec<_>>()
+            .await;
+        let seqs = events.iter().map(|(id, _)| id.seq()).collect::<Vec<_>>();
+        assert_eq!(seqs, [1, 2, 3]);
+        assert!(
+            events
+                .iter()
+                .all(|(id, _)| id.stream() == events[0].0.stream())
+        );
+
+        let last = events[0].0;
+        let resumed = buffer
+            .resume("alice", "foo", last)
+            .unwrap()
+            .map(|(_, n)| n)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(resumed, [2, 3]);
+    }
+
+    #[tokio::test]
+    async fn generates_random_stream_ids() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let (first, _) = buffer
+            .record("alice", "foo", stream::iter(1..=3).boxed())
+            .next()
+            .await
+            .unwrap();
+        let (second, _) = buffer
+            .record("alice", "foo", stream::iter(1..=3).boxed())
+            .next()
+            .await
+            .unwrap();
+
+        assert_ne!(first.stream(), second.stream());
+        assert_ne!(second.stream(), first.stream() + 1);
+    }
+
+    #[tokio::test]
+    async fn does_not_resume_different_operation() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let (last, _) = buffer
+            .record("alice", "foo", stream::iter(1..=3).boxed())
+            .next()
+            .await
+            .unwrap();
+
+        assert!(buffer.resume("alice", "bar", last).is_none());
+        assert!(
+            buffer
+                .resume("alice", "foo", "2:1".parse().unwrap())
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_resume_for_different_identity() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let (last, _) = buffer
+            .record("alice", "foo", stream::iter(1..=3).boxed())
+            .next()
+            .await
+            .unwrap();
+
+        assert!(buffer.resume("bob", "foo", last).is_none());
+        assert!(buffer.resume("alice", "foo", last).is_some());
+    }
+
+    #[tokio::test]
+    async fn retains_window_only() {
+        let buffer = ReplayBuffer::new(2, Duration::from_secs(10));
+
+        let replay = buffer.record("alice", "foo", stream::iter(1..=5).boxed());
+        // Let the subscription be driven to its end before consuming anything.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let events = replay.map(|(_, n)| n).collect::<Vec<_>>().await;
+        assert_eq!(events, [4, 5]);
+    }
+
+    #[tokio::test]
+    async fn forgets_after_retention() {
+        let buffer = ReplayBuffer::new(10, Duration::from_millis(10));
+
+        let (last, _) = buffer
+            .record("alice", "foo", stream::iter(1..=3).boxed())
+            .next()
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(buffer.resume("alice", "foo", last).is_none());
+    }
+
+    #[tokio::test]
+    async fn forgets_released_immediately() {
+        let buffer = ReplayBuffer::new(10, Duration::from_secs(10));
+
+        let mut replay = buffer.record("alice", "foo", stream::iter(1..=3).boxed());
+        let (last, _) = replay.next().await.unwrap();
+        replay.release();
+
+        assert!(buffer.resume("alice", "foo", last).is_none());
+    }
+}
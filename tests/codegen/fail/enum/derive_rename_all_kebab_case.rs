@@ -0,0 +1,9 @@
+use juniper::GraphQLEnum;
+
+#[derive(GraphQLEnum)]
+#[graphql(rename_all = "kebab-case")]
+enum Test {
+    NewHope,
+}
+
+fn main() {}
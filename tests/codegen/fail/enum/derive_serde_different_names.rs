@@ -0,0 +1,11 @@
+use juniper::GraphQLEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, GraphQLEnum, Serialize)]
+#[graphql(serde)]
+enum Test {
+    #[serde(rename(serialize = "new", deserialize = "old"))]
+    NewHope,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use juniper::GraphQLErrorCode;
+
+#[derive(GraphQLErrorCode)]
+enum Error {
+    Internal { code: i32 },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("internal")
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use juniper::GraphQLErrorCode;
+
+#[derive(GraphQLErrorCode)]
+enum Error {
+    #[graphql(safe)]
+    Internal,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("internal")
+    }
+}
+
+fn main() {}
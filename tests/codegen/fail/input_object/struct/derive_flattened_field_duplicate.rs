@@ -0,0 +1,16 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Pagination {
+    first: i32,
+    after: Option<String>,
+}
+
+#[derive(GraphQLInputObject)]
+struct UsersFilter {
+    first: Option<i32>,
+    #[graphql(flatten)]
+    pagination: Pagination,
+}
+
+fn main() {}
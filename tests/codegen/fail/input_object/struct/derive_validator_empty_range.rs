@@ -0,0 +1,9 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    #[graphql(range())]
+    test: i32,
+}
+
+fn main() {}
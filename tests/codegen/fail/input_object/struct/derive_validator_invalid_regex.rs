@@ -0,0 +1,9 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    #[graphql(regex = "[a-z")]
+    test: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use juniper::GraphQLInputObject;
+
+#[derive(GraphQLInputObject)]
+struct Object {
+    test: String,
+    #[graphql(ignore, min_length = 3)]
+    ignored: String,
+}
+
+fn main() {}
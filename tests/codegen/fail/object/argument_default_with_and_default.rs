@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+#[graphql_object]
+impl ObjA {
+    fn id(&self, #[graphql(default = 1, default_with = |_: &()| 2)] n: i32) -> i32 {
+        n
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+#[graphql_object]
+impl ObjA {
+    #[graphql(concurrency = 0)]
+    fn ids(&self) -> Vec<i32> {
+        vec![1]
+    }
+}
+
+fn main() {}
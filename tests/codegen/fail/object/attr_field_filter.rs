@@ -0,0 +1,17 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+fn always(_: &i32, _: &()) -> bool {
+    true
+}
+
+#[graphql_object]
+impl ObjA {
+    #[graphql(filter = always)]
+    fn id(&self) -> i32 {
+        1
+    }
+}
+
+fn main() {}
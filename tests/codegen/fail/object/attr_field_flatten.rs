@@ -0,0 +1,13 @@
+use juniper::graphql_object;
+
+struct ObjA;
+
+#[graphql_object]
+impl ObjA {
+    #[graphql(flatten)]
+    fn id(&self) -> i32 {
+        1
+    }
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use std::marker::PhantomData;
+
+use juniper::graphql_object;
+
+struct Page<T>(PhantomData<T>);
+
+#[graphql_object(name = "{TPage")]
+impl<T> Page<T> {
+    fn total() -> i32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use juniper::graphql_object;
+
+struct Object;
+
+#[graphql_object(serde)]
+impl Object {
+    fn field() -> i32 {
+        0
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+struct Audit {
+    id: String,
+    created_by: String,
+}
+
+#[derive(GraphQLObject)]
+struct ObjA {
+    id: String,
+    #[graphql(flatten)]
+    audit: Audit,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use juniper::{graphql_object, GraphQLMergedObject};
+
+struct UserQuery;
+
+#[graphql_object]
+impl UserQuery {
+    fn count() -> i32 {
+        42
+    }
+}
+
+struct PostQuery;
+
+#[graphql_object]
+impl PostQuery {
+    fn count() -> i32 {
+        7
+    }
+}
+
+#[derive(GraphQLMergedObject)]
+struct Query(UserQuery, PostQuery);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use juniper::GraphQLMergedObject;
+
+#[derive(GraphQLMergedObject)]
+struct Query {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use std::marker::PhantomData;
+
+use juniper::GraphQLObject;
+
+#[derive(GraphQLObject)]
+#[graphql(name = "{U}Page")]
+struct Page<T> {
+    total: i32,
+    #[graphql(ignore)]
+    _items: PhantomData<T>,
+}
+
+fn main() {}
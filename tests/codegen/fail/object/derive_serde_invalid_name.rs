@@ -0,0 +1,11 @@
+use juniper::GraphQLObject;
+use serde::Serialize;
+
+#[derive(GraphQLObject, Serialize)]
+#[graphql(serde)]
+struct Object {
+    #[serde(rename = "my-field")]
+    field: i32,
+}
+
+fn main() {}
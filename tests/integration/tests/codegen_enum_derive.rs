@@ -752,6 +752,140 @@ mod renamed_all_fields {
     }
 }
 
+mod renamed_all_fields_case_policies {
+    use super::*;
+
+    #[derive(GraphQLEnum)]
+    #[graphql(rename_all = "lowercase")]
+    enum Lower {
+        NewHope,
+        Empire,
+    }
+
+    #[derive(GraphQLEnum)]
+    #[graphql(rename_all = "UPPERCASE")]
+    enum Upper {
+        NewHope,
+        Empire,
+    }
+
+    #[derive(GraphQLEnum)]
+    #[graphql(rename_all = "PascalCase")]
+    enum Pascal {
+        NewHope,
+        Empire,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn lower(value: Lower) -> Lower {
+            value
+        }
+
+        fn upper(value: Upper) -> Upper {
+            value
+        }
+
+        fn pascal(value: Pascal) -> Pascal {
+            value
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            lower(value: newhope)
+            upper(value: NEWHOPE)
+            pascal(value: NewHope)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "lower": "newhope",
+                    "upper": "NEWHOPE",
+                    "pascal": "NewHope",
+                }),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod serde_naming {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Deserialize, GraphQLEnum, Serialize)]
+    #[graphql(serde)]
+    #[serde(rename = "Kind", rename_all = "camelCase")]
+    enum Character {
+        HumanBeing,
+        #[serde(rename = "robot")]
+        Droid,
+        #[serde(skip)]
+        #[expect(dead_code, reason = "GraphQL schema testing")]
+        Ghost,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn pass_as_is(character: Character) -> Character {
+            character
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            human: passAsIs(character: humanBeing)
+            droid: passAsIs(character: robot)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": "humanBeing", "droid": "robot"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_enum_values() {
+        const DOC: &str = r#"{
+            __type(name: "Kind") {
+                enumValues {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"enumValues": [
+                    {"name": "humanBeing"},
+                    {"name": "robot"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
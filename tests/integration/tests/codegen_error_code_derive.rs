@@ -0,0 +1,231 @@
+//! Tests for `#[derive(GraphQLErrorCode)]` macro.
+
+pub mod common;
+
+use derive_more::with_trait::Display;
+use juniper::{
+    DefaultScalarValue, FieldError, GraphQLErrorCode, IntoFieldError as _, SafeError, execute,
+    graphql_object, graphql_value, graphql_vars,
+};
+
+use self::common::{MyScalarValue, util::schema};
+
+// Override `std::prelude` items to check whether macros expand hygienically.
+use self::common::hygiene::*;
+
+mod enum_variants {
+    use super::*;
+
+    #[derive(Display, GraphQLErrorCode)]
+    enum OrderError {
+        #[display("Order not found")]
+        NotFound { order_id: i32 },
+
+        #[display("Order is locked")]
+        #[graphql(code = "ORDER_LOCKED")]
+        Locked {
+            #[graphql(name = "by")]
+            locked_by: prelude::String,
+            #[graphql(skip)]
+            _reason: prelude::String,
+        },
+
+        #[display("Order storage is unavailable")]
+        StorageUnavailable(#[graphql(name = "retryAfter")] i32, prelude::String),
+    }
+
+    #[test]
+    fn has_codes() {
+        let err = OrderError::NotFound { order_id: 1 };
+        assert_eq!(
+            GraphQLErrorCode::<DefaultScalarValue>::code(&err),
+            "NOT_FOUND",
+        );
+
+        let err = OrderError::Locked {
+            locked_by: "admin".into(),
+            _reason: "audit".into(),
+        };
+        assert_eq!(
+            GraphQLErrorCode::<DefaultScalarValue>::code(&err),
+            "ORDER_LOCKED",
+        );
+
+        let err = OrderError::StorageUnavailable(5, "disk".into());
+        assert_eq!(
+            GraphQLErrorCode::<DefaultScalarValue>::code(&err),
+            "STORAGE_UNAVAILABLE",
+        );
+    }
+
+    #[test]
+    fn converts_into_field_error() {
+        let err: FieldError = OrderError::NotFound { order_id: 1 }.into_field_error();
+        assert_eq!(err.message(), "Order not found");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "NOT_FOUND", "orderId": 1}),
+        );
+
+        let err: FieldError = OrderError::Locked {
+            locked_by: "admin".into(),
+            _reason: "audit".into(),
+        }
+        .into_field_error();
+        assert_eq!(err.message(), "Order is locked");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "ORDER_LOCKED", "by": "admin"}),
+        );
+
+        let err: FieldError = OrderError::StorageUnavailable(5, "disk".into()).into_field_error();
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "STORAGE_UNAVAILABLE", "retryAfter": 5}),
+        );
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn order(id: i32) -> prelude::Result<i32, OrderError> {
+            prelude::Err(OrderError::NotFound { order_id: id })
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_into_errors() {
+        const DOC: &str = r#"{
+            order(id: 42)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (res, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &())
+            .await
+            .unwrap();
+
+        assert_eq!(res, graphql_value!(null));
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].error().message(), "Order not found");
+        assert_eq!(
+            errs[0].error().extensions(),
+            &graphql_value!({"code": "NOT_FOUND", "orderId": 42}),
+        );
+    }
+}
+
+mod structs {
+    use super::*;
+
+    #[derive(Display, GraphQLErrorCode)]
+    #[display("Rate limit exceeded")]
+    struct RateLimited {
+        retry_after_secs: prelude::Option<i32>,
+    }
+
+    #[derive(Display, GraphQLErrorCode)]
+    #[display("Payment required")]
+    #[graphql(code = "PAYMENT_REQUIRED")]
+    struct NoFunds;
+
+    #[test]
+    fn converts_into_field_error() {
+        let err: FieldError = RateLimited {
+            retry_after_secs: prelude::Some(30),
+        }
+        .into_field_error();
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "RATE_LIMITED", "retryAfterSecs": 30}),
+        );
+
+        let err: FieldError = RateLimited {
+            retry_after_secs: prelude::None,
+        }
+        .into_field_error();
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "RATE_LIMITED", "retryAfterSecs": null}),
+        );
+
+        let err: FieldError = NoFunds.into_field_error();
+        assert_eq!(err.message(), "Payment required");
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "PAYMENT_REQUIRED"}),
+        );
+    }
+}
+
+mod generic {
+    use super::*;
+
+    #[derive(Display, GraphQLErrorCode)]
+    #[display("Invalid value")]
+    struct InvalidValue<T> {
+        value: T,
+    }
+
+    #[test]
+    fn converts_into_field_error() {
+        let err: FieldError = InvalidValue { value: true }.into_field_error();
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "INVALID_VALUE", "value": true}),
+        );
+    }
+}
+
+mod explicit_scalar {
+    use super::*;
+
+    #[derive(Display, GraphQLErrorCode)]
+    #[display("Unknown user")]
+    #[graphql(scalar = MyScalarValue)]
+    struct UnknownUser {
+        id: i32,
+    }
+
+    #[test]
+    fn converts_into_field_error() {
+        let err: FieldError<MyScalarValue> = UnknownUser { id: 7 }.into_field_error();
+        assert_eq!(
+            err.extensions(),
+            &graphql_value!({"code": "UNKNOWN_USER", "id": 7}),
+        );
+    }
+}
+
+mod safe {
+    use super::*;
+
+    #[derive(Display, GraphQLErrorCode)]
+    #[graphql(safe)]
+    enum PaymentError {
+        #[display("Card declined")]
+        CardDeclined,
+    }
+
+    #[derive(Display, GraphQLErrorCode)]
+    #[display("Payment gateway failed")]
+    struct GatewayFailed;
+
+    fn assert_safe<T: SafeError>() {}
+
+    #[test]
+    fn implements_safe_error() {
+        assert_safe::<PaymentError>();
+    }
+
+    #[test]
+    fn converts_into_safe_field_error() {
+        let err: FieldError = PaymentError::CardDeclined.into_field_error();
+        assert!(err.is_safe());
+        assert_eq!(err.extensions(), &graphql_value!({"code": "CARD_DECLINED"}),);
+
+        let err: FieldError = GatewayFailed.into_field_error();
+        assert!(!err.is_safe());
+    }
+}
@@ -1000,3 +1000,79 @@ mod renamed_all_variants {
         );
     }
 }
+
+mod validated_variants {
+    use juniper::{DefaultScalarValue, ExecutionError, FieldError};
+
+    use super::*;
+
+    #[derive(GraphQLInputObject)]
+    enum UserBy {
+        #[graphql(min_length = 3)]
+        Username(prelude::String),
+        #[graphql(range(min = 1))]
+        RegistrationNumber(i32),
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn user_info(by: UserBy) -> prelude::String {
+            match by {
+                UserBy::Username(name) => name,
+                UserBy::RegistrationNumber(_) => "int".into(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_valid() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            userName: userInfo(by: {username: "John"})
+            userNum: userInfo(by: {registrationNumber: 123})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"userName": "John", "userNum": "int"}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn errs_on_invalid() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            userInfo(by: {registrationNumber: 0})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::<DefaultScalarValue>::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["userInfo"],
+                    FieldError::new(
+                        "Invalid input",
+                        graphql_value!({
+                            "code": "BAD_USER_INPUT",
+                            "errors": [{
+                                "path": ["by", "registrationNumber"],
+                                "message": "must be at least 1",
+                            }],
+                        }),
+                    ),
+                )],
+            )),
+        );
+    }
+}
@@ -3,8 +3,8 @@
 pub mod common;
 
 use juniper::{
-    GraphQLInputObject, RuleError, execute, graphql_object, graphql_value, graphql_vars,
-    parser::SourcePosition,
+    GraphQLInputObject, RuleError, error_code, execute, graphql_object, graphql_value,
+    graphql_vars, parser::SourcePosition,
 };
 
 use self::common::util::schema;
@@ -218,6 +218,7 @@ mod default_value {
                 "Variable \"$x\" of required type \"Float!\" was not provided.",
                 &[SourcePosition::new(8, 0, 8)],
             )
+            .with_code(error_code::BAD_USER_INPUT)
             .into()),
         );
     }
@@ -889,3 +890,432 @@ mod renamed_all_fields {
         );
     }
 }
+
+mod serde_naming {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, GraphQLInputObject)]
+    #[graphql(serde)]
+    #[serde(rename = "Point", rename_all = "SCREAMING_SNAKE_CASE")]
+    struct Point2D {
+        x_coord: f64,
+        #[serde(rename = "yCoord")]
+        y: f64,
+        #[serde(skip_deserializing)]
+        z: f64,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn sum(point: Point2D) -> f64 {
+            point.x_coord + point.y + point.z
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            sum(point: { X_COORD: 10, yCoord: 20 })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"sum": 30.0}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn has_input_fields() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __type(name: "Point") {
+                inputFields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "X_COORD"},
+                    {"name": "yCoord"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod validated_fields {
+    use juniper::{DefaultScalarValue, ExecutionError, FieldError, parser::SourcePosition};
+
+    use super::*;
+
+    fn not_reserved(login: &prelude::String) -> prelude::Result<(), &'static str> {
+        if login == "admin" {
+            Err("is reserved")
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct Address {
+        #[graphql(regex = "^[A-Z]{2}$")]
+        country: prelude::String,
+    }
+
+    #[derive(GraphQLInputObject)]
+    struct User {
+        #[graphql(min_length = 3, max_length = 10, custom = not_reserved)]
+        login: prelude::String,
+        #[graphql(email)]
+        email: prelude::Option<prelude::String>,
+        #[graphql(range(min = 18, max = 150))]
+        age: i32,
+        #[graphql(url)]
+        homepage: prelude::Option<prelude::String>,
+        #[graphql(min_items = 1)]
+        addresses: prelude::Vec<Address>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn login(user: User) -> prelude::String {
+            user.login
+        }
+
+        fn page(
+            #[graphql(range(min = 1), default = 1)] number: i32,
+            #[graphql(range(min = 1, max = 100), default = 10)] size: i32,
+        ) -> i32 {
+            number * size
+        }
+    }
+
+    fn invalid(
+        at: SourcePosition,
+        field: &str,
+        errors: juniper::Value<DefaultScalarValue>,
+    ) -> ExecutionError<DefaultScalarValue> {
+        ExecutionError::new(
+            at,
+            &[field],
+            FieldError::new(
+                "Invalid input",
+                graphql_value!({"code": "BAD_USER_INPUT", "errors": errors}),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn resolves_valid() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            login(user: {
+                login: "luke",
+                email: "luke@skywalker.com",
+                age: 19,
+                homepage: "https://skywalker.com",
+                addresses: [{country: "TA"}],
+            })
+            page(number: 2)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"login": "luke", "page": 20}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_absent_values() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            login(user: {login: "luke", age: 19, addresses: [{country: "TA"}]})
+            page
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"login": "luke", "page": 10}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn collects_all_errors() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            login(user: {
+                login: "admin-with-long-name",
+                email: "luke",
+                age: 16,
+                homepage: "skywalker",
+                addresses: [],
+            })
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![invalid(
+                    SourcePosition::new(14, 1, 12),
+                    "login",
+                    graphql_value!([{
+                        "path": ["user", "login"],
+                        "message": "must be at most 10 characters long",
+                    }, {
+                        "path": ["user", "email"],
+                        "message": "must be a valid email address",
+                    }, {
+                        "path": ["user", "age"],
+                        "message": "must be between 18 and 150",
+                    }, {
+                        "path": ["user", "homepage"],
+                        "message": "must be a valid URL",
+                    }, {
+                        "path": ["user", "addresses"],
+                        "message": "must contain at least 1 items",
+                    }]),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_custom_and_nested_errors() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            login(user: {login: "admin", age: 19, addresses: [{country: "Tatooine"}]})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![invalid(
+                    SourcePosition::new(14, 1, 12),
+                    "login",
+                    graphql_value!([{
+                        "path": ["user", "login"],
+                        "message": "is reserved",
+                    }, {
+                        "path": ["user", "addresses", "country"],
+                        "message": "must match `^[A-Z]{2}$` pattern",
+                    }]),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn validates_arguments() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            page(number: 0)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![invalid(
+                    SourcePosition::new(14, 1, 12),
+                    "page",
+                    graphql_value!([{"path": ["number"], "message": "must be at least 1"}]),
+                )],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn validates_all_arguments_at_once() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            page(number: 0, size: 101)
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![invalid(
+                    SourcePosition::new(14, 1, 12),
+                    "page",
+                    graphql_value!([
+                        {"path": ["number"], "message": "must be at least 1"},
+                        {"path": ["size"], "message": "must be between 1 and 100"},
+                    ]),
+                )],
+            )),
+        );
+    }
+}
+
+mod flattened_fields {
+    use juniper::{
+        DefaultScalarValue, ExecutionError, FieldError, FromInputValue as _, InputValue,
+        ToInputValue as _, graphql_input_value,
+    };
+
+    use super::*;
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct Pagination {
+        #[graphql(range(min = 1), default = 10)]
+        first: i32,
+        after: prelude::Option<prelude::String>,
+    }
+
+    #[derive(Debug, GraphQLInputObject, PartialEq)]
+    struct UsersFilter {
+        name: prelude::Option<prelude::String>,
+        #[graphql(flatten)]
+        pagination: Pagination,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn users(filter: UsersFilter) -> prelude::String {
+            prelude::format!(
+                "{}: {} after {}",
+                filter.name.unwrap_or_default(),
+                filter.pagination.first,
+                filter.pagination.after.unwrap_or_default(),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            users(filter: {name: "luke", first: 2, after: "abc"})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"users": "luke: 2 after abc"}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_defaults() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            users(filter: {})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"users": ": 10 after "}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn splices_fields() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __type(name: "UsersFilter") {
+                inputFields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"inputFields": [
+                    {"name": "name"},
+                    {"name": "first"},
+                    {"name": "after"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn validates_flattened_fields() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            users(filter: {first: 0})
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!(null),
+                vec![ExecutionError::<DefaultScalarValue>::new(
+                    SourcePosition::new(14, 1, 12),
+                    &["users"],
+                    FieldError::new(
+                        "Invalid input",
+                        graphql_value!({
+                            "code": "BAD_USER_INPUT",
+                            "errors": [{
+                                "path": ["filter", "first"],
+                                "message": "must be at least 1",
+                            }],
+                        }),
+                    ),
+                )],
+            )),
+        );
+    }
+
+    #[test]
+    fn converts_to_and_from_input_value() {
+        let filter = UsersFilter {
+            name: prelude::Some("luke".into()),
+            pagination: Pagination {
+                first: 2,
+                after: prelude::None,
+            },
+        };
+
+        let value: InputValue = filter.to_input_value();
+        assert_eq!(
+            value,
+            graphql_input_value!({"name": "luke", "first": 2, "after": null}),
+        );
+        assert_eq!(UsersFilter::from_input_value(&value), prelude::Ok(filter));
+    }
+}
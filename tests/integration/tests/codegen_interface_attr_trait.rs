@@ -1363,6 +1363,71 @@ mod default_argument {
     }
 }
 
+mod computed_default_argument {
+    use super::*;
+
+    struct Config {
+        page_size: i32,
+    }
+
+    impl juniper::Context for Config {}
+
+    #[graphql_interface(for = Human, context = Config)]
+    trait Character {
+        fn id(&self, #[graphql(default_with = |c: &Config| c.page_size)] first: i32) -> i32;
+
+        fn info(&self) -> i32;
+    }
+
+    struct Human;
+
+    #[graphql_object(impl = CharacterValue, context = Config)]
+    impl Human {
+        fn id(&self, #[graphql(default_with = |c: &Config| c.page_size)] first: i32) -> i32 {
+            first
+        }
+
+        fn info(&self, #[graphql(default_with = |c: &Config| c.page_size * 2)] first: i32) -> i32 {
+            first
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Config)]
+    impl QueryRoot {
+        fn character(&self) -> CharacterValue {
+            Human.into()
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        let schema = schema(QueryRoot);
+        let ctx = Config { page_size: 10 };
+
+        for (input, expected) in [
+            (
+                "{ character { id info } }",
+                graphql_value!({"id": 10, "info": 20}),
+            ),
+            (
+                "{ character { id(first: 1) info } }",
+                graphql_value!({"id": 1, "info": 20}),
+            ),
+            (
+                "{ character { ... on Human { info(first: 2) } } }",
+                graphql_value!({"info": 2}),
+            ),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &ctx).await,
+                Ok((graphql_value!({"character": expected}), vec![])),
+            );
+        }
+    }
+}
+
 mod description_from_doc_comment {
     use super::*;
 
@@ -4048,3 +4113,203 @@ mod has_no_missing_docs {
         home_planet: prelude::String,
     }
 }
+
+mod guard {
+    use juniper::{execute_sync, guard::Rejection};
+
+    use super::*;
+
+    struct Context {
+        is_admin: bool,
+    }
+
+    impl juniper::Context for Context {}
+
+    fn admin(ctx: &Context) -> prelude::Result<(), Rejection> {
+        if ctx.is_admin {
+            prelude::Ok(())
+        } else {
+            prelude::Err(Rejection::new("Admin role required"))
+        }
+    }
+
+    #[graphql_interface(for = Human, context = Context)]
+    trait Character {
+        fn id(&self) -> &str;
+
+        #[graphql(guard = admin)]
+        fn email(&self) -> prelude::Option<&str>;
+    }
+
+    struct Human {
+        id: prelude::String,
+        email: prelude::String,
+    }
+
+    #[graphql_object(impl = CharacterValue, context = Context)]
+    impl Human {
+        fn id(&self) -> &str {
+            &self.id
+        }
+
+        fn email(&self) -> prelude::Option<&str> {
+            prelude::Some(&self.email)
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn character(&self) -> CharacterValue {
+            Human {
+                id: "human-32".into(),
+                email: "luke@skywalker.com".into(),
+            }
+            .into()
+        }
+    }
+
+    #[tokio::test]
+    async fn allows_passed() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            character { id email }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { is_admin: true };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"character": {
+                    "id": "human-32",
+                    "email": "luke@skywalker.com",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_field() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            character { id email }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { is_admin: false };
+
+        let (data, errors) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            data,
+            graphql_value!({"character": {"id": "human-32", "email": null}}),
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), ["character", "email"]);
+        assert_eq!(
+            errors[0].error(),
+            &FieldError::new("Admin role required", graphql_value!({"code": "FORBIDDEN"})),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_field_via_fragment() {
+        let schema = schema(QueryRoot);
+        let ctx = Context { is_admin: false };
+
+        for doc in [
+            // language=GraphQL
+            r#"{
+                character { id ... on Human { email } }
+            }"#,
+            // language=GraphQL
+            r#"{
+                character { id ...HumanEmail }
+            }
+            fragment HumanEmail on Human { ... on Human { email } }"#,
+        ] {
+            let (data, errors) = execute(doc, None, &schema, &graphql_vars! {}, &ctx)
+                .await
+                .unwrap();
+
+            assert_eq!(data, graphql_value!(null), "doc: {doc}");
+            assert_eq!(errors.len(), 1, "doc: {doc}");
+            assert_eq!(errors[0].path(), ["character"], "doc: {doc}");
+            assert_eq!(
+                errors[0].error(),
+                &FieldError::new("Admin role required", graphql_value!({"code": "FORBIDDEN"})),
+                "doc: {doc}",
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn allows_passed_via_fragment() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            character { id ... on Human { email } }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { is_admin: true };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"character": {
+                    "id": "human-32",
+                    "email": "luke@skywalker.com",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_skipped_via_fragment() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            character { id ... on Human { email @skip(if: true) } }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Context { is_admin: false };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((graphql_value!({"character": {"id": "human-32"}}), vec![])),
+        );
+    }
+
+    #[test]
+    fn errors_on_sync_resolution() {
+        let schema = schema(QueryRoot);
+        let ctx = Context { is_admin: true };
+
+        for doc in [
+            // language=GraphQL
+            r#"{
+                character { id email }
+            }"#,
+            // language=GraphQL
+            r#"{
+                character { id ... on Human { email } }
+            }"#,
+        ] {
+            let (_, errors) = execute_sync(doc, None, &schema, &graphql_vars! {}, &ctx).unwrap();
+
+            assert_eq!(errors.len(), 1, "doc: {doc}");
+            assert_eq!(
+                errors[0].error().message(),
+                "Tried to resolve guarded field `email` on type `Character` with a sync resolver",
+                "doc: {doc}",
+            );
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Tests for `#[derive(GraphQLMergedObject)]` macro.
+
+pub mod common;
+
+use juniper::{
+    EmptySubscription, GraphQLMergedObject, RootNode, execute, graphql_object, graphql_value,
+    graphql_vars,
+};
+
+use self::common::util::schema;
+
+// Override `std::prelude` items to check whether macros expand hygienically.
+use self::common::hygiene::*;
+
+mod trivial {
+    use super::*;
+
+    struct UserQuery;
+
+    #[graphql_object]
+    impl UserQuery {
+        fn user_count() -> i32 {
+            42
+        }
+    }
+
+    struct PostQuery;
+
+    #[graphql_object]
+    impl PostQuery {
+        async fn post_count() -> i32 {
+            7
+        }
+
+        fn post_title(id: i32) -> prelude::String {
+            prelude::format!("Post #{id}")
+        }
+    }
+
+    #[derive(GraphQLMergedObject)]
+    #[graphql(name = "Query")]
+    struct QueryRoot(UserQuery, PostQuery);
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            userCount
+            postCount
+            postTitle(id: 3)
+        }"#;
+
+        let schema = schema(QueryRoot(UserQuery, PostQuery));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "userCount": 42,
+                    "postCount": 7,
+                    "postTitle": "Post #3",
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_sync_fields() {
+        const DOC: &str = r#"{
+            userCount
+        }"#;
+
+        let schema = schema(QueryRoot(UserQuery, PostQuery));
+
+        assert_eq!(
+            juniper::execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((graphql_value!({"userCount": 42}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn merges_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Query") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot(UserQuery, PostQuery));
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "userCount"},
+                    {"name": "postCount"},
+                    {"name": "postTitle"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod custom_context {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Context {
+        users: AtomicI32,
+        posts: AtomicI32,
+    }
+
+    impl juniper::Context for Context {}
+
+    struct Query;
+
+    #[graphql_object(context = Context)]
+    impl Query {
+        fn users(ctx: &Context) -> i32 {
+            ctx.users.load(Ordering::SeqCst)
+        }
+    }
+
+    struct UserMutation;
+
+    #[graphql_object(context = Context)]
+    impl UserMutation {
+        fn add_user(ctx: &Context) -> i32 {
+            ctx.users.fetch_add(1, Ordering::SeqCst) + 1
+        }
+    }
+
+    struct PostMutation;
+
+    #[graphql_object(context = Context)]
+    impl PostMutation {
+        async fn add_post(ctx: &Context) -> i32 {
+            ctx.posts.fetch_add(1, Ordering::SeqCst) + 1
+        }
+    }
+
+    #[derive(GraphQLMergedObject)]
+    #[graphql(name = "Mutation", context = Context)]
+    struct MutationRoot {
+        users: UserMutation,
+        posts: PostMutation,
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"mutation {
+            addUser
+            addPost
+        }"#;
+
+        let schema = RootNode::new(
+            Query,
+            MutationRoot {
+                users: UserMutation,
+                posts: PostMutation,
+            },
+            EmptySubscription::<Context>::new(),
+        );
+        let ctx = Context::default();
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((graphql_value!({"addUser": 1, "addPost": 1}), vec![])),
+        );
+        assert_eq!(ctx.users.load(Ordering::SeqCst), 1);
+    }
+}
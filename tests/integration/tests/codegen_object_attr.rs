@@ -914,6 +914,90 @@ mod nested_generic_lifetime_async {
     }
 }
 
+mod generic_name_template {
+    use std::marker::PhantomData;
+
+    use juniper::macros::reflect::BaseType;
+
+    use super::*;
+
+    struct Human;
+
+    #[graphql_object]
+    impl Human {
+        fn id() -> &'static str {
+            "human-32"
+        }
+    }
+
+    struct Droid;
+
+    #[graphql_object]
+    impl Droid {
+        fn id() -> &'static str {
+            "droid-99"
+        }
+    }
+
+    struct Count<T>(i32, PhantomData<T>);
+
+    #[graphql_object(name = "{T}Count")]
+    impl<T> Count<T> {
+        fn count(&self) -> i32 {
+            self.0
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn humans() -> Count<Human> {
+            Count(1, PhantomData)
+        }
+
+        fn droids() -> Count<Droid> {
+            Count(2, PhantomData)
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            humans {
+                __typename
+                count
+            }
+            droids {
+                __typename
+                count
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "humans": {"__typename": "HumanCount", "count": 1},
+                    "droids": {"__typename": "DroidCount", "count": 2},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn reflects_templated_type_name() {
+        assert_eq!(
+            <Count<Droid> as BaseType<DefaultScalarValue>>::NAME,
+            "DroidCount",
+        );
+    }
+}
+
 mod argument {
     use super::*;
 
@@ -1221,6 +1305,156 @@ mod default_argument {
     }
 }
 
+mod computed_default_argument {
+    use super::*;
+
+    struct Config {
+        page_size: i32,
+        locale: prelude::String,
+    }
+
+    impl juniper::Context for Config {}
+
+    impl Config {
+        fn locale(&self) -> prelude::String {
+            self.locale.clone()
+        }
+    }
+
+    struct Human;
+
+    #[graphql_object(context = Config)]
+    impl Human {
+        fn id(
+            #[graphql(default_with = |c: &Config| c.page_size)] first: i32,
+            #[graphql(default_with = Config::locale)] locale: prelude::String,
+        ) -> prelude::String {
+            format!("{first}|{locale}")
+        }
+
+        async fn info(
+            #[graphql(default_with = |c: &Config| prelude::Some(c.page_size * 2))]
+            first: prelude::Option<i32>,
+        ) -> prelude::Option<i32> {
+            first
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Config)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_id_field() {
+        let schema = schema(QueryRoot);
+        let ctx = Config {
+            page_size: 10,
+            locale: "en".into(),
+        };
+
+        for (input, expected, vars) in [
+            ("{ human { id } }", "10|en", graphql_vars! {}),
+            ("{ human { id(first: 5) } }", "5|en", graphql_vars! {}),
+            (
+                r#"{ human { id(locale: "uk") } }"#,
+                "10|uk",
+                graphql_vars! {},
+            ),
+            ("{ human { id(first: null) } }", "10|en", graphql_vars! {}),
+            (
+                "query q($first: Int) { human { id(first: $first) } }",
+                "10|en",
+                graphql_vars! {},
+            ),
+            (
+                "query q($first: Int) { human { id(first: $first) } }",
+                "3|en",
+                graphql_vars! { "first": 3 },
+            ),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &vars, &ctx).await,
+                Ok((graphql_value!({"human": {"id": expected}}), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_info_field() {
+        let schema = schema(QueryRoot);
+        let ctx = Config {
+            page_size: 10,
+            locale: "en".into(),
+        };
+
+        for (input, expected) in [
+            ("{ human { info } }", 20),
+            ("{ human { info(first: 2) } }", 2),
+        ] {
+            assert_eq!(
+                execute(input, None, &schema, &graphql_vars! {}, &ctx).await,
+                Ok((graphql_value!({"human": {"info": expected}}), vec![])),
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn has_no_static_defaults() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    args {
+                        name
+                        defaultValue
+                        type {
+                            name
+                            ofType {
+                                name
+                            }
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+        let ctx = Config {
+            page_size: 10,
+            locale: "en".into(),
+        };
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &ctx).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [{
+                    "args": [{
+                        "name": "first",
+                        "defaultValue": null,
+                        "type": {"name": "Int", "ofType": null},
+                    }, {
+                        "name": "locale",
+                        "defaultValue": null,
+                        "type": {"name": "String", "ofType": null},
+                    }],
+                }, {
+                    "args": [{
+                        "name": "first",
+                        "defaultValue": null,
+                        "type": {"name": "Int", "ofType": null},
+                    }],
+                }]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod description_from_doc_comment {
     use super::*;
 
@@ -2224,3 +2458,235 @@ mod switched_context {
         );
     }
 }
+
+mod guard {
+    use juniper::{
+        BoxFuture, ExecutionError, FieldError, execute_sync,
+        guard::{Guard, Rejection},
+        parser::SourcePosition,
+    };
+
+    use super::*;
+
+    struct Context {
+        roles: prelude::Vec<&'static str>,
+    }
+
+    impl juniper::Context for Context {}
+
+    struct Role(&'static str);
+
+    impl Guard<Context> for Role {
+        fn check<'a>(&'a self, ctx: &'a Context) -> BoxFuture<'a, prelude::Result<(), Rejection>> {
+            prelude::Box::pin(async move {
+                if ctx.roles.contains(&self.0) {
+                    prelude::Ok(())
+                } else {
+                    prelude::Err(Rejection::new(prelude::format!(
+                        "`{}` role required",
+                        self.0
+                    )))
+                }
+            })
+        }
+    }
+
+    fn authenticated(ctx: &Context) -> prelude::Result<(), Rejection> {
+        if ctx.roles.is_empty() {
+            prelude::Err(Rejection::new("Not authenticated").with_code("UNAUTHENTICATED"))
+        } else {
+            prelude::Ok(())
+        }
+    }
+
+    struct Human;
+
+    #[graphql_object(context = Context, guard = authenticated)]
+    impl Human {
+        fn name() -> &'static str {
+            "Luke"
+        }
+
+        #[graphql(guard = Role("admin").or(Role("owner")))]
+        async fn email() -> &'static str {
+            "luke@skywalker.com"
+        }
+
+        #[graphql(guard = Role("admin").and(Role("auditor")))]
+        fn audit_log() -> &'static str {
+            "nothing"
+        }
+
+        fn friends_count(#[graphql(range(min = 1))] first: i32) -> i32 {
+            first
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human
+        }
+    }
+
+    fn ctx(roles: &[&'static str]) -> Context {
+        Context {
+            roles: roles.to_vec(),
+        }
+    }
+
+    fn rejection(
+        at: SourcePosition,
+        field: &str,
+        message: &str,
+        code: &str,
+    ) -> ExecutionError<DefaultScalarValue> {
+        ExecutionError::new(
+            at,
+            &["human", field],
+            FieldError::new(message, graphql_value!({"code": code})),
+        )
+    }
+
+    #[tokio::test]
+    async fn allows_passed() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            human { name email auditLog }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &ctx(&["admin", "auditor"])
+            )
+            .await,
+            Ok((
+                graphql_value!({"human": {
+                    "name": "Luke",
+                    "email": "luke@skywalker.com",
+                    "auditLog": "nothing",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_field() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            human { name email auditLog }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (data, errors) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx(&["owner"]))
+            .await
+            .unwrap();
+
+        assert_eq!(data, graphql_value!(null));
+        assert_eq!(
+            errors,
+            vec![rejection(
+                SourcePosition::new(33, 1, 31),
+                "auditLog",
+                "`admin` role required",
+                "FORBIDDEN",
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_object() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            human { name }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (data, errors) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx(&[]))
+            .await
+            .unwrap();
+
+        assert_eq!(data, graphql_value!(null));
+        assert_eq!(
+            errors,
+            vec![rejection(
+                SourcePosition::new(22, 1, 20),
+                "name",
+                "Not authenticated",
+                "UNAUTHENTICATED",
+            )],
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_guard_before_arguments() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            human { friendsCount(first: 0) }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (_, errors) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx(&[]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            errors,
+            vec![rejection(
+                SourcePosition::new(22, 1, 20),
+                "friendsCount",
+                "Not authenticated",
+                "UNAUTHENTICATED",
+            )],
+        );
+
+        let (_, errors) = execute(DOC, None, &schema, &graphql_vars! {}, &ctx(&["user"]))
+            .await
+            .unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].error(),
+            &FieldError::new(
+                "Invalid input",
+                graphql_value!({
+                    "code": "BAD_USER_INPUT",
+                    "errors": [{"path": ["first"], "message": "must be at least 1"}],
+                }),
+            ),
+        );
+    }
+
+    #[test]
+    fn errors_on_sync_resolution() {
+        // language=GraphQL
+        const DOC: &str = r#"{
+            human { name }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (data, errors) =
+            execute_sync(DOC, None, &schema, &graphql_vars! {}, &ctx(&["admin"])).unwrap();
+
+        assert_eq!(data, graphql_value!(null));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path(), ["human", "name"]);
+        assert_eq!(
+            errors[0].error().message(),
+            "Tried to resolve guarded field `name` on type `Human` with a sync resolver",
+        );
+    }
+}
@@ -328,6 +328,154 @@ mod generic_lifetime {
     }
 }
 
+mod generic_name_template {
+    use juniper::{
+        GraphQLType, GraphQLValueAsync,
+        macros::reflect::{BaseSubTypes, BaseType, WrappedType},
+        marker::IsOutputType,
+        relay::Connection,
+    };
+
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Human {
+        id: &'static str,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(name = "{T}Page", scalar = DefaultScalarValue)]
+    struct Page<T>
+    where
+        T: GraphQLType<DefaultScalarValue, Context = (), TypeInfo = ()>
+            + GraphQLValueAsync<DefaultScalarValue>
+            + IsOutputType<DefaultScalarValue>
+            + BaseSubTypes<DefaultScalarValue>
+            + WrappedType<DefaultScalarValue>,
+    {
+        items: prelude::Vec<T>,
+        total: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(scalar = DefaultScalarValue)]
+    impl QueryRoot {
+        fn humans() -> Page<Human> {
+            Page {
+                items: prelude::vec![Human { id: "human-32" }],
+                total: 1,
+            }
+        }
+
+        fn numbers() -> Page<i32> {
+            Page {
+                items: prelude::vec![1, 2],
+                total: 2,
+            }
+        }
+
+        fn pages() -> Page<Page<i32>> {
+            Page {
+                items: prelude::vec![],
+                total: 0,
+            }
+        }
+
+        fn human_connections() -> Page<Connection<Human>> {
+            Page {
+                items: prelude::vec![],
+                total: 0,
+            }
+        }
+
+        fn number_connections() -> Page<Connection<i32>> {
+            Page {
+                items: prelude::vec![
+                    Connection::paginate([1], &prelude::Default::default()).unwrap()
+                ],
+                total: 1,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves() {
+        const DOC: &str = r#"{
+            humans {
+                items { id }
+                total
+            }
+            numbers {
+                items
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "humans": {"items": [{"id": "human-32"}], "total": 1},
+                    "numbers": {"items": [1, 2]},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_templated_type_name() {
+        const DOC: &str = r#"{
+            humans { __typename }
+            numbers { __typename }
+            pages { __typename }
+            humanConnections { __typename }
+            numberConnections { items { __typename edges { __typename } } }
+            __type(name: "HumanPage") {
+                name
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({
+                    "humans": {"__typename": "HumanPage"},
+                    "numbers": {"__typename": "IntPage"},
+                    "pages": {"__typename": "IntPagePage"},
+                    "humanConnections": {"__typename": "HumanConnectionPage"},
+                    "numberConnections": {"items": [{
+                        "__typename": "IntConnection",
+                        "edges": [{"__typename": "IntEdge"}],
+                    }]},
+                    "__type": {"name": "HumanPage"},
+                }),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn reflects_templated_type_name() {
+        assert_eq!(
+            <Page<Human> as BaseType<DefaultScalarValue>>::NAME,
+            "HumanPage",
+        );
+        assert_eq!(
+            <Page<Page<i32>> as BaseType<DefaultScalarValue>>::NAME,
+            "IntPagePage",
+        );
+        assert_eq!(
+            <Page<Connection<Human>> as BaseType<DefaultScalarValue>>::NAME,
+            "HumanConnectionPage",
+        );
+    }
+}
+
 mod nested_generic_lifetime_async {
     use super::*;
 
@@ -797,6 +945,145 @@ mod renamed_all_fields {
     }
 }
 
+mod renamed_all_fields_pascal_case {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    #[graphql(rename_all = "PascalCase")]
+    struct Human {
+        id: &'static str,
+        home_planet: prelude::String,
+        r#async_info: i32,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                home_planet: "earth".into(),
+                r#async_info: 3,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                Id
+                HomePlanet
+                AsyncInfo
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "Id": "human-32",
+                    "HomePlanet": "earth",
+                    "AsyncInfo": 3,
+                }}),
+                vec![],
+            )),
+        );
+    }
+}
+
+mod serde_naming {
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(GraphQLObject, Serialize)]
+    #[graphql(serde)]
+    #[serde(rename = "Person", rename_all = "camelCase")]
+    struct Human {
+        id: &'static str,
+        home_planet: prelude::String,
+        #[serde(rename = "age")]
+        years: i32,
+        #[serde(skip)]
+        #[expect(dead_code, reason = "GraphQL schema testing")]
+        secret: i32,
+        #[graphql(name = "friend")]
+        best_friend: prelude::Option<&'static str>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                home_planet: "earth".into(),
+                years: 42,
+                secret: 1,
+                best_friend: None,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                homePlanet
+                age
+                friend
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "homePlanet": "earth",
+                    "age": 42,
+                    "friend": null,
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn uses_serde_names() {
+        const DOC: &str = r#"{
+            __type(name: "Person") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id"},
+                    {"name": "homePlanet"},
+                    {"name": "age"},
+                    {"name": "friend"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+}
+
 mod explicit_scalar {
     use super::*;
 
@@ -985,3 +1272,301 @@ mod explicit_custom_context {
         );
     }
 }
+
+mod flattened_fields {
+    use juniper::macros::reflect::Fields;
+
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Revision {
+        revision: i32,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Audit {
+        created_at: i32,
+        created_by: &'static str,
+        #[graphql(flatten)]
+        revision: Revision,
+    }
+
+    #[derive(GraphQLObject)]
+    struct Human {
+        id: &'static str,
+        #[graphql(flatten)]
+        audit: Audit,
+        home_planet: &'static str,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                audit: Audit {
+                    created_at: 1977,
+                    created_by: "lucas",
+                    revision: Revision { revision: 4 },
+                },
+                home_planet: "earth",
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                createdAt
+                createdBy
+                homePlanet
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {
+                    "id": "human-32",
+                    "createdAt": 1977,
+                    "createdBy": "lucas",
+                    "homePlanet": "earth",
+                }}),
+                vec![],
+            )),
+        );
+    }
+
+    #[test]
+    fn resolves_fields_sync() {
+        const DOC: &str = r#"{
+            human {
+                createdBy
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            juniper::execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+            Ok((graphql_value!({"human": {"createdBy": "lucas"}}), vec![])),
+        );
+    }
+
+    #[tokio::test]
+    async fn splices_fields() {
+        const DOC: &str = r#"{
+            __type(name: "Human") {
+                fields {
+                    name
+                }
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"__type": {"fields": [
+                    {"name": "id"},
+                    {"name": "homePlanet"},
+                    {"name": "createdAt"},
+                    {"name": "createdBy"},
+                    {"name": "revision"},
+                ]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn resolves_nested_fields() {
+        const DOC: &str = r#"{
+            human {
+                revision
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((graphql_value!({"human": {"revision": 4}}), vec![])),
+        );
+    }
+
+    #[test]
+    fn reflects_fields() {
+        assert_eq!(
+            <Human as Fields<DefaultScalarValue>>::NAMES,
+            ["id", "homePlanet", "createdAt", "createdBy", "revision"],
+        );
+    }
+}
+
+mod flattened_guarded_fields {
+    use juniper::guard::Rejection;
+
+    use super::*;
+
+    struct Context {
+        is_admin: bool,
+    }
+
+    impl juniper::Context for Context {}
+
+    fn admin(ctx: &Context) -> prelude::Result<(), Rejection> {
+        if ctx.is_admin {
+            prelude::Ok(())
+        } else {
+            prelude::Err(Rejection::new("Admin role is required"))
+        }
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(context = Context)]
+    struct Audit {
+        created_by: prelude::Option<&'static str>,
+    }
+
+    #[derive(GraphQLObject)]
+    #[graphql(context = Context, guard = admin)]
+    struct Human {
+        id: prelude::Option<&'static str>,
+        #[graphql(flatten)]
+        audit: Audit,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = Context)]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: prelude::Some("human-32"),
+                audit: Audit {
+                    created_by: prelude::Some("lucas"),
+                },
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                createdBy
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(
+                DOC,
+                None,
+                &schema,
+                &graphql_vars! {},
+                &Context { is_admin: true },
+            )
+            .await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "createdBy": "lucas"}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_fields() {
+        const DOC: &str = r#"{
+            human {
+                createdBy
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        let (data, errors) = execute(
+            DOC,
+            None,
+            &schema,
+            &graphql_vars! {},
+            &Context { is_admin: false },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(data, graphql_value!({"human": {"createdBy": null}}));
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| e.error().message())
+                .collect::<prelude::Vec<_>>(),
+            ["Admin role is required"],
+        );
+    }
+}
+
+mod concurrency_limited_fields {
+    use super::*;
+
+    #[derive(GraphQLObject)]
+    struct Human {
+        id: &'static str,
+        #[graphql(concurrency = 2)]
+        friend_ids: prelude::Vec<i32>,
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object]
+    impl QueryRoot {
+        fn human() -> Human {
+            Human {
+                id: "human-32",
+                friend_ids: vec![1, 2, 3],
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_fields() {
+        const DOC: &str = r#"{
+            human {
+                id
+                friendIds
+            }
+        }"#;
+
+        let schema = schema(QueryRoot);
+
+        assert_eq!(
+            execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+            Ok((
+                graphql_value!({"human": {"id": "human-32", "friendIds": [1, 2, 3]}}),
+                vec![],
+            )),
+        );
+    }
+
+    #[tokio::test]
+    async fn registers_limit() {
+        let schema = schema(QueryRoot);
+        let ty = schema.schema.concrete_type_by_name("Human").unwrap();
+
+        assert_eq!(ty.field_by_name("id").unwrap().concurrency_limit, None);
+        assert_eq!(
+            ty.field_by_name("friendIds").unwrap().concurrency_limit,
+            Some(2),
+        );
+    }
+}
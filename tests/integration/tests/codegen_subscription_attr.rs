@@ -1697,6 +1697,166 @@ mod inferred_custom_context_from_field {
     }
 }
 
+mod filter {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::StreamExt as _;
+    use juniper::{ExecutionError, GraphQLError, Value};
+
+    use super::*;
+
+    struct CustomContext {
+        min: i32,
+        resolved: AtomicUsize,
+    }
+
+    impl juniper::Context for CustomContext {}
+
+    impl CustomContext {
+        fn new(min: i32) -> Self {
+            Self {
+                min,
+                resolved: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    struct QueryRoot;
+
+    #[graphql_object(context = CustomContext)]
+    impl QueryRoot {
+        fn empty() -> bool {
+            true
+        }
+    }
+
+    struct Number(i32);
+
+    #[graphql_object(context = CustomContext)]
+    impl Number {
+        fn value(&self, context: &CustomContext) -> i32 {
+            context.resolved.fetch_add(1, Ordering::SeqCst);
+            self.0
+        }
+    }
+
+    fn is_even(n: &i32, _: &CustomContext) -> bool {
+        n % 2 == 0
+    }
+
+    fn is_multiple(n: &Number, divisor: &i32, ctx: &CustomContext) -> bool {
+        n.0 % divisor == 0 && n.0 >= ctx.min
+    }
+
+    struct Human;
+
+    #[graphql_subscription(context = CustomContext)]
+    impl Human {
+        #[graphql(filter = is_even)]
+        async fn even() -> Stream<'static, i32> {
+            prelude::Box::pin(stream::iter(1..=6))
+        }
+
+        #[graphql(filter = is_multiple)]
+        async fn multiples(
+            &self,
+            divisor: i32,
+            #[graphql(context)] _ctx: &CustomContext,
+        ) -> Stream<'static, Number> {
+            prelude::Box::pin(stream::iter((1..=divisor * 3).map(Number)))
+        }
+    }
+
+    /// Collects all the values emitted by the [`Stream`] of the result returned by
+    /// [`juniper::resolve_into_stream()`].
+    ///
+    /// [`Stream`]: futures::Stream
+    async fn collect_all(
+        input: prelude::Result<
+            (
+                Value<juniper::ValuesStream<'_>>,
+                prelude::Vec<ExecutionError<DefaultScalarValue>>,
+            ),
+            GraphQLError,
+        >,
+    ) -> prelude::Vec<Value> {
+        let (stream, errs) = input.expect("failed to resolve into stream");
+        assert_eq!(errs, []);
+
+        let mut out = prelude::Vec::new();
+        if let Value::Object(obj) = stream {
+            for (_, val) in obj {
+                if let Value::Scalar(mut stream) = val {
+                    while let prelude::Some(val) = stream.next().await {
+                        out.push(val.expect("failed to resolve value"));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn filters_even_field() {
+        const DOC: &str = r#"subscription {
+            even
+        }"#;
+
+        let schema = schema(QueryRoot, Human);
+        let ctx = CustomContext::new(0);
+
+        assert_eq!(
+            resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &ctx)
+                .then(collect_all)
+                .await,
+            [graphql_value!(2), graphql_value!(4), graphql_value!(6)],
+        );
+    }
+
+    #[tokio::test]
+    async fn filters_multiples_field_by_arguments_and_context() {
+        const DOC: &str = r#"subscription {
+            multiples(divisor: 3) {
+                value
+            }
+        }"#;
+
+        let schema = schema(QueryRoot, Human);
+        let ctx = CustomContext::new(4);
+
+        assert_eq!(
+            resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &ctx)
+                .then(collect_all)
+                .await,
+            [graphql_value!({"value": 6}), graphql_value!({"value": 9})],
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_resolve_filtered_out_items() {
+        const DOC: &str = r#"subscription {
+            multiples(divisor: 5) {
+                value
+            }
+        }"#;
+
+        let schema = schema(QueryRoot, Human);
+        let ctx = CustomContext::new(0);
+
+        assert_eq!(
+            resolve_into_stream(DOC, None, &schema, &graphql_vars! {}, &ctx)
+                .then(collect_all)
+                .await,
+            [
+                graphql_value!({"value": 5}),
+                graphql_value!({"value": 10}),
+                graphql_value!({"value": 15}),
+            ],
+        );
+        assert_eq!(ctx.resolved.load(Ordering::SeqCst), 3);
+    }
+}
+
 mod executor {
     use super::*;
 
@@ -0,0 +1,376 @@
+//! Tests for `juniper::relay` helpers.
+
+use juniper::{
+    EmptyMutation, EmptySubscription, FieldResult, GraphQLUnion, ID, RootNode, execute,
+    graphql_object, graphql_value, graphql_vars,
+    relay::{self, Connection, ConnectionArgs, ConnectionError, GlobalId, Identifiable},
+};
+
+#[derive(Clone)]
+struct User {
+    num: usize,
+    name: String,
+}
+
+#[graphql_object(impl = relay::Node<AnyNode>)]
+impl User {
+    fn id(&self) -> ID {
+        self.global_id().to_id()
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Identifiable for User {
+    fn global_id(&self) -> GlobalId {
+        GlobalId::new("User", self.num.to_string())
+    }
+}
+
+#[derive(Clone)]
+struct Post {
+    num: usize,
+    title: String,
+}
+
+#[graphql_object(impl = relay::Node<AnyNode>)]
+impl Post {
+    fn id(&self) -> ID {
+        self.global_id().to_id()
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+impl Identifiable for Post {
+    fn global_id(&self) -> GlobalId {
+        GlobalId::new("Post", self.num.to_string())
+    }
+}
+
+#[derive(GraphQLUnion)]
+enum AnyNode {
+    User(User),
+    Post(Post),
+}
+
+impl Identifiable for AnyNode {
+    fn global_id(&self) -> GlobalId {
+        match self {
+            Self::User(u) => u.global_id(),
+            Self::Post(p) => p.global_id(),
+        }
+    }
+}
+
+fn users() -> Vec<User> {
+    ["Alice", "Bob", "Carol", "Dave"]
+        .into_iter()
+        .enumerate()
+        .map(|(num, name)| User {
+            num,
+            name: name.into(),
+        })
+        .collect()
+}
+
+fn posts() -> Vec<Post> {
+    vec![Post {
+        num: 0,
+        title: "Hello".into(),
+    }]
+}
+
+async fn fetch(id: GlobalId) -> FieldResult<Option<relay::Node<AnyNode>>> {
+    let n = id.id.parse::<usize>()?;
+    Ok(match id.type_name.as_str() {
+        "User" => users().get(n).cloned().map(AnyNode::User),
+        "Post" => posts().get(n).cloned().map(AnyNode::Post),
+        _ => None,
+    }
+    .map(relay::Node))
+}
+
+struct Query;
+
+#[graphql_object]
+impl Query {
+    fn users(
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> Result<Connection<User>, ConnectionError> {
+        Connection::paginate(users(), &ConnectionArgs::new(first, after, last, before))
+    }
+
+    fn posts() -> Connection<Post> {
+        let posts = posts();
+        let total = posts.len();
+        Connection::from_range(posts, 0..total, total)
+    }
+
+    async fn node(id: ID) -> FieldResult<Option<relay::Node<AnyNode>>> {
+        relay::fetch_node(&id, fetch).await
+    }
+
+    async fn nodes(ids: Vec<ID>) -> FieldResult<Vec<Option<relay::Node<AnyNode>>>> {
+        relay::fetch_nodes(&ids, fetch).await
+    }
+}
+
+fn schema() -> RootNode<Query, EmptyMutation, EmptySubscription> {
+    RootNode::new(Query, EmptyMutation::new(), EmptySubscription::new())
+}
+
+#[tokio::test]
+async fn paginates_forward() {
+    const DOC: &str = r#"query($after: String) {
+        users(first: 2, after: $after) {
+            edges { node { name } }
+            pageInfo { hasPreviousPage hasNextPage endCursor }
+        }
+    }"#;
+
+    let schema = schema();
+
+    let (res, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &())
+        .await
+        .unwrap();
+
+    assert_eq!(errs, []);
+    let end_cursor = relay::offset_to_cursor(1);
+    assert_eq!(
+        res,
+        graphql_value!({"users": {
+            "edges": [{"node": {"name": "Alice"}}, {"node": {"name": "Bob"}}],
+            "pageInfo": {
+                "hasPreviousPage": false,
+                "hasNextPage": true,
+                "endCursor": (end_cursor.as_str()),
+            },
+        }}),
+    );
+
+    assert_eq!(
+        execute(
+            DOC,
+            None,
+            &schema,
+            &graphql_vars! {"after": (end_cursor)},
+            &(),
+        )
+        .await,
+        Ok((
+            graphql_value!({"users": {
+                "edges": [{"node": {"name": "Carol"}}, {"node": {"name": "Dave"}}],
+                "pageInfo": {
+                    "hasPreviousPage": true,
+                    "hasNextPage": false,
+                    "endCursor": (relay::offset_to_cursor(3).as_str()),
+                },
+            }}),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn paginates_backward() {
+    const DOC: &str = r#"{
+        users(last: 1) {
+            edges { cursor node { name } }
+            pageInfo { hasPreviousPage hasNextPage }
+        }
+    }"#;
+
+    let schema = schema();
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Ok((
+            graphql_value!({"users": {
+                "edges": [{
+                    "cursor": (relay::offset_to_cursor(3).as_str()),
+                    "node": {"name": "Dave"},
+                }],
+                "pageInfo": {"hasPreviousPage": true, "hasNextPage": false},
+            }}),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn errors_on_invalid_args() {
+    const DOC: &str = r#"{
+        users(first: -1) {
+            edges { cursor }
+        }
+    }"#;
+
+    let schema = schema();
+
+    let (res, errs) = execute(DOC, None, &schema, &graphql_vars! {}, &())
+        .await
+        .unwrap();
+
+    assert_eq!(res, graphql_value!(null));
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].error().message(),
+        "`first` argument must be a non-negative integer",
+    );
+    assert_eq!(
+        errs[0].error().extensions(),
+        &graphql_value!({"code": "INVALID_PAGINATION"}),
+    );
+}
+
+#[tokio::test]
+async fn names_types_after_nodes() {
+    const DOC: &str = r#"{
+        users(first: 0) { __typename edges { __typename } }
+        posts { __typename edges { __typename } }
+    }"#;
+
+    let schema = schema();
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Ok((
+            graphql_value!({
+                "users": {"__typename": "UserConnection", "edges": []},
+                "posts": {
+                    "__typename": "PostConnection",
+                    "edges": [{"__typename": "PostEdge"}],
+                },
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn resolves_nodes() {
+    const DOC: &str = r#"query($id: ID!, $ids: [ID!]!) {
+        node(id: $id) {
+            __typename
+            ... on User { name }
+        }
+        nodes(ids: $ids) {
+            id
+            ... on Post { title }
+        }
+    }"#;
+
+    let schema = schema();
+    let post_id = GlobalId::new("Post", "0").to_id();
+
+    assert_eq!(
+        execute(
+            DOC,
+            None,
+            &schema,
+            &graphql_vars! {
+                "id": (GlobalId::new("User", "1").to_id().to_string()),
+                "ids": [
+                    (post_id.to_string()),
+                    (GlobalId::new("Post", "7").to_id().to_string()),
+                    "malformed",
+                ],
+            },
+            &(),
+        )
+        .await,
+        Ok((
+            graphql_value!({
+                "node": {"__typename": "User", "name": "Bob"},
+                "nodes": [
+                    {"id": (post_id.to_string()), "title": "Hello"},
+                    null,
+                    null,
+                ],
+            }),
+            vec![],
+        )),
+    );
+}
+
+#[tokio::test]
+async fn exposes_node_interface() {
+    const DOC: &str = r#"{
+        __type(name: "Node") {
+            kind
+            fields { name type { kind ofType { name } } }
+            possibleTypes { name }
+        }
+    }"#;
+
+    let schema = schema();
+
+    assert_eq!(
+        execute(DOC, None, &schema, &graphql_vars! {}, &()).await,
+        Ok((
+            graphql_value!({"__type": {
+                "kind": "INTERFACE",
+                "fields": [{
+                    "name": "id",
+                    "type": {"kind": "NON_NULL", "ofType": {"name": "ID"}},
+                }],
+                "possibleTypes": [{"name": "Post"}, {"name": "User"}],
+            }}),
+            vec![],
+        )),
+    );
+
+    let (res, errs) = execute(
+        "{ __schema { types { name } } }",
+        None,
+        &schema,
+        &graphql_vars! {},
+        &(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(errs, []);
+    let types = res
+        .as_object_value()
+        .and_then(|o| o.get_field_value("__schema"))
+        .and_then(|s| s.as_object_value())
+        .and_then(|s| s.get_field_value("types"))
+        .and_then(|t| t.as_list_value())
+        .unwrap();
+    assert!(
+        !types.contains(&graphql_value!({"name": "AnyNode"})),
+        "union of nodes should not be exposed in schema",
+    );
+}
+
+#[test]
+fn resolves_sync() {
+    const DOC: &str = r#"{
+        posts {
+            edges { node { title } }
+            pageInfo { hasNextPage }
+        }
+    }"#;
+
+    let schema = schema();
+
+    assert_eq!(
+        juniper::execute_sync(DOC, None, &schema, &graphql_vars! {}, &()),
+        Ok((
+            graphql_value!({"posts": {
+                "edges": [{"node": {"title": "Hello"}}],
+                "pageInfo": {"hasNextPage": false},
+            }}),
+            vec![],
+        )),
+    );
+}